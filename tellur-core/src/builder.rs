@@ -25,7 +25,7 @@ use std::hash::Hash;
 use crate::geometry::{Anchor, Transform, Vec2};
 use crate::layout::{raster::Flexible as RasterFlexible, Flexible};
 use crate::placement::{raster::Positioned as RasterPositioned, Positioned, SnapTarget};
-use crate::raster::{Opacity, RasterComponent, RasterTransform};
+use crate::raster::{Opacity, RasterComponent, RasterTransform, RasterTransformed};
 use crate::vector::{Transformed, VectorComponent, VectorTransform};
 
 /// Implemented (by the component macro) for a *complete* builder of a
@@ -118,6 +118,14 @@ pub trait RasterBuilderTransform: RasterBuilder {
     fn opacity(self, opacity: f32) -> Opacity {
         self.build_component().opacity(opacity)
     }
+
+    fn transform(self, transform: Transform) -> RasterTransformed {
+        self.build_component().transform(transform)
+    }
+
+    fn transform_around(self, anchor: Anchor, transform: Transform) -> RasterTransformed {
+        self.build_component().transform_around(anchor, transform)
+    }
 }
 
 impl<B: RasterBuilder> RasterBuilderTransform for B {}
@@ -0,0 +1,347 @@
+//! Chroma keying shared by the renderer's `ChromaKey` effect, the timeline's
+//! [`Keyed`](crate::timeline_component::Keyed) wrapper and GPU backends.
+//!
+//! Pixels are compared with the key color by their Rec. 709 chroma (Cb, Cr)
+//! alone, ignoring luma, so a screen's falloff into shadow stays within the
+//! tolerance far longer than it would under an RGB distance.
+//! [`ChromaKeySettings::apply_pixels`] is the reference the GPU pass is kept
+//! in lockstep with.
+
+use crate::color::Color;
+use crate::grade::LUMA_WEIGHTS;
+use crate::raster::{PixelFormat, RasterImage, RasterResidency};
+use crate::render_context::{ChromaKeyInput, RenderContext};
+use crate::Keyable;
+
+/// Rec. 709 scale factors from `B - Y` and `R - Y` to Cb and Cr.
+const CB_SCALE: f32 = 1.8556;
+const CR_SCALE: f32 = 1.5748;
+
+/// How a chroma key removes a backdrop color.
+///
+/// Chroma distances are measured in the Cb/Cr plane, where fully saturated
+/// primaries sit roughly `0.5` from gray.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct ChromaKeySettings {
+    /// The backdrop color to remove.
+    pub key_color: Color,
+    /// Chroma distance from the key within which pixels become fully
+    /// transparent.
+    pub tolerance: f32,
+    /// Chroma distance beyond `tolerance` over which alpha ramps back up to
+    /// opaque; `0` gives a hard key.
+    pub softness: f32,
+    /// How much of the key's hue is removed from the pixels that remain, from
+    /// `0` (none) to `1` (all), so the backdrop's reflected light does not
+    /// tint hair and edges.
+    pub spill: f32,
+    /// How far the opaque region's edge is pulled inward, in logical units,
+    /// to cut away the fringe left around the subject.
+    pub choke: f32,
+}
+
+impl Default for ChromaKeySettings {
+    fn default() -> Self {
+        Self {
+            key_color: Color::rgb_u8(0, 255, 0),
+            tolerance: 0.1,
+            softness: 0.1,
+            spill: 1.0,
+            choke: 0.0,
+        }
+    }
+}
+
+impl ChromaKeySettings {
+    /// The key color's Cb and Cr.
+    pub fn key_chroma(&self) -> [f32; 2] {
+        chroma([self.key_color.r, self.key_color.g, self.key_color.b])
+    }
+
+    /// How much of a straight-alpha sRGB color survives the key, from `0`
+    /// (keyed out) to `1` (kept).
+    pub fn key_alpha(&self, rgb: [f32; 3]) -> f32 {
+        let [cb, cr] = chroma(rgb);
+        let [key_cb, key_cr] = self.key_chroma();
+        let distance = ((cb - key_cb).powi(2) + (cr - key_cr).powi(2)).sqrt();
+        let over = distance - self.tolerance;
+        if self.softness <= 0.0 {
+            if over > 0.0 {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            (over / self.softness).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Removes `spill` of the color's chroma along the key's hue, keeping its
+    /// luma. Colors leaning away from the key are unchanged.
+    pub fn suppress_spill(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let [key_cb, key_cr] = self.key_chroma();
+        let length = (key_cb * key_cb + key_cr * key_cr).sqrt();
+        let spill = self.spill.clamp(0.0, 1.0);
+        if length <= f32::EPSILON || spill <= 0.0 {
+            return rgb;
+        }
+        let dir = [key_cb / length, key_cr / length];
+        let y = luma(rgb);
+        let [cb, cr] = chroma(rgb);
+        let along = cb * dir[0] + cr * dir[1];
+        if along <= 0.0 {
+            return rgb;
+        }
+        let cb = cb - spill * along * dir[0];
+        let cr = cr - spill * along * dir[1];
+        let r = y + CR_SCALE * cr;
+        let b = y + CB_SCALE * cb;
+        let g = (y - LUMA_WEIGHTS[0] * r - LUMA_WEIGHTS[2] * b) / LUMA_WEIGHTS[1];
+        [r, g, b].map(|v| v.clamp(0.0, 1.0))
+    }
+
+    /// [`choke`](Self::choke) in pixels at `scale` pixels per logical unit.
+    pub fn choke_radius(&self, scale: f32) -> u32 {
+        let radius = self.choke * scale;
+        if radius.is_finite() {
+            radius.round().max(0.0) as u32
+        } else {
+            0
+        }
+    }
+
+    /// Keys `image`, on the GPU when the context has a backend for it and on
+    /// the CPU otherwise. `scale` converts the choke to pixels.
+    pub fn apply(
+        &self,
+        image: RasterImage,
+        scale: f32,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let choke_radius = self.choke_radius(scale);
+        if ctx.prefers_gpu() {
+            if let Some(gpu) = ctx.gpu_backend() {
+                let input = ChromaKeyInput {
+                    image: &image,
+                    settings: *self,
+                    choke_radius,
+                };
+                if let Some(keyed) = gpu.chroma_key(input) {
+                    return ctx.ensure_residency(keyed, residency);
+                }
+            }
+        }
+
+        let mut image = ctx.readback(image);
+        assert_eq!(
+            image.format,
+            PixelFormat::Rgba8,
+            "chroma keying only supports straight-alpha Rgba8 images",
+        );
+        let mut pixels = image.pixels.to_vec();
+        self.apply_pixels(&mut pixels, image.width, choke_radius);
+        image.pixels = pixels.into();
+        ctx.ensure_residency(RasterImage::Cpu(image), residency)
+    }
+
+    /// Keys a straight-alpha `Rgba8` image `width` pixels wide in place.
+    ///
+    /// Each pixel's alpha is multiplied by the smallest [`key_alpha`] within
+    /// `choke_radius` pixels (a square, clipped to the image), and its color
+    /// has the key's spill suppressed. The square is a row pass then a column
+    /// pass of running minimums, so the choke costs the same at any radius.
+    ///
+    /// [`key_alpha`]: Self::key_alpha
+    pub fn apply_pixels(&self, pixels: &mut [u8], width: u32, choke_radius: u32) {
+        let width = width as usize;
+        if width == 0 {
+            return;
+        }
+        let height = pixels.len() / 4 / width;
+        let rgb_at =
+            |px: &[u8]| -> [f32; 3] { [px[0], px[1], px[2]].map(|v| f32::from(v) / 255.0) };
+        let mut keys: Vec<f32> = pixels
+            .chunks_exact(4)
+            .map(|px| self.key_alpha(rgb_at(px)))
+            .collect();
+        if choke_radius > 0 {
+            let r = choke_radius as usize;
+            let mut line = Vec::new();
+            for row in keys.chunks_exact_mut(width) {
+                window_min(row, r, &mut line);
+                row.copy_from_slice(&line);
+            }
+            let mut column = Vec::with_capacity(height);
+            for x in 0..width {
+                column.clear();
+                column.extend((0..height).map(|y| keys[y * width + x]));
+                window_min(&column, r, &mut line);
+                for (y, key) in line.iter().enumerate() {
+                    keys[y * width + x] = *key;
+                }
+            }
+        }
+        for (px, key) in pixels.chunks_exact_mut(4).zip(keys) {
+            let rgb = self.suppress_spill(rgb_at(px));
+            for i in 0..3 {
+                px[i] = (rgb[i] * 255.0).round() as u8;
+            }
+            px[3] = (f32::from(px[3]) * key).round() as u8;
+        }
+    }
+}
+
+/// Fills `out` with the minimum of `values` within `radius` of each index,
+/// clipped to the ends.
+///
+/// This is the van Herk/Gil-Werman filter: over `values` padded with
+/// `radius` infinities each side and cut into blocks one window long, every
+/// window spans at most two blocks, so its minimum is the first block's
+/// suffix minimum against the second's prefix minimum. That is about three
+/// comparisons per value whatever the radius.
+fn window_min(values: &[f32], radius: usize, out: &mut Vec<f32>) {
+    let window = 2 * radius + 1;
+    let padded: Vec<f32> = std::iter::repeat_n(f32::INFINITY, radius)
+        .chain(values.iter().copied())
+        .chain(std::iter::repeat_n(f32::INFINITY, radius))
+        .collect();
+    let mut prefix = padded.clone();
+    let mut suffix = padded;
+    for block in prefix.chunks_mut(window) {
+        for i in 1..block.len() {
+            block[i] = block[i].min(block[i - 1]);
+        }
+    }
+    for block in suffix.chunks_mut(window) {
+        for i in (0..block.len() - 1).rev() {
+            block[i] = block[i].min(block[i + 1]);
+        }
+    }
+    out.clear();
+    out.extend((0..values.len()).map(|i| suffix[i].min(prefix[i + window - 1])));
+}
+
+fn luma(rgb: [f32; 3]) -> f32 {
+    rgb[0] * LUMA_WEIGHTS[0] + rgb[1] * LUMA_WEIGHTS[1] + rgb[2] * LUMA_WEIGHTS[2]
+}
+
+/// Rec. 709 Cb and Cr of an sRGB color, each in about `[-0.5, 0.5]`.
+fn chroma(rgb: [f32; 3]) -> [f32; 2] {
+    let y = luma(rgb);
+    [(rgb[2] - y) / CB_SCALE, (rgb[0] - y) / CR_SCALE]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GREEN: [u8; 4] = [20, 200, 40, 255];
+    const SKIN: [u8; 4] = [224, 172, 140, 255];
+
+    fn screen() -> ChromaKeySettings {
+        ChromaKeySettings {
+            key_color: Color::rgb_u8(GREEN[0], GREEN[1], GREEN[2]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn backdrop_is_keyed_out_and_subject_kept() {
+        let mut pixels = [GREEN, SKIN].concat();
+        screen().apply_pixels(&mut pixels, 2, 0);
+        assert_eq!(pixels[3], 0);
+        assert_eq!(&pixels[4..], &SKIN);
+    }
+
+    #[test]
+    fn shadowed_backdrop_keys_like_the_lit_one() {
+        // The same screen at half the brightness has half the chroma
+        // distance to gray but stays close to the key's hue.
+        let settings = ChromaKeySettings {
+            tolerance: 0.2,
+            ..screen()
+        };
+        assert_eq!(settings.key_alpha([10.0 / 255.0, 0.4, 20.0 / 255.0]), 0.0);
+    }
+
+    #[test]
+    fn softness_ramps_alpha_between_tolerance_and_tolerance_plus_softness() {
+        let settings = screen();
+        let [key_cb, key_cr] = settings.key_chroma();
+        let distance = (key_cb * key_cb + key_cr * key_cr).sqrt();
+        let hard = ChromaKeySettings {
+            tolerance: distance / 2.0,
+            softness: 0.0,
+            ..settings
+        };
+        let soft = ChromaKeySettings {
+            tolerance: distance - 0.05,
+            softness: 0.1,
+            ..settings
+        };
+        let gray = [0.5, 0.5, 0.5];
+        assert_eq!(hard.key_alpha(gray), 1.0);
+        assert!((soft.key_alpha(gray) - 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn spill_suppression_neutralizes_green_fringes_and_keeps_luma() {
+        let settings = screen();
+        let fringe = [0.5, 0.7, 0.5];
+        let cleaned = settings.suppress_spill(fringe);
+        assert!(cleaned[1] < fringe[1], "{cleaned:?}");
+        assert!((luma(cleaned) - luma(fringe)).abs() < 1e-4);
+        // Magenta leans away from green and is left alone.
+        assert_eq!(settings.suppress_spill([0.8, 0.2, 0.8]), [0.8, 0.2, 0.8]);
+    }
+
+    #[test]
+    fn choke_takes_the_smallest_key_over_a_square() {
+        // One keyed pixel in a 7×5 subject; a choke of 1 clears the 3×3
+        // square around it, corners included, and a choke of 9 everything.
+        let mut pixels = SKIN.repeat(35);
+        pixels[(2 * 7 + 3) * 4..][..4].copy_from_slice(&GREEN);
+        let alpha = |pixels: &[u8]| pixels.chunks_exact(4).map(|px| px[3]).collect::<Vec<_>>();
+        let mut choked = pixels.clone();
+        screen().apply_pixels(&mut choked, 7, 1);
+        let cleared: Vec<_> = (0..35)
+            .filter(|i| alpha(&choked)[*i] == 0)
+            .map(|i| (i % 7, i / 7))
+            .collect();
+        let square: Vec<_> = (1..4).flat_map(|y| (2..5).map(move |x| (x, y))).collect();
+        assert_eq!(cleared, square);
+        screen().apply_pixels(&mut pixels, 7, 9);
+        assert!(alpha(&pixels).iter().all(|a| *a == 0));
+    }
+
+    #[test]
+    fn window_min_matches_a_direct_scan() {
+        let values: Vec<f32> = (0..23).map(|i| ((i * 7919) % 31) as f32).collect();
+        let mut out = Vec::new();
+        for radius in 0..30 {
+            window_min(&values, radius, &mut out);
+            for (i, min) in out.iter().enumerate() {
+                let lo = i.saturating_sub(radius);
+                let hi = (i + radius).min(values.len() - 1);
+                let direct = values[lo..=hi]
+                    .iter()
+                    .copied()
+                    .fold(f32::INFINITY, f32::min);
+                assert_eq!(*min, direct, "radius {radius} at {i}");
+            }
+        }
+    }
+
+    #[test]
+    fn choke_pulls_the_opaque_edge_inward() {
+        let row = [GREEN, SKIN, SKIN, SKIN, GREEN].concat();
+        let mut unchoked = row.clone();
+        screen().apply_pixels(&mut unchoked, 5, 0);
+        let mut choked = row;
+        screen().apply_pixels(&mut choked, 5, 1);
+        let alpha = |pixels: &[u8]| pixels.chunks_exact(4).map(|px| px[3]).collect::<Vec<_>>();
+        assert_eq!(alpha(&unchoked), vec![0, 255, 255, 255, 0]);
+        assert_eq!(alpha(&choked), vec![0, 0, 255, 0, 0]);
+    }
+}
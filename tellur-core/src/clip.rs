@@ -4,22 +4,30 @@
 //! but wraps the rendered output in a [`Node::ClipGroup`], so anything the
 //! child paints outside the region is cut away rather than merely excluded
 //! from `paint_bounds`.
+//!
+//! The edge is always hard. For a feathered, expanded or contracted edge,
+//! the renderer's raster `Mask` takes the same [`ClipRegion`]; regions
+//! interpolate, so either can animate a wipe.
 
 use crate::geometry::{Constraints, Rect, Transform, Vec2};
-use crate::vector::{ClipGroup, Node, PathCommand, VectorComponent, VectorGraphic};
+use crate::vector::{ClipGroup, FillRule, Node, PathCommand, VectorComponent, VectorGraphic};
 use crate::Keyable;
 
 /// The region a [`Clip`] restricts its child to: an axis-aligned rectangle,
-/// or an arbitrary path (filled with the nonzero winding rule, the same as
-/// any other filled path).
+/// or an arbitrary path filled with its [`FillRule`] (nonzero by default, the
+/// same as any other filled path).
 ///
-/// Construct with [`ClipRegion::rect`] / [`ClipRegion::path`], or pass a bare
-/// [`Rect`] to `Clip::builder().region(...)` — the common rectangular case
-/// converts automatically.
+/// Construct with [`ClipRegion::rect`] / [`ClipRegion::path`] /
+/// [`ClipRegion::path_with_fill_rule`], or pass a bare [`Rect`] to
+/// `Clip::builder().region(...)` — the common rectangular case converts
+/// automatically.
 #[derive(Debug, Clone, Keyable)]
 pub enum ClipRegion {
     Rect(Rect),
-    Path(Vec<PathCommand>),
+    Path {
+        commands: Vec<PathCommand>,
+        fill_rule: FillRule,
+    },
 }
 
 impl ClipRegion {
@@ -28,15 +36,25 @@ impl ClipRegion {
     }
 
     pub fn path(commands: impl Into<Vec<PathCommand>>) -> Self {
-        Self::Path(commands.into())
+        Self::path_with_fill_rule(commands, FillRule::NonZero)
+    }
+
+    /// A path region whose self-intersecting or nested contours are resolved
+    /// with `fill_rule` — e.g. [`FillRule::EvenOdd`] to punch holes with
+    /// contours drawn in either direction.
+    pub fn path_with_fill_rule(commands: impl Into<Vec<PathCommand>>, fill_rule: FillRule) -> Self {
+        Self::Path {
+            commands: commands.into(),
+            fill_rule,
+        }
     }
 
     /// The region's own bounding box, in the same local coordinate space the
     /// clipped child paints in.
-    fn bounds(&self) -> Rect {
+    pub fn bounds(&self) -> Rect {
         match self {
             Self::Rect(rect) => *rect,
-            Self::Path(commands) => path_command_bounds(commands).unwrap_or(Rect {
+            Self::Path { commands, .. } => path_command_bounds(commands).unwrap_or(Rect {
                 origin: Vec2::ZERO,
                 size: Vec2::ZERO,
             }),
@@ -44,10 +62,19 @@ impl ClipRegion {
     }
 
     /// The region expressed as path commands, ready for [`ClipGroup::commands`].
-    fn to_commands(&self) -> Vec<PathCommand> {
+    pub fn to_commands(&self) -> Vec<PathCommand> {
         match self {
             Self::Rect(rect) => rect_path_commands(*rect),
-            Self::Path(commands) => commands.clone(),
+            Self::Path { commands, .. } => commands.clone(),
+        }
+    }
+
+    /// The rule deciding which points the region covers; always
+    /// [`FillRule::NonZero`] for a rect.
+    pub fn fill_rule(&self) -> FillRule {
+        match self {
+            Self::Rect(_) => FillRule::NonZero,
+            Self::Path { fill_rule, .. } => *fill_rule,
         }
     }
 }
@@ -88,6 +115,7 @@ impl VectorComponent for Clip {
             view_box: self.paint_bounds(size),
             root: Node::ClipGroup(ClipGroup {
                 commands: self.region.to_commands(),
+                fill_rule: self.region.fill_rule(),
                 transform: Transform::IDENTITY,
                 child: Box::new(inner.root),
             }),
@@ -113,7 +141,7 @@ fn rect_path_commands(rect: Rect) -> Vec<PathCommand> {
 /// conservative superset (a Bezier segment always lies within the convex hull
 /// of its control points), which is exactly what an intersection-based
 /// `paint_bounds` needs. `None` for an empty command list.
-fn path_command_bounds(commands: &[PathCommand]) -> Option<Rect> {
+pub(crate) fn path_command_bounds(commands: &[PathCommand]) -> Option<Rect> {
     let mut min = Vec2(f32::INFINITY, f32::INFINITY);
     let mut max = Vec2(f32::NEG_INFINITY, f32::NEG_INFINITY);
     let mut found = false;
@@ -161,12 +189,13 @@ mod tests {
     use super::*;
     use crate::color::Color;
     use crate::placement::VectorPlacement;
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
     use crate::vector::{Node, Paint};
 
     fn rect(w: f32, h: f32) -> Rectangle {
         Rectangle {
             size: Vec2(w, h),
+            corners: Corners::SQUARE,
             fill: Paint::Solid(Color::rgb_u8(10, 20, 30)).into(),
             stroke: None,
         }
@@ -208,6 +237,27 @@ mod tests {
             ]
         );
         assert!(matches!(*group.child, Node::Path(_)));
+        assert_eq!(group.fill_rule, FillRule::NonZero);
+    }
+
+    #[test]
+    fn path_region_forwards_its_fill_rule() {
+        let clip = Clip::builder()
+            .region(ClipRegion::path_with_fill_rule(
+                vec![
+                    PathCommand::MoveTo(Vec2(0.0, 0.0)),
+                    PathCommand::LineTo(Vec2(10.0, 0.0)),
+                    PathCommand::LineTo(Vec2(5.0, 10.0)),
+                    PathCommand::Close,
+                ],
+                FillRule::EvenOdd,
+            ))
+            .child(rect(10.0, 10.0))
+            .build();
+        let Node::ClipGroup(group) = clip.render(Vec2(10.0, 10.0)).root else {
+            panic!("Clip should render a ClipGroup");
+        };
+        assert_eq!(group.fill_rule, FillRule::EvenOdd);
     }
 
     #[test]
@@ -0,0 +1,489 @@
+//! UV-remap distortions shared by the renderer's `Distort` and `Displace`
+//! effects and GPU backends.
+//!
+//! Every distortion is a map from an output point back to the point of the
+//! source it shows, measured in the child's logical layout space so the
+//! look does not change with the render resolution. The procedural warps
+//! ([`Ripple`], [`Wave`], [`Twirl`], [`Bulge`]) compute that map from a few
+//! parameters; [`displace_pixels`] reads it from another image's luminance.
+//! Sampling goes through the same premultiplied filters as
+//! [`resample_pixels`](crate::resample::resample_pixels), so points pulled
+//! from outside the source read as transparent.
+//!
+//! Each parameter set implements [`Interpolate`](crate::interpolate::Interpolate),
+//! and the travelling warps carry a cycle [`Phase`], so a warp animates by
+//! lerping two settings or by driving `phase` from a window.
+
+use std::f32::consts::TAU;
+
+use crate::geometry::{Anchor, Rect, Vec2};
+use crate::grade::LUMA_WEIGHTS;
+use crate::layer::union_rect;
+use crate::phase::Phase;
+use crate::raster::{CpuRasterImage, PixelFormat, Resolution};
+use crate::resample::sample;
+use crate::vector::ImageQuality;
+use crate::Keyable;
+
+/// Concentric waves pushing the source in and out from `center`, like a
+/// stone dropped in water.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct Ripple {
+    /// Where the rings start, on the child's layout box.
+    pub center: Anchor,
+    /// How far a crest displaces the source (logical units).
+    pub amplitude: f32,
+    /// Distance between crests (logical units); non-positive values leave
+    /// the source unchanged.
+    pub wavelength: f32,
+    /// Position in the wave's cycle. Driving it from `0` to `1` moves every
+    /// ring outward by one `wavelength`.
+    pub phase: Phase,
+}
+
+impl Default for Ripple {
+    fn default() -> Self {
+        Self {
+            center: Anchor::CENTER,
+            amplitude: 4.0,
+            wavelength: 32.0,
+            phase: Phase::ZERO,
+        }
+    }
+}
+
+/// Parallel waves travelling across the whole layer, for flags and heat
+/// haze.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct Wave {
+    /// How far a crest displaces the source, across the direction of travel
+    /// (logical units).
+    pub amplitude: f32,
+    /// Distance between crests (logical units); non-positive values leave
+    /// the source unchanged.
+    pub wavelength: f32,
+    /// Direction of travel in radians; `0` runs along +x, so the source is
+    /// displaced vertically.
+    pub angle: f32,
+    /// Position in the wave's cycle. Driving it from `0` to `1` moves the
+    /// waves forward by one `wavelength`.
+    pub phase: Phase,
+}
+
+impl Default for Wave {
+    fn default() -> Self {
+        Self {
+            amplitude: 4.0,
+            wavelength: 64.0,
+            angle: 0.0,
+            phase: Phase::ZERO,
+        }
+    }
+}
+
+/// A whirlpool: the source turns about `center`, by `angle` at the center and
+/// easing out to nothing at `radius`.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct Twirl {
+    /// The whirlpool's center, on the child's layout box.
+    pub center: Anchor,
+    /// Extent of the twirl (logical units).
+    pub radius: f32,
+    /// Turn at the center in radians, in the direction of
+    /// [`Transform::rotate`](crate::geometry::Transform::rotate).
+    pub angle: f32,
+}
+
+impl Default for Twirl {
+    fn default() -> Self {
+        Self {
+            center: Anchor::CENTER,
+            radius: 100.0,
+            angle: std::f32::consts::FRAC_PI_2,
+        }
+    }
+}
+
+/// A lens swelling (or pinching) the source within `radius` of `center`.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct Bulge {
+    /// The lens's center, on the child's layout box.
+    pub center: Anchor,
+    /// Extent of the lens (logical units).
+    pub radius: f32,
+    /// Magnification from `-1` (pinched to a point) through `0` (none) to `1`
+    /// (the center blown up without limit).
+    pub strength: f32,
+}
+
+impl Default for Bulge {
+    fn default() -> Self {
+        Self {
+            center: Anchor::CENTER,
+            radius: 100.0,
+            strength: 0.5,
+        }
+    }
+}
+
+/// One of the procedural warps.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub enum Distortion {
+    Ripple(Ripple),
+    Wave(Wave),
+    Twirl(Twirl),
+    Bulge(Bulge),
+}
+
+impl From<Ripple> for Distortion {
+    fn from(ripple: Ripple) -> Self {
+        Distortion::Ripple(ripple)
+    }
+}
+
+impl From<Wave> for Distortion {
+    fn from(wave: Wave) -> Self {
+        Distortion::Wave(wave)
+    }
+}
+
+impl From<Twirl> for Distortion {
+    fn from(twirl: Twirl) -> Self {
+        Distortion::Twirl(twirl)
+    }
+}
+
+impl From<Bulge> for Distortion {
+    fn from(bulge: Bulge) -> Self {
+        Distortion::Bulge(bulge)
+    }
+}
+
+impl Distortion {
+    /// The bounds content within `paint` can be moved into: `paint` grown by
+    /// a ripple's or wave's amplitude, or joined with the disk a twirl turns
+    /// or a bulge magnifies.
+    pub fn paint_bounds(&self, paint: Rect, size: Vec2) -> Rect {
+        let disk = |center: Anchor, radius: f32| {
+            let c = center.point(size);
+            let radius = radius.max(0.0);
+            Rect {
+                origin: Vec2(c.0 - radius, c.1 - radius),
+                size: Vec2(2.0 * radius, 2.0 * radius),
+            }
+        };
+        match *self {
+            Distortion::Ripple(Ripple { amplitude, .. })
+            | Distortion::Wave(Wave { amplitude, .. }) => {
+                let reach = amplitude.abs();
+                Rect {
+                    origin: Vec2(paint.origin.0 - reach, paint.origin.1 - reach),
+                    size: Vec2(paint.size.0 + 2.0 * reach, paint.size.1 + 2.0 * reach),
+                }
+            }
+            Distortion::Twirl(twirl) if twirl.angle != 0.0 => {
+                union_rect(paint, disk(twirl.center, twirl.radius))
+            }
+            // Pinching only pulls content inward.
+            Distortion::Bulge(bulge) if bulge.strength > 0.0 => {
+                union_rect(paint, disk(bulge.center, bulge.radius))
+            }
+            Distortion::Twirl(_) | Distortion::Bulge(_) => paint,
+        }
+    }
+
+    /// The source point shown at `at`, both in the layout space of a child
+    /// of `size`.
+    pub fn source_point(&self, at: Vec2, size: Vec2) -> Vec2 {
+        match *self {
+            Distortion::Ripple(ripple) => {
+                let c = ripple.center.point(size);
+                let d = at - c;
+                let r = d.0.hypot(d.1);
+                if ripple.wavelength <= 0.0 || r <= f32::EPSILON {
+                    return at;
+                }
+                let push =
+                    ripple.amplitude * (TAU * (r / ripple.wavelength - ripple.phase.get())).sin();
+                Vec2(at.0 + d.0 / r * push, at.1 + d.1 / r * push)
+            }
+            Distortion::Wave(wave) => {
+                if wave.wavelength <= 0.0 {
+                    return at;
+                }
+                let (sin, cos) = wave.angle.sin_cos();
+                let along = at.0 * cos + at.1 * sin;
+                let push =
+                    wave.amplitude * (TAU * (along / wave.wavelength - wave.phase.get())).sin();
+                Vec2(at.0 - sin * push, at.1 + cos * push)
+            }
+            Distortion::Twirl(twirl) => {
+                let c = twirl.center.point(size);
+                let d = at - c;
+                let r = d.0.hypot(d.1);
+                if r >= twirl.radius {
+                    return at;
+                }
+                let t = 1.0 - r / twirl.radius;
+                // Turning the lookup backwards turns the content forwards.
+                let (sin, cos) = (twirl.angle * t * t).sin_cos();
+                Vec2(c.0 + cos * d.0 + sin * d.1, c.1 - sin * d.0 + cos * d.1)
+            }
+            Distortion::Bulge(bulge) => {
+                let c = bulge.center.point(size);
+                let d = at - c;
+                let r = d.0.hypot(d.1);
+                if r >= bulge.radius {
+                    return at;
+                }
+                let falloff = 1.0 - r / bulge.radius;
+                let k = 1.0 - bulge.strength * falloff * falloff;
+                Vec2(c.0 + d.0 * k, c.1 + d.1 * k)
+            }
+        }
+    }
+}
+
+/// Where a warp writes: a `target`-sized image covering `paint`, with the
+/// source's top-left pixel `offset` pixels into it at the same density.
+/// Content a warp moves past the source lands in the margin between them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WarpPlacement {
+    pub paint: Rect,
+    pub target: Resolution,
+    pub offset: [i32; 2],
+}
+
+/// Warps a straight-alpha `Rgba8` image by `distortion` into `placement`,
+/// in the layout space of a child of `size`.
+pub fn distort_pixels(
+    source: &CpuRasterImage,
+    placement: WarpPlacement,
+    size: Vec2,
+    distortion: &Distortion,
+    quality: ImageQuality,
+) -> Vec<u8> {
+    remap(source, placement, quality, |_, _, at| {
+        distortion.source_point(at, size)
+    })
+}
+
+/// How far a displacement-map pixel pushes, from `-1` (black) through `0`
+/// (mid gray) to `1` (white). Transparency fades toward no displacement, so
+/// a map only acts where it is drawn.
+pub fn displacement(px: [u8; 4]) -> f32 {
+    let [r, g, b, a] = px.map(|v| f32::from(v) / 255.0);
+    let luma = r * LUMA_WEIGHTS[0] + g * LUMA_WEIGHTS[1] + b * LUMA_WEIGHTS[2];
+    (luma * 2.0 - 1.0) * a
+}
+
+/// Displaces a straight-alpha `Rgba8` image by `map` into `placement`.
+///
+/// `map`'s top-left pixel sits `map_offset` pixels from the output's, at
+/// the same density; each output pixel shows the source point
+/// [`displacement`] × `amount` logical units away. Pixels off the map are
+/// not displaced.
+pub fn displace_pixels(
+    source: &CpuRasterImage,
+    placement: WarpPlacement,
+    map: &CpuRasterImage,
+    map_offset: [i32; 2],
+    amount: Vec2,
+    quality: ImageQuality,
+) -> Vec<u8> {
+    assert_eq!(
+        map.format,
+        PixelFormat::Rgba8,
+        "displacement maps only support straight-alpha Rgba8 images",
+    );
+    remap(source, placement, quality, |x, y, at| {
+        let (mx, my) = (
+            x as i64 - map_offset[0] as i64,
+            y as i64 - map_offset[1] as i64,
+        );
+        if mx < 0 || my < 0 || mx >= map.width as i64 || my >= map.height as i64 {
+            return at;
+        }
+        let i = (my as usize * map.width as usize + mx as usize) * 4;
+        let push = displacement([
+            map.pixels[i],
+            map.pixels[i + 1],
+            map.pixels[i + 2],
+            map.pixels[i + 3],
+        ]);
+        Vec2(at.0 + push * amount.0, at.1 + push * amount.1)
+    })
+}
+
+/// Fills each pixel of the `placement` image with the source filtered at
+/// `lookup(x, y, center)`, where `center` is the pixel's center in the
+/// logical space `placement.paint` spans.
+fn remap(
+    source: &CpuRasterImage,
+    placement: WarpPlacement,
+    quality: ImageQuality,
+    lookup: impl Fn(u32, u32, Vec2) -> Vec2,
+) -> Vec<u8> {
+    assert_eq!(
+        source.format,
+        PixelFormat::Rgba8,
+        "distortion only supports straight-alpha Rgba8 images",
+    );
+    let WarpPlacement {
+        paint,
+        target,
+        offset,
+    } = placement;
+    let mut out = vec![0u8; target.width as usize * target.height as usize * 4];
+    if paint.size.0 <= 0.0 || paint.size.1 <= 0.0 {
+        return out;
+    }
+    let sx = target.width as f32 / paint.size.0;
+    let sy = target.height as f32 / paint.size.1;
+    for y in 0..target.height {
+        for x in 0..target.width {
+            let at = Vec2(
+                paint.origin.0 + (x as f32 + 0.5) / sx,
+                paint.origin.1 + (y as f32 + 0.5) / sy,
+            );
+            let from = lookup(x, y, at);
+            let u = (from.0 - paint.origin.0) * sx - 0.5 - offset[0] as f32;
+            let v = (from.1 - paint.origin.1) * sy - 0.5 - offset[1] as f32;
+            let px = sample(source, u, v, quality);
+            out[(y as usize * target.width as usize + x as usize) * 4..][..4].copy_from_slice(&px);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: Vec2 = Vec2(100.0, 100.0);
+
+    fn assert_near(a: Vec2, b: Vec2) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-3 && (a.1 - b.1).abs() < 1e-3,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn ripple_pushes_radially_and_travels_with_phase() {
+        let ripple = Ripple {
+            amplitude: 2.0,
+            wavelength: 40.0,
+            ..Default::default()
+        };
+        // A quarter wavelength out is a crest, pushed straight away.
+        let at = Vec2(60.0, 50.0);
+        assert_near(
+            Distortion::from(ripple).source_point(at, SIZE),
+            Vec2(62.0, 50.0),
+        );
+        // Half a cycle later the trough has reached the same point.
+        let later = Ripple {
+            phase: Phase::saturating(0.5),
+            ..ripple
+        };
+        assert_near(
+            Distortion::from(later).source_point(at, SIZE),
+            Vec2(58.0, 50.0),
+        );
+    }
+
+    #[test]
+    fn wave_displaces_across_its_direction_of_travel() {
+        let wave = Distortion::from(Wave {
+            amplitude: 3.0,
+            wavelength: 20.0,
+            ..Default::default()
+        });
+        assert_near(wave.source_point(Vec2(5.0, 7.0), SIZE), Vec2(5.0, 10.0));
+        assert_near(wave.source_point(Vec2(10.0, 7.0), SIZE), Vec2(10.0, 7.0));
+    }
+
+    #[test]
+    fn twirl_and_bulge_leave_points_outside_their_radius_alone() {
+        let twirl = Distortion::from(Twirl {
+            radius: 20.0,
+            angle: std::f32::consts::PI,
+            ..Default::default()
+        });
+        let bulge = Distortion::from(Bulge {
+            radius: 20.0,
+            strength: 0.5,
+            ..Default::default()
+        });
+        for distortion in [twirl, bulge] {
+            assert_near(
+                distortion.source_point(Vec2(80.0, 50.0), SIZE),
+                Vec2(80.0, 50.0),
+            );
+            assert_near(
+                distortion.source_point(Vec2(50.0, 50.0), SIZE),
+                Vec2(50.0, 50.0),
+            );
+        }
+        // Halfway out the twirl turns a quarter of the way, and the bulge
+        // samples closer to the center so the content there is magnified.
+        let at = Vec2(60.0, 50.0);
+        assert_near(
+            twirl.source_point(at, SIZE),
+            Vec2(50.0 + 10.0 * 0.5f32.sqrt(), 50.0 - 10.0 * 0.5f32.sqrt()),
+        );
+        assert_near(bulge.source_point(at, SIZE), Vec2(58.75, 50.0));
+    }
+
+    #[test]
+    fn displacement_map_gray_is_neutral_and_white_pushes_by_amount() {
+        // A row of four pixels: red, green, blue, white.
+        let source = CpuRasterImage::new(
+            4,
+            1,
+            PixelFormat::Rgba8,
+            [
+                [255, 0, 0, 255],
+                [0, 255, 0, 255],
+                [0, 0, 255, 255],
+                [255, 255, 255, 255],
+            ]
+            .concat(),
+        );
+        let map = CpuRasterImage::new(
+            2,
+            1,
+            PixelFormat::Rgba8,
+            [[255, 255, 255, 255], [128, 128, 128, 0]].concat(),
+        );
+        let placement = WarpPlacement {
+            paint: Rect {
+                origin: Vec2(0.0, 0.0),
+                size: Vec2(4.0, 1.0),
+            },
+            target: Resolution::new(4, 1),
+            offset: [0, 0],
+        };
+        let out = displace_pixels(
+            &source,
+            placement,
+            &map,
+            [1, 0],
+            Vec2(1.0, 0.0),
+            ImageQuality::Nearest,
+        );
+        // Pixel 1 is pushed a pixel along; pixel 2's transparent map pixel
+        // and the unmapped pixels 0 and 3 stay put.
+        assert_eq!(
+            out,
+            [
+                [255, 0, 0, 255],
+                [0, 0, 255, 255],
+                [0, 0, 255, 255],
+                [255, 255, 255, 255],
+            ]
+            .concat()
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! Boolean path operations between vector components.
+//!
+//! [`PathBoolean`] flattens the visible ink of each operand into polygons,
+//! combines them with one [`BooleanOp`], and emits the result as a single
+//! filled (and optionally stroked) [`Path`]. It reuses the silhouette
+//! extraction of [`Outlined`](super::Outlined), so fills, strokes, group
+//! transforms and clip groups all contribute exactly as they would paint.
+
+use clipper2::{difference, intersect, union, xor, FillRule};
+
+use super::outline::{
+    clean_tolerance, collect_node_paths, paths_to_commands, ClipperPaths, DEFAULT_TOLERANCE,
+};
+use crate::geometry::{Constraints, Rect, Transform, Vec2};
+use crate::layer::vector_children_bounds;
+use crate::vector::{
+    Fill, FillRule as VectorFillRule, Node, Path, Stroke, VectorComponent, VectorGraphic,
+};
+use crate::Keyable;
+
+/// How [`PathBoolean`] combines its operands. The first operand is the
+/// subject; every later operand is applied to the running result in order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BooleanOp {
+    /// Ink covered by any operand.
+    #[default]
+    Union,
+    /// Ink covered by every operand.
+    Intersection,
+    /// The first operand with every later operand cut away.
+    Difference,
+    /// Ink covered by an odd number of operands.
+    Xor,
+}
+
+/// A vector component that combines the silhouettes of two or more operands
+/// into one path.
+///
+/// Operands are overlaid like a [`Fragment`](crate::fragment::Fragment): each
+/// is laid out loose to the resolved size and paints at its own
+/// [`Positioned`](crate::placement::Positioned) coordinates. Only the shape of
+/// their ink matters — the result is painted with `fill` and `stroke`, never
+/// with the operands' own paints.
+#[crate::component(vector)]
+#[derive(Clone, Keyable)]
+pub struct PathBoolean {
+    // `#[builder(field)]` members must precede setter members.
+    #[children(each = operand)]
+    pub operands: Vec<Box<dyn VectorComponent>>,
+    pub op: BooleanOp,
+    #[builder(into)]
+    pub fill: Option<Fill>,
+    #[builder(into)]
+    pub stroke: Option<Stroke>,
+    /// Flattening tolerance for curved operands, in logical units.
+    #[builder(default = DEFAULT_TOLERANCE)]
+    pub tolerance: f32,
+}
+
+impl PathBoolean {
+    pub fn new(
+        op: BooleanOp,
+        operands: impl IntoIterator<Item = Box<dyn VectorComponent>>,
+        fill: impl Into<Fill>,
+    ) -> Self {
+        Self {
+            operands: operands.into_iter().collect(),
+            op,
+            fill: Some(fill.into()),
+            stroke: None,
+            tolerance: DEFAULT_TOLERANCE,
+        }
+    }
+
+    fn combined_paths(&self, size: Vec2) -> Option<ClipperPaths> {
+        let tolerance = clean_tolerance(self.tolerance);
+        let constraints = Constraints::loose(size);
+        let mut silhouettes = self.operands.iter().map(|operand| {
+            let operand_size = operand.layout(constraints);
+            let root = operand.render(operand_size).root;
+            collect_node_paths(&root, Transform::IDENTITY, tolerance)
+                .and_then(|paths| union(paths, ClipperPaths::default(), FillRule::NonZero).ok())
+                .unwrap_or_default()
+        });
+
+        let subject = silhouettes.next()?;
+        let result = match self.op {
+            BooleanOp::Union => silhouettes.try_fold(subject, |acc, next| {
+                union(acc, next, FillRule::NonZero).ok()
+            })?,
+            BooleanOp::Intersection => silhouettes.try_fold(subject, |acc, next| {
+                intersect(acc, next, FillRule::NonZero).ok()
+            })?,
+            BooleanOp::Difference => silhouettes.try_fold(subject, |acc, next| {
+                difference(acc, next, FillRule::NonZero).ok()
+            })?,
+            BooleanOp::Xor => {
+                silhouettes.try_fold(subject, |acc, next| xor(acc, next, FillRule::NonZero).ok())?
+            }
+        };
+        result.contains_points().then_some(result)
+    }
+}
+
+impl VectorComponent for PathBoolean {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        constraints.constrain(vector_children_bounds(&self.operands).size)
+    }
+
+    /// Every operation stays inside the union of its operands, so their
+    /// bounds (grown by the result stroke) cover the result without running
+    /// the boolean operation.
+    fn paint_bounds(&self, _size: Vec2) -> Rect {
+        let bounds = vector_children_bounds(&self.operands);
+        let outset = self
+            .stroke
+            .as_ref()
+            .map(Stroke::conservative_outset)
+            .unwrap_or(0.0);
+        Rect {
+            origin: Vec2(bounds.origin.0 - outset, bounds.origin.1 - outset),
+            size: Vec2(bounds.size.0 + outset * 2.0, bounds.size.1 + outset * 2.0),
+        }
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        let view_box = self.paint_bounds(size);
+        let fill = self.fill.clone().filter(Fill::is_visible);
+        let stroke = self.stroke.clone().filter(Stroke::is_visible);
+        let commands = if fill.is_some() || stroke.is_some() {
+            self.combined_paths(size)
+                .map(|paths| paths_to_commands(&paths))
+                .unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        if commands.is_empty() {
+            return VectorGraphic {
+                view_box,
+                root: Node::empty(),
+            };
+        }
+
+        VectorGraphic {
+            view_box,
+            root: Node::Path(Path {
+                commands,
+                fill,
+                fill_rule: VectorFillRule::NonZero,
+                stroke,
+                transform: Transform::IDENTITY,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+    use crate::placement::VectorPlacement;
+    use crate::shapes::{Corners, Rectangle};
+    use crate::vector::{Paint, PathCommand};
+
+    fn square(side: f32, at: Vec2) -> Box<dyn VectorComponent> {
+        Box::new(
+            Rectangle {
+                size: Vec2(side, side),
+                corners: Corners::SQUARE,
+                fill: Some(Paint::solid(Color::rgb_u8(255, 0, 0)).into()),
+                stroke: None,
+            }
+            .place_at(at),
+        )
+    }
+
+    /// Two 10x10 squares overlapping in a 5x10 band.
+    fn overlapping(op: BooleanOp) -> PathBoolean {
+        PathBoolean::new(
+            op,
+            [square(10.0, Vec2::ZERO), square(10.0, Vec2(5.0, 0.0))],
+            Paint::solid(Color::rgb_u8(0, 0, 255)),
+        )
+    }
+
+    /// Signed area of every closed contour, summed — the covered area for
+    /// a nonzero-oriented result.
+    fn area(graphic: &VectorGraphic) -> f32 {
+        let Node::Path(path) = &graphic.root else {
+            return 0.0;
+        };
+        let mut total = 0.0;
+        let mut contour: Vec<Vec2> = Vec::new();
+        let mut close = |contour: &mut Vec<Vec2>| {
+            let n = contour.len();
+            total += (0..n)
+                .map(|i| {
+                    let (a, b) = (contour[i], contour[(i + 1) % n]);
+                    a.0 * b.1 - b.0 * a.1
+                })
+                .sum::<f32>()
+                * 0.5;
+            contour.clear();
+        };
+        for command in &path.commands {
+            match command {
+                PathCommand::MoveTo(p) | PathCommand::LineTo(p) => contour.push(*p),
+                PathCommand::Close => close(&mut contour),
+                _ => panic!("boolean results are polygonal"),
+            }
+        }
+        total.abs()
+    }
+
+    #[test]
+    fn operations_cover_the_expected_area() {
+        let size = Vec2(15.0, 10.0);
+        let cases = [
+            (BooleanOp::Union, 150.0),
+            (BooleanOp::Intersection, 50.0),
+            (BooleanOp::Difference, 50.0),
+            (BooleanOp::Xor, 100.0),
+        ];
+        for (op, expected) in cases {
+            let boolean = overlapping(op);
+            assert_eq!(boolean.layout(Constraints::UNBOUNDED), size);
+            let covered = area(&boolean.render(size));
+            assert!(
+                (covered - expected).abs() < 0.01,
+                "{op:?} covered {covered}, expected {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn difference_subtracts_every_later_operand() {
+        let boolean = PathBoolean::builder()
+            .op(BooleanOp::Difference)
+            .operand(square(10.0, Vec2::ZERO))
+            .operand(square(4.0, Vec2::ZERO))
+            .operand(square(4.0, Vec2(6.0, 6.0)))
+            .fill(Paint::solid(Color::rgb_u8(0, 0, 255)))
+            .build();
+        let covered = area(&boolean.render(Vec2(10.0, 10.0)));
+        assert!((covered - 68.0).abs() < 0.01, "{covered}");
+    }
+
+    #[test]
+    fn disjoint_intersection_renders_no_ink() {
+        let boolean = PathBoolean::new(
+            BooleanOp::Intersection,
+            [square(4.0, Vec2::ZERO), square(4.0, Vec2(6.0, 0.0))],
+            Paint::solid(Color::rgb_u8(0, 0, 255)),
+        );
+        assert!(boolean.render(Vec2(10.0, 4.0)).root.is_empty());
+    }
+
+    #[test]
+    fn result_paints_with_its_own_fill_and_stroke() {
+        let stroke = Stroke::new(Color::rgb_u8(0, 0, 0), 2.0);
+        let boolean = PathBoolean::builder()
+            .op(BooleanOp::Union)
+            .operand(square(10.0, Vec2::ZERO))
+            .operand(square(10.0, Vec2(5.0, 0.0)))
+            .fill(Paint::solid(Color::rgb_u8(0, 0, 255)))
+            .stroke(stroke.clone())
+            .build();
+        let graphic = boolean.render(Vec2(15.0, 10.0));
+        let Node::Path(path) = &graphic.root else {
+            panic!("a boolean renders as one path");
+        };
+        assert_eq!(
+            path.fill,
+            Some(Fill {
+                paint: Paint::solid(Color::rgb_u8(0, 0, 255))
+            })
+        );
+        assert_eq!(path.stroke, Some(stroke));
+        assert_eq!(
+            graphic.view_box,
+            Rect {
+                origin: Vec2(-1.0, -1.0),
+                size: Vec2(17.0, 12.0),
+            }
+        );
+    }
+}
@@ -1,7 +1,11 @@
 //! Visual effects for vector components.
 
+pub mod boolean;
+pub mod morph;
 pub mod outline;
 pub mod write;
 
+pub use boolean::{BooleanOp, PathBoolean};
+pub use morph::{Morph, MorphExtras, TimedMorph, VectorBuilderMorph, VectorMorph};
 pub use outline::{OutlineJoin, OutlineSide, Outlined, VectorBuilderOutline, VectorOutline};
 pub use write::{TimedWrite, VectorBuilderWrite, VectorWrite, Write, WritePacing};
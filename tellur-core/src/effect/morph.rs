@@ -0,0 +1,1151 @@
+//! Manim-style shape morphing between two vector components.
+//!
+//! [`Morph`] renders both endpoints, flattens every visible path into
+//! polylines in root space, resamples each contour to the same number of
+//! points, and interpolates the point sets together with fills and strokes
+//! ([`Interpolate`]). [`TimedMorph`] runs the same morph over a duration in
+//! seconds, driven by a time or a [`Clock`] event.
+//!
+//! Paths pair up in paint order. Within a pair, contours are matched by
+//! length rank (longest with longest), closed contours are rotated so their
+//! sample points line up with as little travel as possible, and a contour
+//! with no partner grows out of (or shrinks into) its own centroid. When the
+//! two sides have different numbers of paths, [`MorphExtras`] decides what
+//! the unpaired ones do.
+//!
+//! Intermediate frames are polygonal and unclipped: clip groups in either
+//! endpoint are ignored until the morph settles on the exact endpoint
+//! graphic at `progress == 0` and `progress == 1`.
+
+use super::outline::{flatten_commands, DEFAULT_TOLERANCE};
+use crate::builder::VectorBuilder;
+use crate::easing::Easing;
+use crate::geometry::{Constraints, Rect, Transform, Vec2};
+use crate::interpolate::Interpolate;
+use crate::layer::union_rect;
+use crate::phase::Phase;
+use crate::time::Time;
+use crate::timeline_component::{Clock, Event};
+use crate::vector::{
+    DashPattern, Fill, FillRule, Group, Node, Paint, Path, PathCommand, Stroke, VectorComponent,
+    VectorGraphic, WidthProfile,
+};
+use crate::Keyable;
+
+const DEFAULT_SAMPLES: usize = 96;
+const MIN_SAMPLES: usize = 4;
+/// Cap on the sample points per contour. Lining up two closed contours
+/// tries every rotation, which costs samples² per pair and frame; past this
+/// the points are far closer than a pixel on any practical shape.
+const MAX_SAMPLES: usize = 1024;
+const DEFAULT_MORPH_SECS: f64 = 1.0;
+
+/// What [`Morph`] does with paths that have no partner on the other side.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MorphExtras {
+    /// Pair paths one to one; unpaired source paths fade out in place and
+    /// unpaired target paths fade in.
+    #[default]
+    Fade,
+    /// Spread the side with fewer paths over the other: each of its paths
+    /// splits into several copies (or several paths merge into one), so
+    /// every path morphs and nothing fades.
+    Split,
+}
+
+/// Morphs `from` into `to` as `progress` runs from `0` to `1`.
+///
+/// The layout size interpolates between the two children's sizes, and both
+/// children render at that size. At `progress == 0` and `progress == 1` the
+/// corresponding child's own graphic is returned unchanged.
+#[crate::component(vector)]
+#[derive(Clone, Keyable)]
+pub struct Morph {
+    pub progress: Phase,
+    #[builder(default)]
+    pub extras: MorphExtras,
+    /// Sample points per contour, clamped to `4..=1024`; more samples
+    /// follow curves more closely.
+    #[builder(default = DEFAULT_SAMPLES)]
+    pub samples: usize,
+    /// Flattening tolerance for curved paths, in logical units.
+    #[builder(default = DEFAULT_TOLERANCE)]
+    pub tolerance: f32,
+    #[builder(into)]
+    pub from: Box<dyn VectorComponent>,
+    #[builder(into)]
+    pub to: Box<dyn VectorComponent>,
+}
+
+impl Morph {
+    pub fn new<A, B>(progress: Phase, from: A, to: B) -> Self
+    where
+        A: VectorComponent + 'static,
+        B: VectorComponent + 'static,
+    {
+        Self::from_boxes(progress, Box::new(from), Box::new(to))
+    }
+
+    pub fn from_boxes(
+        progress: Phase,
+        from: Box<dyn VectorComponent>,
+        to: Box<dyn VectorComponent>,
+    ) -> Self {
+        Self {
+            progress,
+            extras: MorphExtras::Fade,
+            samples: DEFAULT_SAMPLES,
+            tolerance: DEFAULT_TOLERANCE,
+            from,
+            to,
+        }
+    }
+
+    pub fn extras(mut self, extras: MorphExtras) -> Self {
+        self.extras = extras;
+        self
+    }
+
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    fn settings(&self) -> MorphSettings {
+        MorphSettings {
+            extras: self.extras,
+            samples: self.samples,
+            tolerance: self.tolerance,
+        }
+    }
+}
+
+impl VectorComponent for Morph {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        morph_layout(&*self.from, &*self.to, self.progress, constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        morph_paint_bounds(&*self.from, &*self.to, self.progress, size)
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        morph_render(&*self.from, &*self.to, self.progress, self.settings(), size)
+    }
+}
+
+/// Morphs `from` into `to` over `duration` seconds starting at `start`.
+///
+/// The elapsed fraction is shaped by `easing` (smoothstep by default) and
+/// then morphs exactly like [`Morph`]. Before `start` the source renders
+/// unchanged; after `start + duration` the target does.
+#[crate::component(vector)]
+#[derive(Clone, Keyable)]
+pub struct TimedMorph {
+    pub time: f64,
+    pub start: f64,
+    #[builder(default = DEFAULT_MORPH_SECS)]
+    pub duration: f64,
+    #[builder(default = Easing::Smoothstep)]
+    pub easing: Easing,
+    #[builder(default)]
+    pub extras: MorphExtras,
+    /// Sample points per contour, clamped to `4..=1024`, as for [`Morph`].
+    #[builder(default = DEFAULT_SAMPLES)]
+    pub samples: usize,
+    #[builder(default = DEFAULT_TOLERANCE)]
+    pub tolerance: f32,
+    #[builder(into)]
+    pub from: Box<dyn VectorComponent>,
+    #[builder(into)]
+    pub to: Box<dyn VectorComponent>,
+}
+
+impl TimedMorph {
+    pub fn new<T, A, B>(time: T, start: f64, from: A, to: B) -> Self
+    where
+        T: Time,
+        A: VectorComponent + 'static,
+        B: VectorComponent + 'static,
+    {
+        Self::from_boxes(time, start, Box::new(from), Box::new(to))
+    }
+
+    pub fn from_boxes<T: Time>(
+        time: T,
+        start: f64,
+        from: Box<dyn VectorComponent>,
+        to: Box<dyn VectorComponent>,
+    ) -> Self {
+        Self::with_defaults(time.seconds(), start, from, to)
+    }
+
+    pub fn from_elapsed<A, B>(elapsed: f64, from: A, to: B) -> Self
+    where
+        A: VectorComponent + 'static,
+        B: VectorComponent + 'static,
+    {
+        Self::with_defaults(elapsed, 0.0, Box::new(from), Box::new(to))
+    }
+
+    fn with_defaults(
+        time: f64,
+        start: f64,
+        from: Box<dyn VectorComponent>,
+        to: Box<dyn VectorComponent>,
+    ) -> Self {
+        Self {
+            time,
+            start,
+            duration: DEFAULT_MORPH_SECS,
+            easing: Easing::Smoothstep,
+            extras: MorphExtras::Fade,
+            samples: DEFAULT_SAMPLES,
+            tolerance: DEFAULT_TOLERANCE,
+            from,
+            to,
+        }
+    }
+
+    pub fn duration_secs(mut self, duration: f64) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn extras(mut self, extras: MorphExtras) -> Self {
+        self.extras = extras;
+        self
+    }
+
+    pub fn samples(mut self, samples: usize) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// The eased morph progress at the current time.
+    pub fn progress(&self) -> Phase {
+        let elapsed = self.time - self.start;
+        let linear = if self.duration <= 0.0 {
+            if elapsed >= 0.0 {
+                Phase::ONE
+            } else {
+                Phase::ZERO
+            }
+        } else {
+            Phase::saturating((elapsed / self.duration) as f32)
+        };
+        linear.eased(self.easing)
+    }
+
+    fn settings(&self) -> MorphSettings {
+        MorphSettings {
+            extras: self.extras,
+            samples: self.samples,
+            tolerance: self.tolerance,
+        }
+    }
+}
+
+impl VectorComponent for TimedMorph {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        morph_layout(&*self.from, &*self.to, self.progress(), constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        morph_paint_bounds(&*self.from, &*self.to, self.progress(), size)
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        morph_render(
+            &*self.from,
+            &*self.to,
+            self.progress(),
+            self.settings(),
+            size,
+        )
+    }
+}
+
+/// Extension trait adding morph wrappers to built vector components.
+pub trait VectorMorph: VectorComponent + Sized + 'static {
+    fn morph_to<C: VectorComponent + 'static>(self, to: C, progress: Phase) -> Morph {
+        Morph::new(progress, self, to)
+    }
+
+    fn morph_from<T: Time, C: VectorComponent + 'static>(
+        self,
+        to: C,
+        time: T,
+        start: f64,
+    ) -> TimedMorph {
+        TimedMorph::new(time, start, self, to)
+    }
+
+    fn morph_elapsed<C: VectorComponent + 'static>(self, to: C, elapsed: f64) -> TimedMorph {
+        TimedMorph::from_elapsed(elapsed, self, to)
+    }
+
+    fn morph_since<C: VectorComponent + 'static>(
+        self,
+        to: C,
+        event: Event,
+        clock: &Clock<'_>,
+    ) -> TimedMorph {
+        self.morph_elapsed(to, event.elapsed(clock))
+    }
+}
+
+impl<T: VectorComponent + 'static> VectorMorph for T {}
+
+/// Builder-side morph wrappers, so complete builders do not need `.build()`.
+pub trait VectorBuilderMorph: VectorBuilder {
+    fn morph_to<C: VectorComponent + 'static>(self, to: C, progress: Phase) -> Morph {
+        Morph::new(progress, self.build_component(), to)
+    }
+
+    fn morph_from<T: Time, C: VectorComponent + 'static>(
+        self,
+        to: C,
+        time: T,
+        start: f64,
+    ) -> TimedMorph {
+        TimedMorph::new(time, start, self.build_component(), to)
+    }
+
+    fn morph_elapsed<C: VectorComponent + 'static>(self, to: C, elapsed: f64) -> TimedMorph {
+        TimedMorph::from_elapsed(elapsed, self.build_component(), to)
+    }
+
+    fn morph_since<C: VectorComponent + 'static>(
+        self,
+        to: C,
+        event: Event,
+        clock: &Clock<'_>,
+    ) -> TimedMorph {
+        self.morph_elapsed(to, event.elapsed(clock))
+    }
+}
+
+impl<B: VectorBuilder> VectorBuilderMorph for B {}
+
+#[derive(Clone, Copy)]
+struct MorphSettings {
+    extras: MorphExtras,
+    samples: usize,
+    tolerance: f32,
+}
+
+fn morph_layout(
+    from: &dyn VectorComponent,
+    to: &dyn VectorComponent,
+    progress: Phase,
+    constraints: Constraints,
+) -> Vec2 {
+    if progress == Phase::ZERO {
+        return from.layout(constraints);
+    }
+    if progress == Phase::ONE {
+        return to.layout(constraints);
+    }
+    let size = from
+        .layout(constraints)
+        .interpolate(to.layout(constraints), progress);
+    constraints.constrain(size)
+}
+
+/// Every interpolated point lies between a source and a target point, so
+/// the union of both endpoints' bounds covers any intermediate frame.
+fn morph_paint_bounds(
+    from: &dyn VectorComponent,
+    to: &dyn VectorComponent,
+    progress: Phase,
+    size: Vec2,
+) -> Rect {
+    if progress == Phase::ZERO {
+        return from.paint_bounds(size);
+    }
+    if progress == Phase::ONE {
+        return to.paint_bounds(size);
+    }
+    union_rect(from.paint_bounds(size), to.paint_bounds(size))
+}
+
+fn morph_render(
+    from: &dyn VectorComponent,
+    to: &dyn VectorComponent,
+    progress: Phase,
+    settings: MorphSettings,
+    size: Vec2,
+) -> VectorGraphic {
+    if progress == Phase::ZERO {
+        return from.render(size);
+    }
+    if progress == Phase::ONE {
+        return to.render(size);
+    }
+
+    let from_graphic = from.render(size);
+    let to_graphic = to.render(size);
+    let view_box = union_rect(from_graphic.view_box, to_graphic.view_box);
+    let tolerance = settings.tolerance.clamp(0.01, 10.0);
+    let samples = settings.samples.clamp(MIN_SAMPLES, MAX_SAMPLES);
+    let sources = collect_shapes(&from_graphic.root, tolerance);
+    let targets = collect_shapes(&to_graphic.root, tolerance);
+
+    let mut children = Vec::new();
+    for pairing in pair_shapes(sources.len(), targets.len(), settings.extras) {
+        let node = match pairing {
+            Pairing::Both(a, b) => morph_shapes(&sources[a], &targets[b], progress, samples),
+            Pairing::Source(a) => fade_shape(&sources[a], 1.0 - progress.get()),
+            Pairing::Target(b) => fade_shape(&targets[b], progress.get()),
+        };
+        if !node.is_empty() {
+            children.push(node);
+        }
+    }
+
+    VectorGraphic {
+        view_box,
+        root: Node::Group(Group {
+            transform: Transform::IDENTITY,
+            opacity: 1.0,
+            children,
+        }),
+    }
+}
+
+/// One visible path, flattened into root space.
+struct Shape {
+    contours: Vec<Contour>,
+    fill: Option<Fill>,
+    fill_rule: FillRule,
+    stroke: Option<Stroke>,
+    /// Accumulated group opacity.
+    opacity: f32,
+}
+
+struct Contour {
+    points: Vec<Vec2>,
+    closed: bool,
+    length: f32,
+}
+
+fn collect_shapes(root: &Node, tolerance: f32) -> Vec<Shape> {
+    fn walk(node: &Node, transform: Transform, opacity: f32, tolerance: f32, out: &mut Vec<Shape>) {
+        match node {
+            Node::Group(group) => {
+                let opacity = opacity * group.opacity;
+                if opacity > 0.0 {
+                    let transform = transform.concat(group.transform);
+                    for child in &group.children {
+                        walk(child, transform, opacity, tolerance, out);
+                    }
+                }
+            }
+            Node::SingleGroup(group) => {
+                let opacity = opacity * group.opacity;
+                if opacity > 0.0 {
+                    let transform = transform.concat(group.transform);
+                    walk(&group.child, transform, opacity, tolerance, out);
+                }
+            }
+            Node::ClipGroup(group) => walk(&group.child, transform, opacity, tolerance, out),
+            Node::Path(path) => {
+                if let Some(shape) = flatten_shape(path, transform, opacity, tolerance) {
+                    out.push(shape);
+                }
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(root, Transform::IDENTITY, 1.0, tolerance, &mut out);
+    out
+}
+
+fn flatten_shape(path: &Path, parent: Transform, opacity: f32, tolerance: f32) -> Option<Shape> {
+    let fill = path.fill.clone().filter(Fill::is_visible);
+    let stroke = path.stroke.clone().filter(Stroke::is_visible);
+    if fill.is_none() && stroke.is_none() {
+        return None;
+    }
+    let transform = parent.concat(path.transform);
+    let contours: Vec<Contour> = flatten_commands(&path.commands, transform, tolerance, false)
+        .into_iter()
+        .filter_map(contour_from_points)
+        .collect();
+    if contours.is_empty() {
+        return None;
+    }
+    Some(Shape {
+        contours,
+        fill: fill.map(|fill| Fill {
+            paint: transform_paint(fill.paint, transform),
+        }),
+        fill_rule: path.fill_rule,
+        stroke: stroke.map(|stroke| transform_stroke(stroke, transform)),
+        opacity,
+    })
+}
+
+/// A flattened contour; closed contours repeat their start point, which is
+/// dropped so the loop is implicit.
+fn contour_from_points(mut points: Vec<Vec2>) -> Option<Contour> {
+    points.dedup();
+    let closed = points.len() > 2 && points.first() == points.last();
+    if closed {
+        points.pop();
+    }
+    if points.len() < 2 {
+        return None;
+    }
+    let length = polyline_length(&points, closed);
+    Some(Contour {
+        points,
+        closed,
+        length,
+    })
+}
+
+/// Moves gradient and image geometry from path space into root space.
+fn transform_paint(paint: Paint, transform: Transform) -> Paint {
+    match paint {
+        Paint::Solid(color) => Paint::Solid(color),
+        Paint::LinearGradient(mut gradient) => {
+            gradient.transform = transform.concat(gradient.transform);
+            Paint::LinearGradient(gradient)
+        }
+        Paint::RadialGradient(mut gradient) => {
+            gradient.transform = transform.concat(gradient.transform);
+            Paint::RadialGradient(gradient)
+        }
+        Paint::SweepGradient(mut gradient) => {
+            gradient.transform = transform.concat(gradient.transform);
+            Paint::SweepGradient(gradient)
+        }
+        Paint::Image(mut pattern) => {
+            pattern.transform = transform.concat(pattern.transform);
+            Paint::Image(pattern)
+        }
+    }
+}
+
+/// Bakes the transform's (area-preserving) scale into the stroke width and
+/// dash lengths, since the flattened geometry no longer carries it.
+fn transform_stroke(stroke: Stroke, transform: Transform) -> Stroke {
+    let scale = (transform.a * transform.d - transform.b * transform.c)
+        .abs()
+        .sqrt();
+    let scale = if scale.is_finite() { scale } else { 1.0 };
+    let miter_limit = stroke.miter_limit();
+    let mut baked = Stroke::new(
+        transform_paint(stroke.paint, transform),
+        stroke.width * scale,
+    )
+    .with_cap(stroke.cap)
+    .with_join(stroke.join)
+    .with_miter_limit(miter_limit);
+    if let Some(dash) = stroke.dash {
+        let lengths: Vec<f32> = dash.lengths.iter().map(|len| len * scale).collect();
+        baked = baked.with_dash(DashPattern::new(lengths, dash.offset * scale));
+    }
+    if let Some(profile) = stroke.width_profile {
+        let stops = profile
+            .stops()
+            .iter()
+            .map(|stop| (stop.t, stop.width * scale));
+        baked = baked.with_width_profile(WidthProfile::new(stops));
+    }
+    baked
+}
+
+enum Pairing {
+    Both(usize, usize),
+    Source(usize),
+    Target(usize),
+}
+
+fn pair_shapes(sources: usize, targets: usize, extras: MorphExtras) -> Vec<Pairing> {
+    let fewer = sources.min(targets);
+    let more = sources.max(targets);
+    if extras == MorphExtras::Split && fewer > 0 {
+        return (0..more)
+            .map(|i| {
+                let partner = i * fewer / more;
+                if sources >= targets {
+                    Pairing::Both(i, partner)
+                } else {
+                    Pairing::Both(partner, i)
+                }
+            })
+            .collect();
+    }
+    let mut pairs: Vec<Pairing> = (0..fewer).map(|i| Pairing::Both(i, i)).collect();
+    pairs.extend((fewer..sources).map(Pairing::Source));
+    pairs.extend((fewer..targets).map(Pairing::Target));
+    pairs
+}
+
+fn morph_shapes(a: &Shape, b: &Shape, progress: Phase, samples: usize) -> Node {
+    let t = progress.get();
+    let mut commands = Vec::new();
+    for (ca, cb) in pair_contours(&a.contours, &b.contours) {
+        let closed = match (ca, cb) {
+            (Some(ca), Some(cb)) => {
+                if t < 0.5 {
+                    ca.closed
+                } else {
+                    cb.closed
+                }
+            }
+            (Some(c), None) | (None, Some(c)) => c.closed,
+            (None, None) => continue,
+        };
+        let (pa, pb) = aligned_samples(ca, cb, samples);
+        let points = pa
+            .into_iter()
+            .zip(pb)
+            .map(|(p, q)| p.interpolate(q, progress));
+        push_polyline(&mut commands, points, closed);
+    }
+
+    let (fill, fill_alpha) = morph_option(a.fill.clone(), b.fill.clone(), progress);
+    let (stroke, stroke_alpha) = morph_option(a.stroke.clone(), b.stroke.clone(), progress);
+    let opacity = a.opacity.interpolate(b.opacity, progress);
+    let fill_rule = if t < 0.5 { a.fill_rule } else { b.fill_rule };
+    painted_node(
+        commands,
+        fill_rule,
+        (fill, fill_alpha),
+        (stroke, stroke_alpha),
+        opacity,
+    )
+}
+
+/// An unpaired path at `alpha`, with its own geometry.
+fn fade_shape(shape: &Shape, alpha: f32) -> Node {
+    let mut commands = Vec::new();
+    for contour in &shape.contours {
+        push_polyline(
+            &mut commands,
+            contour.points.iter().copied(),
+            contour.closed,
+        );
+    }
+    painted_node(
+        commands,
+        shape.fill_rule,
+        (shape.fill.clone(), 1.0),
+        (shape.stroke.clone(), 1.0),
+        shape.opacity * alpha,
+    )
+}
+
+/// Interpolates a fill or stroke present on both sides; one present on a
+/// single side keeps its paint and fades instead.
+fn morph_option<T: Interpolate>(a: Option<T>, b: Option<T>, progress: Phase) -> (Option<T>, f32) {
+    match (a, b) {
+        (Some(a), Some(b)) => (Some(a.interpolate(b, progress)), 1.0),
+        (Some(a), None) => (Some(a), 1.0 - progress.get()),
+        (None, Some(b)) => (Some(b), progress.get()),
+        (None, None) => (None, 0.0),
+    }
+}
+
+/// Emits one path when fill and stroke share an alpha, otherwise separate
+/// fill and stroke paths so each can fade on its own.
+fn painted_node(
+    commands: Vec<PathCommand>,
+    fill_rule: FillRule,
+    (fill, fill_alpha): (Option<Fill>, f32),
+    (stroke, stroke_alpha): (Option<Stroke>, f32),
+    opacity: f32,
+) -> Node {
+    if commands.is_empty() || opacity <= 0.0 {
+        return Node::empty();
+    }
+    let path = |fill: Option<Fill>, stroke: Option<Stroke>| {
+        Node::Path(Path {
+            commands: commands.clone(),
+            fill,
+            fill_rule,
+            stroke,
+            transform: Transform::IDENTITY,
+        })
+    };
+    let faded = |node: Node, alpha: f32| {
+        let alpha = alpha * opacity;
+        if alpha <= 0.0 {
+            Node::empty()
+        } else if alpha >= 1.0 {
+            node
+        } else {
+            Node::single_group(Transform::IDENTITY, alpha, node)
+        }
+    };
+
+    let same_alpha = fill.is_none() || stroke.is_none() || fill_alpha == stroke_alpha;
+    if same_alpha {
+        let alpha = if fill.is_some() {
+            fill_alpha
+        } else {
+            stroke_alpha
+        };
+        return faded(path(fill, stroke), alpha);
+    }
+    let children: Vec<Node> = [
+        faded(path(fill, None), fill_alpha),
+        faded(path(None, stroke), stroke_alpha),
+    ]
+    .into_iter()
+    .filter(|node| !node.is_empty())
+    .collect();
+    Node::Group(Group {
+        transform: Transform::IDENTITY,
+        opacity: 1.0,
+        children,
+    })
+}
+
+fn push_polyline(
+    commands: &mut Vec<PathCommand>,
+    points: impl IntoIterator<Item = Vec2>,
+    closed: bool,
+) {
+    let mut points = points.into_iter();
+    let Some(first) = points.next() else {
+        return;
+    };
+    commands.push(PathCommand::MoveTo(first));
+    commands.extend(points.map(PathCommand::LineTo));
+    if closed {
+        commands.push(PathCommand::Close);
+    }
+}
+
+/// Pairs contours by length rank; the shorter list is padded with `None`.
+fn pair_contours<'a>(
+    a: &'a [Contour],
+    b: &'a [Contour],
+) -> Vec<(Option<&'a Contour>, Option<&'a Contour>)> {
+    let by_length = |contours: &'a [Contour]| {
+        let mut sorted: Vec<&Contour> = contours.iter().collect();
+        sorted.sort_by(|x, y| y.length.total_cmp(&x.length));
+        sorted
+    };
+    let (a, b) = (by_length(a), by_length(b));
+    (0..a.len().max(b.len()))
+        .map(|i| (a.get(i).copied(), b.get(i).copied()))
+        .collect()
+}
+
+/// Resamples both contours to `samples` points and aligns the target's
+/// start and direction with the source. A missing side collapses to the
+/// present contour's centroid.
+fn aligned_samples(
+    a: Option<&Contour>,
+    b: Option<&Contour>,
+    samples: usize,
+) -> (Vec<Vec2>, Vec<Vec2>) {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let pa = resample(a, samples);
+            let pb = resample(b, samples);
+            let pb = if a.closed && b.closed {
+                align_loop(&pa, pb)
+            } else {
+                align_open(&pa, pb)
+            };
+            (pa, pb)
+        }
+        (Some(a), None) => {
+            let pa = resample(a, samples);
+            let center = centroid(&pa);
+            (pa, vec![center; samples])
+        }
+        (None, Some(b)) => {
+            let pb = resample(b, samples);
+            let center = centroid(&pb);
+            (vec![center; samples], pb)
+        }
+        (None, None) => (Vec::new(), Vec::new()),
+    }
+}
+
+/// `samples` points evenly spaced by arc length. Closed contours spread
+/// them around the loop; open ones include both endpoints.
+fn resample(contour: &Contour, samples: usize) -> Vec<Vec2> {
+    let points = &contour.points;
+    if contour.length <= 0.0 {
+        return vec![points[0]; samples];
+    }
+    let step = if contour.closed {
+        contour.length / samples as f32
+    } else {
+        contour.length / (samples - 1) as f32
+    };
+    let segment_count = if contour.closed {
+        points.len()
+    } else {
+        points.len() - 1
+    };
+
+    let mut out = Vec::with_capacity(samples);
+    let mut segment = 0;
+    let mut walked = 0.0;
+    for i in 0..samples {
+        let target = step * i as f32;
+        loop {
+            let (p, q) = (points[segment], points[(segment + 1) % points.len()]);
+            let len = distance(p, q);
+            if walked + len >= target || segment + 1 >= segment_count {
+                let t = if len > 0.0 {
+                    ((target - walked) / len).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                out.push(lerp(p, q, t));
+                break;
+            }
+            walked += len;
+            segment += 1;
+        }
+    }
+    out
+}
+
+/// Matches winding direction, then rotates `b` to the start offset that
+/// minimizes total point travel.
+fn align_loop(a: &[Vec2], mut b: Vec<Vec2>) -> Vec<Vec2> {
+    if signed_area(a) * signed_area(&b) < 0.0 {
+        b.reverse();
+    }
+    let n = b.len();
+    let best = (0..n)
+        .min_by(|&x, &y| travel(a, &b, x).total_cmp(&travel(a, &b, y)))
+        .unwrap_or(0);
+    b.rotate_left(best);
+    b
+}
+
+/// Reverses `b` when that brings its endpoints closer to `a`'s.
+fn align_open(a: &[Vec2], mut b: Vec<Vec2>) -> Vec<Vec2> {
+    let (Some(&a0), Some(&a1), Some(&b0), Some(&b1)) = (a.first(), a.last(), b.first(), b.last())
+    else {
+        return b;
+    };
+    if distance(a0, b1) + distance(a1, b0) < distance(a0, b0) + distance(a1, b1) {
+        b.reverse();
+    }
+    b
+}
+
+fn travel(a: &[Vec2], b: &[Vec2], offset: usize) -> f32 {
+    let n = b.len();
+    a.iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            let q = b[(i + offset) % n];
+            (p.0 - q.0).powi(2) + (p.1 - q.1).powi(2)
+        })
+        .sum()
+}
+
+fn polyline_length(points: &[Vec2], closed: bool) -> f32 {
+    let open: f32 = points.windows(2).map(|w| distance(w[0], w[1])).sum();
+    match (closed, points.first(), points.last()) {
+        (true, Some(&first), Some(&last)) => open + distance(last, first),
+        _ => open,
+    }
+}
+
+fn signed_area(points: &[Vec2]) -> f32 {
+    let n = points.len();
+    (0..n)
+        .map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % n]);
+            a.0 * b.1 - b.0 * a.1
+        })
+        .sum::<f32>()
+        * 0.5
+}
+
+fn centroid(points: &[Vec2]) -> Vec2 {
+    let n = points.len().max(1) as f32;
+    let sum = points
+        .iter()
+        .fold(Vec2::ZERO, |acc, p| Vec2(acc.0 + p.0, acc.1 + p.1));
+    Vec2(sum.0 / n, sum.1 / n)
+}
+
+fn distance(a: Vec2, b: Vec2) -> f32 {
+    ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+fn lerp(a: Vec2, b: Vec2, t: f32) -> Vec2 {
+    Vec2(a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Color;
+    use crate::placement::VectorPlacement;
+    use crate::shapes::{Circle, Corners, Rectangle};
+    use crate::time::{LocalTime, TimelineTime};
+    use crate::timeline_component::TriggerTable;
+
+    const RED: Color = Color::rgb_u8(255, 0, 0);
+    const BLUE: Color = Color::rgb_u8(0, 0, 255);
+
+    fn square(side: f32, color: Color) -> Rectangle {
+        Rectangle {
+            size: Vec2(side, side),
+            corners: Corners::SQUARE,
+            fill: Some(Paint::solid(color).into()),
+            stroke: None,
+        }
+    }
+
+    fn circle(radius: f32, color: Color) -> Circle {
+        Circle::builder()
+            .radius(radius)
+            .fill(Paint::solid(color))
+            .build()
+    }
+
+    /// Every path in render order, with the opacity of its enclosing groups.
+    fn paths(node: &Node) -> Vec<(&Path, f32)> {
+        fn walk<'a>(node: &'a Node, opacity: f32, out: &mut Vec<(&'a Path, f32)>) {
+            match node {
+                Node::Group(group) => {
+                    for child in &group.children {
+                        walk(child, opacity * group.opacity, out);
+                    }
+                }
+                Node::SingleGroup(group) => walk(&group.child, opacity * group.opacity, out),
+                Node::ClipGroup(group) => walk(&group.child, opacity, out),
+                Node::Path(path) => out.push((path, opacity)),
+            }
+        }
+        let mut out = Vec::new();
+        walk(node, 1.0, &mut out);
+        out
+    }
+
+    fn points(path: &Path) -> Vec<Vec2> {
+        path.commands
+            .iter()
+            .filter_map(|command| match command {
+                PathCommand::MoveTo(p) | PathCommand::LineTo(p) => Some(*p),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn endpoints_render_the_children_unchanged() {
+        let from = square(10.0, RED);
+        let to = circle(8.0, BLUE);
+        let start = Morph::new(Phase::ZERO, from.clone(), to.clone());
+        let end = Morph::new(Phase::ONE, from.clone(), to.clone());
+        assert_eq!(
+            start.render(Vec2(10.0, 10.0)),
+            from.render(Vec2(10.0, 10.0))
+        );
+        assert_eq!(end.render(Vec2(16.0, 16.0)), to.render(Vec2(16.0, 16.0)));
+    }
+
+    #[test]
+    fn layout_interpolates_between_child_sizes() {
+        let morph = Morph::new(Phase::HALF, square(10.0, RED), square(30.0, RED));
+        assert_eq!(morph.layout(Constraints::UNBOUNDED), Vec2(20.0, 20.0));
+    }
+
+    #[test]
+    fn sample_count_is_capped() {
+        let morph = Morph::builder()
+            .progress(Phase::HALF)
+            .samples(usize::MAX)
+            .from(square(20.0, RED))
+            .to(circle(10.0, BLUE))
+            .build();
+        let graphic = morph.render(Vec2(20.0, 20.0));
+        let paths = paths(&graphic.root);
+        assert_eq!(points(paths[0].0).len(), MAX_SAMPLES);
+    }
+
+    #[test]
+    fn halfway_square_to_circle_blends_geometry_and_fill() {
+        let morph = Morph::builder()
+            .progress(Phase::HALF)
+            .samples(64)
+            .from(square(20.0, RED))
+            .to(circle(10.0, BLUE))
+            .build();
+        let graphic = morph.render(Vec2(20.0, 20.0));
+        let paths = paths(&graphic.root);
+        assert_eq!(paths.len(), 1);
+        let (path, opacity) = paths[0];
+        assert_eq!(opacity, 1.0);
+        assert_eq!(
+            path.fill,
+            Some(Fill {
+                paint: Paint::Solid(RED.interpolate(BLUE, Phase::HALF))
+            })
+        );
+
+        let points = points(path);
+        assert_eq!(points.len(), 64);
+        assert_eq!(path.commands.last(), Some(&PathCommand::Close));
+        // Each point sits between the square's boundary (radius 10..14.1
+        // from the center) and the circle (radius 10).
+        for p in points {
+            let r = distance(p, Vec2(10.0, 10.0));
+            assert!((9.9..=12.2).contains(&r), "{p:?} at radius {r}");
+        }
+    }
+
+    #[test]
+    fn resampling_spaces_points_evenly_by_length() {
+        let contour = contour_from_points(vec![
+            Vec2(0.0, 0.0),
+            Vec2(30.0, 0.0),
+            Vec2(30.0, 10.0),
+            Vec2(0.0, 10.0),
+            Vec2(0.0, 0.0),
+        ])
+        .unwrap();
+        assert!(contour.closed);
+        assert_eq!(contour.length, 80.0);
+        let samples = resample(&contour, 8);
+        assert_eq!(
+            samples,
+            vec![
+                Vec2(0.0, 0.0),
+                Vec2(10.0, 0.0),
+                Vec2(20.0, 0.0),
+                Vec2(30.0, 0.0),
+                Vec2(30.0, 10.0),
+                Vec2(20.0, 10.0),
+                Vec2(10.0, 10.0),
+                Vec2(0.0, 10.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn loops_align_direction_and_start_point() {
+        let a = vec![
+            Vec2(0.0, 0.0),
+            Vec2(1.0, 0.0),
+            Vec2(1.0, 1.0),
+            Vec2(0.0, 1.0),
+        ];
+        // Same square, opposite winding, starting at another corner.
+        let b = vec![
+            Vec2(1.0, 1.0),
+            Vec2(1.0, 0.0),
+            Vec2(0.0, 0.0),
+            Vec2(0.0, 1.0),
+        ];
+        assert_eq!(align_loop(&a, b), a);
+    }
+
+    #[test]
+    fn extra_paths_fade_in_place() {
+        let from = square(10.0, RED);
+        let to = crate::fragment::Fragment::builder()
+            .child(square(10.0, BLUE).place_at(Vec2::ZERO))
+            .child(square(10.0, BLUE).place_at(Vec2(20.0, 0.0)))
+            .build();
+        let morph = Morph::new(Phase::new(0.25).unwrap(), from, to);
+        let graphic = morph.render(Vec2(30.0, 10.0));
+        let paths = paths(&graphic.root);
+        assert_eq!(paths.len(), 2);
+        assert_eq!(paths[0].1, 1.0, "the paired square morphs at full opacity");
+        assert_eq!(paths[1].1, 0.25, "the extra square fades in");
+        assert!(points(paths[1].0).iter().all(|p| p.0 >= 20.0));
+    }
+
+    #[test]
+    fn split_spreads_one_path_over_many() {
+        let from = square(10.0, RED);
+        let to = crate::fragment::Fragment::builder()
+            .child(square(10.0, BLUE).place_at(Vec2::ZERO))
+            .child(square(10.0, BLUE).place_at(Vec2(20.0, 0.0)))
+            .build();
+        let morph = Morph::builder()
+            .progress(Phase::HALF)
+            .extras(MorphExtras::Split)
+            .from(from)
+            .to(to)
+            .build();
+        let graphic = morph.render(Vec2(30.0, 10.0));
+        let paths = paths(&graphic.root);
+        assert_eq!(paths.len(), 2);
+        assert!(paths.iter().all(|(_, opacity)| *opacity == 1.0));
+        // The copy heading right is halfway there.
+        let min_x = points(paths[1].0)
+            .iter()
+            .map(|p| p.0)
+            .fold(f32::INFINITY, f32::min);
+        assert!((min_x - 10.0).abs() < 1e-3, "{min_x}");
+    }
+
+    #[test]
+    fn strokes_interpolate_width_and_fade_when_unpaired() {
+        let stroked = |width: f32| Rectangle {
+            size: Vec2(10.0, 10.0),
+            corners: Corners::SQUARE,
+            fill: None,
+            stroke: Some(Stroke::new(RED, width)),
+        };
+        let graphic = Morph::new(Phase::HALF, stroked(2.0), stroked(6.0)).render(Vec2(10.0, 10.0));
+        let paths_at_half = paths(&graphic.root);
+        assert_eq!(paths_at_half[0].0.stroke.as_ref().unwrap().width, 4.0);
+
+        let graphic = Morph::new(Phase::new(0.25).unwrap(), stroked(2.0), square(10.0, BLUE))
+            .render(Vec2(10.0, 10.0));
+        let faded = paths(&graphic.root);
+        assert_eq!(faded.len(), 2, "fill and stroke fade separately");
+        assert_eq!(faded[0].1, 0.25);
+        assert!(faded[0].0.fill.is_some());
+        assert_eq!(faded[1].1, 0.75);
+        assert!(faded[1].0.stroke.is_some());
+    }
+
+    #[test]
+    fn timed_morph_eases_over_its_duration() {
+        let morph = |elapsed: f64| {
+            TimedMorph::from_elapsed(elapsed, square(10.0, RED), square(30.0, RED))
+                .duration_secs(2.0)
+                .easing(Easing::Linear)
+        };
+        assert_eq!(morph(-1.0).progress(), Phase::ZERO);
+        assert_eq!(morph(0.5).progress(), Phase::new(0.25).unwrap());
+        assert_eq!(morph(3.0).progress(), Phase::ONE);
+        assert_eq!(morph(1.0).layout(Constraints::UNBOUNDED), Vec2(20.0, 20.0));
+    }
+
+    #[test]
+    fn timed_morph_can_start_from_an_event() {
+        let event = Event::new();
+        let mut table = TriggerTable::new();
+        table.record(event, 1.0);
+        let clock = Clock::new(TimelineTime::new(1.5), LocalTime::new(0.0), &table);
+        let morph = square(10.0, RED).morph_since(square(30.0, RED), event, &clock);
+        assert_eq!(morph.time, 0.5);
+        assert_eq!(morph.progress(), Phase::HALF.eased(Easing::Smoothstep));
+    }
+}
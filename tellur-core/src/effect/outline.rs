@@ -16,26 +16,34 @@ use lru::LruCache;
 use crate::builder::VectorBuilder;
 use crate::geometry::{Constraints, Rect, Transform, Vec2};
 use crate::vector::{
-    Fill, Node, Paint, Path, PathCommand, Stroke, StrokeCap, StrokeJoin, VectorComponent,
-    VectorGraphic,
+    Fill, FillRule as VectorFillRule, Node, Paint, Path, PathCommand, Stroke, StrokeCap,
+    StrokeJoin, VectorComponent, VectorGraphic, WidthProfile,
 };
 use crate::Keyable;
 
-const DEFAULT_TOLERANCE: f32 = 0.2;
+pub(super) const DEFAULT_TOLERANCE: f32 = 0.2;
 const DEFAULT_MITER_LIMIT: f32 = 4.0;
 const MAX_CURVE_STEPS: usize = 96;
 const OUTLINE_CACHE_ENTRIES: usize = 512;
+/// Flattening tolerance of width-profile outlines, in device pixels.
+const WIDTH_PROFILE_TOLERANCE: f32 = 0.1;
+const MIN_CIRCLE_SEGMENTS: usize = 8;
+const MAX_CIRCLE_SEGMENTS: usize = 128;
+const MAX_DASH_SPANS: usize = 100_000;
 
-type ClipperPaths = clipper2::Paths<clipper2::Milli>;
+pub(super) type ClipperPaths = clipper2::Paths<clipper2::Milli>;
 
 static OUTLINE_CACHE: LazyLock<Mutex<LruCache<OutlineCacheKey, OutlineCacheEntry>>> =
     LazyLock::new(|| Mutex::new(LruCache::unbounded()));
 
+/// An [`Outlined`] band keyed by its content and layout size, or a
+/// width-profile stroke keyed by its path, stroke and device scale.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 struct OutlineCacheKey {
     content_hash: u64,
     size_x_bits: u32,
     size_y_bits: u32,
+    width_profile: bool,
 }
 
 #[derive(Clone)]
@@ -44,6 +52,22 @@ struct OutlineCacheEntry {
     paths: Option<Arc<ClipperPaths>>,
 }
 
+fn cached_outline(key: &OutlineCacheKey) -> Option<OutlineCacheEntry> {
+    OUTLINE_CACHE
+        .lock()
+        .ok()
+        .and_then(|mut cache| cache.get(key).cloned())
+}
+
+fn cache_outline(key: OutlineCacheKey, entry: OutlineCacheEntry) {
+    if let Ok(mut cache) = OUTLINE_CACHE.lock() {
+        cache.put(key, entry);
+        while cache.len() > OUTLINE_CACHE_ENTRIES {
+            cache.pop_lru();
+        }
+    }
+}
+
 #[cfg(test)]
 fn clear_outline_cache_for_tests() {
     if let Ok(mut cache) = OUTLINE_CACHE.lock() {
@@ -186,11 +210,7 @@ impl<B: VectorBuilder> VectorBuilderOutline for B {}
 impl Outlined {
     fn outline_entry(&self, size: Vec2) -> OutlineCacheEntry {
         let key = self.outline_cache_key(size);
-        if let Some(entry) = OUTLINE_CACHE
-            .lock()
-            .ok()
-            .and_then(|mut cache| cache.get(&key).cloned())
-        {
+        if let Some(entry) = cached_outline(&key) {
             return entry;
         }
 
@@ -200,12 +220,7 @@ impl Outlined {
             view_box: outline_view_box(size, &inner.view_box, paths.as_deref()),
             paths,
         };
-        if let Ok(mut cache) = OUTLINE_CACHE.lock() {
-            cache.put(key, entry.clone());
-            while cache.len() > OUTLINE_CACHE_ENTRIES {
-                cache.pop_lru();
-            }
-        }
+        cache_outline(key, entry.clone());
         entry
     }
 
@@ -216,6 +231,7 @@ impl Outlined {
             content_hash: hasher.finish(),
             size_x_bits: size.0.to_bits(),
             size_y_bits: size.1.to_bits(),
+            width_profile: false,
         }
     }
 
@@ -273,7 +289,7 @@ impl From<OutlineJoin> for JoinType {
     }
 }
 
-fn clean_tolerance(tolerance: f32) -> f32 {
+pub(super) fn clean_tolerance(tolerance: f32) -> f32 {
     tolerance.clamp(0.01, 10.0)
 }
 
@@ -288,7 +304,11 @@ fn offset_paths(
     simplify(paths, tolerance as f64, false)
 }
 
-fn collect_node_paths(node: &Node, transform: Transform, tolerance: f32) -> Option<ClipperPaths> {
+pub(super) fn collect_node_paths(
+    node: &Node,
+    transform: Transform,
+    tolerance: f32,
+) -> Option<ClipperPaths> {
     match node {
         Node::Group(group) => collect_group_paths(
             &group.children,
@@ -307,6 +327,7 @@ fn collect_node_paths(node: &Node, transform: Transform, tolerance: f32) -> Opti
             let child = collect_node_paths(&group.child, transform, tolerance)?;
             let clip = path_fill_to_paths(
                 &group.commands,
+                group.fill_rule,
                 transform.concat(group.transform),
                 tolerance,
             )?;
@@ -339,15 +360,21 @@ fn path_to_visible_paths(path: &Path, parent: Transform, tolerance: f32) -> Opti
     let mut paths = ClipperPaths::default();
 
     if path.fill.as_ref().is_some_and(|fill| fill.is_visible()) {
-        if let Some(fill_paths) = path_fill_to_paths(&path.commands, transform, tolerance) {
+        if let Some(fill_paths) =
+            path_fill_to_paths(&path.commands, path.fill_rule, transform, tolerance)
+        {
             paths.push(fill_paths);
         }
     }
 
     if let Some(stroke) = path.stroke.as_ref().filter(|stroke| stroke.is_visible()) {
-        if let Some(stroke_paths) =
-            path_stroke_to_paths(&path.commands, stroke, transform, tolerance)
-        {
+        let stroke_paths = match stroke.width_profile.as_ref() {
+            Some(profile) => {
+                width_profile_paths(&path.commands, stroke, profile, transform, tolerance)
+            }
+            None => path_stroke_to_paths(&path.commands, stroke, transform, tolerance),
+        };
+        if let Some(stroke_paths) = stroke_paths {
             paths.push(stroke_paths);
         }
     }
@@ -357,11 +384,21 @@ fn path_to_visible_paths(path: &Path, parent: Transform, tolerance: f32) -> Opti
 
 fn path_fill_to_paths(
     commands: &[PathCommand],
+    fill_rule: VectorFillRule,
     transform: Transform,
     tolerance: f32,
 ) -> Option<ClipperPaths> {
     let contours = flatten_commands(commands, transform, tolerance, true);
-    contours_to_paths(contours)
+    let paths = contours_to_paths(contours)?;
+    match fill_rule {
+        VectorFillRule::NonZero => Some(paths),
+        // Resolve even-odd holes up front: the result is oriented so that the
+        // nonzero unions downstream keep them open.
+        VectorFillRule::EvenOdd => {
+            let resolved = union(paths, ClipperPaths::default(), FillRule::EvenOdd).ok()?;
+            resolved.contains_points().then_some(resolved)
+        }
+    }
 }
 
 fn path_stroke_to_paths(
@@ -406,6 +443,308 @@ fn path_stroke_to_paths(
     contours_to_paths(contours)
 }
 
+/// Expands a variable-width stroke into filled outline commands in the
+/// path's own space; backs [`Path::width_profile_outline`].
+///
+/// The outline is built at device scale so the tolerance is in pixels, then
+/// scaled back. Scaling uniformly keeps any anisotropy of `to_device` for the
+/// renderer's fill to apply exactly. Outlines are cached with [`Outlined`]'s,
+/// so a stroke redrawn at the same scale is swept once.
+pub(crate) fn width_profile_commands(
+    commands: &[PathCommand],
+    stroke: &Stroke,
+    profile: &WidthProfile,
+    to_device: Transform,
+) -> Vec<PathCommand> {
+    let scale = max_scale(to_device);
+    if !scale.is_finite() || scale <= f32::EPSILON {
+        return Vec::new();
+    }
+    let key = width_profile_cache_key(commands, stroke, profile, scale);
+    let paths = match cached_outline(&key) {
+        Some(entry) => entry.paths,
+        None => {
+            let paths = width_profile_paths(
+                commands,
+                stroke,
+                profile,
+                Transform::scale(Vec2(scale, scale)),
+                WIDTH_PROFILE_TOLERANCE,
+            )
+            .map(Arc::new);
+            // Only the paths are read back for a width profile.
+            let entry = OutlineCacheEntry {
+                view_box: Rect {
+                    origin: Vec2::ZERO,
+                    size: Vec2::ZERO,
+                },
+                paths: paths.clone(),
+            };
+            cache_outline(key, entry);
+            paths
+        }
+    };
+    let Some(paths) = paths else {
+        return Vec::new();
+    };
+    let back = |p: Vec2| Vec2(p.0 / scale, p.1 / scale);
+    paths_to_commands(&paths)
+        .into_iter()
+        .map(|command| match command {
+            PathCommand::MoveTo(p) => PathCommand::MoveTo(back(p)),
+            PathCommand::LineTo(p) => PathCommand::LineTo(back(p)),
+            other => other,
+        })
+        .collect()
+}
+
+fn width_profile_cache_key(
+    commands: &[PathCommand],
+    stroke: &Stroke,
+    profile: &WidthProfile,
+    scale: f32,
+) -> OutlineCacheKey {
+    let mut hasher = DefaultHasher::new();
+    commands.hash(&mut hasher);
+    stroke.hash(&mut hasher);
+    profile.hash(&mut hasher);
+    OutlineCacheKey {
+        content_hash: hasher.finish(),
+        size_x_bits: scale.to_bits(),
+        size_y_bits: scale.to_bits(),
+        width_profile: true,
+    }
+}
+
+/// Sweeps a circle whose diameter follows `profile` along each flattened
+/// subpath: a tangent hull per segment, a disc per vertex (so joins are
+/// always round) and the stroke's caps at open and dash ends.
+fn width_profile_paths(
+    commands: &[PathCommand],
+    stroke: &Stroke,
+    profile: &WidthProfile,
+    transform: Transform,
+    tolerance: f32,
+) -> Option<ClipperPaths> {
+    let scale = max_scale(transform);
+    let dash = stroke.dash.as_ref().and_then(|dash| {
+        let lengths: Vec<f32> = dash
+            .normalized_lengths()?
+            .into_iter()
+            .map(|length| length * scale)
+            .collect();
+        Some((lengths, dash.offset * scale))
+    });
+    let mut polygons = Vec::new();
+
+    for mut contour in flatten_commands(commands, transform, tolerance, false) {
+        dedupe_consecutive(&mut contour);
+        let closed = contour.len() > 2 && nearly_same(contour[0], contour[contour.len() - 1]);
+        let mut lengths = Vec::with_capacity(contour.len());
+        let mut total = 0.0;
+        for (i, &point) in contour.iter().enumerate() {
+            if i > 0 {
+                total += distance(contour[i - 1], point);
+            }
+            lengths.push(total);
+        }
+        let radius_at = |at: f32| {
+            let t = if total > 0.0 { at / total } else { 0.0 };
+            profile.width_at(t) * scale * 0.5
+        };
+        if total <= f32::EPSILON {
+            if stroke.cap == StrokeCap::Round {
+                push_disc(&mut polygons, contour[0], radius_at(0.0), tolerance);
+            }
+            continue;
+        }
+
+        // Sample at every vertex and every profile stop so the width stays
+        // piecewise linear along the outline.
+        let mut samples: Vec<(f32, Vec2)> = lengths.iter().copied().zip(contour).collect();
+        for stop in profile.stops() {
+            let at = stop.t * total;
+            if at > 0.0 && at < total {
+                samples.push((at, point_along(&samples, at)));
+            }
+        }
+        samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let spans = match dash.as_ref() {
+            Some((lengths, offset)) => dash_spans(total, lengths, *offset),
+            None => vec![(0.0, total)],
+        };
+        let whole = dash.is_none();
+        for (start, end) in spans {
+            let mut span = vec![(point_along(&samples, start), radius_at(start))];
+            span.extend(
+                samples
+                    .iter()
+                    .filter(|(at, _)| *at > start && *at < end)
+                    .map(|&(at, point)| (point, radius_at(at))),
+            );
+            span.push((point_along(&samples, end), radius_at(end)));
+            push_variable_polyline(&mut polygons, &span, whole && closed, stroke.cap, tolerance);
+        }
+    }
+
+    let paths = contours_to_paths(polygons)?;
+    let merged = union(paths, ClipperPaths::default(), FillRule::NonZero).ok()?;
+    merged.contains_points().then_some(merged)
+}
+
+/// The point `at` units along a polyline sampled as `(arc length, point)`.
+fn point_along(samples: &[(f32, Vec2)], at: f32) -> Vec2 {
+    let next = samples
+        .partition_point(|(length, _)| *length < at)
+        .clamp(1, samples.len() - 1);
+    let (a_at, a) = samples[next - 1];
+    let (b_at, b) = samples[next];
+    let span = b_at - a_at;
+    if span <= f32::EPSILON {
+        return b;
+    }
+    lerp(a, b, ((at - a_at) / span).clamp(0.0, 1.0))
+}
+
+/// The visible `(start, end)` arc-length runs of a dash pattern over a
+/// subpath `total` units long.
+fn dash_spans(total: f32, lengths: &[f32], offset: f32) -> Vec<(f32, f32)> {
+    let period: f32 = lengths.iter().sum();
+    let mut phase = offset.rem_euclid(period);
+    let mut index = 0;
+    while phase >= lengths[index] && phase > 0.0 {
+        phase -= lengths[index];
+        index = (index + 1) % lengths.len();
+    }
+    let mut spans = Vec::new();
+    let mut at = -phase;
+    while at <= total && spans.len() < MAX_DASH_SPANS {
+        let end = at + lengths[index];
+        if index % 2 == 0 && end >= 0.0 {
+            spans.push((at.max(0.0), end.min(total)));
+        }
+        at = end;
+        index = (index + 1) % lengths.len();
+        if index == 0 && period <= f32::EPSILON {
+            break;
+        }
+    }
+    spans
+}
+
+fn push_variable_polyline(
+    polygons: &mut Vec<Vec<Vec2>>,
+    points: &[(Vec2, f32)],
+    closed: bool,
+    cap: StrokeCap,
+    tolerance: f32,
+) {
+    let last = points.len() - 1;
+    for (i, &(point, radius)) in points.iter().enumerate() {
+        let is_end = !closed && (i == 0 || i == last);
+        if !is_end || cap == StrokeCap::Round {
+            push_disc(polygons, point, radius, tolerance);
+        }
+    }
+    for pair in points.windows(2) {
+        let [(p0, r0), (p1, r1)] = [pair[0], pair[1]];
+        let d = distance(p0, p1);
+        if d <= (r0 - r1).abs() + f32::EPSILON {
+            // One disc contains the other; the vertex discs cover it.
+            continue;
+        }
+        let u = Vec2((p1.0 - p0.0) / d, (p1.1 - p0.1) / d);
+        let n = Vec2(-u.1, u.0);
+        let sin = (r0 - r1) / d;
+        let cos = (1.0 - sin * sin).max(0.0).sqrt();
+        let left = Vec2(n.0 * cos + u.0 * sin, n.1 * cos + u.1 * sin);
+        let right = Vec2(-n.0 * cos + u.0 * sin, -n.1 * cos + u.1 * sin);
+        let at = |p: Vec2, dir: Vec2, r: f32| Vec2(p.0 + dir.0 * r, p.1 + dir.1 * r);
+        push_oriented(
+            polygons,
+            vec![
+                at(p0, left, r0),
+                at(p1, left, r1),
+                at(p1, right, r1),
+                at(p0, right, r0),
+            ],
+        );
+    }
+    if !closed && cap != StrokeCap::Round {
+        // Flat ends: the disc's inner half fills out to the perpendicular
+        // through the endpoint, and square caps add the outer box.
+        for (end, inner) in [(0, 1), (last, last - 1)] {
+            let ((p, r), (q, _)) = (points[end], points[inner]);
+            if nearly_same(p, q) || r <= 0.0 {
+                continue;
+            }
+            let d = distance(p, q);
+            // Points out of the stroke at this end.
+            let u = Vec2((p.0 - q.0) / d, (p.1 - q.1) / d);
+            let n = Vec2(-u.1 * r, u.0 * r);
+            let segments = circle_segments(r, tolerance) / 2;
+            let half = (0..=segments)
+                .map(|i| {
+                    let angle = std::f32::consts::PI * i as f32 / segments as f32;
+                    let (sin, cos) = angle.sin_cos();
+                    Vec2(
+                        p.0 + n.0 * cos - u.0 * r * sin,
+                        p.1 + n.1 * cos - u.1 * r * sin,
+                    )
+                })
+                .collect();
+            push_oriented(polygons, half);
+            if cap == StrokeCap::Square {
+                let out = Vec2(p.0 + u.0 * r, p.1 + u.1 * r);
+                push_oriented(
+                    polygons,
+                    vec![
+                        Vec2(p.0 + n.0, p.1 + n.1),
+                        Vec2(out.0 + n.0, out.1 + n.1),
+                        Vec2(out.0 - n.0, out.1 - n.1),
+                        Vec2(p.0 - n.0, p.1 - n.1),
+                    ],
+                );
+            }
+        }
+    }
+}
+
+fn push_disc(polygons: &mut Vec<Vec<Vec2>>, center: Vec2, radius: f32, tolerance: f32) {
+    if radius <= 0.0 || !radius.is_finite() {
+        return;
+    }
+    let segments = circle_segments(radius, tolerance);
+    let disc = (0..segments)
+        .map(|i| {
+            let angle = std::f32::consts::TAU * i as f32 / segments as f32;
+            Vec2(
+                center.0 + radius * angle.cos(),
+                center.1 + radius * angle.sin(),
+            )
+        })
+        .collect();
+    push_oriented(polygons, disc);
+}
+
+fn circle_segments(radius: f32, tolerance: f32) -> usize {
+    if tolerance >= radius {
+        return MIN_CIRCLE_SEGMENTS;
+    }
+    ((std::f32::consts::PI / (1.0 - tolerance / radius).acos()).ceil() as usize)
+        .clamp(MIN_CIRCLE_SEGMENTS, MAX_CIRCLE_SEGMENTS)
+}
+
+/// Pushes `polygon` with positive winding so that nonzero unions of the
+/// pieces never cancel where they overlap.
+fn push_oriented(polygons: &mut Vec<Vec<Vec2>>, mut polygon: Vec<Vec2>) {
+    if polygon_area(&polygon) < 0.0 {
+        polygon.reverse();
+    }
+    polygons.push(polygon);
+}
+
 fn contours_to_paths(contours: Vec<Vec<Vec2>>) -> Option<ClipperPaths> {
     let paths: Vec<Vec<(f64, f64)>> = contours
         .into_iter()
@@ -442,7 +781,7 @@ fn dedupe_consecutive(points: &mut Vec<Vec2>) {
     *points = deduped;
 }
 
-fn flatten_commands(
+pub(super) fn flatten_commands(
     commands: &[PathCommand],
     transform: Transform,
     tolerance: f32,
@@ -576,12 +915,13 @@ fn paths_to_node(paths: &ClipperPaths, paint: Paint) -> Node {
     Node::Path(Path {
         commands,
         fill: Some(Fill { paint }),
+        fill_rule: VectorFillRule::NonZero,
         stroke: None,
         transform: Transform::IDENTITY,
     })
 }
 
-fn paths_to_commands(paths: &ClipperPaths) -> Vec<PathCommand> {
+pub(super) fn paths_to_commands(paths: &ClipperPaths) -> Vec<PathCommand> {
     let raw: Vec<Vec<(f64, f64)>> = paths.clone().into();
     let mut commands = Vec::new();
     for path in raw {
@@ -612,7 +952,7 @@ fn outline_view_box(size: Vec2, inner_view_box: &Rect, paths: Option<&ClipperPat
     bounds
 }
 
-fn paths_bounds(paths: &ClipperPaths) -> Option<Rect> {
+pub(super) fn paths_bounds(paths: &ClipperPaths) -> Option<Rect> {
     let raw: Vec<Vec<(f64, f64)>> = paths.clone().into();
     let mut min_x = f32::INFINITY;
     let mut min_y = f32::INFINITY;
@@ -719,7 +1059,7 @@ fn max_scale(transform: Transform) -> f32 {
 mod tests {
     use super::*;
     use crate::color::Color;
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     fn red() -> Paint {
@@ -758,6 +1098,7 @@ mod tests {
             self.renders.fetch_add(1, Ordering::Relaxed);
             Rectangle {
                 size: Vec2(10.0, 6.0),
+                corners: Corners::SQUARE,
                 fill: Some(red().into()),
                 stroke: None,
             }
@@ -769,6 +1110,7 @@ mod tests {
     fn outset_outline_expands_paint_bounds() {
         let outlined = Rectangle {
             size: Vec2(10.0, 6.0),
+            corners: Corners::SQUARE,
             fill: Some(red().into()),
             stroke: None,
         }
@@ -785,6 +1127,7 @@ mod tests {
     fn inset_outline_keeps_layout_bounds() {
         let outlined = Rectangle {
             size: Vec2(10.0, 6.0),
+            corners: Corners::SQUARE,
             fill: Some(red().into()),
             stroke: None,
         }
@@ -804,6 +1147,7 @@ mod tests {
     fn outlined_outputs_only_the_outline_paint() {
         let outlined = Rectangle {
             size: Vec2(10.0, 6.0),
+            corners: Corners::SQUARE,
             fill: Some(red().into()),
             stroke: None,
         }
@@ -833,6 +1177,7 @@ mod tests {
     fn centered_outline_uses_both_sides() {
         let outlined = Rectangle {
             size: Vec2(10.0, 6.0),
+            corners: Corners::SQUARE,
             fill: Some(red().into()),
             stroke: None,
         }
@@ -932,4 +1277,138 @@ mod tests {
 
         assert_eq!(contours.len(), 3, "one contour per visible dash");
     }
+
+    fn commands_area(commands: &[PathCommand]) -> f32 {
+        flatten_commands(commands, Transform::IDENTITY, DEFAULT_TOLERANCE, true)
+            .into_iter()
+            .map(|contour| polygon_area(&contour))
+            .sum::<f32>()
+            .abs()
+    }
+
+    #[test]
+    fn width_profile_outline_follows_the_profile() {
+        let commands = [
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::LineTo(Vec2(100.0, 0.0)),
+        ];
+        // Widening from nothing to 20 units sweeps a 100 x 20 triangle.
+        let stroke = Stroke::new(red(), 1.0)
+            .with_cap(StrokeCap::Butt)
+            .with_width_profile(WidthProfile::new([(0.0, 0.0), (1.0, 20.0)]));
+
+        let profile = stroke.width_profile.as_ref().unwrap();
+
+        let outline = width_profile_commands(&commands, &stroke, profile, Transform::IDENTITY);
+        let paths = width_profile_paths(
+            &commands,
+            &stroke,
+            profile,
+            Transform::IDENTITY,
+            DEFAULT_TOLERANCE,
+        )
+        .expect("a widening stroke has ink");
+        let bounds = paths_bounds(&paths).unwrap();
+
+        assert!((commands_area(&outline) - 1000.0).abs() < 5.0);
+        assert!(bounds.origin.0.abs() < 0.01 && (bounds.size.0 - 100.0).abs() < 0.01);
+        assert!((bounds.origin.1 + 10.0).abs() < 0.01 && (bounds.size.1 - 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn width_profile_outline_is_independent_of_device_scale() {
+        let commands = [
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::CubicTo {
+                c1: Vec2(10.0, 20.0),
+                c2: Vec2(30.0, -20.0),
+                to: Vec2(40.0, 0.0),
+            },
+        ];
+        let stroke = Stroke::new(red(), 1.0).with_width_profile(WidthProfile::tapered(6.0, 0.25));
+        let profile = stroke.width_profile.as_ref().unwrap();
+
+        let coarse = width_profile_commands(&commands, &stroke, profile, Transform::IDENTITY);
+        let fine = width_profile_commands(
+            &commands,
+            &stroke,
+            profile,
+            Transform::scale(Vec2(8.0, 8.0)),
+        );
+
+        let (coarse, fine) = (commands_area(&coarse), commands_area(&fine));
+        assert!(coarse > 0.0);
+        assert!((coarse - fine).abs() / fine < 0.02, "{coarse} vs {fine}");
+    }
+
+    #[test]
+    fn width_profile_outline_is_cached_per_device_scale() {
+        let commands = [
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::LineTo(Vec2(30.0, 10.0)),
+        ];
+        let stroke = Stroke::new(red(), 1.0).with_width_profile(WidthProfile::tapered(4.0, 0.5));
+        let profile = stroke.width_profile.as_ref().unwrap();
+        let to_device = Transform::scale(Vec2(3.0, 2.0));
+
+        let first = width_profile_commands(&commands, &stroke, profile, to_device);
+        let key = width_profile_cache_key(&commands, &stroke, profile, 3.0);
+        assert!(cached_outline(&key).is_some_and(|entry| entry.paths.is_some()));
+        assert_eq!(
+            width_profile_commands(&commands, &stroke, profile, to_device),
+            first
+        );
+    }
+
+    #[test]
+    fn width_profile_outline_breaks_at_dash_gaps() {
+        let commands = [
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::LineTo(Vec2(100.0, 0.0)),
+        ];
+        let stroke = Stroke::new(red(), 1.0)
+            .with_cap(StrokeCap::Butt)
+            .with_dash(crate::vector::DashPattern::new(vec![10.0, 10.0], 0.0))
+            .with_width_profile(WidthProfile::new([(0.0, 2.0), (1.0, 8.0)]));
+        let profile = stroke.width_profile.as_ref().unwrap();
+
+        let outline = width_profile_commands(&commands, &stroke, profile, Transform::IDENTITY);
+        let dashes = outline
+            .iter()
+            .filter(|command| matches!(command, PathCommand::MoveTo(_)))
+            .count();
+
+        assert_eq!(dashes, 5);
+    }
+
+    #[test]
+    fn fill_silhouette_honors_even_odd_holes() {
+        // Both squares wind the same way, so only even-odd opens the hole.
+        let square = |min: f32, max: f32| {
+            [
+                PathCommand::MoveTo(Vec2(min, min)),
+                PathCommand::LineTo(Vec2(max, min)),
+                PathCommand::LineTo(Vec2(max, max)),
+                PathCommand::LineTo(Vec2(min, max)),
+                PathCommand::Close,
+            ]
+        };
+        let commands: Vec<PathCommand> = square(0.0, 10.0)
+            .into_iter()
+            .chain(square(3.0, 7.0))
+            .collect();
+        let silhouette = |fill_rule| {
+            let paths =
+                path_fill_to_paths(&commands, fill_rule, Transform::IDENTITY, DEFAULT_TOLERANCE)
+                    .expect("donut should produce a silhouette");
+            let merged: Vec<Vec<(f64, f64)>> =
+                union(paths, ClipperPaths::default(), FillRule::NonZero)
+                    .expect("union should succeed")
+                    .into();
+            merged.len()
+        };
+
+        assert_eq!(silhouette(VectorFillRule::NonZero), 1);
+        assert_eq!(silhouette(VectorFillRule::EvenOdd), 2);
+    }
 }
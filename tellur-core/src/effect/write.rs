@@ -14,6 +14,9 @@
 
 use crate::builder::VectorBuilder;
 use crate::geometry::{Constraints, Rect, Transform, Vec2};
+use crate::path_measure::{
+    cubic_length, cubic_t_at_length, path_length, quad_length, quad_t_at_length,
+};
 use crate::phase::Phase;
 use crate::scalar::clamp_unit;
 use crate::time::Time;
@@ -35,8 +38,6 @@ const DEFAULT_LAG_RATIO: f32 = 0.5;
 const DEFAULT_TIMED_FILL_LEAD: f64 = 0.08;
 const DEFAULT_TIMED_FILL_DURATION: f64 = 0.18;
 const DEFAULT_COMPLETED_STROKE_OPACITY: f32 = 0.35;
-const QUAD_STEPS: usize = 16;
-const CUBIC_STEPS: usize = 24;
 
 /// How write time is distributed across the child's writable paths.
 ///
@@ -806,6 +807,7 @@ fn stroke_node(node: Node, walk: &mut StrokeWalk<'_>) -> Node {
         }),
         Node::ClipGroup(group) => Node::ClipGroup(ClipGroup {
             commands: group.commands,
+            fill_rule: group.fill_rule,
             transform: group.transform,
             child: Box::new(stroke_node(*group.child, walk)),
         }),
@@ -836,6 +838,7 @@ fn stroke_path_node(path: Path, walk: &mut StrokeWalk<'_>) -> Node {
     let stroke_node = Node::Path(Path {
         commands,
         fill: None,
+        fill_rule: path.fill_rule,
         stroke: Some(stroke),
         transform: path.transform,
     });
@@ -852,6 +855,7 @@ fn stroke_path_node(path: Path, walk: &mut StrokeWalk<'_>) -> Node {
     if path.fill.as_ref().is_some_and(|fill| fill.is_visible()) {
         let clipped = Node::ClipGroup(ClipGroup {
             commands: path.commands,
+            fill_rule: path.fill_rule,
             transform: path.transform,
             child: Box::new(stroke_node),
         });
@@ -883,47 +887,6 @@ fn temporary_stroke_from_fill(path: &Path) -> bool {
         && path.fill.as_ref().is_some_and(|fill| fill.is_visible())
 }
 
-fn path_length(commands: &[PathCommand]) -> f32 {
-    let mut current = None;
-    let mut start = None;
-    let mut total = 0.0;
-
-    for &cmd in commands {
-        match cmd {
-            PathCommand::MoveTo(p) => {
-                current = Some(p);
-                start = Some(p);
-            }
-            PathCommand::LineTo(to) => {
-                if let Some(from) = current {
-                    total += distance(from, to);
-                }
-                current = Some(to);
-            }
-            PathCommand::QuadTo { control, to } => {
-                if let Some(from) = current {
-                    total += quad_length(from, control, to);
-                }
-                current = Some(to);
-            }
-            PathCommand::CubicTo { c1, c2, to } => {
-                if let Some(from) = current {
-                    total += cubic_length(from, c1, c2, to);
-                }
-                current = Some(to);
-            }
-            PathCommand::Close => {
-                if let (Some(from), Some(to)) = (current, start) {
-                    total += distance(from, to);
-                    current = Some(to);
-                }
-            }
-        }
-    }
-
-    total
-}
-
 fn trim_path(commands: &[PathCommand], target_len: f32) -> Vec<PathCommand> {
     let mut out = Vec::new();
     let mut current = None;
@@ -1072,6 +1035,7 @@ fn fill_node<F: Fn(f64) -> f32>(node: Node, walk: &mut FillWalk<'_, F>) -> Node
         }),
         Node::ClipGroup(group) => Node::ClipGroup(ClipGroup {
             commands: group.commands,
+            fill_rule: group.fill_rule,
             transform: group.transform,
             child: Box::new(fill_node(*group.child, walk)),
         }),
@@ -1094,6 +1058,7 @@ fn fill_path_node<F: Fn(f64) -> f32>(path: Path, walk: &mut FillWalk<'_, F>) ->
     let filled = Node::Path(Path {
         commands: path.commands,
         fill: Some(fill),
+        fill_rule: path.fill_rule,
         stroke: None,
         transform: path.transform,
     });
@@ -1161,21 +1126,6 @@ fn max_scale(transform: Transform) -> f32 {
     }
 }
 
-fn quad_point(p0: Vec2, c: Vec2, p1: Vec2, t: f32) -> Vec2 {
-    let p01 = lerp(p0, c, t);
-    let p12 = lerp(c, p1, t);
-    lerp(p01, p12, t)
-}
-
-fn cubic_point(p0: Vec2, c1: Vec2, c2: Vec2, p1: Vec2, t: f32) -> Vec2 {
-    let p01 = lerp(p0, c1, t);
-    let p12 = lerp(c1, c2, t);
-    let p23 = lerp(c2, p1, t);
-    let p012 = lerp(p01, p12, t);
-    let p123 = lerp(p12, p23, t);
-    lerp(p012, p123, t)
-}
-
 fn quad_prefix(p0: Vec2, c: Vec2, p1: Vec2, t: f32) -> (Vec2, Vec2) {
     let p01 = lerp(p0, c, t);
     let p12 = lerp(c, p1, t);
@@ -1193,63 +1143,15 @@ fn cubic_prefix(p0: Vec2, c1: Vec2, c2: Vec2, p1: Vec2, t: f32) -> (Vec2, Vec2,
     (p01, p012, p0123)
 }
 
-fn quad_length(p0: Vec2, c: Vec2, p1: Vec2) -> f32 {
-    sample_length(QUAD_STEPS, |t| quad_point(p0, c, p1, t))
-}
-
-fn cubic_length(p0: Vec2, c1: Vec2, c2: Vec2, p1: Vec2) -> f32 {
-    sample_length(CUBIC_STEPS, |t| cubic_point(p0, c1, c2, p1, t))
-}
-
-fn sample_length(steps: usize, point_at: impl Fn(f32) -> Vec2) -> f32 {
-    let mut prev = point_at(0.0);
-    let mut total = 0.0;
-    for i in 1..=steps {
-        let p = point_at(i as f32 / steps as f32);
-        total += distance(prev, p);
-        prev = p;
-    }
-    total
-}
-
-fn quad_t_at_length(p0: Vec2, c: Vec2, p1: Vec2, target: f32) -> f32 {
-    t_at_sampled_length(QUAD_STEPS, target, |t| quad_point(p0, c, p1, t))
-}
-
-fn cubic_t_at_length(p0: Vec2, c1: Vec2, c2: Vec2, p1: Vec2, target: f32) -> f32 {
-    t_at_sampled_length(CUBIC_STEPS, target, |t| cubic_point(p0, c1, c2, p1, t))
-}
-
-fn t_at_sampled_length(steps: usize, target: f32, point_at: impl Fn(f32) -> Vec2) -> f32 {
-    let mut prev_t = 0.0;
-    let mut prev = point_at(prev_t);
-    let mut walked = 0.0;
-
-    for i in 1..=steps {
-        let t = i as f32 / steps as f32;
-        let p = point_at(t);
-        let len = distance(prev, p);
-        if walked + len >= target {
-            let local = segment_t(target - walked, len);
-            return prev_t + (t - prev_t) * local;
-        }
-        walked += len;
-        prev = p;
-        prev_t = t;
-    }
-
-    1.0
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::color::Color;
     use crate::geometry::Transform;
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
     use crate::time::{LocalTime, TimelineTime};
     use crate::timeline_component::{Clock, Event, TriggerTable};
-    use crate::vector::{Fill, Paint, VectorTransform};
+    use crate::vector::{Fill, FillRule, Paint, VectorTransform};
 
     fn paint() -> Paint {
         Paint::Solid(Color::rgb_u8(20, 30, 40))
@@ -1258,6 +1160,7 @@ mod tests {
     fn rect() -> Rectangle {
         Rectangle {
             size: Vec2(10.0, 10.0),
+            corners: Corners::SQUARE,
             fill: Some(Fill { paint: paint() }),
             stroke: None,
         }
@@ -1273,6 +1176,7 @@ mod tests {
                 PathCommand::Close,
             ],
             fill: Some(Fill { paint: paint() }),
+            fill_rule: FillRule::NonZero,
             stroke: None,
             transform: Transform::IDENTITY,
         })
@@ -1828,6 +1732,66 @@ mod tests {
         assert!((stroke.opacity - DEFAULT_COMPLETED_STROKE_OPACITY).abs() < 0.000_01);
     }
 
+    /// A single even-odd path: a square with a same-direction square hole.
+    #[derive(Clone, PartialEq, Hash)]
+    struct EvenOddDonut;
+
+    impl VectorComponent for EvenOddDonut {
+        fn layout(&self, constraints: Constraints) -> Vec2 {
+            constraints.constrain(Vec2(10.0, 10.0))
+        }
+
+        fn render(&self, size: Vec2) -> VectorGraphic {
+            let Node::Path(mut path) = rect_path(0.0, 10.0) else {
+                unreachable!("rect_path builds a path");
+            };
+            path.commands.extend([
+                PathCommand::MoveTo(Vec2(3.0, 3.0)),
+                PathCommand::LineTo(Vec2(7.0, 3.0)),
+                PathCommand::LineTo(Vec2(7.0, 7.0)),
+                PathCommand::LineTo(Vec2(3.0, 7.0)),
+                PathCommand::Close,
+            ]);
+            path.fill_rule = FillRule::EvenOdd;
+            VectorGraphic {
+                view_box: Rect {
+                    origin: Vec2::ZERO,
+                    size,
+                },
+                root: Node::Path(path),
+            }
+        }
+    }
+
+    #[test]
+    fn fill_phase_keeps_the_path_fill_rule() {
+        let write = EvenOddDonut
+            .write_on(Phase::saturating(0.7))
+            .stroke_end(Phase::HALF)
+            .fill_delay(Phase::saturating(0.1))
+            .fill_lead(Phase::ZERO)
+            .fill_duration(Phase::saturating(0.2));
+        let graphic = write.render(Vec2(10.0, 10.0));
+        let Node::Group(root) = graphic.root else {
+            panic!("write reveal should render a group");
+        };
+        let Node::SingleGroup(fill) = &root.children[0] else {
+            panic!("first child should be the fading original fill");
+        };
+        let Node::Path(fill) = fill.child.as_ref() else {
+            panic!("the fading fill should wrap the original path");
+        };
+        let Node::SingleGroup(stroke) = &root.children[1] else {
+            panic!("second child should keep the completed temporary stroke");
+        };
+        let Node::ClipGroup(clip) = stroke.child.as_ref() else {
+            panic!("the temporary stroke should be clipped to the fill");
+        };
+
+        assert_eq!(fill.fill_rule, FillRule::EvenOdd);
+        assert_eq!(clip.fill_rule, FillRule::EvenOdd);
+    }
+
     #[test]
     fn partial_line_is_cut_to_remaining_length() {
         let commands = vec![
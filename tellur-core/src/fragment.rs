@@ -140,12 +140,13 @@ mod tests {
     use super::*;
     use crate::color::Color;
     use crate::placement::VectorPlacement;
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
     use crate::vector::Paint;
 
     fn rect(w: f32, h: f32) -> Rectangle {
         Rectangle {
             size: Vec2(w, h),
+            corners: Corners::SQUARE,
             fill: Paint::Solid(Color::rgb_u8(0, 0, 0)).into(),
             stroke: None,
         }
@@ -106,6 +106,19 @@ impl Transform {
         }
     }
 
+    /// Shears by the angles `radians.0` (x shifts as y grows) and
+    /// `radians.1` (y shifts as x grows), like CSS `skew(ax, ay)`.
+    pub fn skew(radians: Vec2) -> Self {
+        Self {
+            a: 1.0,
+            b: radians.1.tan(),
+            c: radians.0.tan(),
+            d: 1.0,
+            tx: 0.0,
+            ty: 0.0,
+        }
+    }
+
     /// Matrix concatenation: `self * child`, applying `child` first and then
     /// `self` to points.
     pub fn concat(self, child: Self) -> Self {
@@ -131,6 +144,24 @@ impl Transform {
             .concat(Self::translate(Vec2(-point.0, -point.1)))
     }
 
+    /// The transform that undoes `self`, or `None` when `self` collapses
+    /// the plane onto a line or a point.
+    pub fn invert(self) -> Option<Self> {
+        let det = self.a * self.d - self.b * self.c;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let inv = 1.0 / det;
+        Some(Self {
+            a: self.d * inv,
+            b: -self.b * inv,
+            c: -self.c * inv,
+            d: self.a * inv,
+            tx: (self.c * self.ty - self.d * self.tx) * inv,
+            ty: (self.b * self.tx - self.a * self.ty) * inv,
+        })
+    }
+
     pub fn transform_point(self, point: Vec2) -> Vec2 {
         Vec2(
             self.a * point.0 + self.c * point.1 + self.tx,
@@ -159,6 +190,96 @@ impl Transform {
     }
 }
 
+/// A projective transform of the plane: a 3×3 matrix, row-major, acting on
+/// `(x, y, 1)` and divided through by the third coordinate.
+///
+/// Affine [`Transform`]s are the homographies whose last row is `0, 0, 1`;
+/// the general case is what a plane tilted in perspective looks like on
+/// screen.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct Homography {
+    pub m: [[f32; 3]; 3],
+}
+
+impl Homography {
+    pub const IDENTITY: Self = Self {
+        m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+
+    /// Matrix product `self * child`, applying `child` first, like
+    /// [`Transform::concat`].
+    pub fn concat(self, child: Self) -> Self {
+        let mut m = [[0.0; 3]; 3];
+        for (r, row) in m.iter_mut().enumerate() {
+            for (c, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.m[r][k] * child.m[k][c]).sum();
+            }
+        }
+        Self { m }
+    }
+
+    /// Returns a homography that applies `self` first, then `next`.
+    pub fn then(self, next: Self) -> Self {
+        next.concat(self)
+    }
+
+    /// The exact inverse (adjugate over determinant), or `None` when `self`
+    /// is singular. Being exact rather than up to scale, it maps a point
+    /// back to `(x, y, 1) / w`, where `w` is the third coordinate `self`
+    /// gave it.
+    pub fn invert(self) -> Option<Self> {
+        let m = self.m;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        let adj = [
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        let det = m[0][0] * adj[0][0] + m[0][1] * adj[1][0] + m[0][2] * adj[2][0];
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        Some(Self {
+            m: adj.map(|row| row.map(|v| v / det)),
+        })
+    }
+
+    /// `point` in homogeneous coordinates `(x·w, y·w, w)`.
+    pub fn apply(self, point: Vec2) -> [f32; 3] {
+        self.m
+            .map(|row| row[0] * point.0 + row[1] * point.1 + row[2])
+    }
+
+    /// `point` mapped and divided through, or `None` when it lands on or
+    /// behind the line at infinity (`w <= 0`).
+    pub fn transform_point(self, point: Vec2) -> Option<Vec2> {
+        let [x, y, w] = self.apply(point);
+        (w > 0.0).then(|| Vec2(x / w, y / w))
+    }
+}
+
+impl From<Transform> for Homography {
+    fn from(t: Transform) -> Self {
+        Self {
+            m: [[t.a, t.c, t.tx], [t.b, t.d, t.ty], [0.0, 0.0, 1.0]],
+        }
+    }
+}
+
 /// A relative position within an axis-aligned box.
 ///
 /// `(rx, ry)` are fractions in `[0, 1]`: `(0, 0)` is top-left, `(1, 1)` is
@@ -430,6 +551,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn invert_undoes_the_transform() {
+        let transform = Transform::rotate(0.3)
+            .then(Transform::skew(Vec2(0.2, 0.0)))
+            .then(Transform::scale(Vec2(2.0, 0.5)))
+            .then(Transform::translate(Vec2(5.0, -3.0)));
+        let inverse = transform.invert().expect("invertible");
+        let point = Vec2(7.0, 11.0);
+        assert_vec_near(
+            inverse.transform_point(transform.transform_point(point)),
+            point,
+        );
+        assert!(Transform::scale(Vec2(0.0, 1.0)).invert().is_none());
+    }
+
+    #[test]
+    fn homography_agrees_with_affine_transforms_and_inverts() {
+        let affine = Transform::rotate(0.7).then(Transform::translate(Vec2(3.0, -1.0)));
+        let point = Vec2(2.0, 5.0);
+        let mapped = Homography::from(affine).transform_point(point).unwrap();
+        assert_vec_near(mapped, affine.transform_point(point));
+
+        let projective = Homography {
+            m: [[1.2, 0.1, 4.0], [-0.2, 0.9, 2.0], [0.01, -0.02, 1.1]],
+        };
+        let [x, y, w] = projective.apply(point);
+        let back = projective.invert().unwrap().apply(Vec2(x / w, y / w));
+        assert_vec_near(Vec2(back[0], back[1]), Vec2(point.0 / w, point.1 / w));
+        assert_near(back[2], 1.0 / w);
+    }
+
     #[test]
     fn transform_rect_returns_axis_aligned_bounds() {
         let rect = Rect {
@@ -0,0 +1,651 @@
+//! Color-grading data shared by the renderer's grading effects and GPU
+//! backends.
+//!
+//! [`ColorAdjustment`] is a fixed chain of per-pixel corrections and
+//! [`CubeLut`] a 3D lookup table parsed from an Adobe/Resolve `.cube` file.
+//! Both work on straight-alpha sRGB values in `[0, 1]` and leave alpha
+//! untouched; [`ColorAdjustment::apply`] and [`CubeLut::sample`] are the
+//! reference the GPU passes are kept in lockstep with.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use crate::color::Color;
+use crate::raster::{PixelFormat, RasterImage, RasterResidency};
+use crate::render_context::{ColorAdjustInput, Lut3dInput, RenderContext};
+use crate::Keyable;
+
+/// Rec. 709 luma weights, applied to the sRGB-encoded channels.
+pub const LUMA_WEIGHTS: [f32; 3] = [0.2126, 0.7152, 0.0722];
+
+/// A chain of color corrections, applied in field order.
+///
+/// Every field's default is the identity, so `..Default::default()` picks
+/// out the corrections to apply. The fields are plain `f32`s (and a
+/// [`Color`]), so they animate with [`PhaseEasing`](crate::easing::PhaseEasing)
+/// and whole adjustments blend through
+/// [`Interpolate`](crate::interpolate::Interpolate).
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct ColorAdjustment {
+    /// Exposure in stops; each stop doubles the channel values.
+    pub exposure: f32,
+    /// Offset added to every channel, in `[-1, 1]` for useful results.
+    pub brightness: f32,
+    /// Contrast multiplier around mid-gray; `0` flattens to gray.
+    pub contrast: f32,
+    /// Saturation multiplier around the pixel's luma; `0` is grayscale.
+    pub saturation: f32,
+    /// Hue rotation in degrees around the gray axis.
+    pub hue: f32,
+    /// Tint color: the pixel is mixed toward `luma * tint` by the tint's
+    /// alpha, so transparent tints leave it unchanged.
+    pub tint: Color,
+    /// Gamma; values above `1` lift the midtones (`out = in^(1/gamma)`).
+    pub gamma: f32,
+}
+
+impl Default for ColorAdjustment {
+    fn default() -> Self {
+        Self {
+            exposure: 0.0,
+            brightness: 0.0,
+            contrast: 1.0,
+            saturation: 1.0,
+            hue: 0.0,
+            tint: Color::rgba_u8(0, 0, 0, 0),
+            gamma: 1.0,
+        }
+    }
+}
+
+impl ColorAdjustment {
+    /// True when [`apply`](Self::apply) returns every color unchanged.
+    pub fn is_identity(&self) -> bool {
+        self.exposure == 0.0
+            && self.brightness == 0.0
+            && self.contrast == 1.0
+            && self.saturation == 1.0
+            && self.hue.rem_euclid(360.0) == 0.0
+            && self.tint.a <= 0.0
+            && self.gamma == 1.0
+    }
+
+    /// Applies the corrections to one straight-alpha sRGB color. The result
+    /// is clamped to `[0, 1]`.
+    pub fn apply(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let scale = self.exposure.exp2();
+        let mut c = rgb.map(|v| (v * scale + self.brightness - 0.5) * self.contrast + 0.5);
+
+        let luma = dot(c, LUMA_WEIGHTS);
+        c = c.map(|v| luma + (v - luma) * self.saturation);
+
+        let [m0, m1, m2] = self.hue_matrix();
+        c = [dot(m0, c), dot(m1, c), dot(m2, c)];
+
+        let amount = self.tint.a.clamp(0.0, 1.0);
+        let luma = dot(c, LUMA_WEIGHTS);
+        let tint = [self.tint.r, self.tint.g, self.tint.b];
+        for i in 0..3 {
+            c[i] += (luma * tint[i] - c[i]) * amount;
+        }
+
+        let inverse_gamma = 1.0 / self.gamma.max(1e-3);
+        c.map(|v| v.clamp(0.0, 1.0).powf(inverse_gamma))
+    }
+
+    /// Rows of the rotation by [`hue`](Self::hue) degrees around the
+    /// `(1, 1, 1)` gray axis.
+    pub fn hue_matrix(&self) -> [[f32; 3]; 3] {
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+        let third = (1.0 - cos) / 3.0;
+        let root = (1.0f32 / 3.0).sqrt() * sin;
+        [
+            [cos + third, third - root, third + root],
+            [third + root, cos + third, third - root],
+            [third - root, third + root, cos + third],
+        ]
+    }
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+/// A 3D color lookup table, sampled trilinearly.
+///
+/// Cloning shares the table. Equality and hashing use a content fingerprint
+/// taken when the table is built, so a LUT is a cheap cache-key term even
+/// at 65³ entries.
+#[derive(Debug, Clone)]
+pub struct CubeLut {
+    size: u32,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+    table: Arc<[[f32; 3]]>,
+    fingerprint: u64,
+}
+
+impl CubeLut {
+    /// Builds a LUT from `size³` entries in `.cube` order: red varies
+    /// fastest, then green, then blue.
+    pub fn new(
+        size: u32,
+        domain_min: [f32; 3],
+        domain_max: [f32; 3],
+        table: Vec<[f32; 3]>,
+    ) -> Result<Self, CubeLutError> {
+        if !(2..=MAX_CUBE_SIZE).contains(&size) {
+            return Err(CubeLutError::InvalidSize(size));
+        }
+        let expected = (size as usize).pow(3);
+        if table.len() != expected {
+            return Err(CubeLutError::EntryCount {
+                expected,
+                actual: table.len(),
+            });
+        }
+        if !(0..3).all(|i| domain_max[i] > domain_min[i]) {
+            return Err(CubeLutError::InvalidDomain {
+                min: domain_min,
+                max: domain_max,
+            });
+        }
+        let mut hasher = DefaultHasher::new();
+        for entry in &table {
+            for value in entry {
+                value.to_bits().hash(&mut hasher);
+            }
+        }
+        Ok(Self {
+            size,
+            domain_min,
+            domain_max,
+            table: table.into(),
+            fingerprint: hasher.finish(),
+        })
+    }
+
+    /// The identity LUT of edge length `size`.
+    pub fn identity(size: u32) -> Self {
+        let n = size.clamp(2, MAX_CUBE_SIZE);
+        let scale = 1.0 / (n - 1) as f32;
+        let mut table = Vec::with_capacity((n as usize).pow(3));
+        for b in 0..n {
+            for g in 0..n {
+                for r in 0..n {
+                    table.push([r as f32 * scale, g as f32 * scale, b as f32 * scale]);
+                }
+            }
+        }
+        Self::new(n, [0.0; 3], [1.0; 3], table).expect("identity LUT is well-formed")
+    }
+
+    /// Loads a `.cube` file from disk.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, CubeLutError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| CubeLutError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&text)
+    }
+
+    /// Parses the text of a `.cube` file. `LUT_3D_INPUT_RANGE` (as Resolve
+    /// writes it) sets the same domain on every channel, like a matching
+    /// `DOMAIN_MIN`/`DOMAIN_MAX` pair. `TITLE`, comments, blank lines and
+    /// other keywords (such as `LUT_1D_INPUT_RANGE` or vendor tags) are
+    /// skipped. 1D LUTs are rejected, and so are 3D LUTs behind a 1D shaper.
+    pub fn parse(text: &str) -> Result<Self, CubeLutError> {
+        let mut size = None;
+        let mut shaper = false;
+        let mut domain_min = [0.0; 3];
+        let mut domain_max = [1.0; 3];
+        let mut table = Vec::new();
+        for (index, raw) in text.lines().enumerate() {
+            let line = index + 1;
+            let content = raw.split('#').next().unwrap_or_default().trim();
+            let mut words = content.split_whitespace();
+            let Some(first) = words.next() else {
+                continue;
+            };
+            match first {
+                "TITLE" => {}
+                "LUT_1D_SIZE" => shaper = true,
+                "LUT_3D_SIZE" => {
+                    let value = words.next().unwrap_or_default();
+                    let parsed = value
+                        .parse::<u32>()
+                        .map_err(|_| CubeLutError::parse(line, "LUT_3D_SIZE needs an integer"))?;
+                    size = Some(parsed);
+                }
+                "DOMAIN_MIN" => domain_min = triple(words, line)?,
+                "DOMAIN_MAX" => domain_max = triple(words, line)?,
+                "LUT_3D_INPUT_RANGE" => {
+                    let [min, max] = numbers(words, line)?;
+                    domain_min = [min; 3];
+                    domain_max = [max; 3];
+                }
+                word if word.starts_with(|c: char| c.is_ascii_alphabetic()) => {}
+                _ => table.push(triple(content.split_whitespace(), line)?),
+            }
+        }
+        match (size, shaper) {
+            (Some(size), false) => Self::new(size, domain_min, domain_max, table),
+            (Some(_), true) => Err(CubeLutError::UnsupportedShaper),
+            (None, true) => Err(CubeLutError::Unsupported1d),
+            (None, false) => Err(CubeLutError::MissingSize),
+        }
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    pub fn domain(&self) -> ([f32; 3], [f32; 3]) {
+        (self.domain_min, self.domain_max)
+    }
+
+    /// The `size³` entries in `.cube` order.
+    pub fn table(&self) -> &[[f32; 3]] {
+        &self.table
+    }
+
+    /// Content fingerprint of the table; GPU backends key uploads by it.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Looks `rgb` up with trilinear interpolation. Inputs outside the
+    /// domain clamp to its edge.
+    pub fn sample(&self, rgb: [f32; 3]) -> [f32; 3] {
+        let last = (self.size - 1) as f32;
+        let mut base = [0usize; 3];
+        let mut frac = [0.0f32; 3];
+        for i in 0..3 {
+            let unit = ((rgb[i] - self.domain_min[i]) / (self.domain_max[i] - self.domain_min[i]))
+                .clamp(0.0, 1.0);
+            let scaled = unit * last;
+            let floor = scaled.floor().min(last - 1.0);
+            base[i] = floor as usize;
+            frac[i] = scaled - floor;
+        }
+        let n = self.size as usize;
+        let at = |r: usize, g: usize, b: usize| self.table[r + g * n + b * n * n];
+        let lerp = |a: [f32; 3], b: [f32; 3], t: f32| {
+            [
+                a[0] + (b[0] - a[0]) * t,
+                a[1] + (b[1] - a[1]) * t,
+                a[2] + (b[2] - a[2]) * t,
+            ]
+        };
+        let [r, g, b] = base;
+        let plane = |b: usize| {
+            let near = lerp(at(r, g, b), at(r + 1, g, b), frac[0]);
+            let far = lerp(at(r, g + 1, b), at(r + 1, g + 1, b), frac[0]);
+            lerp(near, far, frac[1])
+        };
+        lerp(plane(b), plane(b + 1), frac[2])
+    }
+}
+
+impl PartialEq for CubeLut {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.fingerprint == other.fingerprint
+            && self.domain_min.map(f32::to_bits) == other.domain_min.map(f32::to_bits)
+            && self.domain_max.map(f32::to_bits) == other.domain_max.map(f32::to_bits)
+            && (Arc::ptr_eq(&self.table, &other.table)
+                || self.table.iter().flatten().map(|v| v.to_bits()).eq(other
+                    .table
+                    .iter()
+                    .flatten()
+                    .map(|v| v.to_bits())))
+    }
+}
+
+impl Eq for CubeLut {}
+
+impl Hash for CubeLut {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size.hash(state);
+        self.domain_min.map(f32::to_bits).hash(state);
+        self.domain_max.map(f32::to_bits).hash(state);
+        self.fingerprint.hash(state);
+    }
+}
+
+/// Largest accepted `LUT_3D_SIZE`; the `.cube` specification caps it at 256.
+pub const MAX_CUBE_SIZE: u32 = 256;
+
+/// One frame's grade: what the renderer's `ColorAdjust` and `Lut3d`
+/// effects and the timeline's [`Graded`](crate::timeline_component::Graded)
+/// wrapper hand to [`apply`](Self::apply).
+#[derive(Debug, Clone, Keyable)]
+pub enum Grade {
+    Adjust(ColorAdjustment),
+    /// `mix` blends from the input (`0`) to the looked-up color (`1`).
+    Lut {
+        lut: CubeLut,
+        mix: f32,
+    },
+}
+
+impl Grade {
+    /// True when grading leaves every pixel unchanged.
+    pub fn is_identity(&self) -> bool {
+        match self {
+            Self::Adjust(adjustment) => adjustment.is_identity(),
+            Self::Lut { mix, .. } => *mix <= 0.0,
+        }
+    }
+
+    /// Grades `image`, on the GPU when the context has a backend for it and
+    /// on the CPU otherwise.
+    pub fn apply(
+        &self,
+        image: RasterImage,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        if self.is_identity() {
+            return ctx.ensure_residency(image, residency);
+        }
+        if ctx.prefers_gpu() {
+            if let Some(gpu) = ctx.gpu_backend() {
+                let graded = match self {
+                    Self::Adjust(adjustment) => gpu.color_adjust(ColorAdjustInput {
+                        image: &image,
+                        adjustment: *adjustment,
+                    }),
+                    Self::Lut { lut, mix } => gpu.lut3d(Lut3dInput {
+                        image: &image,
+                        lut,
+                        mix: *mix,
+                    }),
+                };
+                if let Some(graded) = graded {
+                    return ctx.ensure_residency(graded, residency);
+                }
+            }
+        }
+
+        let mut image = ctx.readback(image);
+        assert_eq!(
+            image.format,
+            PixelFormat::Rgba8,
+            "grading only supports straight-alpha Rgba8 images",
+        );
+        let mut pixels = image.pixels.to_vec();
+        self.apply_pixels(&mut pixels);
+        image.pixels = pixels.into();
+        ctx.ensure_residency(RasterImage::Cpu(image), residency)
+    }
+
+    /// Grades straight-alpha `Rgba8` pixels in place; alpha is untouched.
+    pub fn apply_pixels(&self, pixels: &mut [u8]) {
+        let grade = |rgb: [f32; 3]| match self {
+            Self::Adjust(adjustment) => adjustment.apply(rgb),
+            Self::Lut { lut, mix } => {
+                let mix = mix.clamp(0.0, 1.0);
+                let looked_up = lut.sample(rgb);
+                [0, 1, 2].map(|i| (rgb[i] + (looked_up[i] - rgb[i]) * mix).clamp(0.0, 1.0))
+            }
+        };
+        for px in pixels.chunks_exact_mut(4) {
+            let rgb = [px[0], px[1], px[2]].map(|v| f32::from(v) / 255.0);
+            let graded = grade(rgb);
+            for i in 0..3 {
+                px[i] = (graded[i] * 255.0).round() as u8;
+            }
+        }
+    }
+}
+
+fn triple<'a>(words: impl Iterator<Item = &'a str>, line: usize) -> Result<[f32; 3], CubeLutError> {
+    numbers(words, line)
+}
+
+/// Exactly `N` finite numbers from the rest of a line.
+fn numbers<'a, const N: usize>(
+    mut words: impl Iterator<Item = &'a str>,
+    line: usize,
+) -> Result<[f32; N], CubeLutError> {
+    let expected = || CubeLutError::parse(line, format!("expected {N} numbers"));
+    let mut out = [0.0; N];
+    for value in &mut out {
+        let word = words.next().ok_or_else(expected)?;
+        *value = word
+            .parse::<f32>()
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| CubeLutError::parse(line, format!("invalid number {word:?}")))?;
+    }
+    if words.next().is_some() {
+        return Err(expected());
+    }
+    Ok(out)
+}
+
+#[derive(Debug, Error)]
+pub enum CubeLutError {
+    #[error("failed to read LUT {path:?}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("line {line}: {message}")]
+    Parse { line: usize, message: String },
+    #[error("1D LUTs are not supported; expected LUT_3D_SIZE")]
+    Unsupported1d,
+    #[error("3D LUTs with a 1D shaper (LUT_1D_SIZE and LUT_3D_SIZE) are not supported")]
+    UnsupportedShaper,
+    #[error("missing LUT_3D_SIZE")]
+    MissingSize,
+    #[error("LUT_3D_SIZE {0} is outside 2..=256")]
+    InvalidSize(u32),
+    #[error("LUT has {actual} entries, expected {expected}")]
+    EntryCount { expected: usize, actual: usize },
+    #[error("empty LUT domain {min:?}..{max:?}")]
+    InvalidDomain { min: [f32; 3], max: [f32; 3] },
+}
+
+impl CubeLutError {
+    fn parse(line: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: [f32; 3], b: [f32; 3]) -> bool {
+        (0..3).all(|i| (a[i] - b[i]).abs() < 1e-4)
+    }
+
+    #[test]
+    fn default_adjustment_is_identity() {
+        let adjustment = ColorAdjustment::default();
+
+        assert!(adjustment.is_identity());
+        assert!(close(adjustment.apply([0.2, 0.5, 0.9]), [0.2, 0.5, 0.9]));
+    }
+
+    #[test]
+    fn exposure_brightness_and_contrast_shift_levels() {
+        let exposure = ColorAdjustment {
+            exposure: 1.0,
+            ..Default::default()
+        };
+        let brightness = ColorAdjustment {
+            brightness: -0.1,
+            ..Default::default()
+        };
+        let contrast = ColorAdjustment {
+            contrast: 2.0,
+            ..Default::default()
+        };
+
+        assert!(close(exposure.apply([0.2, 0.3, 0.7]), [0.4, 0.6, 1.0]));
+        assert!(close(brightness.apply([0.2, 0.3, 0.05]), [0.1, 0.2, 0.0]));
+        assert!(close(contrast.apply([0.5, 0.6, 0.3]), [0.5, 0.7, 0.1]));
+    }
+
+    #[test]
+    fn zero_saturation_is_luma_gray() {
+        let adjustment = ColorAdjustment {
+            saturation: 0.0,
+            ..Default::default()
+        };
+
+        let [r, g, b] = adjustment.apply([1.0, 0.0, 0.0]);
+
+        assert!((r - 0.2126).abs() < 1e-4 && r == g && g == b);
+    }
+
+    #[test]
+    fn hue_rotates_primaries_around_the_gray_axis() {
+        let adjustment = ColorAdjustment {
+            hue: 120.0,
+            ..Default::default()
+        };
+
+        assert!(close(adjustment.apply([1.0, 0.0, 0.0]), [0.0, 1.0, 0.0]));
+        assert!(close(adjustment.apply([0.4, 0.4, 0.4]), [0.4, 0.4, 0.4]));
+    }
+
+    #[test]
+    fn tint_maps_luma_onto_the_tint_color() {
+        let adjustment = ColorAdjustment {
+            tint: Color::rgba_u8(255, 128, 0, 255),
+            ..Default::default()
+        };
+
+        let [r, g, b] = adjustment.apply([1.0, 1.0, 1.0]);
+
+        assert!(close([r, g, b], [1.0, 128.0 / 255.0, 0.0]));
+    }
+
+    #[test]
+    fn gamma_lifts_midtones() {
+        let adjustment = ColorAdjustment {
+            gamma: 2.0,
+            ..Default::default()
+        };
+
+        assert!(close(adjustment.apply([0.25, 0.0, 1.0]), [0.5, 0.0, 1.0]));
+    }
+
+    #[test]
+    fn parses_cube_text_and_samples_trilinearly() {
+        let text = "\
+# inverted 2x2x2 LUT
+TITLE \"invert\"
+LUT_3D_SIZE 2
+
+1 1 1
+0 1 1
+1 0 1
+0 0 1
+1 1 0
+0 1 0
+1 0 0
+0 0 0
+";
+        let lut = CubeLut::parse(text).expect("valid cube");
+
+        assert_eq!(lut.size(), 2);
+        assert!(close(lut.sample([0.0, 0.0, 0.0]), [1.0, 1.0, 1.0]));
+        assert!(close(lut.sample([0.25, 0.5, 1.0]), [0.75, 0.5, 0.0]));
+        assert!(close(lut.sample([2.0, -1.0, 0.5]), [0.0, 1.0, 0.5]));
+    }
+
+    #[test]
+    fn domain_rescales_lookups() {
+        let text = "LUT_3D_SIZE 2\nDOMAIN_MIN 0 0 0\nDOMAIN_MAX 2 2 2\n\
+                    0 0 0\n1 0 0\n0 1 0\n1 1 0\n0 0 1\n1 0 1\n0 1 1\n1 1 1\n";
+        let lut = CubeLut::parse(text).expect("valid cube");
+
+        assert!(close(lut.sample([1.0, 0.5, 2.0]), [0.5, 0.25, 1.0]));
+    }
+
+    #[test]
+    fn reads_resolve_style_headers() {
+        // As DaVinci Resolve exports it, with a 1D range beside the 3D one,
+        // plus a keyword no version of the format defines.
+        let text = "\
+TITLE \"Generated by Resolve\"
+LUT_3D_SIZE 2
+LUT_1D_INPUT_RANGE 0.0 1.0
+LUT_3D_INPUT_RANGE 0.0 2.0
+SOME_FUTURE_KEYWORD 1
+
+0 0 0
+1 0 0
+0 1 0
+1 1 0
+0 0 1
+1 0 1
+0 1 1
+1 1 1
+";
+        let lut = CubeLut::parse(text).expect("Resolve cube");
+
+        assert_eq!(lut.domain(), ([0.0; 3], [2.0; 3]));
+        assert!(close(lut.sample([1.0, 0.5, 2.0]), [0.5, 0.25, 1.0]));
+        assert!(matches!(
+            CubeLut::parse("LUT_3D_SIZE 2\nLUT_3D_INPUT_RANGE 0\n"),
+            Err(CubeLutError::Parse { line: 2, .. })
+        ));
+    }
+
+    #[test]
+    fn identity_lut_returns_its_input() {
+        let lut = CubeLut::identity(17);
+
+        assert!(close(lut.sample([0.13, 0.5, 0.91]), [0.13, 0.5, 0.91]));
+        assert_eq!(lut, CubeLut::identity(17));
+        assert_ne!(lut, CubeLut::identity(9));
+    }
+
+    #[test]
+    fn malformed_cube_files_are_rejected() {
+        assert!(matches!(
+            CubeLut::parse("0 0 0\n"),
+            Err(CubeLutError::MissingSize)
+        ));
+        assert!(matches!(
+            CubeLut::parse("LUT_1D_SIZE 4\n"),
+            Err(CubeLutError::Unsupported1d)
+        ));
+        let shaper = "LUT_1D_SIZE 2\nLUT_3D_SIZE 2\n0 0 0\n1 1 1\n";
+        assert!(matches!(
+            CubeLut::parse(&format!("{shaper}{}", "0 0 0\n".repeat(8))),
+            Err(CubeLutError::UnsupportedShaper)
+        ));
+        assert!(matches!(
+            CubeLut::parse("LUT_3D_SIZE 2\n0 0 0\n"),
+            Err(CubeLutError::EntryCount {
+                expected: 8,
+                actual: 1
+            })
+        ));
+        assert!(matches!(
+            CubeLut::parse("LUT_3D_SIZE 2\n0 zero 0\n"),
+            Err(CubeLutError::Parse { line: 2, .. })
+        ));
+        assert!(matches!(
+            CubeLut::load("/nonexistent/look.cube"),
+            Err(CubeLutError::Io { .. })
+        ));
+    }
+}
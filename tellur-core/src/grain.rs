@@ -0,0 +1,455 @@
+//! Film grain and output dithering, shared by the renderer's `Grain` effect,
+//! the encoder's dither step and GPU backends.
+//!
+//! Both are driven by integer hashes and tables rather than a random number
+//! generator, so a frame's noise is a pure function of its seed, its frame
+//! number and the pixel position: re-rendering a frame, or rendering it on the
+//! GPU, reproduces the same grain. [`grain_pixels`] and [`dither_pixels`] are
+//! the references the GPU passes are kept in lockstep with; they stick to
+//! integer math and correctly rounded float operations (no division), so the
+//! two agree to within one code value.
+
+use std::sync::OnceLock;
+
+use crate::raster::{CpuRasterImage, PixelFormat};
+
+/// Side length of the tiling [`blue_noise_ranks`] table.
+pub const BLUE_NOISE_SIZE: u32 = 64;
+
+/// Side length of the Bayer matrix [`Dither::Ordered`] tiles.
+pub const BAYER_SIZE: u32 = 8;
+
+/// The noise a grain pass adds to one frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrainNoise {
+    /// Peak deviation, as a fraction of full scale. Each channel moves by a
+    /// triangular-distributed amount within `±amount`.
+    pub amount: f32,
+    /// Grain cells per pixel along each axis: `1 / (grain size in pixels)`.
+    pub cells_per_pixel: f32,
+    /// Picks one of many unrelated grain patterns.
+    pub seed: u32,
+    /// The noise frame; each one is an independent pattern.
+    pub frame: u32,
+    /// Whether every channel moves together, as luminance grain, instead of
+    /// each getting its own noise.
+    pub monochrome: bool,
+}
+
+impl GrainNoise {
+    /// The noise frame showing at `time` seconds when the grain refreshes
+    /// `rate` times a second. A `rate` of zero (or less) freezes the grain on
+    /// frame `0`. Frames wrap rather than saturate, so time never runs out of
+    /// patterns.
+    pub fn frame_at(time: f64, rate: f64) -> u32 {
+        let frame = time * rate;
+        if rate > 0.0 && frame.is_finite() {
+            frame.floor() as i64 as u32
+        } else {
+            0
+        }
+    }
+}
+
+/// How the encoder dithers a finished frame before its 8-bit YUV conversion.
+///
+/// The frame is already 8-bit, so the dither does not add precision: it
+/// nudges each pixel by at most one code value along a fine, zero-mean
+/// pattern. A smooth gradient's steps then no longer line up into long flat
+/// bands, which the lossy encode would otherwise keep as visible contours.
+/// The nudge is the same on every channel, so it lands in luma and survives
+/// chroma subsampling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dither {
+    /// A static 8×8 Bayer matrix: cheap and stable, with a faint
+    /// cross-hatch.
+    Ordered,
+    /// A 64×64 blue-noise tile, shifted every frame: no visible structure,
+    /// and the shift averages the noise out over time.
+    BlueNoise,
+}
+
+impl Dither {
+    /// How far the pattern tile is shifted on `frame`, in pixels. Blue noise
+    /// steps by an R2 low-discrepancy sequence so consecutive frames never
+    /// line up; the ordered matrix stays put.
+    pub fn offset(self, frame: u64) -> [u32; 2] {
+        match self {
+            Dither::Ordered => [0, 0],
+            Dither::BlueNoise => {
+                // The R2 sequence's two irrational steps, 1/φ₂ and 1/φ₂².
+                let n = (frame % (1 << 24)) as f64;
+                let size = BLUE_NOISE_SIZE as f64;
+                [
+                    ((n * 0.754_877_666_246_692_7).fract() * size) as u32,
+                    ((n * 0.569_840_290_998_053_2).fract() * size) as u32,
+                ]
+            }
+        }
+    }
+
+    /// The code-value step at pixel `(x, y)` once the tile is shifted by
+    /// `offset`: `-1` on the pattern's lowest quarter, `+1` on its highest and
+    /// `0` between, which keeps the mean unchanged.
+    pub fn step(self, x: u32, y: u32, offset: [u32; 2]) -> i32 {
+        let (rank, count) = match self {
+            Dither::Ordered => (
+                bayer_rank(x.wrapping_add(offset[0]), y.wrapping_add(offset[1])),
+                BAYER_SIZE * BAYER_SIZE,
+            ),
+            Dither::BlueNoise => {
+                let tx = x.wrapping_add(offset[0]) % BLUE_NOISE_SIZE;
+                let ty = y.wrapping_add(offset[1]) % BLUE_NOISE_SIZE;
+                (
+                    blue_noise_ranks()[(ty * BLUE_NOISE_SIZE + tx) as usize] as u32,
+                    BLUE_NOISE_SIZE * BLUE_NOISE_SIZE,
+                )
+            }
+        };
+        if rank * 4 < count {
+            -1
+        } else if rank * 4 >= count * 3 {
+            1
+        } else {
+            0
+        }
+    }
+}
+
+/// Adds `noise` to a straight-alpha `Rgba8` image, returning the new pixels.
+/// Alpha is kept, and fully transparent pixels are left alone.
+pub fn grain_pixels(image: &CpuRasterImage, noise: GrainNoise) -> Vec<u8> {
+    assert_eq!(
+        image.format,
+        PixelFormat::Rgba8,
+        "grain only supports straight-alpha Rgba8 images",
+    );
+    let mut out = image.pixels.to_vec();
+    if noise.amount == 0.0 {
+        return out;
+    }
+    let amount = noise.amount * 255.0;
+    let frame_seed = pcg(noise.frame.wrapping_add(pcg(noise.seed)));
+    for y in 0..image.height {
+        let cy = cell(y, noise.cells_per_pixel);
+        let row_seed = pcg(cy.wrapping_add(frame_seed));
+        for x in 0..image.width {
+            let px = &mut out[(y as usize * image.width as usize + x as usize) * 4..][..4];
+            if px[3] == 0 {
+                continue;
+            }
+            let base = pcg(cell(x, noise.cells_per_pixel).wrapping_add(row_seed));
+            for (channel, value) in px[..3].iter_mut().enumerate() {
+                let hash = if noise.monochrome {
+                    base
+                } else {
+                    pcg(base.wrapping_add(channel as u32))
+                };
+                let grained = *value as f32 + triangular(hash) * amount;
+                *value = (grained.clamp(0.0, 255.0) + 0.5).floor() as u8;
+            }
+        }
+    }
+    out
+}
+
+/// Dithers a straight-alpha `Rgba8` image for output, as
+/// [`Dither::step`] describes, returning the new pixels. Alpha is kept.
+pub fn dither_pixels(image: &CpuRasterImage, dither: Dither, frame: u64) -> Vec<u8> {
+    assert_eq!(
+        image.format,
+        PixelFormat::Rgba8,
+        "dithering only supports straight-alpha Rgba8 images",
+    );
+    let offset = dither.offset(frame);
+    let mut out = image.pixels.to_vec();
+    for y in 0..image.height {
+        for x in 0..image.width {
+            let step = dither.step(x, y, offset);
+            if step == 0 {
+                continue;
+            }
+            let px = &mut out[(y as usize * image.width as usize + x as usize) * 4..][..4];
+            for value in &mut px[..3] {
+                *value = (*value as i32 + step).clamp(0, 255) as u8;
+            }
+        }
+    }
+    out
+}
+
+/// The rank of `(x, y)` in the tiling 8×8 Bayer matrix, `0..64`.
+pub fn bayer_rank(x: u32, y: u32) -> u32 {
+    let mut rank = 0;
+    for bit in 0..3 {
+        let (xb, yb) = ((x >> bit) & 1, (y >> bit) & 1);
+        rank |= (((xb ^ yb) << 1) | yb) << (2 * (2 - bit));
+    }
+    rank
+}
+
+/// A 64×64 blue-noise tile, row-major: every pixel's rank `0..4096` in a
+/// void-and-cluster ordering, so thresholding at any level spreads the
+/// chosen pixels evenly with no low-frequency clumps. Generated once per
+/// process; the generator is deterministic, so every run gets the same tile.
+pub fn blue_noise_ranks() -> &'static [u16] {
+    static RANKS: OnceLock<Vec<u16>> = OnceLock::new();
+    RANKS.get_or_init(void_and_cluster)
+}
+
+/// Ulichney's void-and-cluster method on a torus, with a Gaussian energy
+/// filter of σ = 1.5 pixels.
+fn void_and_cluster() -> Vec<u16> {
+    const N: usize = BLUE_NOISE_SIZE as usize;
+    const SIGMA: f32 = 1.5;
+    let len = N * N;
+    let kernel: Vec<f32> = (0..len)
+        .map(|i| {
+            let (x, y) = (i % N, i / N);
+            let (dx, dy) = (x.min(N - x) as f32, y.min(N - y) as f32);
+            (-(dx * dx + dy * dy) / (2.0 * SIGMA * SIGMA)).exp()
+        })
+        .collect();
+    let splat = |energy: &mut [f32], at: usize, sign: f32| {
+        let (ax, ay) = (at % N, at / N);
+        for y in 0..N {
+            let ky = (y + N - ay) % N;
+            for x in 0..N {
+                energy[y * N + x] += sign * kernel[ky * N + (x + N - ax) % N];
+            }
+        }
+    };
+    // The tightest cluster among set pixels, or the largest void among unset
+    // ones. Ties go to the lowest index, keeping the result deterministic.
+    let extreme = |energy: &[f32], set: &[bool], want: bool| {
+        let mut best: Option<usize> = None;
+        for i in (0..len).filter(|&i| set[i] == want) {
+            let better = match best {
+                None => true,
+                Some(b) if want => energy[i] > energy[b],
+                Some(b) => energy[i] < energy[b],
+            };
+            if better {
+                best = Some(i);
+            }
+        }
+        best.expect("a pixel of each kind exists")
+    };
+
+    // A sparse random start, relaxed by moving its tightest cluster into its
+    // largest void until the two coincide. Float ties could keep the swap
+    // cycling, so relaxing stops after `len` swaps regardless; the start is
+    // well spread by then.
+    let initial = len / 10;
+    let mut set = vec![false; len];
+    let mut energy = vec![0.0f32; len];
+    let mut state = 0x9e37_79b9u32;
+    let mut placed = 0;
+    while placed < initial {
+        state = pcg(state);
+        let at = state as usize % len;
+        if !set[at] {
+            set[at] = true;
+            splat(&mut energy, at, 1.0);
+            placed += 1;
+        }
+    }
+    for _ in 0..len {
+        let cluster = extreme(&energy, &set, true);
+        set[cluster] = false;
+        splat(&mut energy, cluster, -1.0);
+        let void = extreme(&energy, &set, false);
+        set[void] = true;
+        splat(&mut energy, void, 1.0);
+        if void == cluster {
+            break;
+        }
+    }
+
+    let mut ranks = vec![0u16; len];
+    // Ranks below the start: peel clusters off a copy of it.
+    let (mut peeled, mut peeled_energy) = (set.clone(), energy.clone());
+    for rank in (0..initial).rev() {
+        let cluster = extreme(&peeled_energy, &peeled, true);
+        peeled[cluster] = false;
+        splat(&mut peeled_energy, cluster, -1.0);
+        ranks[cluster] = rank as u16;
+    }
+    // Ranks above it: fill the largest void, one pixel at a time.
+    for rank in initial..len {
+        let void = extreme(&energy, &set, false);
+        set[void] = true;
+        splat(&mut energy, void, 1.0);
+        ranks[void] = rank as u16;
+    }
+    ranks
+}
+
+/// The grain cell containing pixel `i` along one axis.
+fn cell(i: u32, cells_per_pixel: f32) -> u32 {
+    ((i as f32 + 0.5) * cells_per_pixel).floor() as u32
+}
+
+/// A triangular-distributed value in `(-1, 1)` from the two halves of a
+/// hash: the sum of two uniforms, so small deviations dominate as in real
+/// grain.
+fn triangular(hash: u32) -> f32 {
+    const UNIT: f32 = 1.0 / 65536.0;
+    let a = (hash & 0xffff) as f32 * UNIT;
+    let b = (hash >> 16) as f32 * UNIT;
+    a + b - 1.0 + UNIT
+}
+
+/// The PCG-RXS-M-XS hash: a well-mixed `u32` from a `u32`, cheap on both CPU
+/// and GPU.
+pub(crate) fn pcg(v: u32) -> u32 {
+    let state = v.wrapping_mul(747_796_405).wrapping_add(2_891_336_453);
+    let word = ((state >> ((state >> 28) + 4)) ^ state).wrapping_mul(277_803_737);
+    (word >> 22) ^ word
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flat(width: u32, height: u32, px: [u8; 4]) -> CpuRasterImage {
+        CpuRasterImage::new(
+            width,
+            height,
+            PixelFormat::Rgba8,
+            px.repeat((width * height) as usize),
+        )
+    }
+
+    fn noise() -> GrainNoise {
+        GrainNoise {
+            amount: 0.1,
+            cells_per_pixel: 1.0,
+            seed: 7,
+            frame: 0,
+            monochrome: true,
+        }
+    }
+
+    fn mean_red(pixels: &[u8]) -> f32 {
+        let reds: Vec<f32> = pixels.chunks_exact(4).map(|px| px[0] as f32).collect();
+        reds.iter().sum::<f32>() / reds.len() as f32
+    }
+
+    #[test]
+    fn grain_is_a_pure_function_of_seed_and_frame() {
+        let image = flat(32, 32, [128, 128, 128, 255]);
+        let first = grain_pixels(&image, noise());
+        assert_eq!(grain_pixels(&image, noise()), first);
+        assert_ne!(first, image.pixels.to_vec());
+        let next_frame = GrainNoise {
+            frame: 1,
+            ..noise()
+        };
+        assert_ne!(grain_pixels(&image, next_frame), first);
+        let other_seed = GrainNoise { seed: 8, ..noise() };
+        assert_ne!(grain_pixels(&image, other_seed), first);
+
+        let none = GrainNoise {
+            amount: 0.0,
+            ..noise()
+        };
+        assert_eq!(grain_pixels(&image, none), image.pixels.to_vec());
+    }
+
+    #[test]
+    fn grain_stays_centered_and_bounded() {
+        let image = flat(64, 64, [128, 128, 128, 255]);
+        let grained = grain_pixels(&image, noise());
+        assert!((mean_red(&grained) - 128.0).abs() < 0.5);
+        for px in grained.chunks_exact(4) {
+            assert!(px[0].abs_diff(128) <= 26, "{px:?}");
+            assert_eq!(px[0], px[1], "monochrome grain moves channels together");
+            assert_eq!(px[3], 255);
+        }
+        let color = grain_pixels(
+            &image,
+            GrainNoise {
+                monochrome: false,
+                ..noise()
+            },
+        );
+        assert!(color.chunks_exact(4).any(|px| px[0] != px[1]));
+
+        let clear = flat(4, 4, [128, 128, 128, 0]);
+        assert_eq!(grain_pixels(&clear, noise()), clear.pixels.to_vec());
+    }
+
+    #[test]
+    fn grain_cells_span_several_pixels() {
+        let image = flat(8, 8, [128, 128, 128, 255]);
+        let coarse = GrainNoise {
+            cells_per_pixel: 0.25,
+            ..noise()
+        };
+        let grained = grain_pixels(&image, coarse);
+        // Pixels 0..4 of the first row share one cell.
+        let row: Vec<u8> = grained.chunks_exact(4).take(4).map(|px| px[0]).collect();
+        assert!(row.iter().all(|&v| v == row[0]), "{row:?}");
+    }
+
+    #[test]
+    fn grain_frames_follow_the_refresh_rate() {
+        assert_eq!(GrainNoise::frame_at(0.0, 24.0), 0);
+        assert_eq!(GrainNoise::frame_at(1.0 / 48.0, 24.0), 0);
+        assert_eq!(GrainNoise::frame_at(0.5, 24.0), 12);
+        assert_eq!(GrainNoise::frame_at(3.0, 0.0), 0);
+    }
+
+    #[test]
+    fn bayer_ranks_follow_the_recursive_matrix() {
+        let first_row: Vec<u32> = (0..8).map(|x| bayer_rank(x, 0)).collect();
+        assert_eq!(first_row, vec![0, 32, 8, 40, 2, 34, 10, 42]);
+        let mut all: Vec<u32> = (0..64).map(|i| bayer_rank(i % 8, i / 8)).collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..64).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn blue_noise_is_a_well_spread_permutation() {
+        let ranks = blue_noise_ranks();
+        let mut sorted = ranks.to_vec();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..4096).collect::<Vec<u16>>());
+        // Every 8×8 block holds a fair share of the lowest quarter: a white
+        // noise tile would leave some blocks nearly empty.
+        for by in 0..8 {
+            for bx in 0..8 {
+                let low = (0..64)
+                    .filter(|i| {
+                        let (x, y) = (bx * 8 + i % 8, by * 8 + i / 8);
+                        ranks[y * 64 + x] < 1024
+                    })
+                    .count();
+                assert!((10..=22).contains(&low), "block ({bx}, {by}) has {low}");
+            }
+        }
+    }
+
+    #[test]
+    fn dithering_keeps_the_mean_and_moves_one_code_value() {
+        let image = flat(64, 64, [128, 64, 200, 180]);
+        for dither in [Dither::Ordered, Dither::BlueNoise] {
+            for frame in [0, 5] {
+                let dithered = dither_pixels(&image, dither, frame);
+                assert!((mean_red(&dithered) - 128.0).abs() < 0.01, "{dither:?}");
+                for px in dithered.chunks_exact(4) {
+                    assert!(px[0].abs_diff(128) <= 1);
+                    assert_eq!(px[0] as i32 - 128, px[1] as i32 - 64);
+                    assert_eq!(px[3], 180);
+                }
+            }
+        }
+        assert_eq!(Dither::Ordered.offset(9), [0, 0]);
+        assert_ne!(Dither::BlueNoise.offset(1), Dither::BlueNoise.offset(2));
+        let white = flat(4, 4, [255, 255, 255, 255]);
+        assert!(dither_pixels(&white, Dither::Ordered, 0)
+            .chunks_exact(4)
+            .all(|px| px[0] >= 254));
+    }
+}
@@ -0,0 +1,850 @@
+//! Frame decode for the timeline
+//! [`ImageSequence`](crate::timeline_container::ImageSequence) leaf.
+//!
+//! Two pieces live here, both process-global like
+//! [`video_decode`](crate::video_decode)'s, so the leaf stays `Clone +
+//! Keyable` pure data and none of this state enters a cache key:
+//!
+//! 1. [`probe`] — resolves a pattern to its [`SequenceFrames`]: the numbered
+//!    files a printf-style (`fx_%04d.png`) or glob (`fx_*.png`) pattern
+//!    matches on disk, or the frame delays of one animated GIF, APNG or WebP,
+//!    read from its frame headers without decoding any pixels. The result is
+//!    kept in a small LRU until the directory (or the animated file) changes
+//!    on disk.
+//!
+//! 2. [`decode_frame`] — decodes one frame LAZILY into an LRU of decoded
+//!    frames bounded in bytes, so a long sequence never sits in memory whole.
+//!    An animated image can only be decoded front to back, so each one gets a
+//!    decode [`Cursor`]: a thread that decodes a few frames ahead and resumes
+//!    where the last request left off. In-order playback therefore decodes
+//!    every frame once; only a jump backwards restarts from the first frame.
+//!
+//! The caches and cursors key on the source's length and modification time,
+//! so a frame edited during a live session is read again rather than served
+//! stale.
+
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Seek};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::UNIX_EPOCH;
+
+use image::codecs::gif::GifDecoder;
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::AnimationDecoder;
+use lru::LruCache;
+
+use crate::cache_budget::{cache_ram_capacity, try_reserve_cache_ram, BudgetReservation};
+use crate::raster::{CpuRasterImage, PixelFormat};
+
+/// How many bytes of decoded frames the process-global cache keeps across
+/// every sequence: a few seconds of 1080p for a scrub back and forth over a
+/// short effect. Forward export only ever needs the current frame.
+const FRAME_CACHE_CAPACITY_BYTES: usize = 256 * 1024 * 1024;
+
+/// How many resolved patterns the process-global probe cache keeps.
+const PROBE_CACHE_CAPACITY: usize = 64;
+
+/// How many animated images keep a decode [`Cursor`] running. One per source
+/// that is playing; an evicted source restarts from its first frame.
+const CURSOR_POOL_CAPACITY: usize = 8;
+
+/// How many frames a [`Cursor`] decodes ahead of the last one requested.
+const DECODE_AHEAD: usize = 2;
+
+/// How many frames in a row an animated image may fail to decode before the
+/// rest of it is given up on, so a decoder stuck on a truncated file ends.
+const MAX_FAILED_FRAMES: usize = 8;
+
+/// How long an animated-image frame without a delay is shown, in seconds —
+/// the tenth of a second browsers fall back to.
+const DEFAULT_FRAME_DELAY: f64 = 0.1;
+
+/// The frames a sequence pattern resolved to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SequenceFrames {
+    /// One file per frame, in playback order.
+    Files(Vec<PathBuf>),
+    /// The frames of one animated image. `ends[i]` is the time, in seconds,
+    /// at which frame `i` stops showing.
+    Animated { path: PathBuf, ends: Vec<f64> },
+}
+
+impl SequenceFrames {
+    /// The number of frames.
+    pub fn len(&self) -> usize {
+        match self {
+            SequenceFrames::Files(files) => files.len(),
+            SequenceFrames::Animated { ends, .. } => ends.len(),
+        }
+    }
+
+    /// Whether there are no frames.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Playback length in seconds: one `1 / frame_rate` slot per file, or an
+    /// animated image's summed delays.
+    pub fn duration(&self, frame_rate: f64) -> f64 {
+        match self {
+            SequenceFrames::Files(files) if frame_rate > 0.0 => files.len() as f64 / frame_rate,
+            SequenceFrames::Files(_) => 0.0,
+            SequenceFrames::Animated { ends, .. } => ends.last().copied().unwrap_or(0.0),
+        }
+    }
+
+    /// The frame showing `t` seconds in, or `None` outside
+    /// `[0, duration)`.
+    pub fn index_at(&self, t: f64, frame_rate: f64) -> Option<usize> {
+        if !(t >= 0.0 && t < self.duration(frame_rate)) {
+            return None;
+        }
+        match self {
+            // The epsilon keeps `0.3 * 10.0` on frame 3 rather than 2.
+            SequenceFrames::Files(files) => {
+                let index = (t * frame_rate + 1e-9).floor() as usize;
+                Some(index.min(files.len() - 1))
+            }
+            SequenceFrames::Animated { ends, .. } => Some(ends.partition_point(|&end| end <= t)),
+        }
+    }
+}
+
+/// A file's or directory's length and modification time, for telling when a
+/// cached probe or frame has gone stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct SourceStamp {
+    len: u64,
+    modified_ns: Option<u128>,
+}
+
+impl SourceStamp {
+    fn of(path: &Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok()?;
+        let modified_ns = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+            .map(|duration| duration.as_nanos());
+        Some(Self {
+            len: metadata.len(),
+            modified_ns,
+        })
+    }
+}
+
+/// `pattern → (stamp, resolved frames)`, bounded by [`PROBE_CACHE_CAPACITY`].
+/// The stamp is of the directory a numbered pattern lists, which changes as
+/// files are added or removed, or of the single file a pattern names.
+type ProbeCache = LruCache<String, (SourceStamp, Arc<SequenceFrames>)>;
+
+/// `(file, stamp, frame index)`. A still file is frame 0 of itself.
+type FrameKey = (PathBuf, SourceStamp, usize);
+
+/// Decoded frames, bounded by [`FRAME_CACHE_CAPACITY_BYTES`] and charged to
+/// the process-wide cache RAM budget.
+struct FrameCache {
+    entries: LruCache<FrameKey, CachedFrame>,
+    bytes: usize,
+}
+
+struct CachedFrame {
+    image: CpuRasterImage,
+    _reservation: BudgetReservation,
+}
+
+impl FrameCache {
+    fn get(&mut self, key: &FrameKey) -> Option<CpuRasterImage> {
+        self.entries.get(key).map(|entry| entry.image.clone())
+    }
+
+    fn insert(&mut self, key: FrameKey, image: CpuRasterImage) {
+        let bytes = image.pixels.len();
+        let capacity = cache_ram_capacity(FRAME_CACHE_CAPACITY_BYTES);
+        if bytes > capacity {
+            return;
+        }
+
+        while self.bytes + bytes > capacity {
+            let Some((_, old)) = self.entries.pop_lru() else {
+                break;
+            };
+            self.bytes = self.bytes.saturating_sub(old.image.pixels.len());
+        }
+
+        let Some(reservation) = try_reserve_cache_ram(bytes) else {
+            return;
+        };
+        let entry = CachedFrame {
+            image,
+            _reservation: reservation,
+        };
+        if let Some(old) = self.entries.put(key, entry) {
+            self.bytes = self.bytes.saturating_sub(old.image.pixels.len());
+        }
+        self.bytes += bytes;
+    }
+}
+
+/// A running front-to-back decode of one animated image.
+///
+/// The [`image`] crate's frame iterators are not `Send`, so each lives on its
+/// own thread, which hands frames over a channel [`DECODE_AHEAD`] deep.
+/// Dropping the cursor closes the channel, and the thread ends at its next
+/// frame.
+struct Cursor {
+    /// Each frame in order; `None` for one that failed to decode before any
+    /// frame had (see [`hold_failed`]).
+    frames: Receiver<Option<CpuRasterImage>>,
+    /// The index of the frame `frames` yields next.
+    next: usize,
+}
+
+impl Cursor {
+    fn start(path: &Path) -> Option<Self> {
+        let path = path.to_path_buf();
+        let (sender, frames) = sync_channel(DECODE_AHEAD);
+        thread::Builder::new()
+            .name("tellur-image-sequence".into())
+            .spawn(move || {
+                let Some(decoded) = animation_frames(&path) else {
+                    return;
+                };
+                for frame in hold_failed(decoded.map(|frame| frame.map(frame_image))) {
+                    if sender.send(frame).is_err() {
+                        return;
+                    }
+                }
+            })
+            .ok()?;
+        Some(Self { frames, next: 0 })
+    }
+}
+
+fn probe_cache() -> &'static Mutex<ProbeCache> {
+    static CACHE: OnceLock<Mutex<ProbeCache>> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        Mutex::new(LruCache::new(
+            NonZeroUsize::new(PROBE_CACHE_CAPACITY).expect("probe cache capacity is non-zero"),
+        ))
+    })
+}
+
+fn frame_cache() -> &'static Mutex<FrameCache> {
+    static CACHE: OnceLock<Mutex<FrameCache>> = OnceLock::new();
+    CACHE.get_or_init(|| {
+        Mutex::new(FrameCache {
+            entries: LruCache::unbounded(),
+            bytes: 0,
+        })
+    })
+}
+
+/// `(file, stamp) → cursor`, bounded by [`CURSOR_POOL_CAPACITY`].
+fn cursor_pool() -> &'static Mutex<LruCache<(PathBuf, SourceStamp), Cursor>> {
+    static POOL: OnceLock<Mutex<LruCache<(PathBuf, SourceStamp), Cursor>>> = OnceLock::new();
+    POOL.get_or_init(|| {
+        Mutex::new(LruCache::new(
+            NonZeroUsize::new(CURSOR_POOL_CAPACITY).expect("cursor pool capacity is non-zero"),
+        ))
+    })
+}
+
+/// Resolves `pattern` to its frames, caching the result until what it was
+/// resolved from changes on disk.
+///
+/// - A file name holding a printf conversion (`%d`, `%04d`) matches the files
+///   in its directory with a number there, ordered by that number.
+/// - A file name holding `*` or `?` wildcards matches the files in its
+///   directory, ordered by name with digit runs compared as numbers.
+/// - Anything else names one file: an animated GIF, APNG or WebP plays its
+///   own frames, and a still image is a one-frame sequence.
+///
+/// Returns `None` when nothing matches or the file cannot be read; the leaf
+/// then falls back to a stub length, like `VideoFile`.
+pub fn probe(pattern: &str) -> Option<Arc<SequenceFrames>> {
+    let (dir, name) = split_pattern(pattern)?;
+    let numbered = PrintfPattern::parse(name).is_some() || name.contains(['*', '?']);
+    let stamp = SourceStamp::of(if numbered { dir } else { Path::new(pattern) })?;
+    if let Some((cached, frames)) = probe_cache().lock().unwrap().get(pattern) {
+        if *cached == stamp {
+            return Some(Arc::clone(frames));
+        }
+    }
+    let frames = Arc::new(resolve_pattern(pattern)?);
+    if frames.is_empty() {
+        return None;
+    }
+    probe_cache()
+        .lock()
+        .unwrap()
+        .put(pattern.to_string(), (stamp, Arc::clone(&frames)));
+    Some(frames)
+}
+
+/// Decodes frame `index` of `pattern` as straight-alpha RGBA8, at the
+/// source's own size. `None` if the pattern does not resolve, the index is
+/// out of range, or the frame fails to decode. An animated frame that fails
+/// to decode shows the frame before it instead.
+pub fn decode_frame(pattern: &str, index: usize) -> Option<CpuRasterImage> {
+    let frames = probe(pattern)?;
+    let (path, frame_index) = match &*frames {
+        SequenceFrames::Files(files) => (files.get(index)?, 0),
+        SequenceFrames::Animated { path, ends } if index < ends.len() => (path, index),
+        SequenceFrames::Animated { .. } => return None,
+    };
+    let stamp = SourceStamp::of(path)?;
+    let key = (path.clone(), stamp, frame_index);
+    if let Some(frame) = frame_cache().lock().unwrap().get(&key) {
+        return Some(frame);
+    }
+    match &*frames {
+        SequenceFrames::Files(_) => {
+            let frame = CpuRasterImage::load(path).ok()?;
+            frame_cache().lock().unwrap().insert(key, frame.clone());
+            Some(frame)
+        }
+        SequenceFrames::Animated { .. } => decode_animated(path, stamp, index),
+    }
+}
+
+/// Walks `path`'s cursor forward to frame `index`, caching each frame on the
+/// way; a cursor already past `index` is restarted from the first frame.
+fn decode_animated(path: &Path, stamp: SourceStamp, index: usize) -> Option<CpuRasterImage> {
+    let source = (path.to_path_buf(), stamp);
+    let mut pool = cursor_pool().lock().unwrap();
+    if pool.get(&source).is_none_or(|cursor| cursor.next > index) {
+        pool.put(source.clone(), Cursor::start(path)?);
+    }
+    let cursor = pool.get_mut(&source)?;
+    while cursor.next <= index {
+        let frame = cursor.frames.recv().ok()?;
+        let i = cursor.next;
+        cursor.next += 1;
+        if let Some(frame) = frame {
+            frame_cache()
+                .lock()
+                .unwrap()
+                .insert((path.to_path_buf(), stamp, i), frame.clone());
+            if i == index {
+                return Some(frame);
+            }
+        }
+    }
+    None
+}
+
+/// The directory a pattern lists and the file-name part matched in it.
+fn split_pattern(pattern: &str) -> Option<(&Path, &str)> {
+    let path = Path::new(pattern);
+    let name = path.file_name()?.to_str()?;
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    Some((dir, name))
+}
+
+fn resolve_pattern(pattern: &str) -> Option<SequenceFrames> {
+    let path = Path::new(pattern);
+    let (dir, name) = split_pattern(pattern)?;
+    if let Some(printf) = PrintfPattern::parse(name) {
+        let mut numbered: Vec<(u64, PathBuf)> = list_dir(dir)?
+            .filter_map(|(file, path)| printf.number(&file).map(|n| (n, path)))
+            .collect();
+        numbered.sort_by_key(|(n, _)| *n);
+        return Some(SequenceFrames::Files(
+            numbered.into_iter().map(|(_, path)| path).collect(),
+        ));
+    }
+    if name.contains(['*', '?']) {
+        let mut matched: Vec<(String, PathBuf)> = list_dir(dir)?
+            .filter(|(file, _)| wildcard_match(name, file))
+            .collect();
+        matched.sort_by(|(a, _), (b, _)| natural_cmp(a, b));
+        return Some(SequenceFrames::Files(
+            matched.into_iter().map(|(_, path)| path).collect(),
+        ));
+    }
+    let delays = animation_delays(path).unwrap_or_default();
+    if delays.len() > 1 {
+        let ends = delays
+            .iter()
+            .scan(0.0, |end, delay| {
+                *end += delay;
+                Some(*end)
+            })
+            .collect();
+        Some(SequenceFrames::Animated {
+            path: path.to_path_buf(),
+            ends,
+        })
+    } else if path.is_file() {
+        Some(SequenceFrames::Files(vec![path.to_path_buf()]))
+    } else {
+        None
+    }
+}
+
+/// The regular files in `dir`, as `(file name, path)`.
+fn list_dir(dir: &Path) -> Option<impl Iterator<Item = (String, PathBuf)>> {
+    Some(std::fs::read_dir(dir).ok()?.filter_map(|entry| {
+        let entry = entry.ok()?;
+        if !entry.file_type().ok()?.is_file() {
+            return None;
+        }
+        Some((entry.file_name().into_string().ok()?, entry.path()))
+    }))
+}
+
+/// The frames of an animated GIF, APNG or WebP, chosen by extension; `None`
+/// for other files. A PNG or WebP without animation yields no frames.
+fn animation_frames(path: &Path) -> Option<image::Frames<'static>> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    let reader = BufReader::new(File::open(path).ok()?);
+    match extension.as_str() {
+        "gif" => Some(GifDecoder::new(reader).ok()?.into_frames()),
+        "png" | "apng" => {
+            let decoder = PngDecoder::new(reader).ok()?;
+            if !decoder.is_apng().ok()? {
+                return None;
+            }
+            Some(decoder.apng().ok()?.into_frames())
+        }
+        "webp" => {
+            let decoder = WebPDecoder::new(reader).ok()?;
+            if !decoder.has_animation() {
+                return None;
+            }
+            Some(decoder.into_frames())
+        }
+        _ => None,
+    }
+}
+
+/// One item per frame of `frames`: each `Ok` frame, and in place of each
+/// error the last frame that decoded (`None` before any has), so the frames
+/// around a corrupt one still play at their own times. Ends once
+/// [`MAX_FAILED_FRAMES`] errors come in a row.
+fn hold_failed<T: Clone, E>(
+    frames: impl Iterator<Item = Result<T, E>>,
+) -> impl Iterator<Item = Option<T>> {
+    let mut last = None;
+    let mut failed = 0;
+    frames.map_while(move |frame| match frame {
+        Ok(frame) => {
+            failed = 0;
+            last = Some(frame);
+            Some(last.clone())
+        }
+        Err(_) if failed + 1 < MAX_FAILED_FRAMES => {
+            failed += 1;
+            Some(last.clone())
+        }
+        Err(_) => None,
+    })
+}
+
+/// How long each frame of an animated GIF, APNG or WebP shows, in seconds,
+/// read from the frame headers alone; `None` for other files. A file cut
+/// short yields the frames before the cut.
+fn animation_delays(path: &Path) -> Option<Vec<f64>> {
+    let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    let mut delays = Vec::new();
+    match extension.as_str() {
+        "gif" => gif_delays(&mut reader, &mut delays),
+        "png" | "apng" => apng_delays(&mut reader, &mut delays),
+        "webp" => webp_delays(&mut reader, &mut delays),
+        _ => return None,
+    };
+    Some(delays)
+}
+
+/// A frame delay of `numer / denom` seconds, or [`DEFAULT_FRAME_DELAY`] for
+/// a frame without one.
+fn frame_delay(numer: u32, denom: u32) -> f64 {
+    if numer == 0 || denom == 0 {
+        DEFAULT_FRAME_DELAY
+    } else {
+        f64::from(numer) / f64::from(denom)
+    }
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> Option<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes).ok()?;
+    Some(bytes)
+}
+
+/// Pushes the delay of each image in a GIF: its graphic control extension's
+/// delay, in hundredths of a second.
+fn gif_delays(reader: &mut (impl BufRead + Seek), delays: &mut Vec<f64>) -> Option<()> {
+    let header: [u8; 13] = read_array(reader)?;
+    if &header[..4] != b"GIF8" {
+        return None;
+    }
+    skip_color_table(reader, header[10])?;
+    let mut delay = 0;
+    loop {
+        match read_array::<1>(reader)?[0] {
+            // Extension: a graphic control one carries the next image's delay.
+            0x21 => {
+                let [label, size] = read_array(reader)?;
+                if label == 0xf9 && size == 4 {
+                    let control: [u8; 4] = read_array(reader)?;
+                    delay = u16::from_le_bytes([control[1], control[2]]);
+                } else {
+                    reader.seek_relative(i64::from(size)).ok()?;
+                }
+                skip_sub_blocks(reader)?;
+            }
+            // Image descriptor, then the LZW code size and the image data.
+            0x2c => {
+                let descriptor: [u8; 9] = read_array(reader)?;
+                skip_color_table(reader, descriptor[8])?;
+                read_array::<1>(reader)?;
+                skip_sub_blocks(reader)?;
+                delays.push(frame_delay(delay.into(), 100));
+                delay = 0;
+            }
+            _ => return Some(()),
+        }
+    }
+}
+
+/// Skips the color table a GIF's screen or image descriptor flags announce.
+fn skip_color_table(reader: &mut impl Seek, flags: u8) -> Option<()> {
+    if flags & 0x80 != 0 {
+        reader.seek_relative(3 << ((flags & 0x07) + 1)).ok()?;
+    }
+    Some(())
+}
+
+fn skip_sub_blocks(reader: &mut (impl Read + Seek)) -> Option<()> {
+    loop {
+        match read_array::<1>(reader)?[0] {
+            0 => return Some(()),
+            size => reader.seek_relative(i64::from(size)).ok()?,
+        }
+    }
+}
+
+/// Pushes the delay of each `fcTL` chunk in an APNG; a still PNG has none.
+fn apng_delays(reader: &mut (impl Read + Seek), delays: &mut Vec<f64>) -> Option<()> {
+    if read_array::<8>(reader)? != *b"\x89PNG\r\n\x1a\n" {
+        return None;
+    }
+    loop {
+        let header: [u8; 8] = read_array(reader)?;
+        let len = u32::from_be_bytes(header[..4].try_into().ok()?);
+        match &header[4..] {
+            b"IEND" => return Some(()),
+            b"fcTL" if len >= 26 => {
+                let control: [u8; 26] = read_array(reader)?;
+                let numer = u16::from_be_bytes([control[20], control[21]]);
+                // A zero denominator means hundredths of a second.
+                let denom = match u16::from_be_bytes([control[22], control[23]]) {
+                    0 => 100,
+                    denom => denom,
+                };
+                delays.push(frame_delay(numer.into(), denom.into()));
+                reader.seek_relative(i64::from(len) - 26 + 4).ok()?;
+            }
+            // The chunk's data and CRC.
+            _ => reader.seek_relative(i64::from(len) + 4).ok()?,
+        }
+    }
+}
+
+/// Pushes the duration of each `ANMF` chunk in an animated WebP, in
+/// milliseconds; a still WebP has none.
+fn webp_delays(reader: &mut (impl Read + Seek), delays: &mut Vec<f64>) -> Option<()> {
+    let header: [u8; 12] = read_array(reader)?;
+    if &header[..4] != b"RIFF" || &header[8..] != b"WEBP" {
+        return None;
+    }
+    loop {
+        let header: [u8; 8] = read_array(reader)?;
+        let len = u32::from_le_bytes(header[4..].try_into().ok()?);
+        // Chunks are padded to an even length.
+        let padded = i64::from(len) + i64::from(len & 1);
+        if &header[..4] == b"ANMF" && len >= 16 {
+            let frame: [u8; 16] = read_array(reader)?;
+            let duration = u32::from_le_bytes([frame[12], frame[13], frame[14], 0]);
+            delays.push(frame_delay(duration, 1000));
+            reader.seek_relative(padded - 16).ok()?;
+        } else {
+            reader.seek_relative(padded).ok()?;
+        }
+    }
+}
+
+fn frame_image(frame: image::Frame) -> CpuRasterImage {
+    let buffer = frame.into_buffer();
+    let (width, height) = buffer.dimensions();
+    CpuRasterImage::new(width, height, PixelFormat::Rgba8, buffer.into_raw())
+}
+
+/// A file name with one printf integer conversion, e.g. `fx_%04d.png`.
+struct PrintfPattern<'a> {
+    prefix: &'a str,
+    suffix: &'a str,
+    /// The zero-padded width, for `%0Nd`.
+    padded: Option<usize>,
+}
+
+impl<'a> PrintfPattern<'a> {
+    fn parse(name: &'a str) -> Option<Self> {
+        let start = name.find('%')?;
+        let rest = &name[start + 1..];
+        let d = rest.find('d')?;
+        let spec = &rest[..d];
+        if !spec.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let padded = match spec.strip_prefix('0') {
+            Some(width) => Some(width.parse().ok()?),
+            None => None,
+        };
+        Some(Self {
+            prefix: &name[..start],
+            suffix: &rest[d + 1..],
+            padded,
+        })
+    }
+
+    /// The frame number in `file`, if it matches.
+    fn number(&self, file: &str) -> Option<u64> {
+        let digits = file.strip_prefix(self.prefix)?.strip_suffix(self.suffix)?;
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        if let Some(width) = self.padded {
+            // Padding is only ever added, so a longer number has no leading zero.
+            if digits.len() < width || (digits.len() > width && digits.starts_with('0')) {
+                return None;
+            }
+        }
+        digits.parse().ok()
+    }
+}
+
+/// Matches `name` against `pattern`, where `*` is any run of characters and
+/// `?` any one character.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    // Where the last `*` was, and how much of `name` it has swallowed.
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Orders names with their digit runs compared as numbers, so `fx_2`
+/// precedes `fx_10`.
+fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let (mut a, mut b) = (a, b);
+    loop {
+        match (a.chars().next(), b.chars().next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let a_len = a.find(|c: char| !c.is_ascii_digit()).unwrap_or(a.len());
+                let b_len = b.find(|c: char| !c.is_ascii_digit()).unwrap_or(b.len());
+                let (a_run, b_run) = (
+                    a[..a_len].trim_start_matches('0'),
+                    b[..b_len].trim_start_matches('0'),
+                );
+                let order = a_run
+                    .len()
+                    .cmp(&b_run.len())
+                    .then_with(|| a_run.cmp(b_run))
+                    .then_with(|| a_len.cmp(&b_len));
+                if order != Ordering::Equal {
+                    return order;
+                }
+                a = &a[a_len..];
+                b = &b[b_len..];
+            }
+            (Some(x), Some(y)) => {
+                if x != y {
+                    return x.cmp(&y);
+                }
+                a = &a[x.len_utf8()..];
+                b = &b[y.len_utf8()..];
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn printf_patterns_match_padded_and_unpadded_numbers() {
+        let padded = PrintfPattern::parse("fx_%04d.png").unwrap();
+        assert_eq!(padded.number("fx_0001.png"), Some(1));
+        assert_eq!(padded.number("fx_12345.png"), Some(12345));
+        assert_eq!(padded.number("fx_01.png"), None);
+        assert_eq!(padded.number("fx_00001.png"), None);
+        assert_eq!(padded.number("fx_0001.jpg"), None);
+        let plain = PrintfPattern::parse("%d.png").unwrap();
+        assert_eq!(plain.number("7.png"), Some(7));
+        assert_eq!(plain.number("x.png"), None);
+        assert!(PrintfPattern::parse("fx_%s.png").is_none());
+        assert!(PrintfPattern::parse("fx.png").is_none());
+    }
+
+    #[test]
+    fn wildcards_match_runs_and_single_characters() {
+        assert!(wildcard_match("fx_*.png", "fx_0001.png"));
+        assert!(wildcard_match("fx_*.png", "fx_.png"));
+        assert!(wildcard_match("fx_?.png", "fx_7.png"));
+        assert!(!wildcard_match("fx_?.png", "fx_10.png"));
+        assert!(!wildcard_match("fx_*.png", "fx_0001.png.bak"));
+        assert!(wildcard_match("*a*b", "xxaxxb"));
+    }
+
+    #[test]
+    fn natural_order_compares_digit_runs_as_numbers() {
+        let mut names = vec!["fx_10.png", "fx_2.png", "fx_1.png", "fx_02.png"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            vec!["fx_1.png", "fx_2.png", "fx_02.png", "fx_10.png"]
+        );
+    }
+
+    #[test]
+    fn failed_frames_hold_the_last_good_one_until_too_many_fail_in_a_row() {
+        let frames = [Err(()), Ok(0), Err(()), Ok(1), Err(()), Err(()), Ok(2)];
+        assert_eq!(
+            hold_failed(frames.into_iter()).collect::<Vec<_>>(),
+            [None, Some(0), Some(0), Some(1), Some(1), Some(1), Some(2)]
+        );
+        let stuck = std::iter::once(Ok(0)).chain(std::iter::repeat(Err(())));
+        assert_eq!(
+            hold_failed(stuck).collect::<Vec<_>>(),
+            [Some(0); MAX_FAILED_FRAMES]
+        );
+    }
+
+    #[test]
+    fn gif_delays_are_read_from_the_frame_headers() {
+        let gif = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/fixtures/images/sample.gif"
+        );
+        assert_eq!(animation_delays(Path::new(gif)), Some(vec![0.1, 0.1]));
+    }
+
+    /// Writes a 1×1 APNG with one `(delay in tenths, pixel)` per frame.
+    fn write_apng(name: &str, frames: &[(u16, [u8; 4])]) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("tellur-apng-{name}-{}.png", std::process::id()));
+        let mut encoder = png::Encoder::new(File::create(&path).unwrap(), 1, 1);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_animated(frames.len() as u32, 0).unwrap();
+        let mut writer = encoder.write_header().unwrap();
+        for (delay, pixel) in frames {
+            writer.set_frame_delay(*delay, 10).unwrap();
+            writer.write_image_data(pixel).unwrap();
+        }
+        writer.finish().unwrap();
+        path
+    }
+
+    #[test]
+    fn apng_delays_are_read_from_the_frame_headers() {
+        let path = write_apng("delays", &[(1, [255; 4]), (0, [255; 4]), (3, [255; 4])]);
+        let delays = animation_delays(&path);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(delays, Some(vec![0.1, DEFAULT_FRAME_DELAY, 0.3]));
+    }
+
+    #[test]
+    fn animated_frames_decode_forwards_and_after_a_jump_back() {
+        let frames: Vec<_> = (0..4u8).map(|i| (1, [i * 60, 0, 0, 255])).collect();
+        let path = write_apng("cursor", &frames);
+        let pattern = path.to_str().unwrap();
+        let red = |index| decode_frame(pattern, index).map(|frame| frame.pixels[0]);
+        let forward: Vec<_> = (0..4).map(red).collect();
+        assert_eq!(red(4), None);
+        // With the cache emptied, frame 1 comes from a restarted cursor.
+        let mut cache = frame_cache().lock().unwrap();
+        cache.entries.clear();
+        cache.bytes = 0;
+        drop(cache);
+        let back = red(1);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(forward, [Some(0), Some(60), Some(120), Some(180)]);
+        assert_eq!(back, Some(60));
+    }
+
+    #[test]
+    fn webp_delays_are_read_from_the_frame_headers() {
+        // A RIFF header, then a VP8X and two ANMF chunks with only their
+        // 16-byte frame headers, the second padded to an even length.
+        let anmf = |duration: u32, len: u32| {
+            let mut chunk = b"ANMF".to_vec();
+            chunk.extend(len.to_le_bytes());
+            chunk.extend([0; 12]);
+            chunk.extend(&duration.to_le_bytes()[..3]);
+            chunk.push(0);
+            chunk.resize(chunk.len() + (len as usize - 16) + (len as usize & 1), 0);
+            chunk
+        };
+        let mut webp = b"RIFF\0\0\0\0WEBPVP8X".to_vec();
+        webp.extend(10u32.to_le_bytes());
+        webp.extend([0; 10]);
+        webp.extend(anmf(250, 16));
+        webp.extend(anmf(0, 17));
+        let mut reader = std::io::Cursor::new(webp);
+        let mut delays = Vec::new();
+        webp_delays(&mut reader, &mut delays);
+        assert_eq!(delays, [0.25, DEFAULT_FRAME_DELAY]);
+    }
+
+    #[test]
+    fn frame_index_follows_the_frame_rate_or_the_delays() {
+        let files = SequenceFrames::Files(vec![PathBuf::new(); 4]);
+        assert_eq!(files.duration(10.0), 0.4);
+        assert_eq!(files.index_at(0.0, 10.0), Some(0));
+        assert_eq!(files.index_at(0.3, 10.0), Some(3));
+        assert_eq!(files.index_at(0.4, 10.0), None);
+        assert_eq!(files.index_at(-0.1, 10.0), None);
+
+        let animated = SequenceFrames::Animated {
+            path: PathBuf::new(),
+            ends: vec![0.1, 0.5, 0.6],
+        };
+        assert_eq!(animated.duration(10.0), 0.6);
+        assert_eq!(animated.index_at(0.1, 10.0), Some(1));
+        assert_eq!(animated.index_at(0.45, 10.0), Some(1));
+        assert_eq!(animated.index_at(0.55, 10.0), Some(2));
+    }
+}
@@ -13,9 +13,16 @@
 //! ease a typed interpolation, reshape the Phase first via
 //! [`Phase::eased`]: `a.interpolate(b, p.eased(Easing::OutCubic))`.
 
+use crate::clip::ClipRegion;
 use crate::color::Color;
-use crate::geometry::{Anchor, Vec2};
+use crate::distort::{Bulge, Distortion, Ripple, Twirl, Wave};
+use crate::geometry::{Anchor, Rect, Transform, Vec2};
+use crate::grade::ColorAdjustment;
 use crate::phase::Phase;
+use crate::vector::{
+    DashPattern, Fill, GradientStop, ImagePattern, LinearGradient, Paint, PathCommand,
+    RadialGradient, Stroke, SweepGradient, WidthProfile,
+};
 
 /// Linear interpolation between two values of the same type, parameterized
 /// by a [`Phase`].
@@ -66,6 +73,426 @@ impl Interpolate for Color {
     }
 }
 
+impl Interpolate for ColorAdjustment {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        ColorAdjustment {
+            exposure: self.exposure.interpolate(other.exposure, p),
+            brightness: self.brightness.interpolate(other.brightness, p),
+            contrast: self.contrast.interpolate(other.contrast, p),
+            saturation: self.saturation.interpolate(other.saturation, p),
+            hue: self.hue.interpolate(other.hue, p),
+            tint: self.tint.interpolate(other.tint, p),
+            gamma: self.gamma.interpolate(other.gamma, p),
+        }
+    }
+}
+
+/// Component-wise lerp of the six matrix entries. Exact for translations and
+/// scales; a rotation passes through a (briefly shrunken) shear rather than
+/// turning rigidly, which is what animating a gradient transform wants.
+impl Interpolate for Transform {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        Transform {
+            a: self.a.interpolate(other.a, p),
+            b: self.b.interpolate(other.b, p),
+            c: self.c.interpolate(other.c, p),
+            d: self.d.interpolate(other.d, p),
+            tx: self.tx.interpolate(other.tx, p),
+            ty: self.ty.interpolate(other.ty, p),
+        }
+    }
+}
+
+impl Interpolate for GradientStop {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        GradientStop::new(
+            self.offset.interpolate(other.offset, p),
+            self.color.interpolate(other.color, p),
+        )
+    }
+}
+
+/// Stop lists of equal length pair up index by index, so a stop can slide
+/// along the gradient. Otherwise both lists are resampled at the union of
+/// their offsets and the colors are lerped there.
+impl Interpolate for Vec<GradientStop> {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        if self.len() == other.len() {
+            return self
+                .into_iter()
+                .zip(other)
+                .map(|(a, b)| a.interpolate(b, p))
+                .collect();
+        }
+        let mut offsets: Vec<f32> = self.iter().chain(&other).map(|s| s.offset).collect();
+        offsets.sort_by(f32::total_cmp);
+        offsets.dedup();
+        offsets
+            .into_iter()
+            .map(|offset| {
+                GradientStop::new(
+                    offset,
+                    sample_stops(&self, offset).interpolate(sample_stops(&other, offset), p),
+                )
+            })
+            .collect()
+    }
+}
+
+/// The padded color of `stops` at `offset`.
+fn sample_stops(stops: &[GradientStop], offset: f32) -> Color {
+    let mut sorted = stops.to_vec();
+    sorted.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    let Some(first) = sorted.first() else {
+        return Color::rgba_u8(0, 0, 0, 0);
+    };
+    if offset <= first.offset {
+        return first.color;
+    }
+    for pair in sorted.windows(2) {
+        let (a, b) = (pair[0], pair[1]);
+        if offset <= b.offset {
+            let span = b.offset - a.offset;
+            if span <= 0.0 {
+                return b.color;
+            }
+            let t = Phase::new((offset - a.offset) / span).unwrap_or(Phase::ONE);
+            return a.color.interpolate(b.color, t);
+        }
+    }
+    sorted[sorted.len() - 1].color
+}
+
+/// Discrete fields (the spread mode) switch at the halfway point.
+fn step<T>(a: T, b: T, p: Phase) -> T {
+    if p.get() < 0.5 {
+        a
+    } else {
+        b
+    }
+}
+
+impl Interpolate for LinearGradient {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        LinearGradient {
+            start: self.start.interpolate(other.start, p),
+            end: self.end.interpolate(other.end, p),
+            stops: self.stops.interpolate(other.stops, p),
+            spread: step(self.spread, other.spread, p),
+            transform: self.transform.interpolate(other.transform, p),
+        }
+    }
+}
+
+impl Interpolate for RadialGradient {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        RadialGradient {
+            center: self.center.interpolate(other.center, p),
+            radius: self.radius.interpolate(other.radius, p),
+            focal: self.focal.interpolate(other.focal, p),
+            stops: self.stops.interpolate(other.stops, p),
+            spread: step(self.spread, other.spread, p),
+            transform: self.transform.interpolate(other.transform, p),
+        }
+    }
+}
+
+impl Interpolate for SweepGradient {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        SweepGradient {
+            center: self.center.interpolate(other.center, p),
+            start_angle: self.start_angle.interpolate(other.start_angle, p),
+            end_angle: self.end_angle.interpolate(other.end_angle, p),
+            stops: self.stops.interpolate(other.stops, p),
+            spread: step(self.spread, other.spread, p),
+            transform: self.transform.interpolate(other.transform, p),
+        }
+    }
+}
+
+/// Paints of the same kind interpolate field by field; an image pattern
+/// only moves (its transform lerps) when both sides show the same image. A
+/// solid color against a gradient is treated as that gradient painted in one
+/// flat color, so it fades in or out of the gradient in place. Anything else
+/// cannot be blended and switches at the halfway point.
+impl Interpolate for Paint {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        match (self, other) {
+            (Paint::Solid(a), Paint::Solid(b)) => Paint::Solid(a.interpolate(b, p)),
+            (Paint::LinearGradient(a), Paint::LinearGradient(b)) => {
+                Paint::LinearGradient(a.interpolate(b, p))
+            }
+            (Paint::RadialGradient(a), Paint::RadialGradient(b)) => {
+                Paint::RadialGradient(a.interpolate(b, p))
+            }
+            (Paint::SweepGradient(a), Paint::SweepGradient(b)) => {
+                Paint::SweepGradient(a.interpolate(b, p))
+            }
+            (Paint::Image(a), Paint::Image(b)) if a.image == b.image => {
+                Paint::Image(Box::new(ImagePattern {
+                    transform: a.transform.interpolate(b.transform, p),
+                    extend: step(a.extend, b.extend, p),
+                    quality: step(a.quality, b.quality, p),
+                    image: a.image,
+                }))
+            }
+            (Paint::Solid(color), gradient) if is_gradient(&gradient) => {
+                flat_like(&gradient, color).interpolate(gradient, p)
+            }
+            (gradient, Paint::Solid(color)) if is_gradient(&gradient) => {
+                let flat = flat_like(&gradient, color);
+                gradient.interpolate(flat, p)
+            }
+            (a, b) => step(a, b, p),
+        }
+    }
+}
+
+impl Interpolate for Fill {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        Fill {
+            paint: self.paint.interpolate(other.paint, p),
+        }
+    }
+}
+
+/// Width, miter limit and paint lerp; cap and join switch at the halfway
+/// point. Dash patterns of equal length lerp run by run, so dashes can grow
+/// or slide; a solid stroke against a dashed one switches at the halfway
+/// point. Width profiles follow the same rule stop by stop.
+impl Interpolate for Stroke {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        let miter_limit = self.miter_limit().interpolate(other.miter_limit(), p);
+        let dash = match (self.dash, other.dash) {
+            (Some(a), Some(b)) if a.lengths.len() == b.lengths.len() => Some(a.interpolate(b, p)),
+            (a, b) => step(a, b, p),
+        };
+        let width_profile = match (self.width_profile, other.width_profile) {
+            (Some(a), Some(b)) if a.stops().len() == b.stops().len() => Some(a.interpolate(b, p)),
+            (a, b) => step(a, b, p),
+        };
+        let stroke = Stroke::new(
+            self.paint.interpolate(other.paint, p),
+            self.width.interpolate(other.width, p),
+        )
+        .with_cap(step(self.cap, other.cap, p))
+        .with_join(step(self.join, other.join, p))
+        .with_miter_limit(miter_limit);
+        let stroke = match dash {
+            Some(dash) => stroke.with_dash(dash),
+            None => stroke,
+        };
+        match width_profile {
+            Some(profile) => stroke.with_width_profile(profile),
+            None => stroke,
+        }
+    }
+}
+
+/// Stops pair up index by index; profiles with different stop counts switch
+/// at the halfway point.
+impl Interpolate for WidthProfile {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        if self.stops().len() != other.stops().len() {
+            return step(self, other, p);
+        }
+        WidthProfile::new(
+            self.stops()
+                .iter()
+                .zip(other.stops())
+                .map(|(a, b)| (a.t.interpolate(b.t, p), a.width.interpolate(b.width, p))),
+        )
+    }
+}
+
+/// Run lengths pair up index by index; patterns of different lengths switch
+/// at the halfway point.
+impl Interpolate for DashPattern {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        let offset = self.offset.interpolate(other.offset, p);
+        if self.lengths.len() != other.lengths.len() {
+            return DashPattern::new(step(self.lengths, other.lengths, p), offset);
+        }
+        let lengths = self
+            .lengths
+            .into_iter()
+            .zip(other.lengths)
+            .map(|(a, b)| a.interpolate(b, p))
+            .collect::<Vec<_>>();
+        DashPattern::new(lengths, offset)
+    }
+}
+
+impl Interpolate for Rect {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        Rect {
+            origin: self.origin.interpolate(other.origin, p),
+            size: self.size.interpolate(other.size, p),
+        }
+    }
+}
+
+/// Two rects lerp their origin and size. Otherwise both sides are taken as
+/// paths (a rect is its four corners, clockwise from the origin) and, when
+/// their commands match kind for kind, every point lerps so the shape morphs
+/// in place; the fill rule switches at the halfway point. Paths with
+/// different command structure cannot be morphed and switch at the halfway
+/// point, like a mismatched [`DashPattern`].
+impl Interpolate for ClipRegion {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        if let (ClipRegion::Rect(a), ClipRegion::Rect(b)) = (&self, &other) {
+            return ClipRegion::Rect(a.interpolate(*b, p));
+        }
+        let fill_rule = step(self.fill_rule(), other.fill_rule(), p);
+        match interpolate_commands(&self.to_commands(), &other.to_commands(), p) {
+            Some(commands) => ClipRegion::path_with_fill_rule(commands, fill_rule),
+            None => step(self, other, p),
+        }
+    }
+}
+
+/// Point-by-point lerp of two command lists; `None` unless they have the same
+/// length and the same command at every index.
+fn interpolate_commands(
+    a: &[PathCommand],
+    b: &[PathCommand],
+    p: Phase,
+) -> Option<Vec<PathCommand>> {
+    if a.len() != b.len() {
+        return None;
+    }
+    a.iter()
+        .zip(b)
+        .map(|(&a, &b)| match (a, b) {
+            (PathCommand::MoveTo(a), PathCommand::MoveTo(b)) => {
+                Some(PathCommand::MoveTo(a.interpolate(b, p)))
+            }
+            (PathCommand::LineTo(a), PathCommand::LineTo(b)) => {
+                Some(PathCommand::LineTo(a.interpolate(b, p)))
+            }
+            (
+                PathCommand::QuadTo { control, to },
+                PathCommand::QuadTo {
+                    control: other_control,
+                    to: other_to,
+                },
+            ) => Some(PathCommand::QuadTo {
+                control: control.interpolate(other_control, p),
+                to: to.interpolate(other_to, p),
+            }),
+            (
+                PathCommand::CubicTo { c1, c2, to },
+                PathCommand::CubicTo {
+                    c1: other_c1,
+                    c2: other_c2,
+                    to: other_to,
+                },
+            ) => Some(PathCommand::CubicTo {
+                c1: c1.interpolate(other_c1, p),
+                c2: c2.interpolate(other_c2, p),
+                to: to.interpolate(other_to, p),
+            }),
+            (PathCommand::Close, PathCommand::Close) => Some(PathCommand::Close),
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_gradient(paint: &Paint) -> bool {
+    matches!(
+        paint,
+        Paint::LinearGradient(_) | Paint::RadialGradient(_) | Paint::SweepGradient(_)
+    )
+}
+
+/// `template` repainted so every stop is `color`.
+fn flat_like(template: &Paint, color: Color) -> Paint {
+    let flatten = |stops: &[GradientStop]| -> Vec<GradientStop> {
+        stops
+            .iter()
+            .map(|stop| GradientStop::new(stop.offset, color))
+            .collect()
+    };
+    match template {
+        Paint::LinearGradient(g) => Paint::LinearGradient(LinearGradient {
+            stops: flatten(&g.stops),
+            ..g.clone()
+        }),
+        Paint::RadialGradient(g) => Paint::RadialGradient(RadialGradient {
+            stops: flatten(&g.stops),
+            ..g.clone()
+        }),
+        Paint::SweepGradient(g) => Paint::SweepGradient(SweepGradient {
+            stops: flatten(&g.stops),
+            ..g.clone()
+        }),
+        Paint::Solid(_) | Paint::Image(_) => Paint::Solid(color),
+    }
+}
+
+impl Interpolate for Ripple {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        Ripple {
+            center: self.center.interpolate(other.center, p),
+            amplitude: self.amplitude.interpolate(other.amplitude, p),
+            wavelength: self.wavelength.interpolate(other.wavelength, p),
+            phase: lerp_phase(self.phase, other.phase, p),
+        }
+    }
+}
+
+impl Interpolate for Wave {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        Wave {
+            amplitude: self.amplitude.interpolate(other.amplitude, p),
+            wavelength: self.wavelength.interpolate(other.wavelength, p),
+            angle: self.angle.interpolate(other.angle, p),
+            phase: lerp_phase(self.phase, other.phase, p),
+        }
+    }
+}
+
+impl Interpolate for Twirl {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        Twirl {
+            center: self.center.interpolate(other.center, p),
+            radius: self.radius.interpolate(other.radius, p),
+            angle: self.angle.interpolate(other.angle, p),
+        }
+    }
+}
+
+impl Interpolate for Bulge {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        Bulge {
+            center: self.center.interpolate(other.center, p),
+            radius: self.radius.interpolate(other.radius, p),
+            strength: self.strength.interpolate(other.strength, p),
+        }
+    }
+}
+
+/// Distortions of the same kind interpolate field by field; different kinds
+/// switch at the halfway point.
+impl Interpolate for Distortion {
+    fn interpolate(self, other: Self, p: Phase) -> Self {
+        match (self, other) {
+            (Distortion::Ripple(a), Distortion::Ripple(b)) => {
+                Distortion::Ripple(a.interpolate(b, p))
+            }
+            (Distortion::Wave(a), Distortion::Wave(b)) => Distortion::Wave(a.interpolate(b, p)),
+            (Distortion::Twirl(a), Distortion::Twirl(b)) => Distortion::Twirl(a.interpolate(b, p)),
+            (Distortion::Bulge(a), Distortion::Bulge(b)) => Distortion::Bulge(a.interpolate(b, p)),
+            (a, b) => step(a, b, p),
+        }
+    }
+}
+
+/// Lerps a cycle position; the result stays in the unit interval.
+fn lerp_phase(a: Phase, b: Phase, p: Phase) -> Phase {
+    Phase::saturating(a.get().interpolate(b.get(), p))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,6 +545,26 @@ mod tests {
         assert_eq!(mid, Anchor::CENTER);
     }
 
+    #[test]
+    fn color_adjustment_blends_every_field() {
+        let neutral = ColorAdjustment::default();
+        let graded = ColorAdjustment {
+            exposure: 1.0,
+            saturation: 0.0,
+            hue: 90.0,
+            gamma: 2.0,
+            ..Default::default()
+        };
+
+        let half = neutral.interpolate(graded, Phase::HALF);
+
+        assert_eq!(half.exposure, 0.5);
+        assert_eq!(half.saturation, 0.5);
+        assert_eq!(half.hue, 45.0);
+        assert_eq!(half.gamma, 1.5);
+        assert_eq!(neutral.interpolate(graded, Phase::ONE), graded);
+    }
+
     #[test]
     fn color_endpoints() {
         let a = Color::rgba_u8(0, 0, 0, 0);
@@ -165,4 +612,225 @@ mod tests {
         };
         assert_eq!(ink.interpolate(muted, p), expected);
     }
+
+    #[test]
+    fn equal_length_gradient_stops_pair_up_so_stops_can_slide() {
+        let red = Color::rgb_u8(255, 0, 0);
+        let blue = Color::rgb_u8(0, 0, 255);
+        let a = vec![GradientStop::new(0.0, red), GradientStop::new(0.2, blue)];
+        let b = vec![GradientStop::new(0.0, red), GradientStop::new(0.8, blue)];
+        let mid = a.interpolate(b, Phase::HALF);
+        assert_eq!(mid[1].offset, 0.5);
+        assert_eq!(mid[1].color, blue);
+    }
+
+    #[test]
+    fn mismatched_gradient_stops_resample_at_the_union_of_offsets() {
+        let black = Color::rgb_u8(0, 0, 0);
+        let white = Color::rgb_u8(255, 255, 255);
+        let a = vec![GradientStop::new(0.0, black), GradientStop::new(1.0, white)];
+        let b = vec![
+            GradientStop::new(0.0, white),
+            GradientStop::new(0.5, white),
+            GradientStop::new(1.0, white),
+        ];
+        let mid = a.interpolate(b, Phase::HALF);
+        let offsets: Vec<f32> = mid.iter().map(|s| s.offset).collect();
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0]);
+        // At offset 0.5 the two-stop ramp is mid-gray; halfway to white.
+        assert_eq!(mid[1].color.r, 0.75);
+    }
+
+    #[test]
+    fn solid_paint_fades_into_a_gradient_in_place() {
+        let black = Color::rgb_u8(0, 0, 0);
+        let white = Color::rgb_u8(255, 255, 255);
+        let gradient = Paint::LinearGradient(LinearGradient::evenly(
+            Vec2(0.0, 0.0),
+            Vec2(10.0, 0.0),
+            [black, white],
+        ));
+        let Paint::LinearGradient(mid) = Paint::Solid(white).interpolate(gradient, Phase::HALF)
+        else {
+            panic!("a solid against a linear gradient stays linear");
+        };
+        assert_eq!(mid.end, Vec2(10.0, 0.0));
+        assert_eq!(mid.stops[0].color.r, 0.5);
+        assert_eq!(mid.stops[1].color, white);
+    }
+
+    #[test]
+    fn gradient_endpoints_are_exact() {
+        let a = RadialGradient::evenly(
+            Vec2(0.0, 0.0),
+            5.0,
+            [Color::rgb_u8(255, 255, 255), Color::rgb_u8(0, 0, 0)],
+        );
+        let b = RadialGradient::evenly(
+            Vec2(4.0, 2.0),
+            9.0,
+            [Color::rgb_u8(0, 0, 0), Color::rgb_u8(255, 255, 255)],
+        );
+        assert_eq!(a.clone().interpolate(b.clone(), Phase::ZERO), a);
+        assert_eq!(a.clone().interpolate(b.clone(), Phase::ONE), b);
+    }
+
+    #[test]
+    fn image_patterns_slide_only_over_the_same_image() {
+        use crate::raster::{CpuRasterImage, PixelFormat};
+
+        let photo = CpuRasterImage::new(1, 1, PixelFormat::Rgba8, vec![1, 2, 3, 255]);
+        let other = CpuRasterImage::new(1, 1, PixelFormat::Rgba8, vec![9, 9, 9, 255]);
+        let at = |image: &CpuRasterImage, x: f32| {
+            Paint::from(
+                ImagePattern::new(image.clone()).with_transform(Transform::translate(Vec2(x, 0.0))),
+            )
+        };
+
+        let Paint::Image(mid) = at(&photo, 0.0).interpolate(at(&photo, 10.0), Phase::HALF) else {
+            panic!("two patterns of one image stay a pattern");
+        };
+        assert_eq!(mid.transform.tx, 5.0);
+
+        let swapped = at(&photo, 0.0).interpolate(at(&other, 10.0), Phase::new(0.25).unwrap());
+        assert_eq!(swapped, at(&photo, 0.0));
+    }
+
+    #[test]
+    fn strokes_lerp_width_and_matching_dashes() {
+        use crate::vector::{StrokeCap, StrokeJoin};
+
+        let a = Stroke::new(Color::rgb_u8(0, 0, 0), 2.0)
+            .with_cap(StrokeCap::Butt)
+            .with_dash(DashPattern::new([4.0, 2.0], 0.0));
+        let b = Stroke::new(Color::rgb_u8(255, 255, 255), 6.0)
+            .with_join(StrokeJoin::Bevel)
+            .with_dash(DashPattern::new([8.0, 4.0], 2.0));
+
+        let quarter = a.clone().interpolate(b.clone(), Phase::new(0.25).unwrap());
+        assert_eq!(quarter.width, 3.0);
+        assert_eq!(quarter.cap, StrokeCap::Butt);
+        assert_eq!(quarter.join, StrokeJoin::Round);
+        let dash = quarter.dash.expect("both sides are dashed");
+        assert_eq!(dash.lengths, vec![5.0, 2.5]);
+        assert_eq!(dash.offset, 0.5);
+
+        let solid = Stroke::new(Color::rgb_u8(0, 0, 0), 2.0);
+        assert!(solid
+            .clone()
+            .interpolate(a.clone(), Phase::new(0.4).unwrap())
+            .dash
+            .is_none());
+        assert!(solid
+            .interpolate(a, Phase::new(0.6).unwrap())
+            .dash
+            .is_some());
+    }
+
+    #[test]
+    fn strokes_lerp_matching_width_profiles() {
+        let a = Stroke::new(Color::rgb_u8(0, 0, 0), 1.0)
+            .with_width_profile(WidthProfile::new([(0.0, 0.0), (1.0, 4.0)]));
+        let b = Stroke::new(Color::rgb_u8(0, 0, 0), 1.0)
+            .with_width_profile(WidthProfile::new([(0.0, 8.0), (1.0, 8.0)]));
+
+        let half = a.clone().interpolate(b, Phase::HALF);
+        let profile = half.width_profile.expect("both sides have profiles");
+        assert_eq!(profile.width_at(0.0), 4.0);
+        assert_eq!(profile.width_at(1.0), 6.0);
+        assert_eq!(half.width, 6.0);
+
+        let plain = Stroke::new(Color::rgb_u8(0, 0, 0), 2.0);
+        let early = plain.interpolate(a, Phase::new(0.4).unwrap());
+        assert!(early.width_profile.is_none());
+    }
+
+    #[test]
+    fn clip_rects_lerp_origin_and_size() {
+        let a = ClipRegion::rect(Rect {
+            origin: Vec2(0.0, 0.0),
+            size: Vec2(0.0, 20.0),
+        });
+        let b = ClipRegion::rect(Rect {
+            origin: Vec2(10.0, 0.0),
+            size: Vec2(100.0, 20.0),
+        });
+        assert_eq!(
+            a.interpolate(b, Phase::HALF),
+            ClipRegion::rect(Rect {
+                origin: Vec2(5.0, 0.0),
+                size: Vec2(50.0, 20.0),
+            })
+        );
+    }
+
+    #[test]
+    fn clip_rect_morphs_into_a_matching_path() {
+        let rect = ClipRegion::rect(Rect {
+            origin: Vec2::ZERO,
+            size: Vec2(10.0, 10.0),
+        });
+        // A diamond drawn with the same five commands as a rect.
+        let diamond = ClipRegion::path_with_fill_rule(
+            vec![
+                PathCommand::MoveTo(Vec2(5.0, 0.0)),
+                PathCommand::LineTo(Vec2(10.0, 5.0)),
+                PathCommand::LineTo(Vec2(5.0, 10.0)),
+                PathCommand::LineTo(Vec2(0.0, 5.0)),
+                PathCommand::Close,
+            ],
+            crate::vector::FillRule::EvenOdd,
+        );
+        let mid = rect.interpolate(diamond, Phase::HALF);
+        assert_eq!(mid.fill_rule(), crate::vector::FillRule::EvenOdd);
+        assert_eq!(
+            mid.to_commands(),
+            vec![
+                PathCommand::MoveTo(Vec2(2.5, 0.0)),
+                PathCommand::LineTo(Vec2(10.0, 2.5)),
+                PathCommand::LineTo(Vec2(7.5, 10.0)),
+                PathCommand::LineTo(Vec2(0.0, 7.5)),
+                PathCommand::Close,
+            ]
+        );
+    }
+
+    #[test]
+    fn clip_paths_with_different_structure_switch_at_half() {
+        let rect = ClipRegion::rect(Rect {
+            origin: Vec2::ZERO,
+            size: Vec2(10.0, 10.0),
+        });
+        let triangle = ClipRegion::path(vec![
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::LineTo(Vec2(10.0, 0.0)),
+            PathCommand::LineTo(Vec2(5.0, 10.0)),
+            PathCommand::Close,
+        ]);
+        let early = rect
+            .clone()
+            .interpolate(triangle.clone(), Phase::new(0.4).unwrap());
+        assert_eq!(early, rect);
+        let late = rect.interpolate(triangle.clone(), Phase::new(0.6).unwrap());
+        assert_eq!(late, triangle);
+    }
+
+    #[test]
+    fn distortions_lerp_within_a_kind_and_switch_across_kinds() {
+        let still = Distortion::from(Ripple::default());
+        let moving = Distortion::from(Ripple {
+            amplitude: 8.0,
+            phase: Phase::ONE,
+            ..Ripple::default()
+        });
+        let Distortion::Ripple(mid) = still.interpolate(moving, Phase::HALF) else {
+            panic!("expected a ripple");
+        };
+        assert_eq!(mid.amplitude, 6.0);
+        assert_eq!(mid.phase, Phase::HALF);
+
+        let twirl = Distortion::from(Twirl::default());
+        assert_eq!(still.interpolate(twirl, Phase::new(0.4).unwrap()), still);
+        assert_eq!(still.interpolate(twirl, Phase::new(0.6).unwrap()), twirl);
+    }
 }
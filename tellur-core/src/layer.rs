@@ -18,10 +18,12 @@
 //!
 //! `Layer` composes `RasterComponent` children by rendering each one at
 //! a pixel sub-resolution matching its logical paint bounds and
-//! source-over compositing it onto the output at the corresponding pixel
-//! offset.
+//! compositing it onto the output at the corresponding pixel offset, with
+//! the child's [`blend_mode`](crate::raster::RasterComponent::blend_mode)
+//! (source-over unless the child is wrapped in a
+//! [`Blend`](crate::raster::Blend)).
 
-use crate::composite::composite_at;
+use crate::composite::composite_at_with;
 use crate::geometry::{Constraints, Rect, Transform, Vec2};
 use crate::raster::{PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution};
 use crate::render_context::{CompositeInput, RenderContext};
@@ -257,20 +259,21 @@ pub(crate) fn composite_children(
             child_residency,
         );
         let image = ctx.ensure_residency(image, child_residency);
-        rendered.push((image, offset_x, offset_y));
+        rendered.push((image, offset_x, offset_y, child.blend_mode()));
     }
 
     if gpu_available
         && rendered
             .iter()
-            .all(|(image, _, _)| image.residency() == RasterResidency::Gpu)
+            .all(|(image, _, _, _)| image.residency() == RasterResidency::Gpu)
     {
         let inputs: Vec<CompositeInput<'_>> = rendered
             .iter()
-            .map(|(image, offset_x, offset_y)| CompositeInput {
+            .map(|(image, offset_x, offset_y, blend)| CompositeInput {
                 image,
                 offset_x: *offset_x,
                 offset_y: *offset_y,
+                blend: *blend,
             })
             .collect();
         if let Some(gpu) = ctx.gpu_backend() {
@@ -281,9 +284,9 @@ pub(crate) fn composite_children(
     }
 
     let mut accum = vec![0u8; (target.width as usize) * (target.height as usize) * 4];
-    for (image, offset_x, offset_y) in rendered {
+    for (image, offset_x, offset_y, mode) in rendered {
         let image = ctx.readback(image);
-        composite_at(&mut accum, target, &image, offset_x, offset_y);
+        composite_at_with(&mut accum, target, &image, offset_x, offset_y, mode);
     }
 
     let image = RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, accum);
@@ -317,12 +320,13 @@ mod tests {
     use crate::placement::VectorPlacement;
     use crate::raster::CpuRasterImage;
     use crate::render_context::{DropShadowInput, GpuPreference, GpuRasterBackend, OutlineInput};
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
     use crate::vector::{Node, Paint};
 
     fn rect(w: f32, h: f32) -> Rectangle {
         Rectangle {
             size: Vec2(w, h),
+            corners: Corners::SQUARE,
             fill: Paint::Solid(Color::rgb_u8(0, 0, 0)).into(),
             stroke: None,
         }
@@ -341,6 +345,7 @@ mod tests {
     fn vector_layer_prunes_empty_child_nodes() {
         let invisible = Rectangle {
             size: Vec2(10.0, 10.0),
+            corners: Corners::SQUARE,
             fill: Paint::Solid(Color::rgba_u8(255, 0, 0, 0)).into(),
             stroke: None,
         };
@@ -357,6 +362,35 @@ mod tests {
         assert!(matches!(root.children[0], Node::Path(_)));
     }
 
+    #[test]
+    fn layer_composites_each_child_with_its_blend_mode() {
+        use crate::composite::BlendMode;
+        use crate::placement::RasterPlacement;
+        use crate::raster::{Background, RasterTransform};
+
+        let backdrop = Background::new(Color::rgb_u8(200, 100, 0));
+        let layer = Layer::builder()
+            .size(Vec2(2.0, 1.0))
+            .child(backdrop)
+            // Placement forwards the mode of the `Blend` inside it.
+            .child(
+                Background::new(Color::rgb_u8(100, 100, 255))
+                    .blend(BlendMode::Multiply)
+                    .place_at(Vec2(1.0, 0.0)),
+            )
+            .build();
+
+        let mut ctx = crate::render_context::PassThrough;
+        let image = layer.render(
+            Vec2(2.0, 1.0),
+            Resolution::new(2, 1),
+            RasterResidency::Cpu,
+            &mut ctx,
+        );
+        let image = image.into_cpu().unwrap();
+        assert_eq!(image.pixels.as_ref(), &[200, 100, 0, 255, 78, 39, 0, 255]);
+    }
+
     #[derive(Clone, PartialEq, Eq, Hash)]
     struct DummyRaster;
 
@@ -2,7 +2,7 @@
 
 use crate::geometry::{Constraints, Rect, Transform, Vec2};
 use crate::vector::{
-    Fill, Group, Node, Paint, Path, PathCommand, Stroke, VectorComponent, VectorGraphic,
+    Fill, FillRule, Group, Node, Paint, Path, PathCommand, Stroke, VectorComponent, VectorGraphic,
 };
 use crate::Keyable;
 
@@ -38,6 +38,7 @@ impl VectorComponent for DecoratedBox {
                     PathCommand::Close,
                 ],
                 fill: self.background.clone().map(|paint| Fill { paint }),
+                fill_rule: FillRule::NonZero,
                 stroke: self.border.clone(),
                 transform: Transform::IDENTITY,
             }));
@@ -537,11 +537,12 @@ pub(super) mod raster {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
 
     fn rect(w: f32, h: f32) -> Rectangle {
         Rectangle {
             size: Vec2(w, h),
+            corners: Corners::SQUARE,
             fill: None,
             stroke: None,
         }
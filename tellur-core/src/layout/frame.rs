@@ -153,12 +153,13 @@ mod tests {
     use crate::placement::raster::RasterPlacement;
     use crate::raster::{PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution};
     use crate::render_context::{PassThrough, RenderContext};
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
     use crate::vector::VectorComponent;
 
     fn rect(w: f32, h: f32) -> Rectangle {
         Rectangle {
             size: Vec2(w, h),
+            corners: Corners::SQUARE,
             fill: None,
             stroke: None,
         }
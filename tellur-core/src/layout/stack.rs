@@ -160,12 +160,13 @@ mod tests {
         CpuRasterImage, PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution,
     };
     use crate::render_context::{PassThrough, RenderContext};
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
     use crate::vector::{Fill, Paint};
 
     fn rect(size: Vec2, color: Color) -> Rectangle {
         Rectangle {
             size,
+            corners: Corners::SQUARE,
             fill: Some(Fill {
                 paint: Paint::Solid(color),
             }),
@@ -1,26 +1,38 @@
 pub mod audio;
 pub mod builder;
 pub mod cache_budget;
+pub mod chroma_key;
 pub mod clip;
 pub mod color;
 pub mod composite;
+pub mod distort;
 pub mod dyn_compare;
 pub mod easing;
 pub mod effect;
 pub mod fragment;
 pub mod geometry;
+pub mod grade;
+pub mod grain;
+pub mod image_sequence;
 pub mod interpolate;
 pub mod layer;
 pub mod layout;
 #[cfg(feature = "latex")]
 pub mod math;
+pub mod matte;
+pub mod nine_slice;
+pub mod path_measure;
+pub mod pattern;
+pub mod perspective;
 pub mod phase;
 pub mod placement;
 pub mod raster;
 pub mod render_context;
+pub mod resample;
 pub(crate) mod scalar;
 pub mod shapes;
 pub mod span;
+pub mod svg;
 pub mod text;
 pub mod time;
 pub mod timeline_component;
@@ -0,0 +1,149 @@
+//! Track mattes: masking one raster by another raster's alpha or luma.
+//!
+//! [`MatteMode`] says how a matte pixel turns into coverage, and
+//! [`apply_matte_pixels`] is the CPU reference for multiplying a content
+//! image's alpha by that coverage. The math is integer-only so the GPU pass
+//! can match it byte-for-byte.
+
+use crate::raster::{CpuRasterImage, PixelFormat};
+
+/// Which channel of the matte masks the content.
+///
+/// The luma modes weigh Rec. 709 luma by the matte's alpha, so transparent
+/// matte pixels count as black: a luma matte with holes hides the content
+/// there, and an inverted one reveals it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MatteMode {
+    /// Content shows where the matte is opaque.
+    #[default]
+    Alpha,
+    /// Content shows where the matte is transparent.
+    AlphaInverted,
+    /// Content shows where the matte is bright.
+    Luma,
+    /// Content shows where the matte is dark.
+    LumaInverted,
+}
+
+impl MatteMode {
+    pub const ALL: [Self; 4] = [
+        Self::Alpha,
+        Self::AlphaInverted,
+        Self::Luma,
+        Self::LumaInverted,
+    ];
+
+    /// How much of the content a straight-alpha matte pixel lets through,
+    /// from `0` (hidden) to `255` (fully shown).
+    pub fn coverage(self, px: [u8; 4]) -> u8 {
+        let [r, g, b, a] = px.map(u32::from);
+        let value = match self {
+            Self::Alpha | Self::AlphaInverted => a,
+            // Rec. 709 weights in 8.8 fixed point; they sum to 256.
+            Self::Luma | Self::LumaInverted => {
+                let luma = (54 * r + 183 * g + 19 * b + 128) >> 8;
+                mul_div_255(luma, a)
+            }
+        };
+        match self {
+            Self::Alpha | Self::Luma => value as u8,
+            Self::AlphaInverted | Self::LumaInverted => (255 - value) as u8,
+        }
+    }
+}
+
+/// Multiplies the alpha of straight-alpha `Rgba8` `content` pixels by the
+/// [`MatteMode::coverage`] of `matte`, placed at `(offset_x, offset_y)` in
+/// content pixels. Content outside the matte image is treated as lying over
+/// a fully transparent matte pixel.
+pub fn apply_matte_pixels(
+    content: &mut [u8],
+    content_width: u32,
+    matte: &CpuRasterImage,
+    offset_x: i32,
+    offset_y: i32,
+    mode: MatteMode,
+) {
+    assert_eq!(
+        matte.format,
+        PixelFormat::Rgba8,
+        "mattes only support straight-alpha Rgba8 images",
+    );
+    let outside = mode.coverage([0, 0, 0, 0]);
+    let width = content_width as usize;
+    if width == 0 {
+        return;
+    }
+    for (i, px) in content.chunks_exact_mut(4).enumerate() {
+        let mx = (i % width) as i64 - i64::from(offset_x);
+        let my = (i / width) as i64 - i64::from(offset_y);
+        let coverage =
+            if mx >= 0 && my >= 0 && mx < i64::from(matte.width) && my < i64::from(matte.height) {
+                let at = (my as usize * matte.width as usize + mx as usize) * 4;
+                let m = &matte.pixels[at..at + 4];
+                mode.coverage([m[0], m[1], m[2], m[3]])
+            } else {
+                outside
+            };
+        px[3] = mul_div_255(u32::from(px[3]), u32::from(coverage)) as u8;
+    }
+}
+
+/// `a * b / 255`, rounded to nearest.
+fn mul_div_255(a: u32, b: u32) -> u32 {
+    (a * b + 127) / 255
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matte(width: u32, height: u32, pixels: &[u8]) -> CpuRasterImage {
+        CpuRasterImage::new(width, height, PixelFormat::Rgba8, pixels.to_vec())
+    }
+
+    #[test]
+    fn coverage_follows_the_mode() {
+        let half_white = [255, 255, 255, 128];
+        assert_eq!(MatteMode::Alpha.coverage(half_white), 128);
+        assert_eq!(MatteMode::AlphaInverted.coverage(half_white), 127);
+        assert_eq!(MatteMode::Luma.coverage(half_white), 128);
+        assert_eq!(MatteMode::LumaInverted.coverage(half_white), 127);
+
+        let opaque_red = [255, 0, 0, 255];
+        assert_eq!(MatteMode::Alpha.coverage(opaque_red), 255);
+        // Rec. 709 luma of pure red is 0.2126 → 54.
+        assert_eq!(MatteMode::Luma.coverage(opaque_red), 54);
+        assert_eq!(MatteMode::LumaInverted.coverage(opaque_red), 201);
+    }
+
+    #[test]
+    fn transparent_matte_pixels_count_as_black() {
+        let clear_white = [255, 255, 255, 0];
+        assert_eq!(MatteMode::Luma.coverage(clear_white), 0);
+        assert_eq!(MatteMode::LumaInverted.coverage(clear_white), 255);
+    }
+
+    #[test]
+    fn apply_scales_alpha_and_keeps_color() {
+        let mut content = vec![10, 20, 30, 200, 40, 50, 60, 255];
+        let matte = matte(2, 1, &[0, 0, 0, 255, 0, 0, 0, 64]);
+        apply_matte_pixels(&mut content, 2, &matte, 0, 0, MatteMode::Alpha);
+        assert_eq!(content, vec![10, 20, 30, 200, 40, 50, 60, 64]);
+    }
+
+    #[test]
+    fn content_outside_an_offset_matte_sees_a_transparent_matte() {
+        // A 1×1 opaque matte over the second of three content pixels.
+        let opaque = matte(1, 1, &[255, 255, 255, 255]);
+        let content = [9, 9, 9, 255].repeat(3);
+
+        let mut shown = content.clone();
+        apply_matte_pixels(&mut shown, 3, &opaque, 1, 0, MatteMode::Alpha);
+        assert_eq!([shown[3], shown[7], shown[11]], [0, 255, 0]);
+
+        let mut inverted = content;
+        apply_matte_pixels(&mut inverted, 3, &opaque, 1, 0, MatteMode::AlphaInverted);
+        assert_eq!([inverted[3], inverted[7], inverted[11]], [255, 0, 255]);
+    }
+}
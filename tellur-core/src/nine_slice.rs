@@ -0,0 +1,282 @@
+//! Nine-slice scaling for bitmap frames.
+//!
+//! [`NineSlice`] cuts a [`StillImage`] into a 3×3 grid along its
+//! [`EdgeInsets`] — four corners, four edges and the center — and lays the
+//! grid out at whatever size its constraints choose. Corners keep their
+//! native scale, edges stretch or tile along their length, and the center
+//! does both, so a designer's speech bubble or panel frame grows without
+//! distorting its rounded corners. Each cell is filtered only from its own
+//! slice of the source, so a tile seam never picks up a neighboring cell.
+
+use crate::geometry::{Constraints, EdgeInsets, Vec2};
+use crate::raster::{
+    lerp_premul, lerp_rgba8_premul, pixel, unpremul, CpuRasterImage, PixelFormat, RasterComponent,
+    RasterImage, RasterResidency, Resolution, StillImage,
+};
+use crate::render_context::RenderContext;
+
+/// How a [`NineSlice`] fills the edges and center between its corners.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SliceFill {
+    /// Stretches each slice across the space it covers.
+    #[default]
+    Stretch,
+    /// Repeats each slice, rounded to a whole number of tiles that are
+    /// scaled slightly to fit, so no tile is cut off.
+    Tile,
+}
+
+/// A [`StillImage`] scaled by nine-slicing.
+///
+/// `insets` are measured in the image's own pixels, which are also its
+/// logical units (see [`StillImage`]), so the corners render at the same
+/// size a plain `StillImage` would give them. Layout takes the image's
+/// native size constrained like a `StillImage`'s, so a parent's tight or
+/// minimum constraints pick any size. When that size is smaller than two
+/// opposite insets, both corners shrink proportionally to meet in the middle.
+#[crate::component(raster)]
+#[derive(Clone, crate::Keyable)]
+pub struct NineSlice {
+    pub image: StillImage,
+    /// Where the corners end, from each side of the image.
+    pub insets: EdgeInsets,
+    #[builder(default)]
+    pub fill: SliceFill,
+}
+
+impl RasterComponent for NineSlice {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.image.layout(constraints)
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let image = nine_slice_pixels(&self.image.image, self.insets, self.fill, size, target);
+        ctx.ensure_residency(RasterImage::Cpu(image), residency)
+    }
+}
+
+/// Renders `source` nine-sliced along `insets` into a `target`-sized image
+/// covering a `size` layout box.
+pub fn nine_slice_pixels(
+    source: &CpuRasterImage,
+    insets: EdgeInsets,
+    fill: SliceFill,
+    size: Vec2,
+    target: Resolution,
+) -> CpuRasterImage {
+    assert_eq!(
+        source.format,
+        PixelFormat::Rgba8,
+        "NineSlice only supports Rgba8 images",
+    );
+    let mut out = vec![0u8; target.width as usize * target.height as usize * 4];
+    let columns = slice_axis(
+        target.width,
+        size.0,
+        source.width,
+        [insets.left, insets.right],
+        fill,
+    );
+    let rows = slice_axis(
+        target.height,
+        size.1,
+        source.height,
+        [insets.top, insets.bottom],
+        fill,
+    );
+    let src = source.pixels.as_ref();
+    for (y, row) in rows.iter().enumerate() {
+        let Some((y0, y1, wy)) = *row else { continue };
+        for (x, column) in columns.iter().enumerate() {
+            let Some((x0, x1, wx)) = *column else {
+                continue;
+            };
+            let top = lerp_rgba8_premul(
+                pixel(src, source.width, x0, y0),
+                pixel(src, source.width, x1, y0),
+                wx,
+            );
+            let bottom = lerp_rgba8_premul(
+                pixel(src, source.width, x0, y1),
+                pixel(src, source.width, x1, y1),
+                wx,
+            );
+            let offset = (y * target.width as usize + x) * 4;
+            out[offset..offset + 4].copy_from_slice(&unpremul(lerp_premul(top, bottom, wy)));
+        }
+    }
+    CpuRasterImage::new(target.width, target.height, PixelFormat::Rgba8, out)
+}
+
+/// The bilinear taps `(lo, hi, weight)` along one axis for each of
+/// `dst_len` target pixels spanning `size` logical units, or `None` where
+/// the slice under a pixel is empty.
+///
+/// `insets` are the leading and trailing corner lengths in source pixels.
+fn slice_axis(
+    dst_len: u32,
+    size: f32,
+    src_len: u32,
+    insets: [f32; 2],
+    fill: SliceFill,
+) -> Vec<Option<(u32, u32, f32)>> {
+    if size.is_nan() || size <= 0.0 || src_len == 0 {
+        return vec![None; dst_len as usize];
+    }
+    // Corners are whole source pixels and never overlap in the source.
+    let [mut start, mut end] = insets.map(|inset| inset.max(0.0));
+    if start + end > src_len as f32 {
+        let scale = src_len as f32 / (start + end);
+        start *= scale;
+        end *= scale;
+    }
+    let (start, end) = (
+        start.round(),
+        end.round().min(src_len as f32 - start.round()),
+    );
+    // On the target they keep their size unless the box is too small for
+    // both, in which case they shrink together.
+    let squeeze = if start + end > size {
+        size / (start + end)
+    } else {
+        1.0
+    };
+    let (dst_start, dst_end) = (start * squeeze, end * squeeze);
+    let src_middle = src_len as f32 - start - end;
+    let dst_middle = size - dst_start - dst_end;
+    let tile = match fill {
+        SliceFill::Stretch => dst_middle,
+        SliceFill::Tile if src_middle > 0.0 => {
+            dst_middle / (dst_middle / src_middle).round().max(1.0)
+        }
+        SliceFill::Tile => dst_middle,
+    };
+
+    let px_per_unit = dst_len as f32 / size;
+    (0..dst_len)
+        .map(|i| {
+            let at = (i as f32 + 0.5) / px_per_unit;
+            // The source coordinate under `at`, and the source pixels its
+            // slice covers.
+            let (src, first, last) = if at < dst_start {
+                (at / squeeze, 0.0, start)
+            } else if at >= size - dst_end {
+                (
+                    src_len as f32 - (size - at) / squeeze,
+                    src_len as f32 - end,
+                    src_len as f32,
+                )
+            } else {
+                let local = (at - dst_start).rem_euclid(tile);
+                (start + local * src_middle / tile, start, start + src_middle)
+            };
+            if last <= first {
+                return None;
+            }
+            let (first, last) = (first as u32, last as u32 - 1);
+            let pos = (src - 0.5).clamp(first as f32, last as f32);
+            let lo = pos.floor() as u32;
+            Some((lo, (lo + 1).min(last), pos - lo as f32))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render_context::PassThrough;
+
+    /// A 4×4 frame: a distinct red per column and a distinct green per row,
+    /// with a one-pixel border around a 2×2 center.
+    fn frame() -> StillImage {
+        let mut pixels = Vec::new();
+        for y in 0..4u8 {
+            for x in 0..4u8 {
+                pixels.extend_from_slice(&[x * 60, y * 60, 0, 255]);
+            }
+        }
+        StillImage::new(CpuRasterImage::new(4, 4, PixelFormat::Rgba8, pixels))
+    }
+
+    fn render(slice: &NineSlice, size: Vec2, target: Resolution) -> CpuRasterImage {
+        let mut ctx = PassThrough;
+        match slice.render(size, target, RasterResidency::Cpu, &mut ctx) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        }
+    }
+
+    /// The red channel of every pixel in row `y`.
+    fn reds(image: &CpuRasterImage, y: u32) -> Vec<u8> {
+        (0..image.width)
+            .map(|x| pixel(&image.pixels, image.width, x, y)[0])
+            .collect()
+    }
+
+    #[test]
+    fn lays_out_like_the_image_under_any_constraints() {
+        let slice = NineSlice::builder()
+            .image(frame())
+            .insets(EdgeInsets::all(1.0))
+            .build();
+        assert_eq!(slice.layout(Constraints::UNBOUNDED), Vec2(4.0, 4.0));
+        assert_eq!(
+            slice.layout(Constraints::tight(Vec2(30.0, 12.0))),
+            Vec2(30.0, 12.0)
+        );
+    }
+
+    #[test]
+    fn corners_keep_native_scale_while_the_middle_stretches() {
+        let slice = NineSlice::builder()
+            .image(frame())
+            .insets(EdgeInsets::all(1.0))
+            .build();
+        let image = render(&slice, Vec2(8.0, 6.0), Resolution::new(8, 6));
+        // Corner columns and rows are the source's, untouched.
+        assert_eq!(reds(&image, 0)[0], 0);
+        assert_eq!(reds(&image, 0)[7], 180);
+        assert_eq!(pixel(&image.pixels, 8, 0, 5), [0, 180, 0, 255]);
+        assert_eq!(pixel(&image.pixels, 8, 7, 5), [180, 180, 0, 255]);
+        // The middle ramps from the center's first column to its last,
+        // never reaching into the border columns.
+        let middle = &reds(&image, 2)[1..7];
+        assert_eq!(middle.first(), Some(&60));
+        assert_eq!(middle.last(), Some(&120));
+        assert!(middle.windows(2).all(|pair| pair[0] <= pair[1]));
+
+        // At twice the pixel density the corners are two pixels wide.
+        let dense = render(&slice, Vec2(8.0, 6.0), Resolution::new(16, 12));
+        assert_eq!(&reds(&dense, 0)[..2], &[0, 0]);
+        assert_eq!(&reds(&dense, 0)[14..], &[180, 180]);
+    }
+
+    #[test]
+    fn tiling_repeats_whole_slices() {
+        let slice = NineSlice::builder()
+            .image(frame())
+            .insets(EdgeInsets::all(1.0))
+            .fill(SliceFill::Tile)
+            .build();
+        let image = render(&slice, Vec2(8.0, 4.0), Resolution::new(8, 4));
+        assert_eq!(reds(&image, 1), vec![0, 60, 120, 60, 120, 60, 120, 180]);
+    }
+
+    #[test]
+    fn corners_shrink_together_when_the_box_is_too_small() {
+        let slice = NineSlice::builder()
+            .image(frame())
+            .insets(EdgeInsets::symmetric(2.0, 1.0))
+            .build();
+        let image = render(&slice, Vec2(2.0, 4.0), Resolution::new(2, 4));
+        // Two 2-pixel corners squeezed into two pixels: one pixel each,
+        // filtered from the left and right halves of the source.
+        assert_eq!(reds(&image, 0), vec![30, 150]);
+    }
+}
@@ -0,0 +1,426 @@
+//! Arc-length measurement over [`PathCommand`] sequences.
+//!
+//! [`PathMeasure`] walks a command list once, records every drawn segment
+//! with its cumulative length, and then answers "where is the path, and
+//! which way is it heading, `d` units along?" in logarithmic time. Curves
+//! are measured by sampling a fixed number of chords, the same
+//! approximation [`Write`](crate::effect::Write) uses to pace strokes by
+//! length, so a point placed at a given length lines up with the tip of a
+//! stroke trimmed to that length.
+//!
+//! Contours are measured back to back: a `MoveTo` starts a new contour
+//! without adding the jump to the total, and `Close` adds the closing edge.
+
+use crate::geometry::Vec2;
+use crate::phase::Phase;
+use crate::vector::PathCommand;
+
+const QUAD_STEPS: usize = 16;
+const CUBIC_STEPS: usize = 24;
+/// Parameter step used to recover a direction where a curve's derivative
+/// vanishes (a control point sitting on its endpoint).
+const TANGENT_PROBE: f32 = 1.0e-3;
+
+/// A position on a path and the unit direction of travel there.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PathSample {
+    pub point: Vec2,
+    /// Unit tangent in the direction of travel. Y points down, so a tangent
+    /// of `(0, 1)` heads towards the bottom of the canvas.
+    pub tangent: Vec2,
+}
+
+impl PathSample {
+    /// The tangent's angle in radians, measured from the +X axis towards
+    /// +Y — the rotation [`Transform::rotate`](crate::geometry::Transform::rotate)
+    /// needs to align a +X-facing shape with the path.
+    pub fn angle(&self) -> f32 {
+        self.tangent.1.atan2(self.tangent.0)
+    }
+}
+
+/// Cumulative arc-length index over a path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathMeasure {
+    segments: Vec<MeasuredSegment>,
+    length: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MeasuredSegment {
+    curve: Curve,
+    /// Path length at the start of this segment.
+    start: f32,
+    length: f32,
+}
+
+impl PathMeasure {
+    pub fn new(commands: &[PathCommand]) -> Self {
+        let mut length = 0.0;
+        let segments = curves(commands)
+            .map(|curve| {
+                let segment = MeasuredSegment {
+                    curve,
+                    start: length,
+                    length: curve.length(),
+                };
+                length += segment.length;
+                segment
+            })
+            .collect();
+        Self { segments, length }
+    }
+
+    /// Total drawn length of every contour.
+    pub fn length(&self) -> f32 {
+        self.length
+    }
+
+    /// True when the path draws no segment at all (for example, it is empty
+    /// or only moves the pen).
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// The point `distance` units along the path, clamped to its ends.
+    pub fn point_at(&self, distance: f32) -> Option<Vec2> {
+        self.sample_at(distance).map(|sample| sample.point)
+    }
+
+    /// The unit tangent `distance` units along the path, clamped to its ends.
+    pub fn tangent_at(&self, distance: f32) -> Option<Vec2> {
+        self.sample_at(distance).map(|sample| sample.tangent)
+    }
+
+    /// Point and tangent `distance` units along the path, clamped to its
+    /// ends. `None` when the path draws nothing.
+    pub fn sample_at(&self, distance: f32) -> Option<PathSample> {
+        let first = self.segments.first()?;
+        let distance = if distance.is_nan() {
+            0.0
+        } else {
+            distance.clamp(0.0, self.length)
+        };
+        // The first segment with positive length that reaches `distance`;
+        // zero-length segments (repeated points) have no direction to offer.
+        let index = self
+            .segments
+            .partition_point(|segment| segment.start + segment.length < distance);
+        let Some(segment) = self.segments[index.min(self.segments.len() - 1)..]
+            .iter()
+            .find(|segment| segment.length > 0.0)
+            .or_else(|| {
+                self.segments[..index]
+                    .iter()
+                    .rev()
+                    .find(|segment| segment.length > 0.0)
+            })
+        else {
+            return Some(PathSample {
+                point: first.curve.start(),
+                tangent: Vec2(1.0, 0.0),
+            });
+        };
+        let t = segment.curve.t_at_length(distance - segment.start);
+        Some(PathSample {
+            point: segment.curve.point(t),
+            tangent: segment.curve.tangent(t),
+        })
+    }
+
+    /// Point and tangent at a fraction of the total length.
+    pub fn sample_at_phase(&self, progress: Phase) -> Option<PathSample> {
+        self.sample_at(self.length * progress.get())
+    }
+}
+
+/// Total drawn length of `commands`, without building an index.
+pub(crate) fn path_length(commands: &[PathCommand]) -> f32 {
+    curves(commands).map(|curve| curve.length()).sum()
+}
+
+pub(crate) fn quad_length(p0: Vec2, c: Vec2, p1: Vec2) -> f32 {
+    Curve::Quad(p0, c, p1).length()
+}
+
+pub(crate) fn cubic_length(p0: Vec2, c1: Vec2, c2: Vec2, p1: Vec2) -> f32 {
+    Curve::Cubic(p0, c1, c2, p1).length()
+}
+
+/// Curve parameter at which the sampled arc length reaches `target`.
+pub(crate) fn quad_t_at_length(p0: Vec2, c: Vec2, p1: Vec2, target: f32) -> f32 {
+    Curve::Quad(p0, c, p1).t_at_length(target)
+}
+
+/// Curve parameter at which the sampled arc length reaches `target`.
+pub(crate) fn cubic_t_at_length(p0: Vec2, c1: Vec2, c2: Vec2, p1: Vec2, target: f32) -> f32 {
+    Curve::Cubic(p0, c1, c2, p1).t_at_length(target)
+}
+
+/// One drawn segment with absolute endpoints.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Curve {
+    Line(Vec2, Vec2),
+    Quad(Vec2, Vec2, Vec2),
+    Cubic(Vec2, Vec2, Vec2, Vec2),
+}
+
+/// Every drawn segment of `commands` in order. Segments without a current
+/// point (a draw command before any `MoveTo`) are skipped.
+fn curves(commands: &[PathCommand]) -> impl Iterator<Item = Curve> + '_ {
+    let mut current = None;
+    let mut start = None;
+    commands.iter().filter_map(move |&cmd| match cmd {
+        PathCommand::MoveTo(p) => {
+            current = Some(p);
+            start = Some(p);
+            None
+        }
+        PathCommand::LineTo(to) => current.replace(to).map(|from| Curve::Line(from, to)),
+        PathCommand::QuadTo { control, to } => current
+            .replace(to)
+            .map(|from| Curve::Quad(from, control, to)),
+        PathCommand::CubicTo { c1, c2, to } => current
+            .replace(to)
+            .map(|from| Curve::Cubic(from, c1, c2, to)),
+        PathCommand::Close => {
+            let (from, to) = (current?, start?);
+            current = Some(to);
+            Some(Curve::Line(from, to))
+        }
+    })
+}
+
+impl Curve {
+    fn start(self) -> Vec2 {
+        match self {
+            Self::Line(p0, _) | Self::Quad(p0, _, _) | Self::Cubic(p0, _, _, _) => p0,
+        }
+    }
+
+    fn end(self) -> Vec2 {
+        match self {
+            Self::Line(_, p1) | Self::Quad(_, _, p1) | Self::Cubic(_, _, _, p1) => p1,
+        }
+    }
+
+    fn steps(self) -> usize {
+        match self {
+            Self::Line(..) => 1,
+            Self::Quad(..) => QUAD_STEPS,
+            Self::Cubic(..) => CUBIC_STEPS,
+        }
+    }
+
+    fn point(self, t: f32) -> Vec2 {
+        match self {
+            Self::Line(p0, p1) => lerp(p0, p1, t),
+            Self::Quad(p0, c, p1) => lerp(lerp(p0, c, t), lerp(c, p1, t), t),
+            Self::Cubic(p0, c1, c2, p1) => {
+                let p01 = lerp(p0, c1, t);
+                let p12 = lerp(c1, c2, t);
+                let p23 = lerp(c2, p1, t);
+                lerp(lerp(p01, p12, t), lerp(p12, p23, t), t)
+            }
+        }
+    }
+
+    fn derivative(self, t: f32) -> Vec2 {
+        match self {
+            Self::Line(p0, p1) => p1 - p0,
+            Self::Quad(p0, c, p1) => {
+                let a = c - p0;
+                let b = p1 - c;
+                Vec2(2.0 * lerp(a, b, t).0, 2.0 * lerp(a, b, t).1)
+            }
+            Self::Cubic(p0, c1, c2, p1) => {
+                let a = c1 - p0;
+                let b = c2 - c1;
+                let c = p1 - c2;
+                let d = lerp(lerp(a, b, t), lerp(b, c, t), t);
+                Vec2(3.0 * d.0, 3.0 * d.1)
+            }
+        }
+    }
+
+    /// Unit direction of travel at `t`. Where the derivative vanishes the
+    /// direction comes from a nearby chord, and finally from the whole
+    /// segment's chord.
+    fn tangent(self, t: f32) -> Vec2 {
+        let probe = if t + TANGENT_PROBE <= 1.0 {
+            self.point(t + TANGENT_PROBE) - self.point(t)
+        } else {
+            self.point(t) - self.point(t - TANGENT_PROBE)
+        };
+        [self.derivative(t), probe, self.end() - self.start()]
+            .into_iter()
+            .find_map(normalized)
+            .unwrap_or(Vec2(1.0, 0.0))
+    }
+
+    /// Chord-sampled arc length.
+    fn length(self) -> f32 {
+        if let Self::Line(p0, p1) = self {
+            return distance(p0, p1);
+        }
+        let steps = self.steps();
+        let mut prev = self.point(0.0);
+        let mut total = 0.0;
+        for i in 1..=steps {
+            let p = self.point(i as f32 / steps as f32);
+            total += distance(prev, p);
+            prev = p;
+        }
+        total
+    }
+
+    /// Curve parameter at which the chord-sampled length reaches `target`,
+    /// interpolating linearly within the chord that crosses it.
+    fn t_at_length(self, target: f32) -> f32 {
+        let steps = self.steps();
+        let mut prev_t = 0.0;
+        let mut prev = self.point(prev_t);
+        let mut walked = 0.0;
+
+        for i in 1..=steps {
+            let t = i as f32 / steps as f32;
+            let p = self.point(t);
+            let len = distance(prev, p);
+            if walked + len >= target {
+                let local = if len <= 0.0 {
+                    1.0
+                } else {
+                    ((target - walked) / len).clamp(0.0, 1.0)
+                };
+                return prev_t + (t - prev_t) * local;
+            }
+            walked += len;
+            prev = p;
+            prev_t = t;
+        }
+
+        1.0
+    }
+}
+
+fn normalized(v: Vec2) -> Option<Vec2> {
+    let len = (v.0 * v.0 + v.1 * v.1).sqrt();
+    (len > 1.0e-6 && len.is_finite()).then(|| Vec2(v.0 / len, v.1 / len))
+}
+
+fn distance(a: Vec2, b: Vec2) -> f32 {
+    ((b.0 - a.0).powi(2) + (b.1 - a.1).powi(2)).sqrt()
+}
+
+fn lerp(a: Vec2, b: Vec2, t: f32) -> Vec2 {
+    Vec2(a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_near(actual: Vec2, expected: Vec2) {
+        assert!(
+            (actual.0 - expected.0).abs() < 1e-3 && (actual.1 - expected.1).abs() < 1e-3,
+            "{actual:?} != {expected:?}"
+        );
+    }
+
+    fn square() -> Vec<PathCommand> {
+        vec![
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::LineTo(Vec2(10.0, 0.0)),
+            PathCommand::LineTo(Vec2(10.0, 10.0)),
+            PathCommand::LineTo(Vec2(0.0, 10.0)),
+            PathCommand::Close,
+        ]
+    }
+
+    #[test]
+    fn lines_measure_exactly_including_the_closing_edge() {
+        let measure = PathMeasure::new(&square());
+        assert_eq!(measure.length(), 40.0);
+        assert_eq!(path_length(&square()), 40.0);
+
+        let sample = measure.sample_at(15.0).unwrap();
+        assert_eq!(sample.point, Vec2(10.0, 5.0));
+        assert_eq!(sample.tangent, Vec2(0.0, 1.0));
+        assert!((sample.angle() - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+
+        assert_eq!(measure.point_at(35.0), Some(Vec2(0.0, 5.0)));
+        assert_eq!(measure.tangent_at(35.0), Some(Vec2(0.0, -1.0)));
+    }
+
+    #[test]
+    fn distances_clamp_to_the_path_ends() {
+        let measure = PathMeasure::new(&square());
+        assert_eq!(measure.point_at(-5.0), Some(Vec2(0.0, 0.0)));
+        assert_eq!(measure.point_at(100.0), Some(Vec2(0.0, 0.0)));
+        assert_eq!(measure.tangent_at(100.0), Some(Vec2(0.0, -1.0)));
+        assert_eq!(
+            measure.sample_at_phase(Phase::HALF).unwrap().point,
+            Vec2(10.0, 10.0)
+        );
+    }
+
+    #[test]
+    fn pen_moves_between_contours_add_no_length() {
+        let measure = PathMeasure::new(&[
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::LineTo(Vec2(10.0, 0.0)),
+            PathCommand::MoveTo(Vec2(100.0, 0.0)),
+            PathCommand::LineTo(Vec2(100.0, 10.0)),
+        ]);
+        assert_eq!(measure.length(), 20.0);
+        assert_eq!(measure.point_at(12.0), Some(Vec2(100.0, 2.0)));
+    }
+
+    #[test]
+    fn curves_follow_arc_length_and_analytic_tangents() {
+        // A quarter circle of radius 10 centered on the origin.
+        const KAPPA: f32 = 0.552_284_8;
+        let measure = PathMeasure::new(&[
+            PathCommand::MoveTo(Vec2(10.0, 0.0)),
+            PathCommand::CubicTo {
+                c1: Vec2(10.0, 10.0 * KAPPA),
+                c2: Vec2(10.0 * KAPPA, 10.0),
+                to: Vec2(0.0, 10.0),
+            },
+        ]);
+        let quarter = std::f32::consts::FRAC_PI_2 * 10.0;
+        assert!((measure.length() - quarter).abs() < 0.01);
+
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        let sample = measure.sample_at(measure.length() * 0.5).unwrap();
+        assert_near(sample.point, Vec2(10.0 * half, 10.0 * half));
+        assert_near(sample.tangent, Vec2(-half, half));
+    }
+
+    #[test]
+    fn degenerate_control_points_still_have_a_direction() {
+        let measure = PathMeasure::new(&[
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::QuadTo {
+                control: Vec2(0.0, 0.0),
+                to: Vec2(0.0, 8.0),
+            },
+        ]);
+        assert_near(measure.tangent_at(0.0).unwrap(), Vec2(0.0, 1.0));
+    }
+
+    #[test]
+    fn paths_without_segments_have_no_samples() {
+        assert!(PathMeasure::new(&[]).is_empty());
+        let moves_only = PathMeasure::new(&[PathCommand::MoveTo(Vec2(3.0, 4.0))]);
+        assert!(moves_only.is_empty());
+        assert_eq!(moves_only.sample_at(0.0), None);
+
+        let dot = PathMeasure::new(&[
+            PathCommand::MoveTo(Vec2(3.0, 4.0)),
+            PathCommand::LineTo(Vec2(3.0, 4.0)),
+        ]);
+        assert_eq!(dot.length(), 0.0);
+        assert_eq!(dot.point_at(0.0), Some(Vec2(3.0, 4.0)));
+    }
+}
@@ -0,0 +1,569 @@
+//! Procedural pattern generators.
+//!
+//! [`NoiseField`], [`Checker`], [`Stripes`] and [`DotGrid`] fill whatever box
+//! they are given, like [`Background`](crate::raster::Background), with a
+//! pattern measured in logical units from the box's top-left corner, so it
+//! keeps its look at any render resolution. Each blends between two colors
+//! and has an `evolution` to animate it by; all of it is plain data, so an
+//! animated pattern gets one cache entry per distinct frame.
+//!
+//! [`pattern_pixels`] is the reference a GPU pass is kept in lockstep with.
+//! The noise is hashed from integers and the hard-edged patterns are
+//! box-filtered over each pixel's footprint, so every pattern is a
+//! continuous function of position and the two agree to within one code
+//! value.
+
+use crate::color::Color;
+use crate::geometry::{Constraints, Vec2};
+use crate::grain::pcg;
+use crate::raster::{
+    fill_layout, PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution,
+};
+use crate::render_context::{PatternInput, RenderContext};
+
+/// The most octaves a [`NoiseField`] sums; more would be finer than a pixel
+/// at any practical `scale`.
+pub const MAX_OCTAVES: u32 = 8;
+
+/// The gradient noise a [`NoiseField`] is built from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum NoiseKind {
+    /// Perlin's improved noise: soft, grid-aligned blobs.
+    #[default]
+    Perlin,
+    /// Simplex noise: similar, with fewer directional artifacts.
+    Simplex,
+}
+
+/// Fractal gradient noise, mapped from `low` to `high`.
+///
+/// Each octave adds detail at twice the frequency and half the amplitude of
+/// the one before. The noise is three-dimensional, with `evolution` as the
+/// third axis, so driving `evolution` steadily makes the field churn in
+/// place rather than scroll.
+#[crate::component(raster)]
+#[derive(Clone, crate::Keyable)]
+pub struct NoiseField {
+    /// Size of a feature at the first octave (logical units).
+    #[builder(default = 64.0)]
+    pub scale: f32,
+    /// How many octaves to sum, clamped to `1..=MAX_OCTAVES`.
+    #[builder(default = 4)]
+    pub octaves: u32,
+    /// Picks one of many unrelated fields.
+    #[builder(default)]
+    pub seed: u32,
+    /// Position along the third axis; a change of `1` is about one feature.
+    #[builder(default)]
+    pub evolution: f32,
+    #[builder(default)]
+    pub kind: NoiseKind,
+    #[builder(default = Color::rgb_u8(0, 0, 0))]
+    pub low: Color,
+    #[builder(default = Color::rgb_u8(255, 255, 255))]
+    pub high: Color,
+}
+
+/// A checkerboard with `first` in the top-left cell.
+#[crate::component(raster)]
+#[derive(Clone, crate::Keyable)]
+pub struct Checker {
+    /// Side of one cell (logical units).
+    #[builder(default = 32.0)]
+    pub scale: f32,
+    /// Scrolls the board along +x, by one pair of cells per `1`.
+    #[builder(default)]
+    pub evolution: f32,
+    #[builder(default = Color::rgb_u8(255, 255, 255))]
+    pub first: Color,
+    #[builder(default = Color::rgb_u8(0, 0, 0))]
+    pub second: Color,
+}
+
+/// Parallel stripes of equal width, alternating `first` and `second`.
+#[crate::component(raster)]
+#[derive(Clone, crate::Keyable)]
+pub struct Stripes {
+    /// Width of one `first` stripe and one `second` stripe together
+    /// (logical units).
+    #[builder(default = 32.0)]
+    pub scale: f32,
+    /// Direction across the stripes in radians; `0` gives vertical stripes.
+    #[builder(default)]
+    pub angle: f32,
+    /// Scrolls the stripes along `angle`, by one pair per `1`.
+    #[builder(default)]
+    pub evolution: f32,
+    #[builder(default = Color::rgb_u8(255, 255, 255))]
+    pub first: Color,
+    #[builder(default = Color::rgb_u8(0, 0, 0))]
+    pub second: Color,
+}
+
+/// Round dots on a square grid, over `background`.
+///
+/// The top-left dot is centered half a `scale` in from each edge. Dots are
+/// clipped to their grid cell, so a `radius` past half the `scale` squares
+/// them off rather than merging them.
+#[crate::component(raster)]
+#[derive(Clone, crate::Keyable)]
+pub struct DotGrid {
+    /// Distance between neighboring dot centers (logical units).
+    #[builder(default = 32.0)]
+    pub scale: f32,
+    /// Dot radius (logical units).
+    #[builder(default = 4.0)]
+    pub radius: f32,
+    /// Scrolls the grid along +x, by one dot per `1`.
+    #[builder(default)]
+    pub evolution: f32,
+    #[builder(default = Color::rgb_u8(255, 255, 255))]
+    pub dot: Color,
+    #[builder(default = Color::rgba_u8(0, 0, 0, 0))]
+    pub background: Color,
+}
+
+/// One of the generators, as [`pattern_pixels`] and GPU backends take it.
+#[derive(Clone, Copy)]
+pub enum Pattern<'a> {
+    Noise(&'a NoiseField),
+    Checker(&'a Checker),
+    Stripes(&'a Stripes),
+    DotGrid(&'a DotGrid),
+}
+
+impl Pattern<'_> {
+    /// The pattern's period or feature size. A non-positive or non-finite
+    /// scale fills the box with the first color (see [`Self::colors`]).
+    pub fn scale(self) -> f32 {
+        match self {
+            Pattern::Noise(noise) => noise.scale,
+            Pattern::Checker(checker) => checker.scale,
+            Pattern::Stripes(stripes) => stripes.scale,
+            Pattern::DotGrid(grid) => grid.scale,
+        }
+    }
+
+    /// The colors at the two ends of the pattern's blend: `low` and `high`,
+    /// `first` and `second`, or `background` and `dot`.
+    pub fn colors(self) -> [Color; 2] {
+        match self {
+            Pattern::Noise(noise) => [noise.low, noise.high],
+            Pattern::Checker(checker) => [checker.first, checker.second],
+            Pattern::Stripes(stripes) => [stripes.first, stripes.second],
+            Pattern::DotGrid(grid) => [grid.background, grid.dot],
+        }
+    }
+}
+
+macro_rules! pattern_component {
+    ($component:ident, $variant:ident) => {
+        impl RasterComponent for $component {
+            fn layout(&self, constraints: Constraints) -> Vec2 {
+                fill_layout(constraints)
+            }
+
+            fn render(
+                &self,
+                size: Vec2,
+                target: Resolution,
+                residency: RasterResidency,
+                ctx: &mut dyn RenderContext,
+            ) -> RasterImage {
+                render_pattern(Pattern::$variant(self), size, target, residency, ctx)
+            }
+        }
+    };
+}
+
+pattern_component!(NoiseField, Noise);
+pattern_component!(Checker, Checker);
+pattern_component!(Stripes, Stripes);
+pattern_component!(DotGrid, DotGrid);
+
+fn render_pattern(
+    pattern: Pattern<'_>,
+    size: Vec2,
+    target: Resolution,
+    residency: RasterResidency,
+    ctx: &mut dyn RenderContext,
+) -> RasterImage {
+    if ctx.prefers_gpu() {
+        if let Some(gpu) = ctx.gpu_backend() {
+            let input = PatternInput {
+                target,
+                size,
+                pattern,
+            };
+            if let Some(image) = gpu.pattern(input) {
+                return ctx.ensure_residency(image, residency);
+            }
+        }
+    }
+
+    let pixels = pattern_pixels(pattern, size, target);
+    let image = RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, pixels);
+    ctx.ensure_residency(image, residency)
+}
+
+/// Renders `pattern` into a `target`-sized straight-alpha `Rgba8` image
+/// covering a `size` layout box.
+pub fn pattern_pixels(pattern: Pattern<'_>, size: Vec2, target: Resolution) -> Vec<u8> {
+    let [from, to] = pattern.colors().map(premultiplied);
+    let scale = pattern.scale();
+    let degenerate = !scale.is_finite() || scale <= 0.0;
+    // Logical units per pixel along each axis.
+    let unit = [size.0 / target.width as f32, size.1 / target.height as f32];
+    let step = unit.map(|u| u / scale);
+    let mut out = Vec::with_capacity(target.width as usize * target.height as usize * 4);
+    for y in 0..target.height {
+        let py = y as f32 + 0.5;
+        for x in 0..target.width {
+            let px = x as f32 + 0.5;
+            let t = if degenerate {
+                0.0
+            } else {
+                match pattern {
+                    Pattern::Noise(noise) => fractal_noise(
+                        noise.kind,
+                        [px * step[0], py * step[1], noise.evolution],
+                        noise.octaves,
+                        noise.seed,
+                    ),
+                    Pattern::Checker(checker) => {
+                        let u = square_wave(px * step[0] - 2.0 * checker.evolution, step[0]);
+                        let v = square_wave(py * step[1], step[1]);
+                        0.5 - 0.5 * u * v
+                    }
+                    Pattern::Stripes(stripes) => {
+                        let (sin, cos) = stripes.angle.sin_cos();
+                        // Half-periods per pixel along each axis.
+                        let a = 2.0 * cos * step[0];
+                        let b = 2.0 * sin * step[1];
+                        let across = px * a + py * b - 2.0 * stripes.evolution;
+                        0.5 - 0.5 * square_wave(across, a.abs() + b.abs())
+                    }
+                    Pattern::DotGrid(grid) => {
+                        let lx = (fract(px * step[0] - grid.evolution) - 0.5) * scale;
+                        let ly = (fract(py * step[1]) - 0.5) * scale;
+                        let edge = (lx * lx + ly * ly).sqrt() - grid.radius;
+                        (0.5 - edge / unit[0].max(unit[1])).clamp(0.0, 1.0)
+                    }
+                }
+            };
+            out.extend_from_slice(&blend(from, to, t));
+        }
+    }
+    out
+}
+
+fn premultiplied(color: Color) -> [f32; 4] {
+    [
+        color.r * color.a,
+        color.g * color.a,
+        color.b * color.a,
+        color.a,
+    ]
+}
+
+/// Straight-alpha `Rgba8` for `t` of the way from premultiplied `from` to
+/// `to`.
+fn blend(from: [f32; 4], to: [f32; 4], t: f32) -> [u8; 4] {
+    let mixed: [f32; 4] = std::array::from_fn(|c| from[c] + (to[c] - from[c]) * t);
+    let alpha = mixed[3];
+    let code = |v: f32| (v * 255.0).clamp(0.0, 255.0).round() as u8;
+    if alpha <= 0.0 {
+        return [0; 4];
+    }
+    [
+        code(mixed[0] / alpha),
+        code(mixed[1] / alpha),
+        code(mixed[2] / alpha),
+        code(alpha),
+    ]
+}
+
+/// `v - floor(v)`, which unlike [`f32::fract`] stays in `[0, 1)` for
+/// negative `v`, as WGSL's `fract` does.
+fn fract(v: f32) -> f32 {
+    v - v.floor()
+}
+
+/// A square wave, `1` on `[0, 1)` and `-1` on `[1, 2)`, box-filtered over a
+/// `width`-wide footprint centered on `p`.
+fn square_wave(p: f32, width: f32) -> f32 {
+    // The wave's integral is a triangle wave, so the average over the
+    // footprint is the triangle's difference across it.
+    let triangle = |v: f32| (fract(v * 0.5) - 0.5).abs();
+    (2.0 * (triangle(p - 0.5 * width) - triangle(p + 0.5 * width)) / width).clamp(-1.0, 1.0)
+}
+
+/// Octaves of `kind` noise at `p`, normalized to `[0, 1]`.
+fn fractal_noise(kind: NoiseKind, p: [f32; 3], octaves: u32, seed: u32) -> f32 {
+    let mut sum = 0.0;
+    let mut total = 0.0;
+    let mut amplitude = 1.0;
+    let mut frequency = 1.0;
+    for octave in 0..octaves.clamp(1, MAX_OCTAVES) {
+        // A seed per octave keeps the octaves' lattices from lining up.
+        let seed = pcg(seed.wrapping_add(octave));
+        let q = p.map(|v| v * frequency);
+        let n = match kind {
+            NoiseKind::Perlin => perlin(q, seed),
+            NoiseKind::Simplex => simplex(q, seed),
+        };
+        sum += amplitude * n;
+        total += amplitude;
+        amplitude *= 0.5;
+        frequency *= 2.0;
+    }
+    (0.5 + 0.5 * sum / total).clamp(0.0, 1.0)
+}
+
+/// The hash of lattice point `(x, y, z)`.
+fn lattice(seed: u32, x: i32, y: i32, z: i32) -> u32 {
+    let h = pcg((z as u32).wrapping_add(seed));
+    let h = pcg((y as u32).wrapping_add(h));
+    pcg((x as u32).wrapping_add(h))
+}
+
+/// Dot product of `(x, y, z)` with one of the 12 cube-edge gradients picked
+/// by `hash`, as in Perlin's improved noise.
+fn gradient(hash: u32, x: f32, y: f32, z: f32) -> f32 {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 {
+        y
+    } else if h == 12 || h == 14 {
+        x
+    } else {
+        z
+    };
+    let u = if h & 1 == 0 { u } else { -u };
+    let v = if h & 2 == 0 { v } else { -v };
+    u + v
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+/// Perlin's improved noise, roughly in `[-1, 1]`.
+fn perlin(p: [f32; 3], seed: u32) -> f32 {
+    let cell = p.map(f32::floor);
+    let [i, j, k] = cell.map(|c| c as i32);
+    let [x, y, z] = [p[0] - cell[0], p[1] - cell[1], p[2] - cell[2]];
+    let fade = |t: f32| t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+    let (u, v, w) = (fade(x), fade(y), fade(z));
+    let corner = |dx: i32, dy: i32, dz: i32| {
+        let hash = lattice(
+            seed,
+            i.wrapping_add(dx),
+            j.wrapping_add(dy),
+            k.wrapping_add(dz),
+        );
+        gradient(hash, x - dx as f32, y - dy as f32, z - dz as f32)
+    };
+    let near = lerp(
+        lerp(corner(0, 0, 0), corner(1, 0, 0), u),
+        lerp(corner(0, 1, 0), corner(1, 1, 0), u),
+        v,
+    );
+    let far = lerp(
+        lerp(corner(0, 0, 1), corner(1, 0, 1), u),
+        lerp(corner(0, 1, 1), corner(1, 1, 1), u),
+        v,
+    );
+    lerp(near, far, w)
+}
+
+/// 3D simplex noise, roughly in `[-1, 1]`.
+///
+/// Each corner's kernel falls to zero at distance `sqrt(0.5)`, within its
+/// own simplex, so the noise is continuous.
+fn simplex(p: [f32; 3], seed: u32) -> f32 {
+    const SKEW: f32 = 1.0 / 3.0;
+    const UNSKEW: f32 = 1.0 / 6.0;
+    let s = (p[0] + p[1] + p[2]) * SKEW;
+    let cell = p.map(|v| (v + s).floor());
+    let t = (cell[0] + cell[1] + cell[2]) * UNSKEW;
+    let d0 = [
+        p[0] - (cell[0] - t),
+        p[1] - (cell[1] - t),
+        p[2] - (cell[2] - t),
+    ];
+    // The second and third corners of the simplex containing `p`.
+    let (c1, c2) = if d0[0] >= d0[1] {
+        if d0[1] >= d0[2] {
+            ([1, 0, 0], [1, 1, 0])
+        } else if d0[0] >= d0[2] {
+            ([1, 0, 0], [1, 0, 1])
+        } else {
+            ([0, 0, 1], [1, 0, 1])
+        }
+    } else if d0[1] < d0[2] {
+        ([0, 0, 1], [0, 1, 1])
+    } else if d0[0] < d0[2] {
+        ([0, 1, 0], [0, 1, 1])
+    } else {
+        ([0, 1, 0], [1, 1, 0])
+    };
+    let [i, j, k] = cell.map(|c| c as i32);
+    let corner = |offset: [i32; 3], unskew: f32| {
+        let d: [f32; 3] = std::array::from_fn(|a| d0[a] - offset[a] as f32 + unskew);
+        let falloff = 0.5 - d[0] * d[0] - d[1] * d[1] - d[2] * d[2];
+        if falloff <= 0.0 {
+            return 0.0;
+        }
+        let hash = lattice(
+            seed,
+            i.wrapping_add(offset[0]),
+            j.wrapping_add(offset[1]),
+            k.wrapping_add(offset[2]),
+        );
+        let falloff = falloff * falloff;
+        falloff * falloff * gradient(hash, d[0], d[1], d[2])
+    };
+    SIMPLEX_GAIN
+        * (corner([0, 0, 0], 0.0)
+            + corner(c1, UNSKEW)
+            + corner(c2, 2.0 * UNSKEW)
+            + corner([1, 1, 1], 3.0 * UNSKEW))
+}
+
+/// Scales [`simplex`]'s sum of kernels to about `[-1, 1]`.
+const SIMPLEX_GAIN: f32 = 76.0;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render_context::PassThrough;
+
+    fn render(component: &dyn RasterComponent, size: Vec2, target: Resolution) -> Vec<u8> {
+        let mut ctx = PassThrough;
+        match component.render(size, target, RasterResidency::Cpu, &mut ctx) {
+            RasterImage::Cpu(image) => image.pixels.to_vec(),
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        }
+    }
+
+    /// The red channel of every pixel in row `y` of a `width`-wide image.
+    fn reds(pixels: &[u8], width: usize, y: usize) -> Vec<u8> {
+        pixels[y * width * 4..(y + 1) * width * 4]
+            .chunks_exact(4)
+            .map(|px| px[0])
+            .collect()
+    }
+
+    #[test]
+    fn fills_the_box_like_a_background() {
+        let checker = Checker::builder().build();
+        assert_eq!(
+            checker.layout(Constraints::loose(Vec2(40.0, 30.0))),
+            Vec2(40.0, 30.0)
+        );
+        assert_eq!(checker.layout(Constraints::UNBOUNDED), Vec2(0.0, 0.0));
+    }
+
+    #[test]
+    fn checker_alternates_cells_at_any_resolution() {
+        let checker = Checker::builder().scale(2.0).build();
+        let pixels = render(&checker, Vec2(4.0, 4.0), Resolution::new(4, 4));
+        assert_eq!(reds(&pixels, 4, 0), vec![255, 255, 0, 0]);
+        assert_eq!(reds(&pixels, 4, 3), vec![0, 0, 255, 255]);
+        // Twice the pixels, the same cells.
+        let dense = render(&checker, Vec2(4.0, 4.0), Resolution::new(8, 8));
+        assert_eq!(reds(&dense, 8, 0), vec![255, 255, 255, 255, 0, 0, 0, 0]);
+        // Half a pair of cells along: the colors swap.
+        let scrolled = Checker::builder().scale(2.0).evolution(0.5).build();
+        let pixels = render(&scrolled, Vec2(4.0, 4.0), Resolution::new(4, 4));
+        assert_eq!(reds(&pixels, 4, 0), vec![0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn stripes_blend_where_an_edge_crosses_a_pixel() {
+        let stripes = Stripes::builder().scale(4.0).build();
+        let pixels = render(&stripes, Vec2(8.0, 2.0), Resolution::new(8, 2));
+        assert_eq!(reds(&pixels, 8, 1), vec![255, 255, 0, 0, 255, 255, 0, 0]);
+        // A one-pixel nudge moves every edge along, and a quarter-pixel one
+        // leaves each edge pixel partly covered.
+        let nudged = Stripes::builder().scale(4.0).evolution(0.25).build();
+        let pixels = render(&nudged, Vec2(8.0, 2.0), Resolution::new(8, 2));
+        assert_eq!(reds(&pixels, 8, 0)[..4], [0, 255, 255, 0]);
+        let quarter = Stripes::builder().scale(4.0).evolution(1.0 / 16.0).build();
+        let pixels = render(&quarter, Vec2(8.0, 2.0), Resolution::new(8, 2));
+        assert_eq!(reds(&pixels, 8, 0)[..4], [191, 255, 64, 0]);
+        // A right angle turns them horizontal.
+        let turned = Stripes::builder()
+            .scale(4.0)
+            .angle(std::f32::consts::FRAC_PI_2)
+            .build();
+        let pixels = render(&turned, Vec2(2.0, 4.0), Resolution::new(2, 4));
+        let column: Vec<u8> = (0..4).map(|y| reds(&pixels, 2, y)[1]).collect();
+        assert_eq!(column, vec![255, 255, 0, 0]);
+    }
+
+    #[test]
+    fn dots_sit_centered_in_their_cells() {
+        let grid = DotGrid::builder().scale(8.0).radius(2.0).build();
+        let pixels = render(&grid, Vec2(16.0, 8.0), Resolution::new(16, 8));
+        // Row 3 runs through the dots' centers.
+        let row = reds(&pixels, 16, 3);
+        assert_eq!(row[0], 0);
+        assert_eq!(row[3], 255);
+        assert_eq!(row[11], 255);
+        assert_eq!(pixels[3], 0, "the background is transparent");
+        // Scrolled by half a cell, the dots sit on the cell borders.
+        let scrolled = DotGrid::builder()
+            .scale(8.0)
+            .radius(2.0)
+            .evolution(0.5)
+            .build();
+        let row = reds(
+            &render(&scrolled, Vec2(16.0, 8.0), Resolution::new(16, 8)),
+            16,
+            3,
+        );
+        assert_eq!((row[3], row[7]), (0, 255));
+    }
+
+    #[test]
+    fn noise_is_seeded_and_evolves_smoothly() {
+        let size = Vec2(32.0, 32.0);
+        let target = Resolution::new(32, 32);
+        for kind in [NoiseKind::Perlin, NoiseKind::Simplex] {
+            let field = |seed: u32, evolution: f32| {
+                NoiseField::builder()
+                    .scale(8.0)
+                    .kind(kind)
+                    .seed(seed)
+                    .evolution(evolution)
+                    .build()
+            };
+            let base = render(&field(1, 0.0), size, target);
+            assert_eq!(render(&field(1, 0.0), size, target), base);
+            assert_ne!(render(&field(2, 0.0), size, target), base);
+            // A small step along the evolution axis only nudges each pixel.
+            let nudged = render(&field(1, 0.01), size, target);
+            assert_ne!(nudged, base);
+            assert!(
+                nudged.iter().zip(&base).all(|(a, b)| a.abs_diff(*b) <= 16),
+                "{kind:?}"
+            );
+            // Gray, opaque and spread well around the middle.
+            let reds: Vec<u8> = base.chunks_exact(4).map(|px| px[0]).collect();
+            assert!(base
+                .chunks_exact(4)
+                .all(|px| px[0] == px[1] && px[3] == 255));
+            let (lo, hi) = (reds.iter().min().unwrap(), reds.iter().max().unwrap());
+            assert!(*lo < 96 && *hi > 160, "{kind:?}: {lo}..{hi}");
+        }
+    }
+
+    #[test]
+    fn non_positive_scale_fills_with_the_first_color() {
+        let checker = Checker::builder().scale(0.0).build();
+        let pixels = render(&checker, Vec2(2.0, 2.0), Resolution::new(2, 2));
+        assert_eq!(pixels, [255, 255, 255, 255].repeat(4));
+    }
+}
@@ -0,0 +1,861 @@
+//! 2.5D perspective: flat raster layers placed on planes in 3D and
+//! projected through a pinhole camera.
+//!
+//! Coordinates follow CSS 3D transforms: `x` right, `y` down and `z` toward
+//! the viewer, with the screen at `z = 0` and the eye `focal_length` in
+//! front of it. A [`Perspective3d`] tilts and moves one child under its own
+//! [`Camera`]; a [`Scene3d`] projects several through one shared camera,
+//! like the children of a CSS `perspective` element, and composites them
+//! back to front.
+//!
+//! A flat layer's projection is a [`Homography`], so each child is rendered
+//! flat — and stays memoized while its plane moves — then drawn through the
+//! homography once, by [`project_pixels`] or the GPU backend's
+//! [`project`](crate::render_context::GpuRasterBackend::project).
+
+use crate::composite::{blend_pixel, BlendMode};
+use crate::geometry::{Anchor, Constraints, Homography, Rect, Transform, Vec2};
+use crate::layer::{composite_children, union_rect};
+use crate::raster::{
+    CpuRasterImage, PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution,
+};
+use crate::render_context::{
+    CachePolicy, DepthCompositeInput, DepthLayer, ProjectInput, RenderContext,
+};
+use crate::resample::sample;
+use crate::vector::ImageQuality;
+use crate::Keyable;
+
+/// How close to the eye a layer may come, as a fraction of the focal
+/// length. Anything nearer is clipped, like a camera's near plane, so a
+/// layer swinging past the viewer never magnifies without bound.
+pub const NEAR_PLANE: f32 = 0.05;
+
+/// A pinhole camera looking into the screen.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct Camera {
+    /// Distance from the eye to the screen plane (logical units), like CSS
+    /// `perspective`: shorter is a stronger perspective. Infinite, zero or
+    /// negative lengths give a flat, orthographic projection.
+    pub focal_length: f32,
+    /// The point of the layout box the eye sits in front of, where lines
+    /// running into depth converge; like CSS `perspective-origin`.
+    pub vanishing_point: Anchor,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            focal_length: 1000.0,
+            vanishing_point: Anchor::CENTER,
+        }
+    }
+}
+
+impl Camera {
+    /// The screen-space homography of `plane` seen from in front of `eye`.
+    fn project(&self, eye: Vec2, plane: Plane) -> Homography {
+        let [x, y, z] = plane.0;
+        let f = self.focal_length;
+        if !(f.is_finite() && f > 0.0) {
+            return Homography {
+                m: [x, y, [0.0, 0.0, 1.0]],
+            };
+        }
+        // A point at depth `z` is scaled about the eye by `1 / w`, with
+        // `w = 1 - z / f`.
+        let toward_eye = |row: [f32; 3], e: f32| {
+            [
+                row[0] - e / f * z[0],
+                row[1] - e / f * z[1],
+                row[2] - e / f * z[2],
+            ]
+        };
+        Homography {
+            m: [
+                toward_eye(x, eye.0),
+                toward_eye(y, eye.1),
+                [-z[0] / f, -z[1] / f, 1.0 - z[2] / f],
+            ],
+        }
+    }
+}
+
+/// How a [`Scene3d`] orders its layers when compositing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DepthSort {
+    /// Whole layers, farthest first by the depth of their centers. Cheap
+    /// and composited on the GPU when available, but layers that intersect
+    /// cannot cut through each other.
+    #[default]
+    Painter,
+    /// Each pixel composites the layers covering it in their own depth
+    /// order there, so intersecting layers cut through each other.
+    /// Composited on the CPU.
+    PerPixel,
+}
+
+/// A layer's plane in the scene: rows giving the scene `x`, `y` and `z` of
+/// the layer-local point `(x, y, 1)`.
+#[derive(Debug, Clone, Copy)]
+struct Plane([[f32; 3]; 3]);
+
+impl Plane {
+    fn depth(&self, local: Vec2) -> f32 {
+        let z = self.0[2];
+        z[0] * local.0 + z[1] * local.1 + z[2]
+    }
+}
+
+/// A [`RasterComponent`] that places its child on a plane in 3D and
+/// projects it through `camera`.
+///
+/// The rotations compose like CSS `rotateX(x) rotateY(y) rotateZ(z)`,
+/// pivoting on `pivot`, before the plane is moved by `translate` and
+/// `translate_z`. Layout is the child's; `paint_bounds` covers the
+/// projected child, clipped at the [`NEAR_PLANE`], so a layer entirely
+/// behind the eye paints nothing.
+#[crate::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Perspective3d {
+    #[builder(default)]
+    pub camera: Camera,
+    /// Rotation about the `x` axis (radians); positive tips the top edge
+    /// away from the viewer.
+    #[builder(default)]
+    pub rotate_x: f32,
+    /// Rotation about the `y` axis (radians); positive turns the right edge
+    /// away from the viewer.
+    #[builder(default)]
+    pub rotate_y: f32,
+    /// Rotation within the plane (radians), clockwise like
+    /// [`Transform::rotate`].
+    #[builder(default)]
+    pub rotate_z: f32,
+    /// Offset along the screen (logical units).
+    #[builder(default = Vec2::ZERO)]
+    pub translate: Vec2,
+    /// Offset toward the viewer (logical units); negative pushes the layer
+    /// back.
+    #[builder(default)]
+    pub translate_z: f32,
+    /// Point of the layout box the rotations pivot on, like CSS
+    /// `transform-origin`.
+    #[builder(default = Anchor::CENTER)]
+    pub pivot: Anchor,
+    #[builder(default)]
+    pub quality: ImageQuality,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl Perspective3d {
+    fn plane(&self, size: Vec2) -> Plane {
+        let (sin_x, cos_x) = self.rotate_x.sin_cos();
+        let (sin_y, cos_y) = self.rotate_y.sin_cos();
+        let (sin_z, cos_z) = self.rotate_z.sin_cos();
+        let rx = [[1.0, 0.0, 0.0], [0.0, cos_x, -sin_x], [0.0, sin_x, cos_x]];
+        let ry = [[cos_y, 0.0, sin_y], [0.0, 1.0, 0.0], [-sin_y, 0.0, cos_y]];
+        let rz = [[cos_z, -sin_z, 0.0], [sin_z, cos_z, 0.0], [0.0, 0.0, 1.0]];
+        let r = mul3(rx, mul3(ry, rz));
+        let pivot = self.pivot.point(size);
+        let origin = [
+            pivot.0 + self.translate.0,
+            pivot.1 + self.translate.1,
+            self.translate_z,
+        ];
+        // The plane is z = 0 in layer space, so only the first two columns
+        // of the rotation matter.
+        Plane(std::array::from_fn(|i| {
+            [
+                r[i][0],
+                r[i][1],
+                origin[i] - r[i][0] * pivot.0 - r[i][1] * pivot.1,
+            ]
+        }))
+    }
+
+    fn own_homography(&self, size: Vec2) -> Homography {
+        let eye = self.camera.vanishing_point.point(size);
+        self.camera.project(eye, self.plane(size))
+    }
+
+    /// This layer drawn through `homography`.
+    fn projected(&self, homography: Homography) -> Projected {
+        Projected {
+            homography,
+            quality: self.quality,
+            child: self.child.clone(),
+        }
+    }
+}
+
+impl RasterComponent for Perspective3d {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        projected_bounds(self.own_homography(size), self.child.paint_bounds(size))
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        // The projection below owns the cache entry.
+        CachePolicy::Transparent
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let projected = self.projected(self.own_homography(size));
+        ctx.render(&projected, size, target, residency)
+    }
+}
+
+/// Several [`Perspective3d`] layers projected through one shared camera.
+///
+/// Each layer is laid out loose to `size` at the scene's origin, like a
+/// [`Layer`](crate::layer::Layer) child, and its own `camera` is ignored in
+/// favor of the scene's, whose vanishing point is resolved against the
+/// scene's box. `depth_sort` picks how the layers are ordered.
+#[crate::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Scene3d {
+    // `#[builder(field)]` members must precede the setter members.
+    #[children(each = layer)]
+    pub layers: Vec<Perspective3d>,
+    pub size: Vec2,
+    #[builder(default)]
+    pub camera: Camera,
+    #[builder(default)]
+    pub depth_sort: DepthSort,
+}
+
+/// A scene layer placed under the scene's camera.
+struct Staged<'a> {
+    layer: &'a Perspective3d,
+    size: Vec2,
+    plane: Plane,
+    homography: Homography,
+}
+
+impl Staged<'_> {
+    fn paint_bounds(&self) -> Rect {
+        projected_bounds(self.homography, self.layer.child.paint_bounds(self.size))
+    }
+}
+
+impl Scene3d {
+    fn staged(&self, size: Vec2) -> Vec<Staged<'_>> {
+        let eye = self.camera.vanishing_point.point(size);
+        let constraints = Constraints::loose(size);
+        self.layers
+            .iter()
+            .map(|layer| {
+                let layer_size = layer.layout(constraints);
+                let plane = layer.plane(layer_size);
+                Staged {
+                    layer,
+                    size: layer_size,
+                    plane,
+                    homography: self.camera.project(eye, plane),
+                }
+            })
+            .collect()
+    }
+}
+
+impl RasterComponent for Scene3d {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        constraints.constrain(self.size)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.staged(size).iter().fold(
+            Rect {
+                origin: Vec2::ZERO,
+                size,
+            },
+            |bounds, staged| union_rect(bounds, staged.paint_bounds()),
+        )
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let paint = self.paint_bounds(size);
+        let mut staged = self.staged(size);
+        match self.depth_sort {
+            DepthSort::Painter => {
+                // Farthest first; layers at equal depth keep their order.
+                let center_depth = |s: &Staged| s.plane.depth(Vec2(s.size.0 / 2.0, s.size.1 / 2.0));
+                staged.sort_by(|a, b| center_depth(a).total_cmp(&center_depth(b)));
+                let projected: Vec<Projected> = staged
+                    .iter()
+                    .map(|s| s.layer.projected(s.homography))
+                    .collect();
+                let placed: Vec<(Vec2, Vec2, &dyn RasterComponent)> = staged
+                    .iter()
+                    .zip(&projected)
+                    .map(|(s, p)| (Vec2::ZERO, s.size, p as &dyn RasterComponent))
+                    .collect();
+                composite_children(paint, target, &placed, residency, ctx)
+            }
+            DepthSort::PerPixel => composite_by_depth(&staged, paint, target, residency, ctx),
+        }
+    }
+}
+
+/// Composites `staged` into `target` pixels spanning `paint`, ordering the
+/// layers at every pixel by their depth there.
+fn composite_by_depth(
+    staged: &[Staged],
+    paint: Rect,
+    target: Resolution,
+    residency: RasterResidency,
+    ctx: &mut dyn RenderContext,
+) -> RasterImage {
+    let scale = Vec2(
+        target.width as f32 / paint.size.0,
+        target.height as f32 / paint.size.1,
+    );
+    let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+    let mut images = Vec::with_capacity(staged.len());
+    let mut placements = Vec::with_capacity(staged.len());
+    for s in staged {
+        let Some(inverse) = s.homography.invert() else {
+            continue;
+        };
+        // Placed exactly as `composite_children` would place it.
+        let bounds = s.paint_bounds();
+        let resolution = Resolution::new(
+            (bounds.size.0 * scale.0).round().max(1.0) as u32,
+            (bounds.size.1 * scale.1).round().max(1.0) as u32,
+        );
+        let offset = [
+            ((bounds.origin.0 - paint.origin.0) * scale.0).round() as i32,
+            ((bounds.origin.1 - paint.origin.1) * scale.1).round() as i32,
+        ];
+        let projected = s.layer.projected(s.homography);
+        let image = ctx.render(
+            &projected,
+            s.size,
+            resolution,
+            if gpu_available {
+                RasterResidency::Gpu
+            } else {
+                RasterResidency::Cpu
+            },
+        );
+        images.push(image);
+        placements.push((offset, inverse, s));
+    }
+
+    if gpu_available {
+        let layers = depth_layers(&images, &placements);
+        let input = DepthCompositeInput {
+            target,
+            origin: paint.origin,
+            scale,
+            layers: &layers,
+        };
+        if let Some(gpu) = ctx.gpu_backend() {
+            if let Some(image) = gpu.depth_composite(input) {
+                return ctx.ensure_residency(image, residency);
+            }
+        }
+    }
+
+    let images: Vec<RasterImage> = images
+        .into_iter()
+        .map(|image| RasterImage::Cpu(ctx.readback(image)))
+        .collect();
+    let layers = depth_layers(&images, &placements);
+    let pixels = depth_composite_pixels(&DepthCompositeInput {
+        target,
+        origin: paint.origin,
+        scale,
+        layers: &layers,
+    });
+    let image = RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, pixels);
+    ctx.ensure_residency(image, residency)
+}
+
+/// Pairs each rendered layer with where it sits and how deep it is.
+fn depth_layers<'a>(
+    images: &'a [RasterImage],
+    placements: &[([i32; 2], Homography, &Staged)],
+) -> Vec<DepthLayer<'a>> {
+    images
+        .iter()
+        .zip(placements)
+        .map(|(image, &(offset, inverse, s))| DepthLayer {
+            image,
+            offset,
+            inverse,
+            depth: s.plane.0[2],
+            blend: s.layer.blend_mode(),
+        })
+        .collect()
+}
+
+/// A child drawn through a homography from its local space to the
+/// parent's. Shared by [`Perspective3d`] and [`Scene3d`] so one projected
+/// layer is one cache entry either way.
+#[derive(Clone, Keyable)]
+struct Projected {
+    homography: Homography,
+    quality: ImageQuality,
+    child: Box<dyn RasterComponent>,
+}
+
+impl RasterComponent for Projected {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        projected_bounds(self.homography, self.child.paint_bounds(size))
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let paint = self.paint_bounds(size);
+        let child_paint = self.child.paint_bounds(size);
+        let drawable = paint.size.0 > 0.0
+            && paint.size.1 > 0.0
+            && child_paint.size.0 > 0.0
+            && child_paint.size.1 > 0.0;
+        let Some(inverse) = self.homography.invert().filter(|_| drawable) else {
+            let blank = RasterImage::cpu(
+                target.width,
+                target.height,
+                PixelFormat::Rgba8,
+                vec![0u8; (target.width as usize) * (target.height as usize) * 4],
+            );
+            return ctx.ensure_residency(blank, residency);
+        };
+
+        // As in `RasterTransformed`, the child renders at the output's pixel
+        // density so it stays cached while the plane moves.
+        let sx = target.width as f32 / paint.size.0;
+        let sy = target.height as f32 / paint.size.1;
+        let child_target = Resolution::new(
+            (child_paint.size.0 * sx).round().max(1.0) as u32,
+            (child_paint.size.1 * sy).round().max(1.0) as u32,
+        );
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+        let child_image = ctx.render(
+            self.child.as_ref(),
+            size,
+            child_target,
+            if gpu_available {
+                RasterResidency::Gpu
+            } else {
+                RasterResidency::Cpu
+            },
+        );
+
+        let child_sx = child_target.width as f32 / child_paint.size.0;
+        let child_sy = child_target.height as f32 / child_paint.size.1;
+        let pixel_inverse = Homography::from(
+            Transform::scale(Vec2(1.0 / sx, 1.0 / sy)).then(Transform::translate(paint.origin)),
+        )
+        .then(inverse)
+        .then(Homography::from(
+            Transform::translate(Vec2(-child_paint.origin.0, -child_paint.origin.1))
+                .then(Transform::scale(Vec2(child_sx, child_sy))),
+        ));
+
+        if gpu_available {
+            let input = ProjectInput {
+                image: &child_image,
+                target,
+                inverse: pixel_inverse,
+                quality: self.quality,
+            };
+            if let Some(gpu) = ctx.gpu_backend() {
+                if let Some(image) = gpu.project(input) {
+                    return ctx.ensure_residency(image, residency);
+                }
+            }
+        }
+
+        let child_image = ctx.readback(child_image);
+        let pixels = project_pixels(&child_image, target, pixel_inverse, self.quality);
+        let image = RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, pixels);
+        ctx.ensure_residency(image, residency)
+    }
+}
+
+/// Bounds of `rect` mapped through `homography`, after clipping away the
+/// part nearer than the [`NEAR_PLANE`]. Empty when nothing is left.
+fn projected_bounds(homography: Homography, rect: Rect) -> Rect {
+    let (x0, y0) = (rect.origin.0, rect.origin.1);
+    let (x1, y1) = (x0 + rect.size.0, y0 + rect.size.1);
+    let corners = [Vec2(x0, y0), Vec2(x1, y0), Vec2(x1, y1), Vec2(x0, y1)];
+    let w = |p: Vec2| homography.apply(p)[2];
+    // Sutherland–Hodgman against `w >= NEAR_PLANE`; `w` is linear in the
+    // layer's local coordinates, so crossings interpolate there.
+    let mut clipped = Vec::with_capacity(5);
+    for (i, &a) in corners.iter().enumerate() {
+        let b = corners[(i + 1) % 4];
+        let (wa, wb) = (w(a), w(b));
+        if wa >= NEAR_PLANE {
+            clipped.push(a);
+        }
+        if (wa >= NEAR_PLANE) != (wb >= NEAR_PLANE) {
+            let t = (NEAR_PLANE - wa) / (wb - wa);
+            clipped.push(Vec2(a.0 + (b.0 - a.0) * t, a.1 + (b.1 - a.1) * t));
+        }
+    }
+    let mut points = clipped
+        .into_iter()
+        .filter_map(|p| homography.transform_point(p));
+    let Some(first) = points.next() else {
+        return Rect {
+            origin: Vec2::ZERO,
+            size: Vec2::ZERO,
+        };
+    };
+    let (min, max) = points.fold((first, first), |(min, max), p| {
+        (
+            Vec2(min.0.min(p.0), min.1.min(p.1)),
+            Vec2(max.0.max(p.0), max.1.max(p.1)),
+        )
+    });
+    Rect {
+        origin: min,
+        size: Vec2(max.0 - min.0, max.1 - min.1),
+    }
+}
+
+/// Draws a straight-alpha `Rgba8` image through a projective map into a
+/// `target`-sized one.
+///
+/// `inverse` maps target pixel space back to source pixel space, exactly
+/// (see [`Homography::invert`]), so its third coordinate is `1 / w` of the
+/// source point: target pixels where that is not positive, or where the
+/// source point is nearer than the [`NEAR_PLANE`], stay transparent. The
+/// source is filtered as by [`resample_pixels`](crate::resample::resample_pixels).
+pub fn project_pixels(
+    source: &CpuRasterImage,
+    target: Resolution,
+    inverse: Homography,
+    quality: ImageQuality,
+) -> Vec<u8> {
+    assert_eq!(
+        source.format,
+        PixelFormat::Rgba8,
+        "projection only supports straight-alpha Rgba8 images",
+    );
+    let mut out = vec![0u8; target.width as usize * target.height as usize * 4];
+    for y in 0..target.height {
+        for x in 0..target.width {
+            let [u, v, q] = inverse.apply(Vec2(x as f32 + 0.5, y as f32 + 0.5));
+            if !(q > 0.0 && q * NEAR_PLANE <= 1.0) {
+                continue;
+            }
+            let px = sample(source, u / q - 0.5, v / q - 0.5, quality);
+            out[(y as usize * target.width as usize + x as usize) * 4..][..4].copy_from_slice(&px);
+        }
+    }
+    out
+}
+
+/// Composites flat layers in per-pixel depth order.
+///
+/// At every target pixel, the layers with a visible pixel there whose plane
+/// the pixel's ray meets in front of the eye are drawn in ascending depth,
+/// layers at equal depth in their given order, each with its own blend mode
+/// (see [`blend_pixel`]). Every layer image must be CPU-resident,
+/// straight-alpha `Rgba8`.
+pub fn depth_composite_pixels(input: &DepthCompositeInput<'_>) -> Vec<u8> {
+    let target = input.target;
+    let layers: Vec<&CpuRasterImage> = input
+        .layers
+        .iter()
+        .map(|layer| {
+            let image = layer
+                .image
+                .as_cpu()
+                .expect("depth compositing on the CPU needs CPU-resident layers");
+            assert_eq!(
+                image.format,
+                PixelFormat::Rgba8,
+                "depth compositing only supports straight-alpha Rgba8 images",
+            );
+            image
+        })
+        .collect();
+
+    let mut pixels = vec![0u8; target.width as usize * target.height as usize * 4];
+    let mut covering = Vec::with_capacity(layers.len());
+    for y in 0..target.height {
+        for x in 0..target.width {
+            let point = Vec2(
+                input.origin.0 + (x as f32 + 0.5) / input.scale.0,
+                input.origin.1 + (y as f32 + 0.5) / input.scale.1,
+            );
+            covering.clear();
+            for (i, (layer, image)) in input.layers.iter().zip(&layers).enumerate() {
+                let lx = x as i64 - i64::from(layer.offset[0]);
+                let ly = y as i64 - i64::from(layer.offset[1]);
+                if lx < 0 || ly < 0 || lx >= i64::from(image.width) || ly >= i64::from(image.height)
+                {
+                    continue;
+                }
+                let at = (ly as usize * image.width as usize + lx as usize) * 4;
+                let px: [u8; 4] = image.pixels[at..at + 4].try_into().unwrap();
+                let [u, v, q] = layer.inverse.apply(point);
+                if px[3] == 0 || q <= 0.0 {
+                    continue;
+                }
+                let [a, b, c] = layer.depth;
+                covering.push((a * (u / q) + b * (v / q) + c, i, px));
+            }
+            covering.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+            let mut out = [0u8; 4];
+            for &(_, i, px) in &covering {
+                out = blend_pixel(out, px, input.layers[i].blend);
+            }
+            pixels[(y as usize * target.width as usize + x as usize) * 4..][..4]
+                .copy_from_slice(&out);
+        }
+    }
+    pixels
+}
+
+fn mul3(a: [[f32; 3]; 3], b: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+    std::array::from_fn(|r| std::array::from_fn(|c| (0..3).map(|k| a[r][k] * b[k][c]).sum()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::raster::StillImage;
+    use crate::render_context::PassThrough;
+
+    /// A solid `size`×`size` card of `rgba`.
+    fn card(size: u32, rgba: [u8; 4]) -> Box<dyn RasterComponent> {
+        let pixels = rgba.repeat((size * size) as usize);
+        Box::new(StillImage::new(CpuRasterImage::new(
+            size,
+            size,
+            PixelFormat::Rgba8,
+            pixels,
+        )))
+    }
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+
+    fn camera(focal_length: f32) -> Camera {
+        Camera {
+            focal_length,
+            ..Camera::default()
+        }
+    }
+
+    fn assert_rect_near(actual: Rect, expected: Rect) {
+        let values = [
+            (actual.origin.0, expected.origin.0),
+            (actual.origin.1, expected.origin.1),
+            (actual.size.0, expected.size.0),
+            (actual.size.1, expected.size.1),
+        ];
+        for (a, e) in values {
+            assert!((a - e).abs() < 1e-3, "{actual:?} vs {expected:?}");
+        }
+    }
+
+    /// Renders `component` one pixel per logical unit of its paint bounds,
+    /// returning the image and those bounds.
+    fn render(component: &dyn RasterComponent, size: Vec2) -> (CpuRasterImage, Rect) {
+        let paint = component.paint_bounds(size);
+        let target = Resolution::new(
+            paint.size.0.round().max(1.0) as u32,
+            paint.size.1.round().max(1.0) as u32,
+        );
+        let image = match component.render(size, target, RasterResidency::Cpu, &mut PassThrough) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        };
+        (image, paint)
+    }
+
+    /// The pixel covering `point` of an image rendered by [`render`].
+    fn pixel_at(image: &CpuRasterImage, paint: Rect, point: Vec2) -> [u8; 4] {
+        let x = ((point.0 - paint.origin.0) * image.width as f32 / paint.size.0) as usize;
+        let y = ((point.1 - paint.origin.1) * image.height as f32 / paint.size.1) as usize;
+        let at = (y * image.width as usize + x) * 4;
+        image.pixels[at..at + 4].try_into().unwrap()
+    }
+
+    #[test]
+    fn a_flat_plane_renders_the_child_unchanged() {
+        let layer = Perspective3d::builder().child(card(4, RED)).build();
+        let (image, paint) = render(&layer, Vec2(4.0, 4.0));
+        assert_rect_near(
+            paint,
+            Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(4.0, 4.0),
+            },
+        );
+        assert!(image.pixels.chunks_exact(4).all(|px| px == RED));
+    }
+
+    #[test]
+    fn moving_toward_the_viewer_magnifies_about_the_vanishing_point() {
+        // Halfway to the eye, the card is seen at twice the size.
+        let layer = Perspective3d::builder()
+            .camera(camera(100.0))
+            .translate_z(50.0)
+            .child(card(4, RED))
+            .build();
+        assert_rect_near(
+            layer.paint_bounds(Vec2(4.0, 4.0)),
+            Rect {
+                origin: Vec2(-2.0, -2.0),
+                size: Vec2(8.0, 8.0),
+            },
+        );
+    }
+
+    #[test]
+    fn the_receding_edge_of_a_turned_card_is_shorter() {
+        let layer = Perspective3d::builder()
+            .camera(camera(100.0))
+            .rotate_y(0.5)
+            .child(card(40, RED))
+            .build();
+        let h = layer.own_homography(Vec2(40.0, 40.0));
+        let edge = |x: f32| {
+            let top = h.transform_point(Vec2(x, 0.0)).unwrap();
+            let bottom = h.transform_point(Vec2(x, 40.0)).unwrap();
+            bottom.1 - top.1
+        };
+        assert!(edge(40.0) < 40.0 && 40.0 < edge(0.0));
+    }
+
+    #[test]
+    fn a_layer_behind_the_eye_paints_nothing() {
+        let layer = Perspective3d::builder()
+            .camera(camera(100.0))
+            .translate_z(200.0)
+            .child(card(4, RED))
+            .build();
+        assert_eq!(layer.paint_bounds(Vec2(4.0, 4.0)).size, Vec2::ZERO);
+        let image = layer.render(
+            Vec2(4.0, 4.0),
+            Resolution::new(2, 2),
+            RasterResidency::Cpu,
+            &mut PassThrough,
+        );
+        let RasterImage::Cpu(image) = image else {
+            panic!("expected a CPU image");
+        };
+        assert!(image.pixels.iter().all(|&v| v == 0));
+    }
+
+    #[test]
+    fn scene_layers_share_the_scene_camera() {
+        // A 10×10 card centered at (30, 20) of a 40×40 scene, halfway to the
+        // eye: it doubles about the scene's center, not its own.
+        let scene = Scene3d::builder()
+            .size(Vec2(40.0, 40.0))
+            .camera(camera(100.0))
+            .layer(
+                Perspective3d::builder()
+                    .translate(Vec2(25.0, 15.0))
+                    .translate_z(50.0)
+                    .child(card(10, RED)),
+            )
+            .build();
+        let staged = scene.staged(Vec2(40.0, 40.0));
+        assert_rect_near(
+            staged[0].paint_bounds(),
+            Rect {
+                origin: Vec2(30.0, 10.0),
+                size: Vec2(20.0, 20.0),
+            },
+        );
+        assert_rect_near(
+            scene.paint_bounds(Vec2(40.0, 40.0)),
+            Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(50.0, 40.0),
+            },
+        );
+    }
+
+    #[test]
+    fn painter_sort_draws_the_nearer_layer_on_top() {
+        let near = Perspective3d::builder()
+            .translate_z(10.0)
+            .child(card(40, RED))
+            .build();
+        let far = Perspective3d::builder().child(card(40, BLUE)).build();
+        for layers in [vec![near.clone(), far.clone()], vec![far, near]] {
+            let scene = Scene3d::builder()
+                .size(Vec2(40.0, 40.0))
+                .layers(layers)
+                .build();
+            let (image, paint) = render(&scene, Vec2(40.0, 40.0));
+            assert_eq!(pixel_at(&image, paint, Vec2(20.0, 20.0)), RED);
+        }
+    }
+
+    #[test]
+    fn per_pixel_sort_lets_intersecting_layers_cut_through_each_other() {
+        // Two cards turned opposite ways through the same center axis: red
+        // is nearer on the left, blue on the right.
+        let turned = |angle: f32, rgba: [u8; 4]| {
+            Perspective3d::builder()
+                .rotate_y(angle)
+                .child(card(40, rgba))
+                .build()
+        };
+        let scene = |depth_sort: DepthSort| {
+            Scene3d::builder()
+                .size(Vec2(40.0, 40.0))
+                .camera(camera(100.0))
+                .depth_sort(depth_sort)
+                .layer(turned(0.6, RED))
+                .layer(turned(-0.6, BLUE))
+                .build()
+        };
+        let (image, paint) = render(&scene(DepthSort::PerPixel), Vec2(40.0, 40.0));
+        assert_eq!(pixel_at(&image, paint, Vec2(12.0, 20.0)), RED);
+        assert_eq!(pixel_at(&image, paint, Vec2(28.0, 20.0)), BLUE);
+
+        // Painter's order cannot interleave them: the centers tie, so the
+        // later layer covers the earlier one on both sides.
+        let (image, paint) = render(&scene(DepthSort::Painter), Vec2(40.0, 40.0));
+        assert_eq!(pixel_at(&image, paint, Vec2(12.0, 20.0)), BLUE);
+        assert_eq!(pixel_at(&image, paint, Vec2(28.0, 20.0)), BLUE);
+    }
+}
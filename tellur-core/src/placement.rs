@@ -21,10 +21,16 @@
 //! [`SnapTarget::Point`] keeps the child out of flow and measures it
 //! unbounded, while [`SnapTarget::Anchor`] fills the finite parent box and
 //! resolves the target against that box at render time.
+//!
+//! [`FollowPath`] is the animated sibling of a point target: the child's
+//! anchor rides a point `progress` of the way along a path (measured with
+//! [`PathMeasure`]), optionally turning with the path's tangent.
 
 use crate::geometry::{Anchor, Constraints, Rect, Transform, Vec2};
 use crate::layer::translate_rect;
-use crate::vector::{Group, Node, VectorComponent, VectorGraphic};
+use crate::path_measure::{PathMeasure, PathSample};
+use crate::phase::Phase;
+use crate::vector::{Group, Node, PathCommand, VectorComponent, VectorGraphic};
 use crate::Keyable;
 
 /// The destination used by [`Positioned`].
@@ -83,6 +89,17 @@ fn resolved_paint_bounds(child_bounds: Rect, position: Vec2) -> Rect {
     translate_rect(child_bounds, position)
 }
 
+/// The point and tangent `progress` of the way along `path`. A path that
+/// draws nothing pins the child to the origin, facing +X.
+fn path_sample(path: &[PathCommand], progress: Phase) -> PathSample {
+    PathMeasure::new(path)
+        .sample_at_phase(progress)
+        .unwrap_or(PathSample {
+            point: Vec2::ZERO,
+            tangent: Vec2(1.0, 0.0),
+        })
+}
+
 /// Places a [`VectorComponent`] by snapping `anchor` on the child to `target`,
 /// then translating it by `offset`.
 ///
@@ -162,6 +179,112 @@ impl From<Positioned> for Box<dyn VectorComponent> {
     }
 }
 
+/// Moves a [`VectorComponent`] along `path`: `anchor` on the child lands on
+/// the point `progress` of the way along the path's length.
+///
+/// Like a point-targeted [`Positioned`], the wrapper is out of flow and
+/// reports the child's unbounded intrinsic size; `path` is in the parent's
+/// coordinate system. With `auto_rotate`, the child also turns around its
+/// anchor so its +X axis follows the path tangent — draw the child facing
+/// right. A path that draws nothing holds the child at the origin.
+#[derive(Clone, Keyable)]
+pub struct FollowPath {
+    pub child: Box<dyn VectorComponent>,
+    pub path: Vec<PathCommand>,
+    pub progress: Phase,
+    pub anchor: Anchor,
+    pub auto_rotate: bool,
+}
+
+impl FollowPath {
+    /// Follows `path` with the child's center, without rotating it.
+    pub fn new(
+        child: impl Into<Box<dyn VectorComponent>>,
+        path: impl Into<Vec<PathCommand>>,
+        progress: Phase,
+    ) -> Self {
+        Self {
+            child: child.into(),
+            path: path.into(),
+            progress,
+            anchor: Anchor::CENTER,
+            auto_rotate: false,
+        }
+    }
+
+    /// Picks the point on the child's box that rides the path.
+    pub fn anchor(mut self, anchor: Anchor) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Turns the child with the path's tangent.
+    pub fn auto_rotate(mut self) -> Self {
+        self.auto_rotate = true;
+        self
+    }
+
+    fn transform(&self, child_size: Vec2) -> Transform {
+        follow_transform(
+            &self.path,
+            self.progress,
+            self.anchor,
+            self.auto_rotate,
+            child_size,
+        )
+    }
+}
+
+/// Places a `child_size` box with `anchor` on the point `progress` of the way
+/// along `path`, turned with the tangent when `auto_rotate` is set.
+fn follow_transform(
+    path: &[PathCommand],
+    progress: Phase,
+    anchor: Anchor,
+    auto_rotate: bool,
+    child_size: Vec2,
+) -> Transform {
+    let sample = path_sample(path, progress);
+    let anchor = anchor.point(child_size);
+    let placed = Transform::translate(sample.point);
+    let placed = if auto_rotate {
+        placed.concat(Transform::rotate(sample.angle()))
+    } else {
+        placed
+    };
+    placed.concat(Transform::translate(Vec2(-anchor.0, -anchor.1)))
+}
+
+impl VectorComponent for FollowPath {
+    fn layout(&self, _constraints: Constraints) -> Vec2 {
+        self.child.layout(Constraints::UNBOUNDED)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.transform(size)
+            .transform_rect(self.child.paint_bounds(size))
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        let transform = self.transform(size);
+        let inner = self.child.render(size);
+        VectorGraphic {
+            view_box: transform.transform_rect(inner.view_box),
+            root: Node::Group(Group {
+                transform,
+                opacity: 1.0,
+                children: vec![inner.root],
+            }),
+        }
+    }
+}
+
+impl From<FollowPath> for Box<dyn VectorComponent> {
+    fn from(follow: FollowPath) -> Self {
+        Box::new(follow)
+    }
+}
+
 /// Extension trait that adds placement methods to every [`VectorComponent`].
 ///
 /// Brought into scope alongside `use tellur_core::vector::VectorComponent`, it
@@ -183,6 +306,12 @@ pub trait VectorPlacement: VectorComponent + Sized + 'static {
             anchor,
         }
     }
+
+    /// Moves the component's center along `path` to the point `progress` of
+    /// the way along it (see [`FollowPath`]).
+    fn follow_path(self, path: impl Into<Vec<PathCommand>>, progress: Phase) -> FollowPath {
+        FollowPath::new(self.boxed(), path, progress)
+    }
 }
 
 impl<T: VectorComponent + 'static> VectorPlacement for T {}
@@ -209,10 +338,18 @@ impl<C: VectorComponent + 'static> AnchoredVectorComponent<C> {
 /// the parent's `composite_children` pass applies the offset at compositing
 /// time (`position + paint_bounds.origin - paint_rect.origin`).
 pub mod raster {
-    use super::{positioned_layout, resolved_paint_bounds, resolved_position, SnapTarget};
+    use super::{
+        follow_transform, path_sample, positioned_layout, resolved_paint_bounds, resolved_position,
+        SnapTarget,
+    };
+    use crate::composite::BlendMode;
     use crate::geometry::{Anchor, Constraints, Rect, Vec2};
-    use crate::raster::{RasterComponent, RasterImage, RasterResidency, Resolution};
+    use crate::phase::Phase;
+    use crate::raster::{
+        RasterComponent, RasterImage, RasterResidency, RasterTransformed, Resolution,
+    };
     use crate::render_context::{CachePolicy, RenderContext};
+    use crate::vector::PathCommand;
     use crate::Keyable;
 
     /// Raster mirror of [`super::Positioned`].
@@ -273,6 +410,10 @@ pub mod raster {
             CachePolicy::Transparent
         }
 
+        fn blend_mode(&self) -> BlendMode {
+            self.child.blend_mode()
+        }
+
         fn render(
             &self,
             size: Vec2,
@@ -293,6 +434,115 @@ pub mod raster {
         }
     }
 
+    /// Raster mirror of [`super::FollowPath`].
+    ///
+    /// Without `auto_rotate` the path position rides in `paint_bounds` and
+    /// the child's pixels pass through untouched. With it, the child is
+    /// turned about its anchor through a [`RasterTransformed`], so it is
+    /// resampled once per distinct position.
+    #[derive(Clone, Keyable)]
+    pub struct FollowPath {
+        pub child: Box<dyn RasterComponent>,
+        pub path: Vec<PathCommand>,
+        pub progress: Phase,
+        pub anchor: Anchor,
+        pub auto_rotate: bool,
+    }
+
+    impl FollowPath {
+        pub fn new(
+            child: impl Into<Box<dyn RasterComponent>>,
+            path: impl Into<Vec<PathCommand>>,
+            progress: Phase,
+        ) -> Self {
+            Self {
+                child: child.into(),
+                path: path.into(),
+                progress,
+                anchor: Anchor::CENTER,
+                auto_rotate: false,
+            }
+        }
+
+        /// Picks the point on the child's box that rides the path.
+        pub fn anchor(mut self, anchor: Anchor) -> Self {
+            self.anchor = anchor;
+            self
+        }
+
+        /// Turns the child with the path's tangent.
+        pub fn auto_rotate(mut self) -> Self {
+            self.auto_rotate = true;
+            self
+        }
+
+        fn position(&self, child_size: Vec2) -> Vec2 {
+            let point = path_sample(&self.path, self.progress).point;
+            child_size.anchored(self.anchor).snap_to(point)
+        }
+
+        /// The child placed and turned on the path, for `auto_rotate`.
+        fn rotated(&self, child_size: Vec2) -> RasterTransformed {
+            RasterTransformed::builder()
+                .transform(follow_transform(
+                    &self.path,
+                    self.progress,
+                    self.anchor,
+                    true,
+                    child_size,
+                ))
+                .child(self.child.clone())
+                .build()
+        }
+    }
+
+    impl RasterComponent for FollowPath {
+        fn layout(&self, _constraints: Constraints) -> Vec2 {
+            self.child.layout(Constraints::UNBOUNDED)
+        }
+
+        fn paint_bounds(&self, size: Vec2) -> Rect {
+            if self.auto_rotate {
+                return self.rotated(size).paint_bounds(size);
+            }
+            resolved_paint_bounds(self.child.paint_bounds(size), self.position(size))
+        }
+
+        fn cache_policy(&self) -> CachePolicy {
+            if self.auto_rotate {
+                CachePolicy::Memoize
+            } else {
+                // Like `Positioned`, the path position lives in `paint_bounds`.
+                CachePolicy::Transparent
+            }
+        }
+
+        fn blend_mode(&self) -> BlendMode {
+            self.child.blend_mode()
+        }
+
+        fn render(
+            &self,
+            size: Vec2,
+            target: Resolution,
+            residency: RasterResidency,
+            ctx: &mut dyn RenderContext,
+        ) -> RasterImage {
+            if self.auto_rotate {
+                // The transformed child renders through `ctx`, so the child
+                // keeps its own cache entry while the path animates.
+                return self.rotated(size).render(size, target, residency, ctx);
+            }
+            ctx.render(self.child.as_ref(), size, target, residency)
+        }
+    }
+
+    impl From<FollowPath> for Box<dyn RasterComponent> {
+        fn from(follow: FollowPath) -> Self {
+            Box::new(follow)
+        }
+    }
+
     /// Raster mirror of [`VectorPlacement`](super::VectorPlacement).
     pub trait RasterPlacement: RasterComponent + Sized + 'static {
         fn place_at(self, position: Vec2) -> Positioned {
@@ -305,6 +555,10 @@ pub mod raster {
                 anchor,
             }
         }
+
+        fn follow_path(self, path: impl Into<Vec<PathCommand>>, progress: Phase) -> FollowPath {
+            FollowPath::new(self.boxed(), path, progress)
+        }
     }
 
     impl<T: RasterComponent + 'static> RasterPlacement for T {}
@@ -332,7 +586,7 @@ mod tests {
     use super::*;
     use crate::color::Color;
     use crate::raster::{PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution};
-    use crate::render_context::{PassThrough, RenderContext};
+    use crate::render_context::{CachePolicy, PassThrough, RenderContext};
     use crate::shapes::Circle;
     use crate::vector::{Paint, Stroke};
 
@@ -459,6 +713,63 @@ mod tests {
         assert_eq!(root_translation(&graphic), expected.origin);
     }
 
+    /// An L-shaped route: 100 right, then 100 down.
+    fn route() -> Vec<PathCommand> {
+        vec![
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::LineTo(Vec2(100.0, 0.0)),
+            PathCommand::LineTo(Vec2(100.0, 100.0)),
+        ]
+    }
+
+    fn marker() -> crate::shapes::Rectangle {
+        crate::shapes::Rectangle {
+            size: Vec2(20.0, 10.0),
+            corners: crate::shapes::Corners::SQUARE,
+            fill: Some(Paint::Solid(Color::rgb_u8(0, 0, 0)).into()),
+            stroke: None,
+        }
+    }
+
+    #[test]
+    fn follow_path_centers_the_child_on_the_path_point() {
+        let follow = marker().follow_path(route(), Phase::new(0.25).unwrap());
+        let size = follow.layout(Constraints::loose(Vec2(10.0, 10.0)));
+        assert_eq!(size, Vec2(20.0, 10.0));
+
+        let expected = Rect {
+            origin: Vec2(40.0, -5.0),
+            size,
+        };
+        assert_eq!(follow.paint_bounds(size), expected);
+        let graphic = follow.render(size);
+        assert_eq!(graphic.view_box, expected);
+        assert_eq!(root_translation(&graphic), Vec2(40.0, -5.0));
+    }
+
+    #[test]
+    fn follow_path_auto_rotate_turns_with_the_tangent() {
+        let follow = marker()
+            .follow_path(route(), Phase::new(0.75).unwrap())
+            .anchor(Anchor::CENTER_LEFT)
+            .auto_rotate();
+        let size = follow.layout(Constraints::UNBOUNDED);
+        let Node::Group(group) = &follow.render(size).root else {
+            panic!("follow path should render a transforming group");
+        };
+        // Heading down the second leg: the marker's +X axis points along +Y
+        // and its left-center anchor sits on the path at (100, 50).
+        let transform = group.transform;
+        let nose = transform.transform_point(Vec2(20.0, 5.0));
+        let tail = transform.transform_point(Vec2(0.0, 5.0));
+        assert!((tail.0 - 100.0).abs() < 1e-4 && (tail.1 - 50.0).abs() < 1e-4);
+        assert!((nose.0 - 100.0).abs() < 1e-4 && (nose.1 - 70.0).abs() < 1e-4);
+
+        let bounds = follow.paint_bounds(size);
+        assert!((bounds.origin.0 - 95.0).abs() < 1e-4);
+        assert!((bounds.size.1 - 20.0).abs() < 1e-4);
+    }
+
     #[derive(Clone, PartialEq, Hash)]
     struct FixedRaster;
 
@@ -520,6 +831,48 @@ mod tests {
         }
     }
 
+    #[test]
+    fn raster_follow_path_translates_paint_bounds_only() {
+        let follow = SmallRaster.follow_path(route(), Phase::ONE);
+        let size = follow.layout(Constraints::loose(Vec2(5.0, 5.0)));
+        assert_eq!(size, Vec2(20.0, 10.0));
+        assert_eq!(
+            follow.paint_bounds(size),
+            Rect {
+                origin: Vec2(90.0, 95.0),
+                size,
+            }
+        );
+    }
+
+    #[test]
+    fn raster_follow_path_auto_rotate_turns_the_pixels() {
+        let follow = SmallRaster
+            .follow_path(route(), Phase::new(0.75).unwrap())
+            .anchor(Anchor::CENTER_LEFT)
+            .auto_rotate();
+        let size = follow.layout(Constraints::UNBOUNDED);
+        assert_eq!(follow.cache_policy(), CachePolicy::Memoize);
+        // Heading down the second leg, as in the vector test: the 20×10
+        // box stands 10 wide and 20 tall below (100, 50).
+        let bounds = follow.paint_bounds(size);
+        assert!((bounds.origin.0 - 95.0).abs() < 1e-4, "{bounds:?}");
+        assert!((bounds.origin.1 - 50.0).abs() < 1e-4, "{bounds:?}");
+        assert!((bounds.size.0 - 10.0).abs() < 1e-4 && (bounds.size.1 - 20.0).abs() < 1e-4);
+
+        let image = follow
+            .render(
+                size,
+                Resolution::new(10, 20),
+                RasterResidency::Cpu,
+                &mut PassThrough,
+            )
+            .into_cpu()
+            .expect("rendered on the CPU");
+        // The turned box covers its whole paint box, interior pixels intact.
+        assert_eq!(&image.pixels[(10 * 10 + 5) * 4..][..4], &[20, 10, 0, 255]);
+    }
+
     #[test]
     fn raster_anchor_target_uses_box_for_position_but_child_size_for_pixels() {
         let placed = SmallRaster
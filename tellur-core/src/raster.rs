@@ -9,13 +9,17 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use bytes::Bytes;
+use image::ImageDecoder;
 use thiserror::Error;
 
 use crate::color::Color;
+use crate::composite::BlendMode;
 use crate::dyn_compare::{DynEq, DynHash};
-use crate::geometry::{Constraints, Rect, Vec2};
-use crate::render_context::{CachePolicy, RenderContext};
+use crate::geometry::{Anchor, Constraints, Rect, Transform, Vec2};
+use crate::render_context::{CachePolicy, RenderContext, ResampleInput};
+use crate::resample::resample_pixels;
 use crate::scalar::clamp_unit;
+use crate::vector::ImageQuality;
 use crate::Keyable;
 
 #[derive(Debug, Clone)]
@@ -366,6 +370,14 @@ pub trait RasterComponent: RasterComponentClone + DynEq + DynHash {
         CachePolicy::Memoize
     }
 
+    /// How a parent [`Layer`](crate::layer::Layer) composites this component
+    /// over the siblings beneath it. Pass-through wrappers (placement,
+    /// [`Opacity`]) forward their child's mode; [`Blend`] sets it. Defaults to
+    /// [`BlendMode::Normal`].
+    fn blend_mode(&self) -> BlendMode {
+        BlendMode::Normal
+    }
+
     /// Display name surfaced when this raster component is placed in a timeline
     /// (via the one-way `RasterComponent → TimelineComponent` blanket). `None`
     /// for plain raster primitives; a `#[component(...)]` fn overrides this to
@@ -429,11 +441,7 @@ impl Background {
 
 impl RasterComponent for Background {
     fn layout(&self, constraints: Constraints) -> Vec2 {
-        let size = Vec2(
-            finite_or_min(constraints.max.0, constraints.min.0),
-            finite_or_min(constraints.max.1, constraints.min.1),
-        );
-        constraints.constrain(size)
+        fill_layout(constraints)
     }
 
     fn render(
@@ -465,6 +473,16 @@ impl RasterComponent for Background {
     }
 }
 
+/// [`Background`]'s layout: the full available size under finite loose
+/// constraints, the minimum under unbounded ones.
+pub(crate) fn fill_layout(constraints: Constraints) -> Vec2 {
+    let size = Vec2(
+        finite_or_min(constraints.max.0, constraints.min.0),
+        finite_or_min(constraints.max.1, constraints.min.1),
+    );
+    constraints.constrain(size)
+}
+
 fn finite_or_min(max: f32, min: f32) -> f32 {
     if max.is_finite() {
         max
@@ -522,6 +540,10 @@ impl RasterComponent for Opacity {
         self.child.paint_bounds(size)
     }
 
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
     fn cache_policy(&self) -> CachePolicy {
         if clamp_unit(self.opacity) >= 1.0 {
             // Full opacity returns the child's image unchanged. Let the child
@@ -570,7 +592,195 @@ impl RasterComponent for Opacity {
     }
 }
 
-/// Extension trait adding opacity wrapping to raster components, mirroring
+/// A [`RasterComponent`] that composites its child with a [`BlendMode`].
+///
+/// The child's pixels pass through untouched; only the parent
+/// [`Layer`](crate::layer::Layer) (or a timeline overlay, through the
+/// `RasterComponent → TimelineComponent` blanket) reads
+/// [`blend_mode`](RasterComponent::blend_mode) when it composites this child
+/// over the ones beneath it. Keep `Blend` outside other effects: placement
+/// wrappers and [`Opacity`] forward the mode, most effects do not.
+#[crate::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Blend {
+    pub mode: BlendMode,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl RasterComponent for Blend {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.child.paint_bounds(size)
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        // The pixels are the child's; the mode only matters to the parent.
+        CachePolicy::Transparent
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.mode
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        ctx.render(self.child.as_ref(), size, target, residency)
+    }
+}
+
+/// A [`RasterComponent`] that draws its child through an affine
+/// [`Transform`]: rotated, scaled, skewed or translated by fractions of a
+/// pixel.
+///
+/// Raster counterpart of [`Transformed`](crate::vector::Transformed). Layout
+/// is the child's, and `paint_bounds` is the transformed child paint box, so
+/// a rotated frame gets the larger image its corners need. The child is
+/// rendered at the output's pixel density regardless of `transform`, so it
+/// keeps its cache entry while the transform animates (a slow Ken Burns zoom
+/// resamples one memoized frame), and is then resampled with `quality` —
+/// on the GPU when the backend offers
+/// [`resample`](crate::render_context::GpuRasterBackend::resample), otherwise
+/// by [`resample_pixels`].
+#[crate::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct RasterTransformed {
+    pub transform: Transform,
+    /// Point of the layout box `transform` pivots on.
+    #[builder(default = Anchor::TOP_LEFT)]
+    pub pivot: Anchor,
+    #[builder(default)]
+    pub quality: ImageQuality,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl RasterTransformed {
+    /// The transform with the pivot folded in, resolved against `size`.
+    fn effective_transform(&self, size: Vec2) -> Transform {
+        if self.pivot == Anchor::TOP_LEFT {
+            return self.transform;
+        }
+        Transform::around_point(self.pivot.point(size), self.transform)
+    }
+}
+
+impl RasterComponent for RasterTransformed {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.effective_transform(size)
+            .transform_rect(self.child.paint_bounds(size))
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        if self.transform == Transform::IDENTITY {
+            // The identity returns the child's image unchanged, as full
+            // `Opacity` does.
+            CachePolicy::Transparent
+        } else {
+            CachePolicy::Memoize
+        }
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let transform = self.effective_transform(size);
+        if transform == Transform::IDENTITY {
+            return ctx.render(self.child.as_ref(), size, target, residency);
+        }
+        let paint = self.paint_bounds(size);
+        let child_paint = self.child.paint_bounds(size);
+        let inverse = transform.invert();
+        let drawable = paint.size.0 > 0.0
+            && paint.size.1 > 0.0
+            && child_paint.size.0 > 0.0
+            && child_paint.size.1 > 0.0;
+        let Some(inverse) = inverse.filter(|_| drawable) else {
+            let blank = RasterImage::cpu(
+                target.width,
+                target.height,
+                PixelFormat::Rgba8,
+                vec![0u8; (target.width as usize) * (target.height as usize) * 4],
+            );
+            return ctx.ensure_residency(blank, residency);
+        };
+
+        let sx = target.width as f32 / paint.size.0;
+        let sy = target.height as f32 / paint.size.1;
+        let child_target = Resolution::new(
+            (child_paint.size.0 * sx).round().max(1.0) as u32,
+            (child_paint.size.1 * sy).round().max(1.0) as u32,
+        );
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+        let child_image = ctx.render(
+            self.child.as_ref(),
+            size,
+            child_target,
+            if gpu_available {
+                RasterResidency::Gpu
+            } else {
+                RasterResidency::Cpu
+            },
+        );
+
+        // Target pixels → logical units → the child's logical units → child
+        // pixels.
+        let child_sx = child_target.width as f32 / child_paint.size.0;
+        let child_sy = child_target.height as f32 / child_paint.size.1;
+        let pixel_inverse = Transform::scale(Vec2(1.0 / sx, 1.0 / sy))
+            .then(Transform::translate(paint.origin))
+            .then(inverse)
+            .then(Transform::translate(Vec2(
+                -child_paint.origin.0,
+                -child_paint.origin.1,
+            )))
+            .then(Transform::scale(Vec2(child_sx, child_sy)));
+
+        if gpu_available {
+            let input = ResampleInput {
+                image: &child_image,
+                target,
+                inverse: pixel_inverse,
+                quality: self.quality,
+            };
+            if let Some(gpu) = ctx.gpu_backend() {
+                if let Some(image) = gpu.resample(input) {
+                    return ctx.ensure_residency(image, residency);
+                }
+            }
+        }
+
+        let child_image = ctx.readback(child_image);
+        let pixels = resample_pixels(&child_image, target, pixel_inverse, self.quality);
+        let image = RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, pixels);
+        ctx.ensure_residency(image, residency)
+    }
+}
+
+/// Extension trait adding opacity, blend-mode and transform wrapping to
+/// raster components, mirroring
 /// [`VectorTransform`](crate::vector::VectorTransform) on the vector side.
 pub trait RasterTransform: RasterComponent + Sized + 'static {
     fn opacity(self, opacity: f32) -> Opacity {
@@ -579,6 +789,31 @@ pub trait RasterTransform: RasterComponent + Sized + 'static {
             child: Box::new(self),
         }
     }
+
+    /// Draws this component through `transform`, sampled bilinearly; see
+    /// [`RasterTransformed`].
+    fn transform(self, transform: Transform) -> RasterTransformed {
+        self.transform_around(Anchor::TOP_LEFT, transform)
+    }
+
+    /// Like [`transform`](Self::transform), but pivots on `anchor` of the
+    /// layout box, so `frame.transform_around(Anchor::CENTER,
+    /// Transform::rotate(a))` spins a frame in place.
+    fn transform_around(self, anchor: Anchor, transform: Transform) -> RasterTransformed {
+        RasterTransformed {
+            transform,
+            pivot: anchor,
+            quality: ImageQuality::default(),
+            child: Box::new(self),
+        }
+    }
+
+    fn blend(self, mode: BlendMode) -> Blend {
+        Blend {
+            mode,
+            child: Box::new(self),
+        }
+    }
 }
 
 impl<T: RasterComponent + 'static> RasterTransform for T {}
@@ -798,6 +1033,85 @@ mod tests {
         assert_eq!(faded.opacity, 0.3);
     }
 
+    /// A 2×1 box painted one logical unit per pixel, red then blue.
+    #[derive(Clone, PartialEq, Hash)]
+    struct RedBlue;
+
+    impl RasterComponent for RedBlue {
+        fn layout(&self, _constraints: Constraints) -> Vec2 {
+            Vec2(2.0, 1.0)
+        }
+
+        fn render(
+            &self,
+            _size: Vec2,
+            target: Resolution,
+            _residency: RasterResidency,
+            _ctx: &mut dyn RenderContext,
+        ) -> RasterImage {
+            assert_eq!(target, Resolution::new(2, 1));
+            let pixels = vec![255, 0, 0, 255, 0, 0, 255, 255];
+            RasterImage::cpu(2, 1, PixelFormat::Rgba8, pixels)
+        }
+    }
+
+    #[test]
+    fn raster_transformed_paint_bounds_cover_the_transformed_box() {
+        let spun = RedBlue.transform_around(
+            Anchor::CENTER,
+            Transform::rotate(std::f32::consts::FRAC_PI_2),
+        );
+        assert_eq!(spun.layout(Constraints::UNBOUNDED), Vec2(2.0, 1.0));
+        let bounds = spun.paint_bounds(Vec2(2.0, 1.0));
+        for (actual, expected) in [
+            (bounds.origin.0, 0.5),
+            (bounds.origin.1, -0.5),
+            (bounds.size.0, 1.0),
+            (bounds.size.1, 2.0),
+        ] {
+            assert!((actual - expected).abs() < 1e-5, "{bounds:?}");
+        }
+    }
+
+    #[test]
+    fn raster_transformed_rotates_the_child_pixels() {
+        let spun = RedBlue.transform(Transform::rotate(std::f32::consts::FRAC_PI_2));
+        let image = match spun.render(
+            Vec2(2.0, 1.0),
+            Resolution::new(1, 2),
+            RasterResidency::Cpu,
+            &mut PassThrough,
+        ) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        };
+        // A quarter turn clockwise stands the strip up, red on top.
+        assert_eq!(&image.pixels[..], &[255, 0, 0, 255, 0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn raster_transformed_identity_is_transparent_to_the_cache() {
+        let still = RedBlue.transform(Transform::IDENTITY);
+        assert_eq!(still.cache_policy(), CachePolicy::Transparent);
+        assert_eq!(
+            RedBlue
+                .transform(Transform::scale(Vec2(1.1, 1.1)))
+                .cache_policy(),
+            CachePolicy::Memoize
+        );
+    }
+
+    #[test]
+    fn raster_builder_transform_wraps_the_built_component() {
+        use crate::builder::RasterBuilderTransform;
+
+        let zoomed = Background::builder()
+            .color(Color::rgb_u8(1, 2, 3))
+            .transform_around(Anchor::CENTER, Transform::scale(Vec2(1.2, 1.2)));
+        assert_eq!(zoomed.pivot, Anchor::CENTER);
+        assert_eq!(zoomed.quality, ImageQuality::Bilinear);
+    }
+
     #[test]
     fn storage_id_is_unique_per_allocation_even_with_identical_content() {
         let a = CpuRasterImage::new(1, 1, PixelFormat::Rgba8, vec![1, 2, 3, 4]);
@@ -896,6 +1210,135 @@ mod tests {
         assert_eq!(image.image.pixels.as_ref(), sample_image().pixels.as_ref());
     }
 
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/images")
+            .join(name)
+    }
+
+    #[test]
+    fn load_decodes_lossless_formats_exactly() {
+        for name in ["sample.webp", "sample.bmp", "sample.tiff"] {
+            let image = CpuRasterImage::load(fixture(name)).expect(name);
+            assert_eq!((image.width, image.height), (2, 1), "{name}");
+            assert_eq!(image.format, PixelFormat::Rgba8, "{name}");
+            assert_eq!(
+                image.pixels.as_ref(),
+                sample_image().pixels.as_ref(),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn load_reads_the_first_gif_frame() {
+        let image = CpuRasterImage::load(fixture("sample.gif")).expect("load GIF");
+        assert_eq!(image.pixels.as_ref(), &[255, 0, 0, 255, 0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn load_applies_exif_orientation() {
+        // Stored 16×8 with red on the left and blue on the right, tagged to be
+        // turned a quarter clockwise for display.
+        let image = CpuRasterImage::load(fixture("orientation-6.jpg")).expect("load JPEG");
+        assert_eq!((image.width, image.height), (8, 16));
+        let at = |x: usize, y: usize| &image.pixels[(y * 8 + x) * 4..][..4];
+        assert!(at(4, 2)[0] > 200 && at(4, 2)[2] < 50, "{:?}", at(4, 2));
+        assert!(at(4, 13)[2] > 200 && at(4, 13)[0] < 50, "{:?}", at(4, 13));
+    }
+
+    #[test]
+    fn load_converts_icc_tagged_pixels_to_srgb() {
+        // Display P3 (200, 100, 50) is more saturated than the same numbers in
+        // sRGB.
+        let image = CpuRasterImage::load(fixture("display-p3.jpg")).expect("load JPEG");
+        let px = &image.pixels[..4];
+        assert!(px[0] > 210 && px[2] < 40 && px[3] == 255, "{px:?}");
+    }
+
+    /// `pixels` as an RGBA8 PNG carrying the given `iCCP` and `eXIf` chunks.
+    fn tagged_png(
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+        icc_profile: Option<Vec<u8>>,
+        exif: Option<Vec<u8>>,
+    ) -> Vec<u8> {
+        let mut info = png::Info::with_size(width, height);
+        info.color_type = png::ColorType::Rgba;
+        info.bit_depth = png::BitDepth::Eight;
+        info.icc_profile = icc_profile.map(Into::into);
+        info.exif_metadata = exif.map(Into::into);
+        let mut bytes = Vec::new();
+        let mut writer = png::Encoder::with_info(&mut bytes, info)
+            .expect("PNG info")
+            .write_header()
+            .expect("PNG header");
+        writer.write_image_data(pixels).expect("PNG data");
+        writer.finish().expect("finish PNG");
+        bytes
+    }
+
+    #[test]
+    fn png_applies_its_icc_profile_like_other_formats() {
+        // The same Display P3 pixels and profile as the JPEG fixture, stored
+        // losslessly.
+        let file = File::open(fixture("display-p3.jpg")).expect("open JPEG");
+        let mut decoder =
+            image::codecs::jpeg::JpegDecoder::new(BufReader::new(file)).expect("read JPEG");
+        let icc_profile = decoder.icc_profile().expect("read ICC profile");
+        assert!(icc_profile.is_some());
+        let raw = image::DynamicImage::from_decoder(decoder)
+            .expect("decode JPEG")
+            .into_rgba8();
+        let png = tagged_png(raw.width(), raw.height(), raw.as_raw(), icc_profile, None);
+
+        let from_png = CpuRasterImage::decode(&png).expect("decode PNG");
+        let from_jpeg = CpuRasterImage::load(fixture("display-p3.jpg")).expect("load JPEG");
+        assert_eq!(from_png.pixels.as_ref(), from_jpeg.pixels.as_ref());
+    }
+
+    #[test]
+    fn png_applies_its_exif_orientation() {
+        // A big-endian TIFF header and one IFD entry: orientation 6, a
+        // quarter turn clockwise.
+        let exif = [
+            b"MM\0*\0\0\0\x08\0\x01".as_slice(),
+            &[0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0],
+            &[0, 0, 0, 0],
+        ]
+        .concat();
+        let png = tagged_png(2, 1, &sample_image().pixels, None, Some(exif));
+        let image = CpuRasterImage::decode_png(&png).expect("decode PNG");
+        assert_eq!((image.width, image.height), (1, 2));
+        assert_eq!(image.pixels.as_ref(), sample_image().pixels.as_ref());
+    }
+
+    #[test]
+    fn decode_detects_the_format_from_contents() {
+        let webp = std::fs::read(fixture("sample.webp")).expect("read WebP");
+        let image = CpuRasterImage::decode(&webp).expect("decode WebP");
+        assert_eq!(image.pixels.as_ref(), sample_image().pixels.as_ref());
+        let png = CpuRasterImage::decode(&sample_png_bytes()).expect("decode PNG");
+        assert_eq!(png.pixels.as_ref(), sample_image().pixels.as_ref());
+        assert!(matches!(
+            CpuRasterImage::decode(b"not an image"),
+            Err(ImageLoadError::UnsupportedFormat {
+                extension: None,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn load_rejects_unknown_extensions_before_opening() {
+        let err = CpuRasterImage::load("missing.psd").unwrap_err();
+        assert!(matches!(
+            err,
+            ImageLoadError::UnsupportedFormat { extension: Some(ref ext), .. } if ext == "psd"
+        ));
+    }
+
     #[test]
     fn still_image_is_a_raster_component() {
         let component = StillImage::new(sample_image());
@@ -962,13 +1405,22 @@ impl CpuRasterImage {
 
     /// Loads an image from disk, selecting a decoder from the file extension.
     ///
-    /// PNG is supported today. Unsupported extensions return
-    /// [`ImageLoadError::UnsupportedFormat`] before opening the file, so callers
-    /// get a clear format error instead of a decode failure.
+    /// PNG, JPEG, WebP, GIF (first frame), BMP and TIFF are supported; see
+    /// [`read_image`](Self::read_image) for how orientation and color
+    /// profiles are applied. Unsupported extensions
+    /// return [`ImageLoadError::UnsupportedFormat`] before opening the file, so
+    /// callers get a clear format error instead of a decode failure.
     pub fn load(path: impl AsRef<Path>) -> Result<Self, ImageLoadError> {
         let path = path.as_ref();
         match image_extension(path).as_deref() {
             Some("png") => Self::load_png(path),
+            Some("jpg" | "jpeg" | "webp" | "gif" | "bmp" | "tif" | "tiff") => {
+                let file = File::open(path).map_err(|source| ImageLoadError::Io {
+                    path: path.to_path_buf(),
+                    source,
+                })?;
+                decode_with_image(BufReader::new(file)).map_err(|err| image_load_error(path, err))
+            }
             extension => Err(ImageLoadError::UnsupportedFormat {
                 path: path.to_path_buf(),
                 extension: extension.map(str::to_owned),
@@ -976,6 +1428,32 @@ impl CpuRasterImage {
         }
     }
 
+    /// Decodes an image of any supported format from memory, detecting the
+    /// format from its contents.
+    pub fn decode(bytes: &[u8]) -> Result<Self, ImageLoadError> {
+        if bytes.starts_with(PNG_SIGNATURE) {
+            Self::decode_png(bytes)
+        } else {
+            Self::read_image(Cursor::new(bytes))
+        }
+    }
+
+    /// Decodes a JPEG, WebP, GIF, BMP or TIFF stream into straight-alpha
+    /// RGBA8 pixels, detecting the format from its contents.
+    ///
+    /// Only a GIF's first frame is read. The EXIF orientation is applied, so
+    /// photos come out upright, and pixels tagged with an RGB ICC profile are
+    /// converted to sRGB. Other profiles (gray, or CMYK ones the JPEG decoder
+    /// has already converted away from) and profiles that fail to parse leave
+    /// the pixels as decoded. [`read_png`](Self::read_png) does the same for
+    /// PNG's `eXIf` and `iCCP` chunks.
+    ///
+    /// Errors use the same variants as PNG decoding; with no file behind the
+    /// stream, their `path` is empty.
+    pub fn read_image<R: BufRead + Seek>(reader: R) -> Result<Self, ImageLoadError> {
+        decode_with_image(reader).map_err(|err| image_load_error(Path::new(""), err))
+    }
+
     /// Loads a PNG image from disk into straight-alpha RGBA8 pixels.
     pub fn load_png(path: impl AsRef<Path>) -> Result<Self, ImageLoadError> {
         let path = path.as_ref();
@@ -991,11 +1469,20 @@ impl CpuRasterImage {
         Self::read_png(BufReader::new(Cursor::new(bytes)))
     }
 
-    /// Decodes a PNG stream into straight-alpha RGBA8 pixels.
+    /// Decodes a PNG stream into straight-alpha RGBA8 pixels, applying its
+    /// EXIF orientation and ICC profile as [`read_image`](Self::read_image)
+    /// does for the other formats.
     pub fn read_png<R: BufRead + Seek>(reader: R) -> Result<Self, ImageLoadError> {
         let mut decoder = png::Decoder::new(reader);
         decoder.set_transformations(png::Transformations::normalize_to_color8());
         let mut reader = decoder.read_info()?;
+        let orientation = reader
+            .info()
+            .exif_metadata
+            .as_deref()
+            .and_then(image::metadata::Orientation::from_exif_chunk)
+            .unwrap_or(image::metadata::Orientation::NoTransforms);
+        let icc_profile = reader.info().icc_profile.as_deref().map(<[u8]>::to_vec);
         let mut pixels = vec![
             0;
             reader
@@ -1004,13 +1491,20 @@ impl CpuRasterImage {
         ];
         let info = reader.next_frame(&mut pixels)?;
         pixels.truncate(info.buffer_size());
-        png_to_rgba8(
+        let rgba = png_to_rgba8(
             info.width,
             info.height,
             info.color_type,
             info.bit_depth,
             pixels,
-        )
+        )?;
+        let decoded = image::RgbaImage::from_raw(info.width, info.height, rgba)
+            .expect("png_to_rgba8 checks the buffer size");
+        Ok(upright_srgb(
+            decoded.into(),
+            orientation,
+            icc_profile.as_deref(),
+        ))
     }
 
     /// Encodes the image as PNG and writes it to `writer`.
@@ -1055,6 +1549,11 @@ impl RasterImage {
         CpuRasterImage::decode_png(bytes).map(Self::Cpu)
     }
 
+    /// Decodes image bytes of any supported format as a CPU [`RasterImage`].
+    pub fn decode(bytes: &[u8]) -> Result<Self, ImageLoadError> {
+        CpuRasterImage::decode(bytes).map(Self::Cpu)
+    }
+
     /// Encodes a CPU image as PNG and writes it to `writer`.
     ///
     /// GPU images must be read back through the active render context first.
@@ -1070,7 +1569,10 @@ impl RasterImage {
 
 #[derive(Debug, Error)]
 pub enum ImageLoadError {
-    #[error("unsupported image format {extension:?} for path {path:?}; supported formats: png")]
+    #[error(
+        "unsupported image format {extension:?} for path {path:?}; \
+         supported formats: png, jpeg, webp, gif, bmp, tiff"
+    )]
     UnsupportedFormat {
         path: PathBuf,
         extension: Option<String>,
@@ -1141,6 +1643,100 @@ impl RasterComponent for StillImage {
     }
 }
 
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// Decodes a stream with the `image` crate, detecting its format.
+fn decode_with_image<R: BufRead + Seek>(reader: R) -> Result<CpuRasterImage, image::ImageError> {
+    let mut decoder = image::ImageReader::new(reader)
+        .with_guessed_format()?
+        .into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let icc_profile = decoder.icc_profile()?;
+    let decoded = image::DynamicImage::from_decoder(decoder)?;
+    Ok(upright_srgb(decoded, orientation, icc_profile.as_deref()))
+}
+
+/// Turns a decoded image upright for its EXIF `orientation` and converts it
+/// to sRGB from its embedded ICC profile, as straight-alpha RGBA8.
+fn upright_srgb(
+    mut decoded: image::DynamicImage,
+    orientation: image::metadata::Orientation,
+    icc_profile: Option<&[u8]>,
+) -> CpuRasterImage {
+    decoded.apply_orientation(orientation);
+    let decoded = decoded.into_rgba8();
+    let (width, height) = decoded.dimensions();
+    let mut pixels = decoded.into_raw();
+    if let Some(icc_profile) = icc_profile {
+        convert_to_srgb(icc_profile, &mut pixels);
+    }
+    CpuRasterImage::new(width, height, PixelFormat::Rgba8, pixels)
+}
+
+/// Maps an `image` crate error onto the [`ImageLoadError`] variants PNG
+/// decoding already uses: an undetectable format is
+/// [`UnsupportedFormat`](ImageLoadError::UnsupportedFormat), exceeded limits
+/// are [`ImageTooLarge`](ImageLoadError::ImageTooLarge), and read failures
+/// and malformed data are [`Io`](ImageLoadError::Io) errors, the latter of
+/// kind [`InvalidData`](std::io::ErrorKind::InvalidData).
+fn image_load_error(path: &Path, err: image::ImageError) -> ImageLoadError {
+    use image::error::{ImageFormatHint, UnsupportedErrorKind};
+
+    let path = path.to_path_buf();
+    match err {
+        image::ImageError::Limits(_) => ImageLoadError::ImageTooLarge,
+        image::ImageError::IoError(source) => ImageLoadError::Io { path, source },
+        image::ImageError::Unsupported(unsupported) => match unsupported.kind() {
+            UnsupportedErrorKind::Format(hint) => ImageLoadError::UnsupportedFormat {
+                path,
+                extension: match hint {
+                    ImageFormatHint::Exact(format) => {
+                        format.extensions_str().first().map(|ext| (*ext).to_owned())
+                    }
+                    ImageFormatHint::Name(name) => Some(name),
+                    ImageFormatHint::PathExtension(ext) => Some(ext.to_string_lossy().into_owned()),
+                    _ => None,
+                },
+            },
+            _ => ImageLoadError::Io {
+                path,
+                source: std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    image::ImageError::Unsupported(unsupported),
+                ),
+            },
+        },
+        err => ImageLoadError::Io {
+            path,
+            source: std::io::Error::new(std::io::ErrorKind::InvalidData, err),
+        },
+    }
+}
+
+/// Converts straight-alpha RGBA8 `pixels` from the RGB color space described
+/// by `icc_profile` to sRGB in place. Non-RGB or unreadable profiles are
+/// ignored.
+fn convert_to_srgb(icc_profile: &[u8], pixels: &mut [u8]) {
+    let Ok(profile) = moxcms::ColorProfile::new_from_slice(icc_profile) else {
+        return;
+    };
+    if profile.color_space != moxcms::DataColorSpace::Rgb {
+        return;
+    }
+    let Ok(transform) = profile.create_transform_8bit(
+        moxcms::Layout::Rgba,
+        &moxcms::ColorProfile::new_srgb(),
+        moxcms::Layout::Rgba,
+        moxcms::TransformOptions::default(),
+    ) else {
+        return;
+    };
+    let source = pixels.to_vec();
+    if transform.transform(&source, pixels).is_err() {
+        pixels.copy_from_slice(&source);
+    }
+}
+
 fn image_extension(path: &Path) -> Option<String> {
     path.extension()
         .and_then(|ext| ext.to_str())
@@ -1153,7 +1749,7 @@ fn png_to_rgba8(
     color_type: png::ColorType,
     bit_depth: png::BitDepth,
     pixels: Vec<u8>,
-) -> Result<CpuRasterImage, ImageLoadError> {
+) -> Result<Vec<u8>, ImageLoadError> {
     if bit_depth != png::BitDepth::Eight {
         return Err(ImageLoadError::UnsupportedPngColor {
             color_type,
@@ -1201,7 +1797,7 @@ fn png_to_rgba8(
         });
     }
 
-    Ok(CpuRasterImage::new(width, height, PixelFormat::Rgba8, rgba))
+    Ok(rgba)
 }
 
 fn rgba_len(width: u32, height: u32) -> Result<usize, ImageLoadError> {
@@ -1211,7 +1807,7 @@ fn rgba_len(width: u32, height: u32) -> Result<usize, ImageLoadError> {
         .ok_or(ImageLoadError::ImageTooLarge)
 }
 
-fn resample_rgba8(image: &CpuRasterImage, target: Resolution) -> CpuRasterImage {
+pub(crate) fn resample_rgba8(image: &CpuRasterImage, target: Resolution) -> CpuRasterImage {
     assert_eq!(
         image.format,
         PixelFormat::Rgba8,
@@ -1266,7 +1862,7 @@ fn sample_axis(dst: u32, dst_len: u32, src_len: u32) -> (u32, u32, f32) {
     (lo, hi, pos - lo as f32)
 }
 
-fn pixel(src: &[u8], width: u32, x: u32, y: u32) -> [u8; 4] {
+pub(crate) fn pixel(src: &[u8], width: u32, x: u32, y: u32) -> [u8; 4] {
     let offset = ((y as usize) * (width as usize) + (x as usize)) * 4;
     [
         src[offset],
@@ -1276,13 +1872,13 @@ fn pixel(src: &[u8], width: u32, x: u32, y: u32) -> [u8; 4] {
     ]
 }
 
-fn lerp_rgba8_premul(a: [u8; 4], b: [u8; 4], t: f32) -> [f32; 4] {
+pub(crate) fn lerp_rgba8_premul(a: [u8; 4], b: [u8; 4], t: f32) -> [f32; 4] {
     let a = premul(a);
     let b = premul(b);
     lerp_premul(a, b, t)
 }
 
-fn lerp_premul(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+pub(crate) fn lerp_premul(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
     [
         lerp(a[0], b[0], t),
         lerp(a[1], b[1], t),
@@ -1305,7 +1901,7 @@ fn lerp(a: f32, b: f32, t: f32) -> f32 {
     a + (b - a) * t
 }
 
-fn unpremul(px: [f32; 4]) -> [u8; 4] {
+pub(crate) fn unpremul(px: [f32; 4]) -> [u8; 4] {
     let alpha = px[3].round().clamp(0.0, 255.0);
     if alpha <= 0.0 {
         return [0, 0, 0, 0];
@@ -14,10 +14,17 @@
 
 use std::any::Any;
 
+use crate::chroma_key::ChromaKeySettings;
 use crate::color::Color;
-use crate::geometry::Vec2;
+use crate::composite::BlendMode;
+use crate::distort::{Distortion, WarpPlacement};
+use crate::geometry::{Homography, Transform, Vec2};
+use crate::grade::{ColorAdjustment, CubeLut};
+use crate::grain::{Dither, GrainNoise};
+use crate::matte::MatteMode;
+use crate::pattern::Pattern;
 use crate::raster::{CpuRasterImage, RasterComponent, RasterImage, RasterResidency, Resolution};
-use crate::vector::VectorGraphic;
+use crate::vector::{ImageQuality, VectorGraphic};
 
 /// How aggressively a render context should try to keep work on the GPU.
 ///
@@ -166,6 +173,8 @@ pub struct CompositeInput<'a> {
     pub image: &'a RasterImage,
     pub offset_x: i32,
     pub offset_y: i32,
+    /// How `image` combines with the inputs before it.
+    pub blend: BlendMode,
 }
 
 pub struct DropShadowInput<'a> {
@@ -189,6 +198,201 @@ pub struct OutlineInput<'a> {
     pub color: Color,
 }
 
+/// A full-color blur of `child`, placed at the child offset inside a
+/// `target`-sized transparent image.
+///
+/// Each of three rounds runs a premultiplied box pass along `axes[0]` and
+/// then along `axes[1]`; together they approximate a Gaussian. A vector's
+/// length is that pass's radius in pixels (fractional radii weight the
+/// outermost taps, so the blur grows smoothly from zero) and a zero vector
+/// skips the pass.
+pub struct BlurInput<'a> {
+    pub child: &'a RasterImage,
+    pub target: Resolution,
+    pub child_offset_x: i32,
+    pub child_offset_y: i32,
+    pub axes: [Vec2; 2],
+}
+
+/// A glow around `child`, composited behind it in a `target`-sized image.
+///
+/// The child's alpha is dilated by the `spread` ellipse, blurred with the
+/// same three box passes as [`DropShadowInput`] and tinted with `color`.
+pub struct OuterGlowInput<'a> {
+    pub child: &'a RasterImage,
+    pub target: Resolution,
+    pub child_offset_x: i32,
+    pub child_offset_y: i32,
+    pub spread_x: u32,
+    pub spread_y: u32,
+    pub blur_radius: u32,
+    pub color: Color,
+}
+
+/// `child` with its bright areas blurred and added back on top.
+///
+/// Each premultiplied pixel is weighted by how far its luminance rises
+/// above `threshold` (reaching full weight at white), blurred along `axes`
+/// exactly like [`BlurInput`], scaled by `intensity` and added to the
+/// child.
+pub struct BloomInput<'a> {
+    pub child: &'a RasterImage,
+    pub target: Resolution,
+    pub child_offset_x: i32,
+    pub child_offset_y: i32,
+    pub threshold: f32,
+    pub intensity: f32,
+    pub axes: [Vec2; 2],
+}
+
+/// `image` with [`ColorAdjustment::apply`] run on every pixel's color;
+/// alpha and size are unchanged.
+pub struct ColorAdjustInput<'a> {
+    pub image: &'a RasterImage,
+    pub adjustment: ColorAdjustment,
+}
+
+/// `image` with each pixel's color mixed toward its [`CubeLut::sample`] by
+/// `mix` (`0` keeps the original, `1` is the full lookup).
+pub struct Lut3dInput<'a> {
+    pub image: &'a RasterImage,
+    pub lut: &'a CubeLut,
+    pub mix: f32,
+}
+
+/// `image` keyed by `settings` as in [`ChromaKeySettings::apply_pixels`],
+/// with the choke already converted to `choke_radius` pixels. The result is
+/// the same size as `image`.
+pub struct ChromaKeyInput<'a> {
+    pub image: &'a RasterImage,
+    pub settings: ChromaKeySettings,
+    pub choke_radius: u32,
+}
+
+/// `content` with its alpha multiplied by the [`MatteMode::coverage`] of
+/// `matte`, which sits at the matte offset in content pixels; content
+/// outside the matte sees a transparent matte pixel. The result is
+/// content-sized.
+pub struct MatteInput<'a> {
+    pub content: &'a RasterImage,
+    pub matte: &'a RasterImage,
+    pub matte_offset_x: i32,
+    pub matte_offset_y: i32,
+    pub mode: MatteMode,
+}
+
+/// `image` resampled into a `target`-sized image as in [`resample_pixels`]:
+/// `inverse` maps target pixel space back to `image`'s pixel space, and
+/// samples off `image` are transparent.
+///
+/// [`resample_pixels`]: crate::resample::resample_pixels
+pub struct ResampleInput<'a> {
+    pub image: &'a RasterImage,
+    pub target: Resolution,
+    pub inverse: Transform,
+    pub quality: ImageQuality,
+}
+
+/// `image` drawn through a projective map into a `target`-sized image, as
+/// in [`project_pixels`]: `inverse` maps target pixel space back to
+/// `image`'s pixel space, and target pixels whose ray misses the image or
+/// meets it in front of the [`NEAR_PLANE`] are transparent.
+///
+/// [`project_pixels`]: crate::perspective::project_pixels
+/// [`NEAR_PLANE`]: crate::perspective::NEAR_PLANE
+pub struct ProjectInput<'a> {
+    pub image: &'a RasterImage,
+    pub target: Resolution,
+    pub inverse: Homography,
+    pub quality: ImageQuality,
+}
+
+/// Flat layers composited into a `target`-sized image in per-pixel depth
+/// order, as in [`depth_composite_pixels`]. Target pixel `(x, y)` is the
+/// layout-space point `origin + (x + 0.5, y + 0.5) / scale`.
+///
+/// [`depth_composite_pixels`]: crate::perspective::depth_composite_pixels
+pub struct DepthCompositeInput<'a> {
+    pub target: Resolution,
+    pub origin: Vec2,
+    pub scale: Vec2,
+    pub layers: &'a [DepthLayer<'a>],
+}
+
+/// One layer of a [`DepthCompositeInput`]: an already projected image whose
+/// top-left pixel sits `offset` pixels into the target. `inverse` maps a
+/// layout-space point back to the layer's plane, and `depth` holds the
+/// coefficients `[a, b, c]` of its depth `a·u + b·v + c` at plane point
+/// `(u, v)`.
+pub struct DepthLayer<'a> {
+    pub image: &'a RasterImage,
+    pub offset: [i32; 2],
+    pub inverse: Homography,
+    pub depth: [f32; 3],
+    pub blend: BlendMode,
+}
+
+/// `image` remapped through a distortion into `placement`, as in
+/// [`distort_pixels`] and [`displace_pixels`], in the layout space of a
+/// child of `size`.
+///
+/// [`distort_pixels`]: crate::distort::distort_pixels
+/// [`displace_pixels`]: crate::distort::displace_pixels
+pub struct WarpInput<'a> {
+    pub image: &'a RasterImage,
+    pub placement: WarpPlacement,
+    pub size: Vec2,
+    pub field: WarpField<'a>,
+    pub quality: ImageQuality,
+}
+
+/// Where a [`WarpInput`]'s source points come from.
+pub enum WarpField<'a> {
+    /// A procedural warp.
+    Distort(Distortion),
+    /// A displacement map whose top-left pixel sits `map_offset` pixels from
+    /// the output's, at the same density.
+    Displace {
+        map: &'a RasterImage,
+        map_offset: [i32; 2],
+        amount: Vec2,
+    },
+}
+
+/// `image` with film grain added, as in [`grain_pixels`].
+///
+/// [`grain_pixels`]: crate::grain::grain_pixels
+pub struct GrainInput<'a> {
+    pub image: &'a RasterImage,
+    pub noise: GrainNoise,
+}
+
+/// `image` dithered for 8-bit output on `frame`, as in [`dither_pixels`].
+///
+/// [`dither_pixels`]: crate::grain::dither_pixels
+pub struct DitherInput<'a> {
+    pub image: &'a RasterImage,
+    pub dither: Dither,
+    pub frame: u64,
+}
+
+/// A `target`-sized render of a procedural pattern covering a `size`
+/// layout box, as in [`pattern_pixels`].
+///
+/// [`pattern_pixels`]: crate::pattern::pattern_pixels
+pub struct PatternInput<'a> {
+    pub target: Resolution,
+    pub size: Vec2,
+    pub pattern: Pattern<'a>,
+}
+
+/// GPU implementations of the raster passes.
+///
+/// Every pass may return `None` (an unsupported format, a failed VRAM
+/// reservation), and the caller then runs the pass's CPU reference instead,
+/// which the GPU pass is kept in lockstep with. Passes with a default body
+/// decline unless a backend overrides them, so lightweight and test backends
+/// only implement what they need.
 pub trait GpuRasterBackend {
     /// Uploads a CPU image into backend-owned GPU storage.
     ///
@@ -209,6 +413,104 @@ pub trait GpuRasterBackend {
 
     fn outline(&mut self, input: OutlineInput<'_>) -> Option<RasterImage>;
 
+    /// Blurs a full-color image with three rounds of premultiplied box
+    /// passes; see [`BlurInput`]. The CPU fallback is the renderer's
+    /// `blur_image`.
+    fn blur(&mut self, _input: BlurInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Paints a dilated, blurred and tinted copy of the child's alpha behind
+    /// it; see [`OuterGlowInput`]. The CPU fallback is the renderer's
+    /// `make_glow`.
+    fn outer_glow(&mut self, _input: OuterGlowInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Blurs the child's above-threshold highlights and adds them back on
+    /// top; see [`BloomInput`]. The CPU fallback is the renderer's
+    /// `bloom_image`.
+    fn bloom(&mut self, _input: BloomInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Runs [`ColorAdjustment::apply`] on every pixel, blended by `mix`; see
+    /// [`ColorAdjustInput`]. The CPU fallback is
+    /// [`Grade::apply_pixels`](crate::grade::Grade::apply_pixels).
+    fn color_adjust(&mut self, _input: ColorAdjustInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Looks every pixel up in a 3D LUT with trilinear filtering; see
+    /// [`Lut3dInput`]. The CPU fallback is
+    /// [`Grade::apply_pixels`](crate::grade::Grade::apply_pixels).
+    fn lut3d(&mut self, _input: Lut3dInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Multiplies the content's alpha by a track matte's coverage; see
+    /// [`MatteInput`]. The CPU fallback is
+    /// [`apply_matte_pixels`](crate::matte::apply_matte_pixels).
+    fn matte(&mut self, _input: MatteInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Keys out a backdrop color, suppresses its spill and chokes the edge;
+    /// see [`ChromaKeyInput`]. The CPU fallback is
+    /// [`ChromaKeySettings::apply_pixels`].
+    fn chroma_key(&mut self, _input: ChromaKeyInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Resamples an image through an inverse affine map; see
+    /// [`ResampleInput`]. The CPU fallback is
+    /// [`resample_pixels`](crate::resample::resample_pixels).
+    fn resample(&mut self, _input: ResampleInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Resamples an image through an inverse homography, dropping rays
+    /// behind the near plane; see [`ProjectInput`]. The CPU fallback is
+    /// [`project_pixels`](crate::perspective::project_pixels).
+    fn project(&mut self, _input: ProjectInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Composites projected layers, ordering them at every pixel by their
+    /// depth there; see [`DepthCompositeInput`]. The CPU fallback is
+    /// [`depth_composite_pixels`](crate::perspective::depth_composite_pixels).
+    fn depth_composite(&mut self, _input: DepthCompositeInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Remaps an image through a procedural warp or a displacement map; see
+    /// [`WarpInput`]. The CPU fallbacks are
+    /// [`distort_pixels`](crate::distort::distort_pixels) and
+    /// [`displace_pixels`](crate::distort::displace_pixels).
+    fn warp(&mut self, _input: WarpInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Adds seeded, hashed film grain to an image; see [`GrainInput`]. The
+    /// CPU fallback is [`grain_pixels`](crate::grain::grain_pixels).
+    fn grain(&mut self, _input: GrainInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Nudges a finished frame by an ordered or blue-noise pattern before its
+    /// 8-bit output; see [`DitherInput`]. The CPU fallback is
+    /// [`dither_pixels`](crate::grain::dither_pixels).
+    fn dither(&mut self, _input: DitherInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
+    /// Generates a noise field, checkerboard, stripes or dot grid from
+    /// nothing; see [`PatternInput`]. The CPU fallback is
+    /// [`pattern_pixels`](crate::pattern::pattern_pixels).
+    fn pattern(&mut self, _input: PatternInput<'_>) -> Option<RasterImage> {
+        None
+    }
+
     fn rasterize(&mut self, graphic: &VectorGraphic, target: Resolution) -> Option<RasterImage>;
 
     /// Produces a target-sized image filled with a single solid color.
@@ -9,13 +9,142 @@
 use std::f32::consts::{FRAC_PI_2, PI, TAU};
 
 use crate::geometry::{Constraints, Rect, Transform, Vec2};
-use crate::vector::{Fill, Node, Path, PathCommand, Stroke, VectorComponent, VectorGraphic};
+use crate::vector::{
+    Fill, FillRule, Group, Node, Path, PathCommand, Stroke, VectorComponent, VectorGraphic,
+};
 use crate::Keyable;
 
+/// How a [`Corners`] radius bends the outline.
+#[derive(Debug, Clone, Copy, Default, Keyable)]
+pub enum CornerStyle {
+    /// A quarter circle (or ellipse) of the given radius — CSS
+    /// `border-radius`.
+    #[default]
+    Circular,
+    /// A continuous-curvature "squircle" corner: the curve starts further
+    /// along each edge (about 1.5× the radius) and eases into the bend, so
+    /// the edge flows into the corner without the visible kink of a circular
+    /// arc. Its deepest point matches a circular corner of the same radius.
+    Squircle,
+}
+
+/// Per-corner radii of a [`Rectangle`], clockwise from the top left.
+///
+/// Radii that would overlap along an edge are scaled down together, as CSS
+/// does for `border-radius`, so `Corners::round(f32::INFINITY)` draws a pill
+/// that is fully round on its shorter side. Negative and NaN radii are square
+/// corners. A bare `f32` is a uniform circular radius (`.corners(12.0)`).
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct Corners {
+    pub top_left: f32,
+    pub top_right: f32,
+    pub bottom_right: f32,
+    pub bottom_left: f32,
+    pub style: CornerStyle,
+}
+
+impl Corners {
+    pub const SQUARE: Self = Self::round(0.0);
+
+    /// The same circular radius on every corner.
+    pub const fn round(radius: f32) -> Self {
+        Self::new(radius, radius, radius, radius)
+    }
+
+    /// The same squircle radius on every corner.
+    pub const fn squircle(radius: f32) -> Self {
+        Self::round(radius).with_style(CornerStyle::Squircle)
+    }
+
+    /// Circular corners with individual radii, clockwise from the top left.
+    pub const fn new(top_left: f32, top_right: f32, bottom_right: f32, bottom_left: f32) -> Self {
+        Self {
+            top_left,
+            top_right,
+            bottom_right,
+            bottom_left,
+            style: CornerStyle::Circular,
+        }
+    }
+
+    pub const fn with_style(mut self, style: CornerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// How far along each edge the corner curve reaches, and how far from
+    /// the corner its Bezier handles sit, per corner (clockwise from the top
+    /// left) for a `size` box.
+    fn resolve(&self, size: Vec2) -> [CornerCurve; 4] {
+        let Vec2(w, h) = size;
+        let longest = w.max(h);
+        let radius = |r: f32| {
+            if r.is_nan() || r <= 0.0 {
+                0.0
+            } else {
+                r.min(longest)
+            }
+        };
+        let radii = [
+            radius(self.top_left),
+            radius(self.top_right),
+            radius(self.bottom_right),
+            radius(self.bottom_left),
+        ];
+        let (extent, handle) = match self.style {
+            CornerStyle::Circular => (1.0, 1.0 - KAPPA),
+            CornerStyle::Squircle => (SQUIRCLE_EXTENT, SQUIRCLE_HANDLE),
+        };
+        let [tl, tr, br, bl] = radii.map(|r| r * extent);
+        let fit = |edge: f32, a: f32, b: f32| if a + b > edge { edge / (a + b) } else { 1.0 };
+        let scale = fit(w, tl, tr)
+            .min(fit(h, tr, br))
+            .min(fit(w, bl, br))
+            .min(fit(h, tl, bl));
+        radii.map(|r| CornerCurve {
+            extent: r * extent * scale,
+            handle: r * handle * scale,
+        })
+    }
+
+    fn is_square(&self) -> bool {
+        [
+            self.top_left,
+            self.top_right,
+            self.bottom_right,
+            self.bottom_left,
+        ]
+        .iter()
+        .all(|r| r.is_nan() || *r <= 0.0)
+    }
+}
+
+impl Default for Corners {
+    fn default() -> Self {
+        Self::SQUARE
+    }
+}
+
+impl From<f32> for Corners {
+    fn from(radius: f32) -> Self {
+        Self::round(radius)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct CornerCurve {
+    extent: f32,
+    handle: f32,
+}
+
+/// An axis-aligned rectangle filling its layout box, with optional rounded
+/// [`Corners`].
 #[crate::component(vector)]
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub struct Rectangle {
     pub size: Vec2,
+    #[builder(default, into)]
+    pub corners: Corners,
     #[builder(into)]
     pub fill: Option<Fill>,
     #[builder(into)]
@@ -32,22 +161,27 @@ impl VectorComponent for Rectangle {
     }
 
     fn render(&self, size: Vec2) -> VectorGraphic {
-        let Vec2(w, h) = size;
         let Some((fill, stroke)) = visible_paints(size, &self.fill, &self.stroke) else {
             return empty_graphic(size);
         };
-        let commands = vec![
-            PathCommand::MoveTo(Vec2(0.0, 0.0)),
-            PathCommand::LineTo(Vec2(w, 0.0)),
-            PathCommand::LineTo(Vec2(w, h)),
-            PathCommand::LineTo(Vec2(0.0, h)),
-            PathCommand::Close,
-        ];
+        let commands = if self.corners.is_square() {
+            let Vec2(w, h) = size;
+            vec![
+                PathCommand::MoveTo(Vec2(0.0, 0.0)),
+                PathCommand::LineTo(Vec2(w, 0.0)),
+                PathCommand::LineTo(Vec2(w, h)),
+                PathCommand::LineTo(Vec2(0.0, h)),
+                PathCommand::Close,
+            ]
+        } else {
+            rounded_rect_commands(size, self.corners.resolve(size))
+        };
         VectorGraphic {
             view_box: stroked_bounds(size, &self.stroke),
             root: Node::Path(Path {
                 commands,
                 fill,
+                fill_rule: FillRule::NonZero,
                 stroke,
                 transform: Transform::IDENTITY,
             }),
@@ -55,6 +189,49 @@ impl VectorComponent for Rectangle {
     }
 }
 
+/// Traces the rectangle clockwise from the end of the top-left corner,
+/// replacing each corner with one cubic whose endpoints sit `extent` along
+/// the two edges and whose handles sit `handle` from the corner point.
+fn rounded_rect_commands(size: Vec2, [tl, tr, br, bl]: [CornerCurve; 4]) -> Vec<PathCommand> {
+    let Vec2(w, h) = size;
+    let mut commands = Vec::with_capacity(10);
+    commands.push(PathCommand::MoveTo(Vec2(tl.extent, 0.0)));
+    commands.push(PathCommand::LineTo(Vec2(w - tr.extent, 0.0)));
+    if tr.extent > 0.0 {
+        commands.push(PathCommand::CubicTo {
+            c1: Vec2(w - tr.handle, 0.0),
+            c2: Vec2(w, tr.handle),
+            to: Vec2(w, tr.extent),
+        });
+    }
+    commands.push(PathCommand::LineTo(Vec2(w, h - br.extent)));
+    if br.extent > 0.0 {
+        commands.push(PathCommand::CubicTo {
+            c1: Vec2(w, h - br.handle),
+            c2: Vec2(w - br.handle, h),
+            to: Vec2(w - br.extent, h),
+        });
+    }
+    commands.push(PathCommand::LineTo(Vec2(bl.extent, h)));
+    if bl.extent > 0.0 {
+        commands.push(PathCommand::CubicTo {
+            c1: Vec2(bl.handle, h),
+            c2: Vec2(0.0, h - bl.handle),
+            to: Vec2(0.0, h - bl.extent),
+        });
+    }
+    if tl.extent > 0.0 {
+        commands.push(PathCommand::LineTo(Vec2(0.0, tl.extent)));
+        commands.push(PathCommand::CubicTo {
+            c1: Vec2(0.0, tl.handle),
+            c2: Vec2(tl.handle, 0.0),
+            to: Vec2(tl.extent, 0.0),
+        });
+    }
+    commands.push(PathCommand::Close);
+    commands
+}
+
 #[crate::component(vector)]
 #[derive(Debug, Clone, Keyable)]
 pub struct Circle {
@@ -164,6 +341,7 @@ impl VectorComponent for Arc {
             root: Node::Path(Path {
                 commands,
                 fill,
+                fill_rule: FillRule::NonZero,
                 stroke,
                 transform: Transform::IDENTITY,
             }),
@@ -266,6 +444,7 @@ impl VectorComponent for RegularPolygon {
             root: Node::Path(Path {
                 commands,
                 fill,
+                fill_rule: FillRule::NonZero,
                 stroke,
                 transform: Transform::IDENTITY,
             }),
@@ -280,6 +459,410 @@ fn assert_valid_sides(sides: usize) {
     );
 }
 
+/// A star with `points` tips on a circle of `outer_radius`, alternating with
+/// notches on a circle of `inner_radius`.
+///
+/// Like [`RegularPolygon`], `rotation` defaults to `-PI/2` (a tip straight
+/// up) and the star stretches with its layout box: under tight non-square
+/// constraints both radii scale with the box, keeping their ratio.
+/// `inner_radius` is clamped to `0..=outer_radius`, so the notches never
+/// reach past the tips' circle and out of the layout box.
+///
+/// Panics (in `layout`/`render`) if `points < 2`.
+#[crate::component(vector)]
+#[derive(Debug, Clone, Keyable)]
+pub struct Star {
+    pub points: usize,
+    pub outer_radius: f32,
+    pub inner_radius: f32,
+    #[builder(default = -FRAC_PI_2)]
+    pub rotation: f32,
+    #[builder(into)]
+    pub fill: Option<Fill>,
+    #[builder(into)]
+    pub stroke: Option<Stroke>,
+}
+
+impl VectorComponent for Star {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        assert_valid_star_points(self.points);
+        let diameter = self.outer_radius * 2.0;
+        constraints.constrain(Vec2(diameter, diameter))
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        stroked_bounds(size, &self.stroke)
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        assert_valid_star_points(self.points);
+        let Some((fill, stroke)) = visible_paints(size, &self.fill, &self.stroke) else {
+            return empty_graphic(size);
+        };
+        let center = Vec2(size.0 * 0.5, size.1 * 0.5);
+        let ratio = if self.outer_radius > 0.0 {
+            (self.inner_radius / self.outer_radius).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let inner = Vec2(center.0 * ratio, center.1 * ratio);
+        let vertices = self.points * 2;
+        let mut commands = Vec::with_capacity(vertices + 1);
+        for i in 0..vertices {
+            let angle = self.rotation + TAU * i as f32 / vertices as f32;
+            let radii = if i % 2 == 0 { center } else { inner };
+            let p = ellipse_point(center, radii, angle);
+            commands.push(if i == 0 {
+                PathCommand::MoveTo(p)
+            } else {
+                PathCommand::LineTo(p)
+            });
+        }
+        commands.push(PathCommand::Close);
+        VectorGraphic {
+            view_box: stroked_bounds(size, &stroke),
+            root: Node::Path(Path {
+                commands,
+                fill,
+                fill_rule: FillRule::NonZero,
+                stroke,
+                transform: Transform::IDENTITY,
+            }),
+        }
+    }
+}
+
+fn assert_valid_star_points(points: usize) {
+    assert!(points >= 2, "Star requires at least 2 points, got {points}");
+}
+
+/// A straight stroked segment from `start` to `end`.
+///
+/// The layout box is the segment's bounding box, so only the points'
+/// relative position matters: place the line with
+/// [`place_at`](crate::placement::VectorPlacement::place_at) like any other
+/// shape. A horizontal or vertical line has a zero-thickness box; its stroke
+/// still paints, and the paint bounds grow by the stroke outset.
+#[crate::component(vector)]
+#[derive(Debug, Clone, Keyable)]
+pub struct Line {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub stroke: Stroke,
+}
+
+impl VectorComponent for Line {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        constraints.constrain(points_box(&[self.start, self.end]).size)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        stroked_bounds(size, &Some(self.stroke.clone()))
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        let [start, end] = fit_points([self.start, self.end], size);
+        polyline_graphic(size, &[start, end], false, None, Some(&self.stroke))
+    }
+}
+
+/// Connected straight segments through `points`, optionally `closed` into a
+/// polygon.
+///
+/// Sized like [`Line`]: the points' bounding box is the layout box. As with
+/// [`Arc`], a visible `fill` closes the outline even when `closed` is false.
+/// Fewer than two points render nothing.
+#[crate::component(vector)]
+#[derive(Debug, Clone, Keyable)]
+pub struct Polyline {
+    pub points: Vec<Vec2>,
+    #[builder(default)]
+    pub closed: bool,
+    #[builder(into)]
+    pub fill: Option<Fill>,
+    #[builder(into)]
+    pub stroke: Option<Stroke>,
+}
+
+impl VectorComponent for Polyline {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        constraints.constrain(points_box(&self.points).size)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        stroked_bounds(size, &self.stroke)
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        let points = fit_points(self.points.clone(), size);
+        polyline_graphic(
+            size,
+            &points,
+            self.closed,
+            self.fill.as_ref(),
+            self.stroke.as_ref(),
+        )
+    }
+}
+
+/// A single cubic Bezier curve from `start` to `end`, shaped by `control1`
+/// and `control2`.
+///
+/// The layout box is the curve's tight bounding box (not the control
+/// polygon's), so only the points' relative position matters. As with
+/// [`Arc`], a visible `fill` closes the chord back to `start`.
+#[crate::component(vector)]
+#[derive(Debug, Clone, Keyable)]
+pub struct Bezier {
+    pub start: Vec2,
+    pub control1: Vec2,
+    pub control2: Vec2,
+    pub end: Vec2,
+    #[builder(into)]
+    pub fill: Option<Fill>,
+    #[builder(into)]
+    pub stroke: Option<Stroke>,
+}
+
+impl Bezier {
+    fn curve_box(&self) -> Rect {
+        let (p0, p1, p2, p3) = (self.start, self.control1, self.control2, self.end);
+        let mut points = vec![p0, p3];
+        for t in cubic_extrema(p0.0, p1.0, p2.0, p3.0)
+            .into_iter()
+            .chain(cubic_extrema(p0.1, p1.1, p2.1, p3.1))
+        {
+            points.push(cubic_point(p0, p1, p2, p3, t));
+        }
+        points_box(&points)
+    }
+}
+
+impl VectorComponent for Bezier {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        constraints.constrain(self.curve_box().size)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        stroked_bounds(size, &self.stroke)
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        let Some((fill, stroke)) = visible_ink(self.fill.as_ref(), self.stroke.as_ref()) else {
+            return empty_graphic(size);
+        };
+        let fit = fit_transform(self.curve_box(), size);
+        let [start, c1, c2, to] =
+            [self.start, self.control1, self.control2, self.end].map(|p| fit.transform_point(p));
+        let mut commands = vec![
+            PathCommand::MoveTo(start),
+            PathCommand::CubicTo { c1, c2, to },
+        ];
+        if fill.is_some() {
+            commands.push(PathCommand::Close);
+        }
+        VectorGraphic {
+            view_box: stroked_bounds(size, &stroke),
+            root: Node::Path(Path {
+                commands,
+                fill,
+                fill_rule: FillRule::NonZero,
+                stroke,
+                transform: Transform::IDENTITY,
+            }),
+        }
+    }
+}
+
+/// Which ends of an [`Arrow`] carry a head.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ArrowHeads {
+    /// A plain shaft.
+    None,
+    Start,
+    #[default]
+    End,
+    Both,
+}
+
+impl ArrowHeads {
+    fn at_start(self) -> bool {
+        matches!(self, Self::Start | Self::Both)
+    }
+
+    fn at_end(self) -> bool {
+        matches!(self, Self::End | Self::Both)
+    }
+}
+
+/// How an [`Arrow`] head is drawn.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ArrowHeadStyle {
+    /// A solid triangle filled with the stroke's paint; the shaft stops at
+    /// the triangle's base so it never pokes through the tip.
+    #[default]
+    Filled,
+    /// Two stroked barbs meeting at the tip, drawn with the shaft's stroke.
+    Open,
+}
+
+/// A stroked shaft from `start` to `end` with heads on one or both ends.
+///
+/// Sized like [`Line`]: the shaft's bounding box is the layout box, and heads
+/// (which are never stretched, even under tight constraints) widen only the
+/// paint bounds. `head_length` defaults to four stroke widths and
+/// `head_width` to the head length. Heads shrink when the shaft is too short
+/// to hold them.
+#[crate::component(vector)]
+#[derive(Debug, Clone, Keyable)]
+pub struct Arrow {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub stroke: Stroke,
+    #[builder(default)]
+    pub heads: ArrowHeads,
+    #[builder(default)]
+    pub head_style: ArrowHeadStyle,
+    pub head_length: Option<f32>,
+    pub head_width: Option<f32>,
+}
+
+/// An arrow resolved into output-space geometry.
+struct ArrowGeometry {
+    shaft: [Vec2; 2],
+    /// Each head as `[barb, tip, barb]`.
+    heads: Vec<[Vec2; 3]>,
+}
+
+impl Arrow {
+    fn geometry(&self, size: Vec2) -> Option<ArrowGeometry> {
+        let [start, end] = fit_points([self.start, self.end], size);
+        let delta = end - start;
+        let length = (delta.0 * delta.0 + delta.1 * delta.1).sqrt();
+        if length <= 0.0 || !length.is_finite() {
+            return None;
+        }
+        let dir = Vec2(delta.0 / length, delta.1 / length);
+        let normal = Vec2(-dir.1, dir.0);
+
+        let count = usize::from(self.heads.at_start()) + usize::from(self.heads.at_end());
+        let default_length = self.stroke.width.max(0.0) * DEFAULT_ARROW_HEAD_WIDTHS;
+        let wanted = self.head_length.unwrap_or(default_length).max(0.0);
+        let head_length = if count == 0 {
+            0.0
+        } else {
+            wanted.min(length / count as f32)
+        };
+        let head_width =
+            self.head_width.unwrap_or(wanted).max(0.0) * head_length / wanted.max(f32::EPSILON);
+        let head = |tip: Vec2, toward: Vec2| {
+            let base = Vec2(
+                tip.0 - toward.0 * head_length,
+                tip.1 - toward.1 * head_length,
+            );
+            let half = head_width * 0.5;
+            [
+                Vec2(base.0 + normal.0 * half, base.1 + normal.1 * half),
+                tip,
+                Vec2(base.0 - normal.0 * half, base.1 - normal.1 * half),
+            ]
+        };
+
+        let mut shaft = [start, end];
+        let mut heads = Vec::with_capacity(count);
+        let pull_back = match self.head_style {
+            ArrowHeadStyle::Filled => head_length,
+            ArrowHeadStyle::Open => 0.0,
+        };
+        if self.heads.at_start() && head_length > 0.0 {
+            heads.push(head(start, Vec2(-dir.0, -dir.1)));
+            shaft[0] = Vec2(start.0 + dir.0 * pull_back, start.1 + dir.1 * pull_back);
+        }
+        if self.heads.at_end() && head_length > 0.0 {
+            heads.push(head(end, dir));
+            shaft[1] = Vec2(end.0 - dir.0 * pull_back, end.1 - dir.1 * pull_back);
+        }
+        Some(ArrowGeometry { shaft, heads })
+    }
+}
+
+impl VectorComponent for Arrow {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        constraints.constrain(points_box(&[self.start, self.end]).size)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        let Some(geometry) = self.geometry(size) else {
+            return stroked_bounds(size, &Some(self.stroke.clone()));
+        };
+        let points: Vec<Vec2> = geometry
+            .shaft
+            .into_iter()
+            .chain(geometry.heads.into_iter().flatten())
+            .collect();
+        outset_rect(points_box(&points), self.stroke.conservative_outset())
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        let view_box = self.paint_bounds(size);
+        let geometry = self.geometry(size).filter(|_| self.stroke.is_visible());
+        let Some(geometry) = geometry else {
+            return VectorGraphic {
+                view_box,
+                root: Node::empty(),
+            };
+        };
+        let [from, to] = geometry.shaft;
+        let mut children = vec![Node::Path(Path {
+            commands: vec![PathCommand::MoveTo(from), PathCommand::LineTo(to)],
+            fill: None,
+            fill_rule: FillRule::NonZero,
+            stroke: Some(self.stroke.clone()),
+            transform: Transform::IDENTITY,
+        })];
+        for [left, tip, right] in geometry.heads {
+            let (commands, fill, stroke) = match self.head_style {
+                ArrowHeadStyle::Filled => (
+                    vec![
+                        PathCommand::MoveTo(tip),
+                        PathCommand::LineTo(left),
+                        PathCommand::LineTo(right),
+                        PathCommand::Close,
+                    ],
+                    Some(Fill {
+                        paint: self.stroke.paint.clone(),
+                    }),
+                    None,
+                ),
+                ArrowHeadStyle::Open => (
+                    vec![
+                        PathCommand::MoveTo(left),
+                        PathCommand::LineTo(tip),
+                        PathCommand::LineTo(right),
+                    ],
+                    None,
+                    Some(self.stroke.clone()),
+                ),
+            };
+            children.push(Node::Path(Path {
+                commands,
+                fill,
+                fill_rule: FillRule::NonZero,
+                stroke,
+                transform: Transform::IDENTITY,
+            }));
+        }
+        VectorGraphic {
+            view_box,
+            root: Node::Group(Group {
+                transform: Transform::IDENTITY,
+                opacity: 1.0,
+                children,
+            }),
+        }
+    }
+}
+
 /// Draws an arbitrary set of [`PathCommand`]s over a fixed logical canvas.
 ///
 /// `size` is the `layout` size and the logical canvas used to derive paint
@@ -290,7 +873,8 @@ fn assert_valid_sides(sides: usize) {
 ///
 /// This is the escape hatch for geometry the other shapes cannot express
 /// (freeform outlines, letter/glyph-style paths, etc.) without having to hand
-/// -write a `VectorComponent` impl.
+/// -write a `VectorComponent` impl. `fill_rule` decides how self-intersecting
+/// or nested contours fill; it defaults to [`FillRule::NonZero`].
 #[crate::component(vector)]
 #[derive(Debug, Clone, Keyable)]
 pub struct PathShape {
@@ -298,6 +882,8 @@ pub struct PathShape {
     pub commands: Vec<PathCommand>,
     #[builder(into)]
     pub fill: Option<Fill>,
+    #[builder(default)]
+    pub fill_rule: FillRule,
     #[builder(into)]
     pub stroke: Option<Stroke>,
 }
@@ -330,6 +916,7 @@ impl VectorComponent for PathShape {
             root: Node::Path(Path {
                 commands: self.commands.clone(),
                 fill,
+                fill_rule: self.fill_rule,
                 stroke,
                 transform: Transform::IDENTITY,
             }),
@@ -341,6 +928,15 @@ impl VectorComponent for PathShape {
 // 4 * (sqrt(2) - 1) / 3. The maximum error is around 0.027% of the radius.
 const KAPPA: f32 = 0.552_284_8;
 
+// Squircle corners start 1.528 radii along each edge (the extent Apple's
+// continuous corners use) with handles placed so the curve's midpoint lands
+// where a circular corner's does: (extent + 3 * handle) / 8 = (4 - 3K) / 8.
+const SQUIRCLE_EXTENT: f32 = 1.528;
+const SQUIRCLE_HANDLE: f32 = (4.0 - 3.0 * KAPPA - SQUIRCLE_EXTENT) / 3.0;
+
+// Default arrow head length, in stroke widths.
+const DEFAULT_ARROW_HEAD_WIDTHS: f32 = 4.0;
+
 fn stroked_bounds(size: Vec2, stroke: &Option<Stroke>) -> Rect {
     let outset = stroke
         .as_ref()
@@ -371,14 +967,150 @@ fn visible_paints(
     if size.0 <= 0.0 || size.1 <= 0.0 {
         return None;
     }
-    let fill = fill.clone().filter(Fill::is_visible);
-    let stroke = stroke.clone().filter(Stroke::is_visible);
+    visible_ink(fill.as_ref(), stroke.as_ref())
+}
+
+/// [`visible_paints`] without the box check, for point-based shapes whose
+/// layout box may legitimately be zero on one axis (a horizontal line).
+#[allow(clippy::type_complexity)]
+fn visible_ink(
+    fill: Option<&Fill>,
+    stroke: Option<&Stroke>,
+) -> Option<(Option<Fill>, Option<Stroke>)> {
+    let fill = fill.filter(|fill| fill.is_visible()).cloned();
+    let stroke = stroke.filter(|stroke| stroke.is_visible()).cloned();
     if fill.is_none() && stroke.is_none() {
         return None;
     }
     Some((fill, stroke))
 }
 
+fn outset_rect(rect: Rect, outset: f32) -> Rect {
+    Rect {
+        origin: Vec2(rect.origin.0 - outset, rect.origin.1 - outset),
+        size: Vec2(rect.size.0 + outset * 2.0, rect.size.1 + outset * 2.0),
+    }
+}
+
+/// The bounding box of `points`; empty at the origin when there are none.
+fn points_box(points: &[Vec2]) -> Rect {
+    let Some(&first) = points.first() else {
+        return Rect {
+            origin: Vec2::ZERO,
+            size: Vec2::ZERO,
+        };
+    };
+    let (min, max) = points.iter().fold((first, first), |(min, max), p| {
+        (
+            Vec2(min.0.min(p.0), min.1.min(p.1)),
+            Vec2(max.0.max(p.0), max.1.max(p.1)),
+        )
+    });
+    Rect {
+        origin: min,
+        size: max - min,
+    }
+}
+
+/// Maps `bounds` onto the `(0, 0)..size` layout box, stretching each axis
+/// independently. An axis with no extent is only translated.
+fn fit_transform(bounds: Rect, size: Vec2) -> Transform {
+    let scale = |extent: f32, target: f32| if extent > 0.0 { target / extent } else { 1.0 };
+    let sx = scale(bounds.size.0, size.0);
+    let sy = scale(bounds.size.1, size.1);
+    Transform::scale(Vec2(sx, sy)).concat(Transform::translate(Vec2(
+        -bounds.origin.0,
+        -bounds.origin.1,
+    )))
+}
+
+/// `points` moved from their own bounding box onto the layout box.
+fn fit_points<P: AsMut<[Vec2]> + AsRef<[Vec2]>>(mut points: P, size: Vec2) -> P {
+    let fit = fit_transform(points_box(points.as_ref()), size);
+    for p in points.as_mut() {
+        *p = fit.transform_point(*p);
+    }
+    points
+}
+
+/// A straight-segment path through `points` in layout space, closed when
+/// `closed` or when it carries a visible fill.
+fn polyline_graphic(
+    size: Vec2,
+    points: &[Vec2],
+    closed: bool,
+    fill: Option<&Fill>,
+    stroke: Option<&Stroke>,
+) -> VectorGraphic {
+    let view_box = stroked_bounds(size, &stroke.cloned());
+    let paints = visible_ink(fill, stroke).filter(|_| points.len() >= 2);
+    let Some((fill, stroke)) = paints else {
+        return VectorGraphic {
+            view_box,
+            root: Node::empty(),
+        };
+    };
+    let mut commands: Vec<PathCommand> = points
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            if i == 0 {
+                PathCommand::MoveTo(p)
+            } else {
+                PathCommand::LineTo(p)
+            }
+        })
+        .collect();
+    if closed || fill.is_some() {
+        commands.push(PathCommand::Close);
+    }
+    VectorGraphic {
+        view_box,
+        root: Node::Path(Path {
+            commands,
+            fill,
+            fill_rule: FillRule::NonZero,
+            stroke,
+            transform: Transform::IDENTITY,
+        }),
+    }
+}
+
+fn cubic_point(p0: Vec2, c1: Vec2, c2: Vec2, p1: Vec2, t: f32) -> Vec2 {
+    let mt = 1.0 - t;
+    let (a, b, c, d) = (mt * mt * mt, 3.0 * mt * mt * t, 3.0 * mt * t * t, t * t * t);
+    Vec2(
+        a * p0.0 + b * c1.0 + c * c2.0 + d * p1.0,
+        a * p0.1 + b * c1.1 + c * c2.1 + d * p1.1,
+    )
+}
+
+/// Parameters in `(0, 1)` where one coordinate of a cubic Bezier has a
+/// local extremum (roots of its derivative).
+fn cubic_extrema(p0: f32, p1: f32, p2: f32, p3: f32) -> Vec<f32> {
+    let (a, b, c) = (p1 - p0, p2 - p1, p3 - p2);
+    // B'(t) / 3 = qa * t^2 + qb * t + qc
+    let qa = a - 2.0 * b + c;
+    let qb = 2.0 * (b - a);
+    let qc = a;
+    let roots = if qa.abs() < 1.0e-6 {
+        if qb.abs() < 1.0e-6 {
+            Vec::new()
+        } else {
+            vec![-qc / qb]
+        }
+    } else {
+        let discriminant = qb * qb - 4.0 * qa * qc;
+        if discriminant < 0.0 {
+            Vec::new()
+        } else {
+            let root = discriminant.sqrt();
+            vec![(-qb + root) / (2.0 * qa), (-qb - root) / (2.0 * qa)]
+        }
+    };
+    roots.into_iter().filter(|t| *t > 0.0 && *t < 1.0).collect()
+}
+
 /// The graphic an invisible shape renders as: the layout box with no ink.
 fn empty_graphic(size: Vec2) -> VectorGraphic {
     VectorGraphic {
@@ -433,6 +1165,7 @@ fn ellipse_to_graphic(radii: Vec2, fill: Option<Fill>, stroke: Option<Stroke>) -
         root: Node::Path(Path {
             commands,
             fill,
+            fill_rule: FillRule::NonZero,
             stroke,
             transform: Transform::IDENTITY,
         }),
@@ -794,6 +1527,279 @@ mod builder_tests {
         degenerate.layout(Constraints::UNBOUNDED);
     }
 
+    fn cubics(path: &Path) -> usize {
+        path.commands
+            .iter()
+            .filter(|c| matches!(c, PathCommand::CubicTo { .. }))
+            .count()
+    }
+
+    #[test]
+    fn square_corners_keep_the_plain_rectangle_outline() {
+        let rect = Rectangle::builder()
+            .size(Vec2(10.0, 20.0))
+            .fill(paint())
+            .build();
+        let Node::Path(path) = rect.render(Vec2(10.0, 20.0)).root else {
+            panic!("a filled rectangle renders as a single path");
+        };
+        assert_eq!(path.commands.len(), 5);
+        assert_eq!(cubics(&path), 0);
+    }
+
+    #[test]
+    fn rounded_corners_use_per_corner_radii() {
+        let rect = Rectangle::builder()
+            .size(Vec2(40.0, 20.0))
+            .corners(Corners::new(4.0, 0.0, 6.0, 0.0))
+            .fill(paint())
+            .build();
+        let Node::Path(path) = rect.render(Vec2(40.0, 20.0)).root else {
+            panic!("a rounded rectangle renders as a single path");
+        };
+        assert_eq!(cubics(&path), 2);
+        assert_eq!(path.commands[0], PathCommand::MoveTo(Vec2(4.0, 0.0)));
+        assert!(path.commands.contains(&PathCommand::CubicTo {
+            c1: Vec2(40.0, 14.0 + 6.0 * KAPPA),
+            c2: Vec2(34.0 + 6.0 * KAPPA, 20.0),
+            to: Vec2(34.0, 20.0),
+        }));
+        assert_eq!(
+            rect.paint_bounds(Vec2(40.0, 20.0)),
+            Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(40.0, 20.0),
+            }
+        );
+    }
+
+    #[test]
+    fn oversized_radii_shrink_into_a_pill() {
+        let rect = Rectangle::builder()
+            .size(Vec2(40.0, 10.0))
+            .corners(f32::INFINITY)
+            .fill(paint())
+            .build();
+        let Node::Path(path) = rect.render(Vec2(40.0, 10.0)).root else {
+            panic!("a rounded rectangle renders as a single path");
+        };
+        // Every radius fits the 10-unit sides: 5 each.
+        assert_eq!(path.commands[0], PathCommand::MoveTo(Vec2(5.0, 0.0)));
+        assert_eq!(path.commands[1], PathCommand::LineTo(Vec2(35.0, 0.0)));
+        assert_eq!(cubics(&path), 4);
+    }
+
+    #[test]
+    fn squircle_corners_reach_further_but_bend_as_deep() {
+        let corner = |corners: Corners| {
+            let rect = Rectangle::builder()
+                .size(Vec2(100.0, 100.0))
+                .corners(corners)
+                .fill(paint())
+                .build();
+            let Node::Path(path) = rect.render(Vec2(100.0, 100.0)).root else {
+                panic!("a rounded rectangle renders as a single path");
+            };
+            let PathCommand::LineTo(edge_end) = path.commands[1] else {
+                panic!("the top edge is a line");
+            };
+            let PathCommand::CubicTo { c1, c2, to } = path.commands[2] else {
+                panic!("the top-right corner is a cubic");
+            };
+            let mid = cubic_point(edge_end, c1, c2, to, 0.5);
+            (100.0 - edge_end.0, mid)
+        };
+        let (circular_reach, circular_mid) = corner(Corners::round(10.0));
+        let (squircle_reach, squircle_mid) = corner(Corners::squircle(10.0));
+        assert_eq!(circular_reach, 10.0);
+        assert!((squircle_reach - 15.28).abs() < 1e-3, "{squircle_reach}");
+        assert!((circular_mid.0 - squircle_mid.0).abs() < 1e-3);
+        assert!((circular_mid.1 - squircle_mid.1).abs() < 1e-3);
+    }
+
+    #[test]
+    fn star_alternates_outer_tips_and_inner_notches() {
+        let star = Star::builder()
+            .points(5)
+            .outer_radius(10.0)
+            .inner_radius(4.0)
+            .fill(paint())
+            .build();
+        assert_eq!(star.layout(Constraints::UNBOUNDED), Vec2(20.0, 20.0));
+        let Node::Path(path) = star.render(Vec2(20.0, 20.0)).root else {
+            panic!("a filled star renders as a single path");
+        };
+        assert_eq!(path.commands.len(), 11); // MoveTo + 9 LineTo + Close
+        let radius = |command: &PathCommand| match command {
+            PathCommand::MoveTo(p) | PathCommand::LineTo(p) => {
+                ((p.0 - 10.0).powi(2) + (p.1 - 10.0).powi(2)).sqrt()
+            }
+            _ => panic!("stars are polygons"),
+        };
+        assert!((radius(&path.commands[0]) - 10.0).abs() < 1e-4);
+        assert!((radius(&path.commands[1]) - 4.0).abs() < 1e-4);
+        assert!((radius(&path.commands[2]) - 10.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn star_notches_stay_inside_its_paint_bounds() {
+        let star = Star::builder()
+            .points(5)
+            .outer_radius(10.0)
+            .inner_radius(25.0)
+            .fill(paint())
+            .build();
+        let size = star.layout(Constraints::UNBOUNDED);
+        let bounds = star.paint_bounds(size);
+        let Node::Path(path) = star.render(size).root else {
+            panic!("a filled star renders as a single path");
+        };
+        for command in &path.commands {
+            if let PathCommand::MoveTo(p) | PathCommand::LineTo(p) = command {
+                let Vec2(x, y) = *p - bounds.origin;
+                let inside = (-1e-4..=bounds.size.0 + 1e-4).contains(&x)
+                    && (-1e-4..=bounds.size.1 + 1e-4).contains(&y);
+                assert!(inside, "{p:?} is outside {bounds:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn line_lays_out_on_its_bounding_box_and_strokes_past_it() {
+        let line = Line::builder()
+            .start(Vec2(50.0, 20.0))
+            .end(Vec2(10.0, 20.0))
+            .stroke(Stroke::new(paint(), 2.0))
+            .build();
+        let size = line.layout(Constraints::UNBOUNDED);
+        assert_eq!(size, Vec2(40.0, 0.0));
+        let graphic = line.render(size);
+        let expected = Rect {
+            origin: Vec2(-1.0, -1.0),
+            size: Vec2(42.0, 2.0),
+        };
+        assert_eq!(line.paint_bounds(size), expected);
+        assert_eq!(graphic.view_box, expected);
+        let Node::Path(path) = graphic.root else {
+            panic!("a line renders as a single path");
+        };
+        assert_eq!(
+            path.commands,
+            vec![
+                PathCommand::MoveTo(Vec2(40.0, 0.0)),
+                PathCommand::LineTo(Vec2(0.0, 0.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn polyline_closes_only_when_asked_or_filled() {
+        let points = vec![Vec2(0.0, 0.0), Vec2(10.0, 10.0), Vec2(20.0, 0.0)];
+        let render = |closed: bool, fill: Option<Fill>| {
+            let polyline = Polyline {
+                points: points.clone(),
+                closed,
+                fill,
+                stroke: Some(Stroke::new(paint(), 1.0)),
+            };
+            let Node::Path(path) = polyline.render(Vec2(20.0, 10.0)).root else {
+                panic!("a polyline renders as a single path");
+            };
+            path.commands.last() == Some(&PathCommand::Close)
+        };
+        assert!(!render(false, None));
+        assert!(render(true, None));
+        assert!(render(false, Some(Fill { paint: paint() })));
+
+        let single = Polyline::builder()
+            .points(vec![Vec2(3.0, 3.0)])
+            .stroke(Stroke::new(paint(), 1.0))
+            .build();
+        assert_eq!(single.render(Vec2::ZERO).root, Node::empty());
+    }
+
+    #[test]
+    fn bezier_layout_is_the_tight_curve_box() {
+        let bezier = Bezier::builder()
+            .start(Vec2(0.0, 0.0))
+            .control1(Vec2(0.0, 40.0))
+            .control2(Vec2(40.0, 40.0))
+            .end(Vec2(40.0, 0.0))
+            .stroke(Stroke::new(paint(), 1.0))
+            .build();
+        // The curve peaks at 3/4 of the control height.
+        assert_eq!(bezier.layout(Constraints::UNBOUNDED), Vec2(40.0, 30.0));
+        let Node::Path(path) = bezier.render(Vec2(40.0, 30.0)).root else {
+            panic!("a bezier renders as a single path");
+        };
+        assert_eq!(path.commands.len(), 2);
+    }
+
+    #[test]
+    fn arrow_heads_sit_on_the_requested_ends_and_widen_paint_bounds() {
+        let arrow = Arrow::builder()
+            .start(Vec2(0.0, 0.0))
+            .end(Vec2(40.0, 0.0))
+            .stroke(Stroke::new(paint(), 2.0).with_join(crate::vector::StrokeJoin::Round))
+            .heads(ArrowHeads::Both)
+            .head_length(8.0)
+            .head_width(6.0)
+            .build();
+        let size = arrow.layout(Constraints::UNBOUNDED);
+        assert_eq!(size, Vec2(40.0, 0.0));
+        let expected = Rect {
+            origin: Vec2(-1.0, -4.0),
+            size: Vec2(42.0, 8.0),
+        };
+        assert_eq!(arrow.paint_bounds(size), expected);
+
+        let graphic = arrow.render(size);
+        assert_eq!(graphic.view_box, expected);
+        let Node::Group(group) = graphic.root else {
+            panic!("an arrow renders as a group of shaft and heads");
+        };
+        assert_eq!(group.children.len(), 3);
+        let Node::Path(shaft) = &group.children[0] else {
+            panic!("the shaft is a path");
+        };
+        // Filled heads stop the shaft at their bases.
+        assert_eq!(
+            shaft.commands,
+            vec![
+                PathCommand::MoveTo(Vec2(8.0, 0.0)),
+                PathCommand::LineTo(Vec2(32.0, 0.0)),
+            ]
+        );
+        let Node::Path(head) = &group.children[2] else {
+            panic!("each head is a path");
+        };
+        assert_eq!(head.commands[0], PathCommand::MoveTo(Vec2(40.0, 0.0)));
+        assert_eq!(head.fill, Some(Fill { paint: paint() }));
+        assert_eq!(head.stroke, None);
+    }
+
+    #[test]
+    fn open_arrow_heads_share_the_shaft_stroke_and_shrink_to_fit() {
+        let arrow = Arrow::builder()
+            .start(Vec2(0.0, 0.0))
+            .end(Vec2(0.0, 6.0))
+            .stroke(Stroke::new(paint(), 2.0))
+            .head_style(ArrowHeadStyle::Open)
+            .build();
+        let graphic = arrow.render(arrow.layout(Constraints::UNBOUNDED));
+        let Node::Group(group) = graphic.root else {
+            panic!("an arrow renders as a group of shaft and heads");
+        };
+        let [Node::Path(shaft), Node::Path(head)] = group.children.as_slice() else {
+            panic!("one shaft and one head");
+        };
+        assert_eq!(shaft.commands[1], PathCommand::LineTo(Vec2(0.0, 6.0)));
+        // The default 8-unit head is cut down to the 6-unit shaft.
+        assert_eq!(head.commands[0], PathCommand::MoveTo(Vec2(-3.0, 0.0)));
+        assert_eq!(head.commands[1], PathCommand::LineTo(Vec2(0.0, 6.0)));
+        assert_eq!(head.stroke, shaft.stroke);
+    }
+
     #[test]
     fn path_shape_layout_matches_declared_size() {
         let shape = PathShape::builder()
@@ -829,6 +1835,26 @@ mod builder_tests {
         };
         assert_eq!(path.commands, commands);
         assert_eq!(path.fill, Some(Fill { paint: paint() }));
+        assert_eq!(path.fill_rule, FillRule::NonZero);
+    }
+
+    #[test]
+    fn path_shape_forwards_its_fill_rule() {
+        let shape = PathShape::builder()
+            .size(Vec2(10.0, 10.0))
+            .commands(vec![
+                PathCommand::MoveTo(Vec2(0.0, 0.0)),
+                PathCommand::LineTo(Vec2(10.0, 0.0)),
+                PathCommand::LineTo(Vec2(5.0, 10.0)),
+                PathCommand::Close,
+            ])
+            .fill(paint())
+            .fill_rule(FillRule::EvenOdd)
+            .build();
+        let Node::Path(path) = shape.render(Vec2(10.0, 10.0)).root else {
+            panic!("PathShape renders as a single path");
+        };
+        assert_eq!(path.fill_rule, FillRule::EvenOdd);
     }
 
     #[test]
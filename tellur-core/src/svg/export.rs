@@ -0,0 +1,677 @@
+//! SVG document export.
+//!
+//! [`export_svg`] serializes one rendered [`VectorGraphic`] frame — groups,
+//! clip groups and filled/stroked paths — as a standalone SVG 1.1 document
+//! whose `viewBox` is the graphic's `view_box`. Paints map onto their SVG
+//! counterparts with two approximations, since SVG has no equivalent:
+//!
+//! - a [`SweepGradient`](crate::vector::SweepGradient) exports as the
+//!   average of its stop colors;
+//! - an [`ImagePattern`] always tiles (`Pad` and `Reflect` export as
+//!   `Repeat`), with the image embedded as a PNG data URI.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use thiserror::Error;
+
+use crate::color::Color;
+use crate::geometry::{Transform, Vec2};
+use crate::raster::PngExportError;
+use crate::vector::{
+    ClipGroup, FillRule, GradientStop, ImagePattern, ImageQuality, LinearGradient, Node, Paint,
+    Path, PathCommand, RadialGradient, SpreadMode, Stroke, StrokeCap, StrokeJoin, VectorGraphic,
+};
+
+const SVG_NS: &str = "http://www.w3.org/2000/svg";
+/// `miter` joins at SVG's initial `stroke-miterlimit` need no attribute.
+const SVG_DEFAULT_MITER_LIMIT: f32 = 4.0;
+
+#[derive(Debug, Error)]
+pub enum SvgExportError {
+    #[error("failed to write SVG: {0}")]
+    Io(#[from] io::Error),
+    #[error("failed to embed image pattern: {0}")]
+    Image(#[from] PngExportError),
+}
+
+/// Serializes `graphic` as an SVG document and writes it to `writer`.
+pub fn export_svg<W: Write>(graphic: &VectorGraphic, mut writer: W) -> Result<(), SvgExportError> {
+    writer.write_all(to_svg_string(graphic)?.as_bytes())?;
+    Ok(())
+}
+
+/// Serializes `graphic` as an SVG document string.
+pub fn to_svg_string(graphic: &VectorGraphic) -> Result<String, SvgExportError> {
+    let mut exporter = Exporter::default();
+    exporter.node(&graphic.root, 1)?;
+
+    let view_box = graphic.view_box;
+    let mut out = String::new();
+    out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<svg xmlns=\"{SVG_NS}\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">",
+        num(view_box.size.0),
+        num(view_box.size.1),
+        num(view_box.origin.0),
+        num(view_box.origin.1),
+        num(view_box.size.0),
+        num(view_box.size.1),
+    );
+    if !exporter.defs.is_empty() {
+        out.push_str("  <defs>\n");
+        out.push_str(&exporter.defs);
+        out.push_str("  </defs>\n");
+    }
+    out.push_str(&exporter.body);
+    out.push_str("</svg>\n");
+    Ok(out)
+}
+
+impl VectorGraphic {
+    /// Serializes this graphic as an SVG document and writes it to `writer`.
+    /// See [`export_svg`].
+    pub fn export_svg<W: Write>(&self, writer: W) -> Result<(), SvgExportError> {
+        export_svg(self, writer)
+    }
+}
+
+/// Accumulates the document body and the `<defs>` its paints and clips
+/// reference. Every definition gets a fresh id, so nothing is deduplicated.
+#[derive(Default)]
+struct Exporter {
+    body: String,
+    defs: String,
+    next_id: usize,
+}
+
+impl Exporter {
+    fn id(&mut self, prefix: &str) -> String {
+        self.next_id += 1;
+        format!("{prefix}{}", self.next_id)
+    }
+
+    fn node(&mut self, node: &Node, depth: usize) -> Result<(), SvgExportError> {
+        match node {
+            Node::Group(group) => {
+                self.open_group(group.transform, group.opacity, None, depth);
+                for child in &group.children {
+                    self.node(child, depth + 1)?;
+                }
+                self.close_group(depth);
+            }
+            Node::SingleGroup(group) => {
+                self.open_group(group.transform, group.opacity, None, depth);
+                self.node(&group.child, depth + 1)?;
+                self.close_group(depth);
+            }
+            Node::ClipGroup(group) => {
+                let clip = self.clip_path(group);
+                self.open_group(Transform::IDENTITY, 1.0, Some(&clip), depth);
+                self.node(&group.child, depth + 1)?;
+                self.close_group(depth);
+            }
+            Node::Path(path) => self.path(path, depth)?,
+        }
+        Ok(())
+    }
+
+    fn open_group(&mut self, transform: Transform, opacity: f32, clip: Option<&str>, depth: usize) {
+        indent(&mut self.body, depth);
+        self.body.push_str("<g");
+        transform_attr(&mut self.body, "transform", transform);
+        if opacity < 1.0 {
+            attr(&mut self.body, "opacity", num(opacity.max(0.0)));
+        }
+        if let Some(clip) = clip {
+            attr(&mut self.body, "clip-path", format!("url(#{clip})"));
+        }
+        self.body.push_str(">\n");
+    }
+
+    fn close_group(&mut self, depth: usize) {
+        indent(&mut self.body, depth);
+        self.body.push_str("</g>\n");
+    }
+
+    /// Defines the clip of `group` and returns its id. The clip path sits in
+    /// the user space of the `<g>` that references it, matching the clip
+    /// group's own coordinate space.
+    fn clip_path(&mut self, group: &ClipGroup) -> String {
+        let id = self.id("clip");
+        let _ = write!(
+            self.defs,
+            "    <clipPath id=\"{id}\" clipPathUnits=\"userSpaceOnUse\">\n      <path"
+        );
+        attr(&mut self.defs, "d", path_data(&group.commands));
+        transform_attr(&mut self.defs, "transform", group.transform);
+        if group.fill_rule == FillRule::EvenOdd {
+            attr(&mut self.defs, "clip-rule", "evenodd");
+        }
+        self.defs.push_str("/>\n    </clipPath>\n");
+        id
+    }
+
+    fn path(&mut self, path: &Path, depth: usize) -> Result<(), SvgExportError> {
+        let mut attrs = String::new();
+        attr(&mut attrs, "d", path_data(&path.commands));
+        transform_attr(&mut attrs, "transform", path.transform);
+        match &path.fill {
+            Some(fill) => {
+                self.paint(&mut attrs, "fill", &fill.paint)?;
+                if path.fill_rule == FillRule::EvenOdd {
+                    attr(&mut attrs, "fill-rule", "evenodd");
+                }
+            }
+            None => attr(&mut attrs, "fill", "none"),
+        }
+        // SVG has no variable-width strokes: write the expanded outline as a
+        // second, filled path on top.
+        let outline = path.width_profile_outline(path.transform);
+        if let Some(stroke) = path.stroke.as_ref().filter(|_| outline.is_none()) {
+            self.stroke(&mut attrs, stroke)?;
+        }
+        indent(&mut self.body, depth);
+        let _ = writeln!(self.body, "<path{attrs}/>");
+        if let (Some(stroke), Some(outline)) = (&path.stroke, outline) {
+            let mut attrs = String::new();
+            attr(&mut attrs, "d", path_data(&outline));
+            transform_attr(&mut attrs, "transform", path.transform);
+            self.paint(&mut attrs, "fill", &stroke.paint)?;
+            indent(&mut self.body, depth);
+            let _ = writeln!(self.body, "<path{attrs}/>");
+        }
+        Ok(())
+    }
+
+    fn stroke(&mut self, attrs: &mut String, stroke: &Stroke) -> Result<(), SvgExportError> {
+        self.paint(attrs, "stroke", &stroke.paint)?;
+        attr(attrs, "stroke-width", num(stroke.width));
+        let cap = match stroke.cap {
+            StrokeCap::Butt => None,
+            StrokeCap::Round => Some("round"),
+            StrokeCap::Square => Some("square"),
+        };
+        if let Some(cap) = cap {
+            attr(attrs, "stroke-linecap", cap);
+        }
+        match stroke.join {
+            StrokeJoin::Miter => {
+                if stroke.miter_limit() != SVG_DEFAULT_MITER_LIMIT {
+                    attr(attrs, "stroke-miterlimit", num(stroke.miter_limit()));
+                }
+            }
+            StrokeJoin::Round => attr(attrs, "stroke-linejoin", "round"),
+            StrokeJoin::Bevel => attr(attrs, "stroke-linejoin", "bevel"),
+        }
+        if let Some(lengths) = stroke
+            .dash
+            .as_ref()
+            .and_then(|dash| dash.normalized_lengths())
+        {
+            let lengths: Vec<String> = lengths.into_iter().map(num).collect();
+            attr(attrs, "stroke-dasharray", lengths.join(" "));
+            let offset = stroke.dash.as_ref().map_or(0.0, |dash| dash.offset);
+            if offset != 0.0 {
+                attr(attrs, "stroke-dashoffset", num(offset));
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the `fill`/`stroke` attribute pair for `paint`, defining a
+    /// gradient or pattern when needed.
+    fn paint(
+        &mut self,
+        attrs: &mut String,
+        name: &str,
+        paint: &Paint,
+    ) -> Result<(), SvgExportError> {
+        let color = match paint {
+            Paint::Solid(color) => *color,
+            Paint::SweepGradient(gradient) => average_color(&gradient.normalized_stops()),
+            Paint::LinearGradient(gradient) => {
+                let id = self.linear_gradient(gradient);
+                attr(attrs, name, format!("url(#{id})"));
+                return Ok(());
+            }
+            Paint::RadialGradient(gradient) => {
+                let id = self.radial_gradient(gradient);
+                attr(attrs, name, format!("url(#{id})"));
+                return Ok(());
+            }
+            Paint::Image(pattern) => {
+                match self.image_pattern(pattern)? {
+                    Some(id) => attr(attrs, name, format!("url(#{id})")),
+                    None => attr(attrs, name, "none"),
+                }
+                return Ok(());
+            }
+        };
+        attr(attrs, name, hex_color(color));
+        if color.a < 1.0 {
+            attr(attrs, &format!("{name}-opacity"), num(color.a.max(0.0)));
+        }
+        Ok(())
+    }
+
+    fn linear_gradient(&mut self, gradient: &LinearGradient) -> String {
+        let id = self.id("gradient");
+        let _ = write!(
+            self.defs,
+            "    <linearGradient id=\"{id}\" gradientUnits=\"userSpaceOnUse\""
+        );
+        attr(&mut self.defs, "x1", num(gradient.start.0));
+        attr(&mut self.defs, "y1", num(gradient.start.1));
+        attr(&mut self.defs, "x2", num(gradient.end.0));
+        attr(&mut self.defs, "y2", num(gradient.end.1));
+        self.gradient_tail(
+            "linearGradient",
+            gradient.spread,
+            gradient.transform,
+            &gradient.normalized_stops(),
+        );
+        id
+    }
+
+    fn radial_gradient(&mut self, gradient: &RadialGradient) -> String {
+        let id = self.id("gradient");
+        let _ = write!(
+            self.defs,
+            "    <radialGradient id=\"{id}\" gradientUnits=\"userSpaceOnUse\""
+        );
+        attr(&mut self.defs, "cx", num(gradient.center.0));
+        attr(&mut self.defs, "cy", num(gradient.center.1));
+        attr(&mut self.defs, "r", num(gradient.radius.max(0.0)));
+        if gradient.focal != gradient.center {
+            attr(&mut self.defs, "fx", num(gradient.focal.0));
+            attr(&mut self.defs, "fy", num(gradient.focal.1));
+        }
+        self.gradient_tail(
+            "radialGradient",
+            gradient.spread,
+            gradient.transform,
+            &gradient.normalized_stops(),
+        );
+        id
+    }
+
+    /// Spread, transform and stops shared by both gradient elements.
+    fn gradient_tail(
+        &mut self,
+        element: &str,
+        spread: SpreadMode,
+        transform: Transform,
+        stops: &[GradientStop],
+    ) {
+        match spread {
+            SpreadMode::Pad => {}
+            SpreadMode::Reflect => attr(&mut self.defs, "spreadMethod", "reflect"),
+            SpreadMode::Repeat => attr(&mut self.defs, "spreadMethod", "repeat"),
+        }
+        transform_attr(&mut self.defs, "gradientTransform", transform);
+        self.defs.push_str(">\n");
+        for stop in stops {
+            self.defs.push_str("      <stop");
+            attr(&mut self.defs, "offset", num(stop.offset));
+            attr(&mut self.defs, "stop-color", hex_color(stop.color));
+            if stop.color.a < 1.0 {
+                attr(&mut self.defs, "stop-opacity", num(stop.color.a.max(0.0)));
+            }
+            self.defs.push_str("/>\n");
+        }
+        let _ = writeln!(self.defs, "    </{element}>");
+    }
+
+    /// Defines an image pattern; `None` when the image has nothing to draw,
+    /// exactly as the renderers skip it.
+    fn image_pattern(&mut self, pattern: &ImagePattern) -> Result<Option<String>, SvgExportError> {
+        if !pattern.is_drawable() {
+            return Ok(None);
+        }
+        let mut png = Vec::new();
+        pattern.image.export_png(&mut png)?;
+
+        let id = self.id("pattern");
+        let (width, height) = (
+            num(pattern.image.width as f32),
+            num(pattern.image.height as f32),
+        );
+        let _ = write!(
+            self.defs,
+            "    <pattern id=\"{id}\" patternUnits=\"userSpaceOnUse\" width=\"{width}\" height=\"{height}\""
+        );
+        transform_attr(&mut self.defs, "patternTransform", pattern.transform);
+        self.defs.push_str(">\n      <image");
+        attr(&mut self.defs, "width", width);
+        attr(&mut self.defs, "height", height);
+        if pattern.quality == ImageQuality::Nearest {
+            attr(&mut self.defs, "image-rendering", "pixelated");
+        }
+        let _ = write!(self.defs, " href=\"data:image/png;base64,");
+        base64_encode(&mut self.defs, &png);
+        self.defs.push_str("\"/>\n    </pattern>\n");
+        Ok(Some(id))
+    }
+}
+
+fn indent(out: &mut String, depth: usize) {
+    for _ in 0..depth {
+        out.push_str("  ");
+    }
+}
+
+fn attr(out: &mut String, name: &str, value: impl AsRef<str>) {
+    let _ = write!(out, " {name}=\"{}\"", value.as_ref());
+}
+
+fn transform_attr(out: &mut String, name: &str, transform: Transform) {
+    if transform == Transform::IDENTITY {
+        return;
+    }
+    let Transform { a, b, c, d, tx, ty } = transform;
+    let value = if (a, b, c, d) == (1.0, 0.0, 0.0, 1.0) {
+        format!("translate({} {})", num(tx), num(ty))
+    } else {
+        format!(
+            "matrix({} {} {} {} {} {})",
+            num(a),
+            num(b),
+            num(c),
+            num(d),
+            num(tx),
+            num(ty)
+        )
+    };
+    attr(out, name, value);
+}
+
+/// Shortest round-tripping decimal form; non-finite values become `0` so
+/// the document stays well-formed.
+fn num(value: f32) -> String {
+    if !value.is_finite() || value == 0.0 {
+        return "0".to_owned();
+    }
+    value.to_string()
+}
+
+fn point(out: &mut String, p: Vec2) {
+    let _ = write!(out, "{} {}", num(p.0), num(p.1));
+}
+
+fn path_data(commands: &[PathCommand]) -> String {
+    let mut d = String::new();
+    for command in commands {
+        if !d.is_empty() {
+            d.push(' ');
+        }
+        match *command {
+            PathCommand::MoveTo(p) => {
+                d.push_str("M ");
+                point(&mut d, p);
+            }
+            PathCommand::LineTo(p) => {
+                d.push_str("L ");
+                point(&mut d, p);
+            }
+            PathCommand::QuadTo { control, to } => {
+                d.push_str("Q ");
+                point(&mut d, control);
+                d.push(' ');
+                point(&mut d, to);
+            }
+            PathCommand::CubicTo { c1, c2, to } => {
+                d.push_str("C ");
+                point(&mut d, c1);
+                d.push(' ');
+                point(&mut d, c2);
+                d.push(' ');
+                point(&mut d, to);
+            }
+            PathCommand::Close => d.push('Z'),
+        }
+    }
+    d
+}
+
+/// `#rrggbb`; alpha travels separately as an `*-opacity` attribute.
+fn hex_color(color: Color) -> String {
+    let byte = |channel: f32| (channel.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!(
+        "#{:02x}{:02x}{:02x}",
+        byte(color.r),
+        byte(color.g),
+        byte(color.b)
+    )
+}
+
+fn average_color(stops: &[GradientStop]) -> Color {
+    if stops.is_empty() {
+        return Color::rgba_u8(0, 0, 0, 0);
+    }
+    let n = stops.len() as f32;
+    let sum = stops.iter().fold([0.0f32; 4], |acc, stop| {
+        let c = stop.color;
+        [acc[0] + c.r, acc[1] + c.g, acc[2] + c.b, acc[3] + c.a]
+    });
+    Color {
+        r: sum[0] / n,
+        g: sum[1] / n,
+        b: sum[2] / n,
+        a: sum[3] / n,
+    }
+}
+
+fn base64_encode(out: &mut String, bytes: &[u8]) {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    for chunk in bytes.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - i * 6)) as usize & 63] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Rect;
+    use crate::raster::{CpuRasterImage, PixelFormat};
+    use crate::shapes::{Corners, Rectangle};
+    use crate::svg::Svg;
+    use crate::vector::{DashPattern, Fill, Group, VectorComponent, WidthProfile};
+
+    fn square() -> Vec<PathCommand> {
+        vec![
+            PathCommand::MoveTo(Vec2(0.0, 0.0)),
+            PathCommand::LineTo(Vec2(10.0, 0.0)),
+            PathCommand::LineTo(Vec2(10.0, 10.0)),
+            PathCommand::LineTo(Vec2(0.0, 10.0)),
+            PathCommand::Close,
+        ]
+    }
+
+    fn graphic(root: Node) -> VectorGraphic {
+        VectorGraphic {
+            view_box: Rect {
+                origin: Vec2(-5.0, -5.0),
+                size: Vec2(40.0, 30.0),
+            },
+            root,
+        }
+    }
+
+    fn filled(paint: impl Into<Paint>) -> Path {
+        Path {
+            commands: square(),
+            fill: Some(Fill {
+                paint: paint.into(),
+            }),
+            fill_rule: FillRule::NonZero,
+            stroke: None,
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    #[test]
+    fn base64_matches_the_rfc_vectors() {
+        for (input, expected) in [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("foobar", "Zm9vYmFy"),
+        ] {
+            let mut out = String::new();
+            base64_encode(&mut out, input.as_bytes());
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    fn document_preserves_the_view_box() {
+        let svg = to_svg_string(&graphic(Node::empty())).unwrap();
+        assert!(
+            svg.contains(r#"width="40" height="30" viewBox="-5 -5 40 30""#),
+            "{svg}"
+        );
+    }
+
+    #[test]
+    fn paths_serialize_commands_fill_and_stroke() {
+        let mut path = filled(Color::rgba_u8(255, 0, 0, 128));
+        path.commands.push(PathCommand::QuadTo {
+            control: Vec2(1.0, 2.0),
+            to: Vec2(3.0, 4.0),
+        });
+        path.fill_rule = FillRule::EvenOdd;
+        path.transform = Transform::translate(Vec2(2.0, 3.0));
+        path.stroke = Some(
+            Stroke::new(Color::rgb_u8(0, 0, 255), 1.5)
+                .with_cap(StrokeCap::Square)
+                .with_join(StrokeJoin::Bevel)
+                .with_dash(DashPattern::new([4.0, 2.0, 1.0], 0.5)),
+        );
+        let svg = to_svg_string(&graphic(Node::Path(path))).unwrap();
+        let expected = concat!(
+            r#"<path d="M 0 0 L 10 0 L 10 10 L 0 10 Z Q 1 2 3 4" transform="translate(2 3)""#,
+            r##" fill="#ff0000" fill-opacity="0.5019608" fill-rule="evenodd""##,
+            r##" stroke="#0000ff" stroke-width="1.5" stroke-linecap="square""##,
+            r#" stroke-linejoin="bevel" stroke-dasharray="4 2 1 4 2 1" stroke-dashoffset="0.5"/>"#,
+        );
+        assert!(svg.contains(expected), "{svg}");
+    }
+
+    #[test]
+    fn width_profile_strokes_export_as_filled_outlines() {
+        let mut path = filled(Color::rgb_u8(0, 0, 0));
+        path.fill = None;
+        path.stroke = Some(
+            Stroke::new(Color::rgb_u8(0, 0, 255), 1.0)
+                .with_width_profile(WidthProfile::tapered(2.0, 0.25)),
+        );
+
+        let svg = to_svg_string(&graphic(Node::Path(path))).unwrap();
+
+        assert!(svg.contains(r#"fill="none"/>"#), "{svg}");
+        assert!(svg.contains(r##"fill="#0000ff"/>"##), "{svg}");
+        assert!(!svg.contains("stroke"), "{svg}");
+    }
+
+    #[test]
+    fn groups_and_clips_nest_with_their_transforms() {
+        let root = Node::Group(Group {
+            transform: Transform::scale(Vec2(2.0, 2.0)),
+            opacity: 0.5,
+            children: vec![Node::ClipGroup(ClipGroup {
+                commands: square(),
+                fill_rule: FillRule::EvenOdd,
+                transform: Transform::translate(Vec2(1.0, 0.0)),
+                child: Box::new(Node::Path(filled(Color::rgb_u8(0, 0, 0)))),
+            })],
+        });
+        let svg = to_svg_string(&graphic(root)).unwrap();
+        assert!(
+            svg.contains(r#"<g transform="matrix(2 0 0 2 0 0)" opacity="0.5">"#),
+            "{svg}"
+        );
+        assert!(
+            svg.contains(
+                r#"<clipPath id="clip1" clipPathUnits="userSpaceOnUse">
+      <path d="M 0 0 L 10 0 L 10 10 L 0 10 Z" transform="translate(1 0)" clip-rule="evenodd"/>"#
+            ),
+            "{svg}"
+        );
+        assert!(svg.contains(r##"<g clip-path="url(#clip1)">"##), "{svg}");
+    }
+
+    #[test]
+    fn gradients_and_images_become_definitions() {
+        let stops = [
+            GradientStop::new(0.0, Color::rgb_u8(255, 0, 0)),
+            GradientStop::new(1.0, Color::rgba_u8(0, 0, 255, 0)),
+        ];
+        let linear = LinearGradient::new(Vec2(0.0, 0.0), Vec2(10.0, 0.0), stops)
+            .with_spread(SpreadMode::Reflect);
+        let radial = RadialGradient::new(Vec2(5.0, 5.0), 5.0, stops).with_focal(Vec2(4.0, 5.0));
+        let image = CpuRasterImage::new(1, 1, PixelFormat::Rgba8, vec![255, 255, 255, 255]);
+        let pattern = ImagePattern::new(image).with_quality(ImageQuality::Nearest);
+        let root = Node::Group(Group {
+            transform: Transform::IDENTITY,
+            opacity: 1.0,
+            children: vec![
+                Node::Path(filled(linear)),
+                Node::Path(filled(radial)),
+                Node::Path(filled(pattern)),
+            ],
+        });
+        let svg = to_svg_string(&graphic(root)).unwrap();
+        assert!(svg.contains(
+            r#"<linearGradient id="gradient1" gradientUnits="userSpaceOnUse" x1="0" y1="0" x2="10" y2="0" spreadMethod="reflect">"#
+        ), "{svg}");
+        assert!(
+            svg.contains(r##"<stop offset="1" stop-color="#0000ff" stop-opacity="0"/>"##),
+            "{svg}"
+        );
+        assert!(
+            svg.contains(r#"cx="5" cy="5" r="5" fx="4" fy="5">"#),
+            "{svg}"
+        );
+        assert!(
+            svg.contains(r#"image-rendering="pixelated" href="data:image/png;base64,iVBORw0KGgo"#),
+            "{svg}"
+        );
+        for id in ["gradient1", "gradient2", "pattern3"] {
+            assert!(svg.contains(&format!("fill=\"url(#{id})\"")), "{id}: {svg}");
+        }
+    }
+
+    #[test]
+    fn exported_documents_import_back_unchanged() {
+        let rectangle = Rectangle {
+            size: Vec2(30.0, 20.0),
+            corners: Corners::SQUARE,
+            fill: Some(Paint::solid(Color::rgb_u8(10, 20, 30)).into()),
+            stroke: Some(Stroke::new(Color::rgb_u8(200, 100, 0), 2.0).with_join(StrokeJoin::Round)),
+        };
+        let rendered = rectangle.render(Vec2(30.0, 20.0));
+        let mut bytes = Vec::new();
+        rendered.export_svg(&mut bytes).unwrap();
+
+        let imported = Svg::parse(std::str::from_utf8(&bytes).unwrap()).unwrap();
+        assert!(imported.warnings().is_empty(), "{:?}", imported.warnings());
+        assert_eq!(imported.view_box(), rendered.view_box);
+        assert_eq!(imported.root(), &rendered.root);
+    }
+}
@@ -0,0 +1,2220 @@
+//! SVG document import.
+//!
+//! [`Svg::parse`] / [`Svg::load`] read a document with `roxmltree` and convert
+//! the static subset tellur can paint into a [`Node`] tree:
+//!
+//! - structure: `<svg>`, `<g>`, `<a>`, `<switch>` (first child), `<use>` and
+//!   `<symbol>` through `<use>`, with `transform` on any element;
+//! - geometry: `<path>` (full path data, arcs included), `<rect>` (rounded),
+//!   `<circle>`, `<ellipse>`, `<line>`, `<polyline>`, `<polygon>`;
+//! - paint: colors, `currentColor`, linear and radial gradients (either
+//!   `gradientUnits`, `href` inheritance), fill/stroke opacity, `fill-rule`;
+//! - strokes: width, caps, joins, miter limit, `stroke-dasharray` and offset;
+//! - compositing: element `opacity`, `display`, `visibility` and `clip-path`.
+//!
+//! Properties come from presentation attributes and inline `style`
+//! declarations. Everything else — text, images, filters, masks, markers,
+//! `<style>` sheets — is skipped and reported through
+//! [`Svg::warnings`], so a partially supported file still imports.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path as FsPath, PathBuf};
+
+use kurbo::{BezPath, PathEl};
+use roxmltree::{Document, Node as XmlNode, NodeId};
+use thiserror::Error;
+
+use crate::clip::path_command_bounds;
+use crate::color::Color;
+use crate::geometry::{Constraints, Rect, Transform, Vec2};
+use crate::layer::union_rect;
+use crate::scalar::clamp_unit;
+use crate::vector::{
+    ClipGroup, DashPattern, Fill, FillRule, GradientStop, Group, LinearGradient, Node, Paint, Path,
+    PathCommand, RadialGradient, SpreadMode, Stroke, StrokeCap, StrokeJoin, VectorComponent,
+    VectorGraphic,
+};
+
+const SVG_NS: &str = "http://www.w3.org/2000/svg";
+const XLINK_NS: &str = "http://www.w3.org/1999/xlink";
+const KAPPA: f32 = 0.552_284_8;
+/// Bound on `<use>` and gradient `href` chains, which may be cyclic.
+const MAX_REFERENCE_DEPTH: usize = 16;
+/// Bound on the elements a document expands to. Each `<use>` instantiates
+/// its target again, so nested references multiply, and a short document
+/// can otherwise expand exponentially within [`MAX_REFERENCE_DEPTH`].
+const MAX_EXPANDED_ELEMENTS: usize = 100_000;
+/// The CSS default size of a replaced element with no intrinsic dimensions.
+const DEFAULT_SIZE: Vec2 = Vec2(300.0, 150.0);
+
+/// An imported SVG document.
+///
+/// The intrinsic layout size comes from the root `width`/`height` (falling
+/// back to the `viewBox` size); `render` maps the `viewBox` onto whatever size
+/// the parent chooses, honoring `preserveAspectRatio`.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct Svg {
+    root: Node,
+    view_box: Rect,
+    size: Vec2,
+    aspect: AspectRatio,
+    content_bounds: Option<Rect>,
+    warnings: Vec<SvgWarning>,
+}
+
+impl Svg {
+    /// Reads and parses an SVG file.
+    pub fn load(path: impl AsRef<FsPath>) -> Result<Self, SvgError> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|source| SvgError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(&source)
+    }
+
+    /// Parses an SVG document from a string.
+    pub fn parse(source: &str) -> Result<Self, SvgError> {
+        let options = roxmltree::ParsingOptions {
+            allow_dtd: true,
+            ..Default::default()
+        };
+        let document = Document::parse_with_options(source, options)?;
+        let svg = document.root_element();
+        if svg.tag_name().name() != "svg" {
+            return Err(SvgError::NotSvg(svg.tag_name().name().to_owned()));
+        }
+
+        let mut importer = Importer::new(&document);
+        let view_box =
+            svg.attribute("viewBox")
+                .and_then(|value| match parse_numbers(value).as_deref() {
+                    Some(&[x, y, w, h]) if w > 0.0 && h > 0.0 => Some(Rect {
+                        origin: Vec2(x, y),
+                        size: Vec2(w, h),
+                    }),
+                    _ => {
+                        importer.invalid("svg", "viewBox", value);
+                        None
+                    }
+                });
+        let width = importer.root_length(svg, "width");
+        let height = importer.root_length(svg, "height");
+        let size = match (width, height, view_box) {
+            (Some(w), Some(h), _) => Vec2(w, h),
+            (Some(w), None, Some(vb)) => Vec2(w, w * vb.size.1 / vb.size.0),
+            (None, Some(h), Some(vb)) => Vec2(h * vb.size.0 / vb.size.1, h),
+            (None, None, Some(vb)) => vb.size,
+            (w, h, None) => Vec2(w.unwrap_or(DEFAULT_SIZE.0), h.unwrap_or(DEFAULT_SIZE.1)),
+        };
+        let view_box = view_box.unwrap_or(Rect {
+            origin: Vec2::ZERO,
+            size,
+        });
+        importer.viewport = view_box.size;
+        let aspect = svg
+            .attribute("preserveAspectRatio")
+            .and_then(|value| {
+                let aspect = AspectRatio::parse(value);
+                if aspect.is_none() {
+                    importer.invalid("svg", "preserveAspectRatio", value);
+                }
+                aspect
+            })
+            .unwrap_or_default();
+
+        let root = importer
+            .convert(svg, &Style::default(), 0)
+            .unwrap_or_else(Node::empty);
+        if importer.expanded > MAX_EXPANDED_ELEMENTS {
+            return Err(SvgError::TooManyElements(MAX_EXPANDED_ELEMENTS));
+        }
+        let content_bounds = node_bounds(&root, Transform::IDENTITY, true);
+        Ok(Self {
+            root,
+            view_box,
+            size,
+            aspect,
+            content_bounds,
+            warnings: importer.warnings,
+        })
+    }
+
+    /// Features of the document that were skipped or could not be read.
+    pub fn warnings(&self) -> &[SvgWarning] {
+        &self.warnings
+    }
+
+    /// The size the document asks for, in logical units (CSS pixels).
+    pub fn intrinsic_size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// The document's `viewBox`: the user-space rectangle mapped onto the
+    /// layout box.
+    pub fn view_box(&self) -> Rect {
+        self.view_box
+    }
+
+    /// The imported node tree in the document's user space, before the
+    /// `viewBox` mapping `render` applies.
+    pub fn root(&self) -> &Node {
+        &self.root
+    }
+
+    /// Maps user space onto a layout box of `size`.
+    fn fit(&self, size: Vec2) -> Transform {
+        let vb = self.view_box;
+        let sx = size.0 / vb.size.0;
+        let sy = size.1 / vb.size.1;
+        let (scale, offset) = match self.aspect.align {
+            None => (Vec2(sx, sy), Vec2::ZERO),
+            Some((align_x, align_y)) => {
+                let s = if self.aspect.slice {
+                    sx.max(sy)
+                } else {
+                    sx.min(sy)
+                };
+                let offset = Vec2(
+                    (size.0 - vb.size.0 * s) * align_x.factor(),
+                    (size.1 - vb.size.1 * s) * align_y.factor(),
+                );
+                (Vec2(s, s), offset)
+            }
+        };
+        Transform::translate(offset)
+            .concat(Transform::scale(scale))
+            .concat(Transform::translate(Vec2(-vb.origin.0, -vb.origin.1)))
+    }
+}
+
+impl VectorComponent for Svg {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        constraints.constrain(self.size)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        let layout = Rect {
+            origin: Vec2::ZERO,
+            size,
+        };
+        match self.content_bounds {
+            Some(bounds) if !self.aspect.slice => {
+                union_rect(layout, self.fit(size).transform_rect(bounds))
+            }
+            _ => layout,
+        }
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        let view_box = self.paint_bounds(size);
+        if self.root.is_empty() || size.0 <= 0.0 || size.1 <= 0.0 {
+            return VectorGraphic {
+                view_box,
+                root: Node::empty(),
+            };
+        }
+        let mut root = Node::single_group(self.fit(size), 1.0, self.root.clone());
+        if self.aspect.slice {
+            // A sliced viewBox overflows the viewport, which SVG clips.
+            root = Node::ClipGroup(ClipGroup {
+                commands: rect_commands(0.0, 0.0, size.0, size.1),
+                fill_rule: FillRule::NonZero,
+                transform: Transform::IDENTITY,
+                child: Box::new(root),
+            });
+        }
+        VectorGraphic { view_box, root }
+    }
+}
+
+impl From<Svg> for Box<dyn VectorComponent> {
+    fn from(svg: Svg) -> Self {
+        Box::new(svg)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SvgError {
+    #[error("failed to read SVG {path:?}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("SVG parse failed: {0}")]
+    Xml(#[from] roxmltree::Error),
+    #[error("root element is <{0}>, expected <svg>")]
+    NotSvg(String),
+    #[error("SVG expands to more than {0} elements through its <use> references")]
+    TooManyElements(usize),
+}
+
+/// A part of an SVG document that [`Svg`] could not import. The rest of the
+/// document still renders; the affected element or property is dropped.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SvgWarning {
+    /// An element tellur cannot paint, such as `<text>` or `<image>`.
+    UnsupportedElement { element: String },
+    /// A property tellur cannot honor, such as `filter` or `mask`.
+    UnsupportedAttribute { element: String, attribute: String },
+    /// A property value that failed to parse.
+    InvalidValue {
+        element: String,
+        attribute: String,
+        value: String,
+    },
+    /// A `url(#id)` or `href` that names a missing or unusable element.
+    UnresolvedReference { element: String, reference: String },
+}
+
+impl fmt::Display for SvgWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedElement { element } => {
+                write!(f, "unsupported element <{element}> was skipped")
+            }
+            Self::UnsupportedAttribute { element, attribute } => {
+                write!(f, "unsupported `{attribute}` on <{element}> was ignored")
+            }
+            Self::InvalidValue {
+                element,
+                attribute,
+                value,
+            } => write!(f, "invalid `{attribute}` value {value:?} on <{element}>"),
+            Self::UnresolvedReference { element, reference } => {
+                write!(
+                    f,
+                    "<{element}> references missing or unsupported {reference:?}"
+                )
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Align {
+    Min,
+    Mid,
+    Max,
+}
+
+impl Align {
+    fn factor(self) -> f32 {
+        match self {
+            Self::Min => 0.0,
+            Self::Mid => 0.5,
+            Self::Max => 1.0,
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "Min" => Some(Self::Min),
+            "Mid" => Some(Self::Mid),
+            "Max" => Some(Self::Max),
+            _ => None,
+        }
+    }
+}
+
+/// `preserveAspectRatio`: `align` is `None` for `none` (non-uniform
+/// stretch), otherwise the x/y alignment of a uniformly scaled viewBox.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct AspectRatio {
+    align: Option<(Align, Align)>,
+    slice: bool,
+}
+
+impl Default for AspectRatio {
+    fn default() -> Self {
+        Self {
+            align: Some((Align::Mid, Align::Mid)),
+            slice: false,
+        }
+    }
+}
+
+impl AspectRatio {
+    fn parse(value: &str) -> Option<Self> {
+        let mut words = value.split_whitespace();
+        let mut align = words.next()?;
+        if align == "defer" {
+            align = words.next()?;
+        }
+        let align = if align == "none" {
+            None
+        } else {
+            let rest = align.strip_prefix('x')?;
+            let (x, y) = rest.split_once('Y')?;
+            Some((Align::parse(x)?, Align::parse(y)?))
+        };
+        let slice = match words.next() {
+            None | Some("meet") => false,
+            Some("slice") => true,
+            Some(_) => return None,
+        };
+        Some(Self { align, slice })
+    }
+}
+
+/// A parsed `fill` / `stroke` value before it is resolved against the
+/// element it paints.
+#[derive(Debug, Clone)]
+enum PaintSpec {
+    None,
+    Color(Color),
+    CurrentColor,
+    Reference {
+        id: String,
+        fallback: Option<Box<PaintSpec>>,
+    },
+}
+
+/// Inherited SVG properties.
+#[derive(Debug, Clone)]
+struct Style {
+    fill: PaintSpec,
+    fill_opacity: f32,
+    fill_rule: FillRule,
+    stroke: PaintSpec,
+    stroke_opacity: f32,
+    stroke_width: f32,
+    cap: StrokeCap,
+    join: StrokeJoin,
+    miter_limit: f32,
+    dash_array: Option<Vec<f32>>,
+    dash_offset: f32,
+    clip_rule: FillRule,
+    color: Color,
+    visible: bool,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            fill: PaintSpec::Color(Color::rgb_u8(0, 0, 0)),
+            fill_opacity: 1.0,
+            fill_rule: FillRule::NonZero,
+            stroke: PaintSpec::None,
+            stroke_opacity: 1.0,
+            stroke_width: 1.0,
+            cap: StrokeCap::Butt,
+            join: StrokeJoin::Miter,
+            miter_limit: 4.0,
+            dash_array: None,
+            dash_offset: 0.0,
+            clip_rule: FillRule::NonZero,
+            color: Color::rgb_u8(0, 0, 0),
+            visible: true,
+        }
+    }
+}
+
+/// Properties that apply to one element and are not inherited.
+struct Local<'a> {
+    opacity: f32,
+    displayed: bool,
+    clip_path: Option<&'a str>,
+}
+
+impl Default for Local<'_> {
+    fn default() -> Self {
+        Self {
+            opacity: 1.0,
+            displayed: true,
+            clip_path: None,
+        }
+    }
+}
+
+/// Property names accepted as presentation attributes.
+const PROPERTIES: &[&str] = &[
+    "fill",
+    "fill-opacity",
+    "fill-rule",
+    "stroke",
+    "stroke-width",
+    "stroke-opacity",
+    "stroke-linecap",
+    "stroke-linejoin",
+    "stroke-miterlimit",
+    "stroke-dasharray",
+    "stroke-dashoffset",
+    "clip-rule",
+    "color",
+    "visibility",
+    "opacity",
+    "display",
+    "clip-path",
+    "mask",
+    "filter",
+    "marker",
+    "marker-start",
+    "marker-mid",
+    "marker-end",
+];
+
+struct Importer<'a, 'input> {
+    root: NodeId,
+    ids: HashMap<&'a str, XmlNode<'a, 'input>>,
+    /// The root viewBox size, against which user-space percentages resolve.
+    viewport: Vec2,
+    warnings: Vec<SvgWarning>,
+    /// Elements converted so far, counting each `<use>` instance; see
+    /// [`MAX_EXPANDED_ELEMENTS`].
+    expanded: usize,
+}
+
+impl<'a, 'input> Importer<'a, 'input> {
+    fn new(document: &'a Document<'input>) -> Self {
+        let ids = document
+            .descendants()
+            .filter_map(|node| node.attribute("id").map(|id| (id, node)))
+            .collect();
+        Self {
+            root: document.root_element().id(),
+            ids,
+            viewport: DEFAULT_SIZE,
+            warnings: Vec::new(),
+            expanded: 0,
+        }
+    }
+
+    fn warn(&mut self, warning: SvgWarning) {
+        if !self.warnings.contains(&warning) {
+            self.warnings.push(warning);
+        }
+    }
+
+    fn invalid(&mut self, element: &str, attribute: &str, value: &str) {
+        self.warn(SvgWarning::InvalidValue {
+            element: element.to_owned(),
+            attribute: attribute.to_owned(),
+            value: value.to_owned(),
+        });
+    }
+
+    fn unresolved(&mut self, element: &str, reference: &str) {
+        self.warn(SvgWarning::UnresolvedReference {
+            element: element.to_owned(),
+            reference: reference.to_owned(),
+        });
+    }
+
+    /// Root `width` / `height`; percentages mean "fill the container", which
+    /// has no intrinsic size, so they fall back like a missing attribute.
+    fn root_length(&mut self, svg: XmlNode, name: &str) -> Option<f32> {
+        let value = svg.attribute(name)?;
+        if value.trim_end().ends_with('%') {
+            return None;
+        }
+        let length = parse_length(value, 0.0).filter(|length| *length > 0.0);
+        if length.is_none() {
+            self.invalid("svg", name, value);
+        }
+        length
+    }
+
+    /// A user-space length; percentages resolve against the viewport along
+    /// `axis` (0 = x, 1 = y, anything else = normalized diagonal).
+    fn length(&mut self, node: XmlNode, name: &str, axis: usize) -> f32 {
+        let Some(value) = node.attribute(name) else {
+            return 0.0;
+        };
+        let reference = match axis {
+            0 => self.viewport.0,
+            1 => self.viewport.1,
+            _ => normalized_diagonal(self.viewport),
+        };
+        parse_length(value, reference).unwrap_or_else(|| {
+            self.invalid(node.tag_name().name(), name, value);
+            0.0
+        })
+    }
+
+    fn element_transform(&mut self, node: XmlNode) -> Transform {
+        let Some(value) = node.attribute("transform") else {
+            return Transform::IDENTITY;
+        };
+        parse_transform(value).unwrap_or_else(|| {
+            self.invalid(node.tag_name().name(), "transform", value);
+            Transform::IDENTITY
+        })
+    }
+
+    fn href(&self, node: XmlNode<'a, 'input>) -> Option<&'a str> {
+        node.attribute("href")
+            .or_else(|| node.attribute((XLINK_NS, "href")))
+    }
+
+    /// The element an `href="#id"` attribute names.
+    fn referenced(&self, node: XmlNode<'a, 'input>) -> Option<XmlNode<'a, 'input>> {
+        let id = self.href(node)?.trim().strip_prefix('#')?;
+        self.ids.get(id).copied()
+    }
+
+    /// Applies presentation attributes, then the inline `style` declarations
+    /// that override them.
+    fn declarations(
+        &mut self,
+        node: XmlNode<'a, 'input>,
+        style: &mut Style,
+        local: &mut Local<'a>,
+    ) {
+        let element = node.tag_name().name();
+        for attribute in node.attributes() {
+            if attribute.namespace().is_none() && PROPERTIES.contains(&attribute.name()) {
+                self.property(element, attribute.name(), attribute.value(), style, local);
+            }
+        }
+        if let Some(inline) = node.attribute("style") {
+            for declaration in inline.split(';') {
+                let Some((name, value)) = declaration.split_once(':') else {
+                    continue;
+                };
+                let value = value.trim();
+                let value = value.strip_suffix("!important").unwrap_or(value).trim();
+                self.property(element, name.trim(), value, style, local);
+            }
+        }
+    }
+
+    fn property(
+        &mut self,
+        element: &str,
+        name: &str,
+        value: &'a str,
+        style: &mut Style,
+        local: &mut Local<'a>,
+    ) {
+        if value == "inherit" {
+            return;
+        }
+        let valid = match name {
+            "fill" => parse_paint(value).map(|paint| style.fill = paint),
+            "stroke" => parse_paint(value).map(|paint| style.stroke = paint),
+            "fill-opacity" => parse_opacity(value).map(|v| style.fill_opacity = v),
+            "stroke-opacity" => parse_opacity(value).map(|v| style.stroke_opacity = v),
+            "opacity" => parse_opacity(value).map(|v| local.opacity = v),
+            "fill-rule" => parse_fill_rule(value).map(|rule| style.fill_rule = rule),
+            "clip-rule" => parse_fill_rule(value).map(|rule| style.clip_rule = rule),
+            "stroke-width" => {
+                let reference = normalized_diagonal(self.viewport);
+                parse_length(value, reference)
+                    .filter(|width| *width >= 0.0)
+                    .map(|width| style.stroke_width = width)
+            }
+            "stroke-linecap" => match value {
+                "butt" => Some(StrokeCap::Butt),
+                "round" => Some(StrokeCap::Round),
+                "square" => Some(StrokeCap::Square),
+                _ => None,
+            }
+            .map(|cap| style.cap = cap),
+            "stroke-linejoin" => match value {
+                "miter" | "miter-clip" | "arcs" => Some(StrokeJoin::Miter),
+                "round" => Some(StrokeJoin::Round),
+                "bevel" => Some(StrokeJoin::Bevel),
+                _ => None,
+            }
+            .map(|join| style.join = join),
+            "stroke-miterlimit" => parse_number(value)
+                .filter(|limit| *limit >= 1.0)
+                .map(|limit| style.miter_limit = limit),
+            "stroke-dasharray" => {
+                if value == "none" {
+                    style.dash_array = None;
+                    Some(())
+                } else {
+                    parse_length_list(value).map(|lengths| {
+                        // An all-zero or negative array renders solid.
+                        style.dash_array = (lengths.iter().all(|len| *len >= 0.0)
+                            && lengths.iter().any(|len| *len > 0.0))
+                        .then_some(lengths);
+                    })
+                }
+            }
+            "stroke-dashoffset" => {
+                parse_length(value, 0.0).map(|offset| style.dash_offset = offset)
+            }
+            "color" => parse_color(value).map(|color| style.color = color),
+            "visibility" => match value {
+                "visible" => Some(true),
+                "hidden" | "collapse" => Some(false),
+                _ => None,
+            }
+            .map(|visible| style.visible = visible),
+            "display" => {
+                local.displayed = value != "none";
+                Some(())
+            }
+            "clip-path" => {
+                local.clip_path = (value != "none").then_some(value);
+                Some(())
+            }
+            "mask" | "filter" | "marker" | "marker-start" | "marker-mid" | "marker-end" => {
+                if value != "none" {
+                    self.warn(SvgWarning::UnsupportedAttribute {
+                        element: element.to_owned(),
+                        attribute: name.to_owned(),
+                    });
+                }
+                Some(())
+            }
+            // Other inline declarations (fonts, text layout, …) have no
+            // effect on the supported elements.
+            _ => Some(()),
+        };
+        if valid.is_none() {
+            self.invalid(element, name, value);
+        }
+    }
+
+    /// Converts one element and its subtree; `None` when it paints nothing,
+    /// or once the document has expanded past [`MAX_EXPANDED_ELEMENTS`].
+    fn convert(&mut self, node: XmlNode<'a, 'input>, parent: &Style, depth: usize) -> Option<Node> {
+        if !node.is_element() || !is_svg_element(node) {
+            return None;
+        }
+        self.expanded += 1;
+        if self.expanded > MAX_EXPANDED_ELEMENTS {
+            return None;
+        }
+        let name = node.tag_name().name();
+        match name {
+            "svg" | "g" | "a" | "switch" | "use" | "path" | "rect" | "circle" | "ellipse"
+            | "line" | "polyline" | "polygon" => {}
+            // Resources and metadata: only painted through a reference.
+            "defs" | "symbol" | "clipPath" | "linearGradient" | "radialGradient" | "stop"
+            | "title" | "desc" | "metadata" => return None,
+            _ => {
+                self.warn(SvgWarning::UnsupportedElement {
+                    element: name.to_owned(),
+                });
+                return None;
+            }
+        }
+
+        let mut style = parent.clone();
+        let mut local = Local::default();
+        self.declarations(node, &mut style, &mut local);
+        if !local.displayed {
+            return None;
+        }
+
+        let mut transform = if name == "svg" {
+            Transform::IDENTITY
+        } else {
+            self.element_transform(node)
+        };
+        let content = match name {
+            "svg" => {
+                if node.id() != self.root {
+                    // Nested viewports are drawn in place, without their own
+                    // viewBox mapping or clipping.
+                    self.warn(SvgWarning::UnsupportedElement {
+                        element: "svg (nested)".to_owned(),
+                    });
+                    let x = self.length(node, "x", 0);
+                    let y = self.length(node, "y", 1);
+                    transform = Transform::translate(Vec2(x, y));
+                }
+                self.group(node, &style, depth)
+            }
+            "g" | "a" => self.group(node, &style, depth),
+            "switch" => node
+                .children()
+                .find_map(|child| self.convert(child, &style, depth))?,
+            "use" => {
+                let x = self.length(node, "x", 0);
+                let y = self.length(node, "y", 1);
+                transform = transform.concat(Transform::translate(Vec2(x, y)));
+                self.use_target(node, &style, depth)?
+            }
+            _ => self.shape(node, &style)?,
+        };
+        let content = match local.clip_path {
+            Some(reference) => self.clip(node, reference, content, depth)?,
+            None => content,
+        };
+        wrap(content, transform, local.opacity)
+    }
+
+    fn group(&mut self, node: XmlNode<'a, 'input>, style: &Style, depth: usize) -> Node {
+        let mut children: Vec<Node> = node
+            .children()
+            .filter_map(|child| self.convert(child, style, depth))
+            .collect();
+        if children.len() == 1 {
+            return children.pop().expect("one child");
+        }
+        Node::Group(Group {
+            transform: Transform::IDENTITY,
+            opacity: 1.0,
+            children,
+        })
+    }
+
+    fn use_target(
+        &mut self,
+        node: XmlNode<'a, 'input>,
+        style: &Style,
+        depth: usize,
+    ) -> Option<Node> {
+        let reference = self.href(node).unwrap_or_default();
+        let target = self.referenced(node);
+        let Some(target) = target.filter(|_| depth < MAX_REFERENCE_DEPTH) else {
+            self.unresolved("use", reference);
+            return None;
+        };
+        if target.tag_name().name() == "symbol" {
+            let mut style = style.clone();
+            let mut local = Local::default();
+            self.declarations(target, &mut style, &mut local);
+            if !local.displayed {
+                return None;
+            }
+            let content = self.group(target, &style, depth + 1);
+            return wrap(content, Transform::IDENTITY, local.opacity);
+        }
+        self.convert(target, style, depth + 1)
+    }
+
+    /// The outline of a basic shape or `<path>`, in its own user space.
+    fn geometry(&mut self, node: XmlNode<'a, 'input>) -> Option<Vec<PathCommand>> {
+        let name = node.tag_name().name();
+        let commands = match name {
+            "path" => {
+                let data = node.attribute("d")?;
+                match BezPath::from_svg(data) {
+                    Ok(path) => bez_commands(&path),
+                    Err(_) => {
+                        self.invalid(name, "d", data);
+                        return None;
+                    }
+                }
+            }
+            "rect" => {
+                let x = self.length(node, "x", 0);
+                let y = self.length(node, "y", 1);
+                let w = self.length(node, "width", 0);
+                let h = self.length(node, "height", 1);
+                if w <= 0.0 || h <= 0.0 {
+                    return None;
+                }
+                let rx = node.attribute("rx").map(|_| self.length(node, "rx", 0));
+                let ry = node.attribute("ry").map(|_| self.length(node, "ry", 1));
+                let (rx, ry) = match (rx, ry) {
+                    (Some(rx), Some(ry)) => (rx, ry),
+                    (Some(r), None) | (None, Some(r)) => (r, r),
+                    (None, None) => (0.0, 0.0),
+                };
+                let rx = rx.clamp(0.0, w * 0.5);
+                let ry = ry.clamp(0.0, h * 0.5);
+                if rx > 0.0 && ry > 0.0 {
+                    rounded_rect_commands(x, y, w, h, rx, ry)
+                } else {
+                    rect_commands(x, y, w, h)
+                }
+            }
+            "circle" => {
+                let r = self.length(node, "r", 2);
+                if r <= 0.0 {
+                    return None;
+                }
+                let center = Vec2(self.length(node, "cx", 0), self.length(node, "cy", 1));
+                ellipse_commands(center, r, r)
+            }
+            "ellipse" => {
+                let rx = node.attribute("rx").map(|_| self.length(node, "rx", 0));
+                let ry = node.attribute("ry").map(|_| self.length(node, "ry", 1));
+                let (rx, ry) = match (rx, ry) {
+                    (Some(rx), Some(ry)) => (rx, ry),
+                    (Some(r), None) | (None, Some(r)) => (r, r),
+                    (None, None) => return None,
+                };
+                if rx <= 0.0 || ry <= 0.0 {
+                    return None;
+                }
+                let center = Vec2(self.length(node, "cx", 0), self.length(node, "cy", 1));
+                ellipse_commands(center, rx, ry)
+            }
+            "line" => vec![
+                PathCommand::MoveTo(Vec2(self.length(node, "x1", 0), self.length(node, "y1", 1))),
+                PathCommand::LineTo(Vec2(self.length(node, "x2", 0), self.length(node, "y2", 1))),
+            ],
+            "polyline" | "polygon" => {
+                let value = node.attribute("points").unwrap_or_default();
+                let Some(numbers) = parse_numbers(value) else {
+                    self.invalid(name, "points", value);
+                    return None;
+                };
+                // An odd trailing coordinate is an error; SVG renders the
+                // pairs before it.
+                let mut commands: Vec<PathCommand> = numbers
+                    .chunks_exact(2)
+                    .enumerate()
+                    .map(|(i, pair)| {
+                        let point = Vec2(pair[0], pair[1]);
+                        if i == 0 {
+                            PathCommand::MoveTo(point)
+                        } else {
+                            PathCommand::LineTo(point)
+                        }
+                    })
+                    .collect();
+                if commands.len() < 2 {
+                    return None;
+                }
+                if name == "polygon" {
+                    commands.push(PathCommand::Close);
+                }
+                commands
+            }
+            _ => return None,
+        };
+        (!commands.is_empty()).then_some(commands)
+    }
+
+    fn shape(&mut self, node: XmlNode<'a, 'input>, style: &Style) -> Option<Node> {
+        let commands = self.geometry(node)?;
+        if !style.visible {
+            return None;
+        }
+        let element = node.tag_name().name();
+        let bounds = path_command_bounds(&commands);
+        let fill = self
+            .resolve_paint(element, &style.fill, style.fill_opacity, style, bounds)
+            .map(|paint| Fill { paint });
+        let stroke = if style.stroke_width > 0.0 {
+            self.resolve_paint(element, &style.stroke, style.stroke_opacity, style, bounds)
+                .map(|paint| {
+                    let stroke = Stroke::new(paint, style.stroke_width)
+                        .with_cap(style.cap)
+                        .with_join(style.join)
+                        .with_miter_limit(style.miter_limit);
+                    match &style.dash_array {
+                        Some(lengths) => {
+                            stroke.with_dash(DashPattern::new(lengths.clone(), style.dash_offset))
+                        }
+                        None => stroke,
+                    }
+                })
+        } else {
+            None
+        };
+        if fill.is_none() && stroke.is_none() {
+            return None;
+        }
+        Some(Node::Path(Path {
+            commands,
+            fill,
+            fill_rule: style.fill_rule,
+            stroke,
+            transform: Transform::IDENTITY,
+        }))
+    }
+
+    fn resolve_paint(
+        &mut self,
+        element: &str,
+        spec: &PaintSpec,
+        opacity: f32,
+        style: &Style,
+        bounds: Option<Rect>,
+    ) -> Option<Paint> {
+        match spec {
+            PaintSpec::None => None,
+            PaintSpec::Color(color) => Some(Paint::Solid(color.multiply_alpha(opacity))),
+            PaintSpec::CurrentColor => Some(Paint::Solid(style.color.multiply_alpha(opacity))),
+            PaintSpec::Reference { id, fallback } => {
+                let gradient = self.ids.get(id.as_str()).copied().filter(|node| {
+                    matches!(node.tag_name().name(), "linearGradient" | "radialGradient")
+                });
+                match (gradient, fallback) {
+                    (Some(gradient), _) => self.gradient(gradient, opacity, bounds),
+                    (None, Some(fallback)) => {
+                        self.resolve_paint(element, fallback, opacity, style, bounds)
+                    }
+                    (None, None) => {
+                        self.unresolved(element, id);
+                        None
+                    }
+                }
+            }
+        }
+    }
+
+    /// Looks `name` up on a gradient, then along its `href` chain.
+    fn gradient_attr(&self, node: XmlNode<'a, 'input>, name: &str) -> Option<&'a str> {
+        let mut current = node;
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            if let Some(value) = current.attribute(name) {
+                return Some(value);
+            }
+            current = self.referenced(current)?;
+        }
+        None
+    }
+
+    fn gradient_stops(&mut self, node: XmlNode<'a, 'input>, opacity: f32) -> Vec<GradientStop> {
+        let mut current = node;
+        for _ in 0..MAX_REFERENCE_DEPTH {
+            let stops: Vec<XmlNode> = current
+                .children()
+                .filter(|child| child.is_element() && child.tag_name().name() == "stop")
+                .collect();
+            if !stops.is_empty() {
+                let mut previous = 0.0f32;
+                return stops
+                    .into_iter()
+                    .map(|stop| {
+                        let offset = stop
+                            .attribute("offset")
+                            .and_then(parse_fraction)
+                            .unwrap_or(0.0);
+                        // Offsets never decrease: each clamps to the last.
+                        previous = clamp_unit(offset).max(previous);
+                        GradientStop::new(previous, self.stop_color(stop).multiply_alpha(opacity))
+                    })
+                    .collect();
+            }
+            match self.referenced(current) {
+                Some(next) => current = next,
+                None => break,
+            }
+        }
+        Vec::new()
+    }
+
+    fn stop_color(&mut self, stop: XmlNode<'a, 'input>) -> Color {
+        let mut color = Color::rgb_u8(0, 0, 0);
+        let mut opacity = 1.0;
+        let declarations = stop
+            .attributes()
+            .filter(|attribute| attribute.namespace().is_none())
+            .map(|attribute| (attribute.name(), attribute.value()))
+            .chain(
+                stop.attribute("style")
+                    .into_iter()
+                    .flat_map(|inline| inline.split(';'))
+                    .filter_map(|declaration| declaration.split_once(':'))
+                    .map(|(name, value)| (name.trim(), value.trim())),
+            )
+            .collect::<Vec<_>>();
+        for (name, value) in declarations {
+            let valid = match name {
+                "stop-color" => parse_color(value).map(|c| color = c),
+                "stop-opacity" => parse_opacity(value).map(|o| opacity = o),
+                _ => Some(()),
+            };
+            if valid.is_none() {
+                self.invalid("stop", name, value);
+            }
+        }
+        color.multiply_alpha(opacity)
+    }
+
+    fn gradient(
+        &mut self,
+        node: XmlNode<'a, 'input>,
+        opacity: f32,
+        bounds: Option<Rect>,
+    ) -> Option<Paint> {
+        let element = node.tag_name().name();
+        let stops = self.gradient_stops(node, opacity);
+        match stops.as_slice() {
+            [] => return None,
+            [only] => return Some(Paint::Solid(only.color)),
+            _ => {}
+        }
+
+        let bounding_box = self.gradient_attr(node, "gradientUnits") != Some("userSpaceOnUse");
+        let mut transform = match self.gradient_attr(node, "gradientTransform") {
+            Some(value) => parse_transform(value).unwrap_or_else(|| {
+                self.invalid(element, "gradientTransform", value);
+                Transform::IDENTITY
+            }),
+            None => Transform::IDENTITY,
+        };
+        if bounding_box {
+            // A zero-area box cannot host a bounding-box gradient.
+            let bounds = bounds.filter(|b| b.size.0 > 0.0 && b.size.1 > 0.0)?;
+            transform = Transform::translate(bounds.origin)
+                .concat(Transform::scale(bounds.size))
+                .concat(transform);
+        }
+        let spread = match self.gradient_attr(node, "spreadMethod") {
+            Some("reflect") => SpreadMode::Reflect,
+            Some("repeat") => SpreadMode::Repeat,
+            _ => SpreadMode::Pad,
+        };
+        let viewport = self.viewport;
+        let diagonal = normalized_diagonal(viewport);
+        let mut coordinate = |name: &str, default: f32, axis: usize| -> f32 {
+            let Some(value) = self.gradient_attr(node, name) else {
+                return default;
+            };
+            let parsed = if bounding_box {
+                parse_fraction(value)
+            } else {
+                let reference = match axis {
+                    0 => viewport.0,
+                    1 => viewport.1,
+                    _ => diagonal,
+                };
+                parse_length(value, reference)
+            };
+            parsed.unwrap_or_else(|| {
+                self.invalid(element, name, value);
+                default
+            })
+        };
+
+        let paint = if element == "linearGradient" {
+            let (full_x, zero) = if bounding_box {
+                (1.0, 0.0)
+            } else {
+                (viewport.0, 0.0)
+            };
+            let start = Vec2(coordinate("x1", zero, 0), coordinate("y1", zero, 1));
+            let end = Vec2(coordinate("x2", full_x, 0), coordinate("y2", zero, 1));
+            LinearGradient::new(start, end, stops)
+                .with_spread(spread)
+                .with_transform(transform)
+                .into()
+        } else {
+            let half = |extent: f32| if bounding_box { 0.5 } else { extent * 0.5 };
+            let center = Vec2(
+                coordinate("cx", half(viewport.0), 0),
+                coordinate("cy", half(viewport.1), 1),
+            );
+            let radius = coordinate("r", half(diagonal), 2);
+            let focal = Vec2(coordinate("fx", center.0, 0), coordinate("fy", center.1, 1));
+            RadialGradient::new(center, radius, stops)
+                .with_focal(focal)
+                .with_spread(spread)
+                .with_transform(transform)
+                .into()
+        };
+        Some(paint)
+    }
+
+    /// Wraps `content` in the clip a `clip-path: url(#id)` names. Each clip
+    /// child is baked into its own command list in the clip's space and
+    /// clips its own copy of `content` under its own `clip-rule`; grouping
+    /// the copies makes the clip their union. Where children overlap,
+    /// translucent content shows through both copies.
+    fn clip(
+        &mut self,
+        node: XmlNode<'a, 'input>,
+        reference: &str,
+        content: Node,
+        depth: usize,
+    ) -> Option<Node> {
+        let element = node.tag_name().name();
+        let clip = parse_url(reference)
+            .and_then(|(id, _)| self.ids.get(id).copied())
+            .filter(|clip| clip.tag_name().name() == "clipPath");
+        let Some(clip) = clip else {
+            // SVG renders the element as if unclipped.
+            self.unresolved(element, reference);
+            return Some(content);
+        };
+
+        let mut clip_style = Style::default();
+        self.declarations(clip, &mut clip_style, &mut Local::default());
+        let mut layers = Vec::new();
+        for child in clip.children().filter(|child| child.is_element()) {
+            let mut style = clip_style.clone();
+            let mut local = Local::default();
+            self.declarations(child, &mut style, &mut local);
+            if !local.displayed || !style.visible {
+                continue;
+            }
+            let mut transform = self.element_transform(child);
+            let shape = if child.tag_name().name() == "use" {
+                let x = self.length(child, "x", 0);
+                let y = self.length(child, "y", 1);
+                transform = transform.concat(Transform::translate(Vec2(x, y)));
+                match self
+                    .referenced(child)
+                    .filter(|_| depth < MAX_REFERENCE_DEPTH)
+                {
+                    Some(target) => {
+                        transform = transform.concat(self.element_transform(target));
+                        self.geometry(target)
+                    }
+                    None => {
+                        let href = self.href(child).unwrap_or_default();
+                        self.unresolved("use", href);
+                        None
+                    }
+                }
+            } else {
+                self.geometry(child)
+            };
+            let Some(shape) = shape else {
+                continue;
+            };
+            let commands = shape
+                .into_iter()
+                .map(|command| transform_command(command, transform))
+                .collect();
+            layers.push((commands, style.clip_rule));
+        }
+
+        let mut transform = self.element_transform(clip);
+        if clip.attribute("clipPathUnits") == Some("objectBoundingBox") {
+            let bounds = node_bounds(&content, Transform::IDENTITY, false)?;
+            transform = Transform::translate(bounds.origin)
+                .concat(Transform::scale(bounds.size))
+                .concat(transform);
+        }
+        let clip_group = |(commands, fill_rule), child| {
+            Node::ClipGroup(ClipGroup {
+                commands,
+                fill_rule,
+                transform,
+                child: Box::new(child),
+            })
+        };
+        if layers.len() <= 1 {
+            // No children leaves an empty clip, which hides the element.
+            let layer = layers.pop().unwrap_or_default();
+            return Some(clip_group(layer, content));
+        }
+        Some(Node::Group(Group {
+            transform: Transform::IDENTITY,
+            opacity: 1.0,
+            children: layers
+                .into_iter()
+                .map(|layer| clip_group(layer, content.clone()))
+                .collect(),
+        }))
+    }
+}
+
+/// The normalized diagonal SVG resolves non-axis percentages against.
+fn normalized_diagonal(viewport: Vec2) -> f32 {
+    viewport.0.hypot(viewport.1) / std::f32::consts::SQRT_2
+}
+
+/// SVG elements in the SVG namespace, or unqualified in hand-written
+/// documents that omit `xmlns`. Foreign elements (editor metadata) are not
+/// painted.
+fn is_svg_element(node: XmlNode) -> bool {
+    matches!(node.tag_name().namespace(), None | Some(SVG_NS))
+}
+
+/// Applies an element's transform and group opacity to its content,
+/// folding the transform into a lone path instead of nesting a group.
+fn wrap(content: Node, transform: Transform, opacity: f32) -> Option<Node> {
+    if opacity <= 0.0 || content.is_empty() {
+        return None;
+    }
+    if opacity >= 1.0 {
+        if transform == Transform::IDENTITY {
+            return Some(content);
+        }
+        if let Node::Path(mut path) = content {
+            path.transform = transform.concat(path.transform);
+            return Some(Node::Path(path));
+        }
+    }
+    Some(Node::single_group(transform, opacity, content))
+}
+
+/// Control-point bounds of everything `node` paints, in the space
+/// `transform` maps it to; strokes widen each path by their outset when
+/// `include_stroke` is set.
+fn node_bounds(node: &Node, transform: Transform, include_stroke: bool) -> Option<Rect> {
+    match node {
+        Node::Group(group) => {
+            let transform = transform.concat(group.transform);
+            group
+                .children
+                .iter()
+                .filter_map(|child| node_bounds(child, transform, include_stroke))
+                .reduce(union_rect)
+        }
+        Node::SingleGroup(group) => node_bounds(
+            &group.child,
+            transform.concat(group.transform),
+            include_stroke,
+        ),
+        Node::ClipGroup(group) => node_bounds(&group.child, transform, include_stroke),
+        Node::Path(path) => {
+            let bounds = path_command_bounds(&path.commands)?;
+            let outset = path
+                .stroke
+                .as_ref()
+                .filter(|_| include_stroke)
+                .map(Stroke::conservative_outset)
+                .unwrap_or(0.0);
+            let bounds = Rect {
+                origin: Vec2(bounds.origin.0 - outset, bounds.origin.1 - outset),
+                size: Vec2(bounds.size.0 + outset * 2.0, bounds.size.1 + outset * 2.0),
+            };
+            Some(transform.concat(path.transform).transform_rect(bounds))
+        }
+    }
+}
+
+fn bez_commands(path: &BezPath) -> Vec<PathCommand> {
+    let point = |p: kurbo::Point| Vec2(p.x as f32, p.y as f32);
+    path.elements()
+        .iter()
+        .map(|element| match *element {
+            PathEl::MoveTo(p) => PathCommand::MoveTo(point(p)),
+            PathEl::LineTo(p) => PathCommand::LineTo(point(p)),
+            PathEl::QuadTo(control, to) => PathCommand::QuadTo {
+                control: point(control),
+                to: point(to),
+            },
+            PathEl::CurveTo(c1, c2, to) => PathCommand::CubicTo {
+                c1: point(c1),
+                c2: point(c2),
+                to: point(to),
+            },
+            PathEl::ClosePath => PathCommand::Close,
+        })
+        .collect()
+}
+
+fn transform_command(command: PathCommand, transform: Transform) -> PathCommand {
+    let map = |p: Vec2| transform.transform_point(p);
+    match command {
+        PathCommand::MoveTo(p) => PathCommand::MoveTo(map(p)),
+        PathCommand::LineTo(p) => PathCommand::LineTo(map(p)),
+        PathCommand::QuadTo { control, to } => PathCommand::QuadTo {
+            control: map(control),
+            to: map(to),
+        },
+        PathCommand::CubicTo { c1, c2, to } => PathCommand::CubicTo {
+            c1: map(c1),
+            c2: map(c2),
+            to: map(to),
+        },
+        PathCommand::Close => PathCommand::Close,
+    }
+}
+
+fn rect_commands(x: f32, y: f32, w: f32, h: f32) -> Vec<PathCommand> {
+    vec![
+        PathCommand::MoveTo(Vec2(x, y)),
+        PathCommand::LineTo(Vec2(x + w, y)),
+        PathCommand::LineTo(Vec2(x + w, y + h)),
+        PathCommand::LineTo(Vec2(x, y + h)),
+        PathCommand::Close,
+    ]
+}
+
+fn rounded_rect_commands(x: f32, y: f32, w: f32, h: f32, rx: f32, ry: f32) -> Vec<PathCommand> {
+    let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+    let (right, bottom) = (x + w, y + h);
+    vec![
+        PathCommand::MoveTo(Vec2(x + rx, y)),
+        PathCommand::LineTo(Vec2(right - rx, y)),
+        PathCommand::CubicTo {
+            c1: Vec2(right - rx + kx, y),
+            c2: Vec2(right, y + ry - ky),
+            to: Vec2(right, y + ry),
+        },
+        PathCommand::LineTo(Vec2(right, bottom - ry)),
+        PathCommand::CubicTo {
+            c1: Vec2(right, bottom - ry + ky),
+            c2: Vec2(right - rx + kx, bottom),
+            to: Vec2(right - rx, bottom),
+        },
+        PathCommand::LineTo(Vec2(x + rx, bottom)),
+        PathCommand::CubicTo {
+            c1: Vec2(x + rx - kx, bottom),
+            c2: Vec2(x, bottom - ry + ky),
+            to: Vec2(x, bottom - ry),
+        },
+        PathCommand::LineTo(Vec2(x, y + ry)),
+        PathCommand::CubicTo {
+            c1: Vec2(x, y + ry - ky),
+            c2: Vec2(x + rx - kx, y),
+            to: Vec2(x + rx, y),
+        },
+        PathCommand::Close,
+    ]
+}
+
+fn ellipse_commands(center: Vec2, rx: f32, ry: f32) -> Vec<PathCommand> {
+    let Vec2(cx, cy) = center;
+    let (kx, ky) = (rx * KAPPA, ry * KAPPA);
+    vec![
+        PathCommand::MoveTo(Vec2(cx + rx, cy)),
+        PathCommand::CubicTo {
+            c1: Vec2(cx + rx, cy + ky),
+            c2: Vec2(cx + kx, cy + ry),
+            to: Vec2(cx, cy + ry),
+        },
+        PathCommand::CubicTo {
+            c1: Vec2(cx - kx, cy + ry),
+            c2: Vec2(cx - rx, cy + ky),
+            to: Vec2(cx - rx, cy),
+        },
+        PathCommand::CubicTo {
+            c1: Vec2(cx - rx, cy - ky),
+            c2: Vec2(cx - kx, cy - ry),
+            to: Vec2(cx, cy - ry),
+        },
+        PathCommand::CubicTo {
+            c1: Vec2(cx + kx, cy - ry),
+            c2: Vec2(cx + rx, cy - ky),
+            to: Vec2(cx + rx, cy),
+        },
+        PathCommand::Close,
+    ]
+}
+
+/// Scans one SVG number starting at byte `start`; returns it and the index
+/// just past it.
+fn scan_number(s: &str, start: usize) -> Option<(f32, usize)> {
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+    let mut i = start;
+    if matches!(bytes.get(i), Some(b'+' | b'-')) {
+        i += 1;
+    }
+    let integer_end = digits(i);
+    let mut has_digits = integer_end > i;
+    i = integer_end;
+    if bytes.get(i) == Some(&b'.') {
+        let fraction_end = digits(i + 1);
+        has_digits |= fraction_end > i + 1;
+        i = fraction_end;
+    }
+    if !has_digits {
+        return None;
+    }
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        let mut j = i + 1;
+        if matches!(bytes.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+        let exponent_end = digits(j);
+        if exponent_end > j {
+            i = exponent_end;
+        }
+    }
+    s[start..i].parse().ok().map(|value| (value, i))
+}
+
+/// A whitespace- and/or comma-separated number list, as in `points` and
+/// `viewBox`.
+fn parse_numbers(s: &str) -> Option<Vec<f32>> {
+    let bytes = s.as_bytes();
+    let mut numbers = Vec::new();
+    let mut i = 0;
+    loop {
+        while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b',') {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return Some(numbers);
+        }
+        let (value, next) = scan_number(s, i)?;
+        numbers.push(value);
+        i = next;
+    }
+}
+
+fn parse_number(s: &str) -> Option<f32> {
+    let s = s.trim();
+    match scan_number(s, 0)? {
+        (value, end) if end == s.len() => Some(value),
+        _ => None,
+    }
+}
+
+/// A length in user units; `%` resolves against `reference`.
+fn parse_length(s: &str, reference: f32) -> Option<f32> {
+    let s = s.trim();
+    let (value, end) = scan_number(s, 0)?;
+    let scale = match s[end..].trim() {
+        "" | "px" => 1.0,
+        "%" => reference / 100.0,
+        "pt" => 4.0 / 3.0,
+        "pc" => 16.0,
+        "mm" => 96.0 / 25.4,
+        "cm" => 96.0 / 2.54,
+        "in" => 96.0,
+        "em" => 16.0,
+        "ex" => 8.0,
+        _ => return None,
+    };
+    Some(value * scale)
+}
+
+fn parse_length_list(s: &str) -> Option<Vec<f32>> {
+    s.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|item| !item.is_empty())
+        .map(|item| parse_length(item, 0.0))
+        .collect()
+}
+
+/// A number, or a percentage mapped onto `0..1`.
+fn parse_fraction(s: &str) -> Option<f32> {
+    let s = s.trim();
+    match s.strip_suffix('%') {
+        Some(percent) => parse_number(percent).map(|v| v / 100.0),
+        None => parse_number(s),
+    }
+}
+
+fn parse_opacity(s: &str) -> Option<f32> {
+    parse_fraction(s).map(clamp_unit)
+}
+
+fn parse_fill_rule(s: &str) -> Option<FillRule> {
+    match s {
+        "nonzero" => Some(FillRule::NonZero),
+        "evenodd" => Some(FillRule::EvenOdd),
+        _ => None,
+    }
+}
+
+/// `url(#id) [fallback]`, returning the id and the trimmed fallback text.
+fn parse_url(s: &str) -> Option<(&str, &str)> {
+    let rest = s.trim().strip_prefix("url(")?;
+    let close = rest.find(')')?;
+    let id = rest[..close]
+        .trim()
+        .trim_matches(|c| c == '"' || c == '\'')
+        .strip_prefix('#')?;
+    Some((id, rest[close + 1..].trim()))
+}
+
+fn parse_paint(s: &str) -> Option<PaintSpec> {
+    let s = s.trim();
+    match s {
+        "none" => Some(PaintSpec::None),
+        "currentColor" => Some(PaintSpec::CurrentColor),
+        _ if s.starts_with("url(") => {
+            let (id, fallback) = parse_url(s)?;
+            let fallback = if fallback.is_empty() {
+                None
+            } else {
+                Some(Box::new(parse_paint(fallback)?))
+            };
+            Some(PaintSpec::Reference {
+                id: id.to_owned(),
+                fallback,
+            })
+        }
+        _ => parse_color(s).map(PaintSpec::Color),
+    }
+}
+
+fn parse_transform(s: &str) -> Option<Transform> {
+    let mut result = Transform::IDENTITY;
+    let mut rest = s.trim();
+    while !rest.is_empty() {
+        let open = rest.find('(')?;
+        let close = open + rest[open..].find(')')?;
+        let name = rest[..open].trim();
+        let args = parse_numbers(&rest[open + 1..close])?;
+        let transform = match (name, args.as_slice()) {
+            ("matrix", &[a, b, c, d, tx, ty]) => Transform { a, b, c, d, tx, ty },
+            ("translate", &[x]) => Transform::translate(Vec2(x, 0.0)),
+            ("translate", &[x, y]) => Transform::translate(Vec2(x, y)),
+            ("scale", &[s]) => Transform::scale(Vec2(s, s)),
+            ("scale", &[x, y]) => Transform::scale(Vec2(x, y)),
+            ("rotate", &[angle]) => Transform::rotate(angle.to_radians()),
+            ("rotate", &[angle, cx, cy]) => {
+                Transform::around_point(Vec2(cx, cy), Transform::rotate(angle.to_radians()))
+            }
+            ("skewX", &[angle]) => Transform {
+                c: angle.to_radians().tan(),
+                ..Transform::IDENTITY
+            },
+            ("skewY", &[angle]) => Transform {
+                b: angle.to_radians().tan(),
+                ..Transform::IDENTITY
+            },
+            _ => return None,
+        };
+        result = result.concat(transform);
+        rest = rest[close + 1..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+    Some(result)
+}
+
+fn parse_color(s: &str) -> Option<Color> {
+    let s = s.trim();
+    if let Some(hex) = s.strip_prefix('#') {
+        return parse_hex_color(hex);
+    }
+    if let Some((function, args)) = s.strip_suffix(')').and_then(|s| s.split_once('(')) {
+        let args: Vec<&str> = args
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|arg| !arg.is_empty())
+            .collect();
+        let alpha = match args.get(3) {
+            Some(alpha) => parse_opacity(alpha)?,
+            None => 1.0,
+        };
+        if args.len() < 3 || args.len() > 4 {
+            return None;
+        }
+        return match function.trim().to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => {
+                let channel = |arg: &str| match arg.strip_suffix('%') {
+                    Some(percent) => parse_number(percent).map(|v| v / 100.0),
+                    None => parse_number(arg).map(|v| v / 255.0),
+                };
+                Some(Color {
+                    r: clamp_unit(channel(args[0])?),
+                    g: clamp_unit(channel(args[1])?),
+                    b: clamp_unit(channel(args[2])?),
+                    a: alpha,
+                })
+            }
+            "hsl" | "hsla" => {
+                let hue = parse_number(args[0].trim_end_matches("deg"))?;
+                let saturation = parse_number(args[1].strip_suffix('%')?)? / 100.0;
+                let lightness = parse_number(args[2].strip_suffix('%')?)? / 100.0;
+                Some(Color::hsla(
+                    hue,
+                    clamp_unit(saturation),
+                    clamp_unit(lightness),
+                    alpha,
+                ))
+            }
+            _ => None,
+        };
+    }
+    let name = s.to_ascii_lowercase();
+    if name == "transparent" {
+        return Some(Color::rgba_u8(0, 0, 0, 0));
+    }
+    NAMED_COLORS
+        .binary_search_by(|(candidate, _)| candidate.cmp(&name.as_str()))
+        .ok()
+        .map(|index| {
+            let [_, r, g, b] = NAMED_COLORS[index].1.to_be_bytes();
+            Color::rgb_u8(r, g, b)
+        })
+}
+
+fn parse_hex_color(hex: &str) -> Option<Color> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let nibble = |i: usize| u8::from_str_radix(&hex[i..i + 1], 16).ok().map(|v| v * 17);
+    let byte = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    match hex.len() {
+        3 => Some(Color::rgb_u8(nibble(0)?, nibble(1)?, nibble(2)?)),
+        4 => Some(Color::rgba_u8(
+            nibble(0)?,
+            nibble(1)?,
+            nibble(2)?,
+            nibble(3)?,
+        )),
+        6 => Some(Color::rgb_u8(byte(0)?, byte(2)?, byte(4)?)),
+        8 => Some(Color::rgba_u8(byte(0)?, byte(2)?, byte(4)?, byte(6)?)),
+        _ => None,
+    }
+}
+
+/// CSS named colors, sorted by name for binary search.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clip::Clip;
+    use crate::effect::{VectorOutline, VectorWrite};
+    use crate::phase::Phase;
+
+    fn parse(body: &str) -> Svg {
+        Svg::parse(&format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50" viewBox="0 0 100 50">{body}</svg>"#
+        ))
+        .expect("test document should parse")
+    }
+
+    fn only_path(svg: &Svg) -> &Path {
+        match svg.root() {
+            Node::Path(path) => path,
+            other => panic!("expected a single path, got {other:?}"),
+        }
+    }
+
+    fn solid(r: u8, g: u8, b: u8) -> Option<Fill> {
+        Some(Fill {
+            paint: Paint::Solid(Color::rgb_u8(r, g, b)),
+        })
+    }
+
+    #[test]
+    fn named_colors_are_sorted_for_binary_search() {
+        assert!(NAMED_COLORS.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+
+    #[test]
+    fn parses_colors_in_every_supported_syntax() {
+        assert_eq!(parse_color("#f00"), Some(Color::rgb_u8(255, 0, 0)));
+        assert_eq!(
+            parse_color("#00ff0080"),
+            Some(Color::rgba_u8(0, 255, 0, 128))
+        );
+        assert_eq!(
+            parse_color("rgb(0, 0, 255)"),
+            Some(Color::rgb_u8(0, 0, 255))
+        );
+        assert_eq!(
+            parse_color("rgba(100%, 0%, 0%, 0.5)"),
+            Some(Color {
+                r: 1.0,
+                g: 0.0,
+                b: 0.0,
+                a: 0.5
+            })
+        );
+        assert_eq!(
+            parse_color("hsl(120, 100%, 50%)"),
+            Some(Color::hsl(120.0, 1.0, 0.5))
+        );
+        assert_eq!(
+            parse_color("RebeccaPurple"),
+            Some(Color::rgb_u8(0x66, 0x33, 0x99))
+        );
+        assert_eq!(parse_color("not-a-color"), None);
+    }
+
+    #[test]
+    fn parses_transform_lists_in_application_order() {
+        let transform = parse_transform("translate(10, 20) scale(2)").unwrap();
+        assert_eq!(transform.transform_point(Vec2(1.0, 1.0)), Vec2(12.0, 22.0));
+
+        let rotated = parse_transform("rotate(90 5 5)").unwrap();
+        let p = rotated.transform_point(Vec2(10.0, 5.0));
+        assert!(
+            (p.0 - 5.0).abs() < 1e-4 && (p.1 - 10.0).abs() < 1e-4,
+            "{p:?}"
+        );
+
+        let matrix = parse_transform("matrix(1 0 0 1 3 4)").unwrap();
+        assert_eq!(matrix, Transform::translate(Vec2(3.0, 4.0)));
+        assert_eq!(parse_transform("wobble(1)"), None);
+    }
+
+    #[test]
+    fn parses_compact_number_lists() {
+        assert_eq!(
+            parse_numbers("1-2.5.5,3e1 -.5"),
+            Some(vec![1.0, -2.5, 0.5, 30.0, -0.5])
+        );
+        assert_eq!(parse_numbers("1 x"), None);
+    }
+
+    #[test]
+    fn intrinsic_size_comes_from_width_height_or_view_box() {
+        let sized =
+            Svg::parse(r#"<svg xmlns="http://www.w3.org/2000/svg" width="1in" height="48pt"/>"#)
+                .unwrap();
+        assert_eq!(sized.intrinsic_size(), Vec2(96.0, 64.0));
+
+        let from_view_box = Svg::parse(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="40" viewBox="0 0 20 10"/>"#,
+        )
+        .unwrap();
+        assert_eq!(from_view_box.intrinsic_size(), Vec2(40.0, 20.0));
+        assert_eq!(
+            from_view_box.layout(Constraints::UNBOUNDED),
+            Vec2(40.0, 20.0)
+        );
+    }
+
+    #[test]
+    fn render_maps_the_view_box_onto_the_layout_size() {
+        let svg = Svg::parse(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="10 10 20 10">
+                <rect x="10" y="10" width="20" height="10"/>
+            </svg>"#,
+        )
+        .unwrap();
+        // Uniform scale of 2 (meet), centered vertically in the taller box.
+        let graphic = svg.render(Vec2(40.0, 40.0));
+        let Node::SingleGroup(group) = &graphic.root else {
+            panic!("render wraps the document in its viewBox transform");
+        };
+        assert_eq!(
+            group.transform.transform_point(Vec2(10.0, 10.0)),
+            Vec2(0.0, 10.0)
+        );
+        assert_eq!(
+            group.transform.transform_point(Vec2(30.0, 20.0)),
+            Vec2(40.0, 30.0)
+        );
+        assert_eq!(graphic.view_box, svg.paint_bounds(Vec2(40.0, 40.0)));
+
+        let stretched = Svg::parse(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 20 10" preserveAspectRatio="none">
+                <rect width="20" height="10"/>
+            </svg>"#,
+        )
+        .unwrap();
+        let Node::SingleGroup(group) = stretched.render(Vec2(40.0, 40.0)).root else {
+            panic!("render wraps the document in its viewBox transform");
+        };
+        assert_eq!(
+            group.transform.transform_point(Vec2(20.0, 10.0)),
+            Vec2(40.0, 40.0)
+        );
+    }
+
+    #[test]
+    fn basic_shapes_become_paths_with_inherited_paint() {
+        let svg = parse(
+            r##"<g fill="red" transform="translate(5 0)">
+                <rect width="10" height="10"/>
+                <circle cx="30" cy="5" r="5" fill="#00f" />
+                <polygon points="40,0 50,0 45,10"/>
+            </g>"##,
+        );
+        let Node::SingleGroup(group) = svg.root() else {
+            panic!("a transformed group stays a group: {:?}", svg.root());
+        };
+        assert_eq!(group.transform, Transform::translate(Vec2(5.0, 0.0)));
+        let Node::Group(children) = group.child.as_ref() else {
+            panic!("the group keeps its three children");
+        };
+        let fills: Vec<_> = children
+            .children
+            .iter()
+            .map(|child| match child {
+                Node::Path(path) => path.fill.clone(),
+                other => panic!("expected a path, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            fills,
+            vec![solid(255, 0, 0), solid(0, 0, 255), solid(255, 0, 0)]
+        );
+        assert!(svg.warnings().is_empty(), "{:?}", svg.warnings());
+    }
+
+    #[test]
+    fn path_data_with_arcs_converts_to_curves() {
+        let svg = parse(r#"<path d="M0 0 H10 A5 5 0 0 1 10 10 Z" fill-rule="evenodd"/>"#);
+        let path = only_path(&svg);
+        assert_eq!(path.commands[0], PathCommand::MoveTo(Vec2(0.0, 0.0)));
+        assert_eq!(path.commands[1], PathCommand::LineTo(Vec2(10.0, 0.0)));
+        assert!(path
+            .commands
+            .iter()
+            .any(|command| matches!(command, PathCommand::CubicTo { .. })));
+        assert_eq!(path.commands.last(), Some(&PathCommand::Close));
+        assert_eq!(path.fill_rule, FillRule::EvenOdd);
+    }
+
+    #[test]
+    fn stroke_properties_and_inline_style_are_honored() {
+        let svg = parse(
+            r#"<line x1="0" y1="0" x2="10" y2="0" stroke="green" stroke-width="3"
+                style="stroke: #000; stroke-linecap: round; stroke-dasharray: 4 2; stroke-dashoffset: 1; stroke-opacity: 0.5"/>"#,
+        );
+        let path = only_path(&svg);
+        let stroke = path.stroke.as_ref().expect("the line is stroked");
+        assert_eq!(path.fill, solid(0, 0, 0), "fill still defaults to black");
+        assert_eq!(
+            stroke.paint,
+            Paint::Solid(Color::rgba_u8(0, 0, 0, 0).with_alpha(0.5))
+        );
+        assert_eq!(stroke.width, 3.0);
+        assert_eq!(stroke.cap, StrokeCap::Round);
+        assert_eq!(stroke.join, StrokeJoin::Miter);
+        let dash = stroke.dash.as_ref().expect("dashes are imported");
+        assert_eq!(dash.lengths, vec![4.0, 2.0]);
+        assert_eq!(dash.offset, 1.0);
+    }
+
+    #[test]
+    fn opacity_display_and_visibility() {
+        let svg = parse(
+            r#"<rect width="10" height="10" opacity="0.5"/>
+               <rect width="10" height="10" display="none"/>
+               <g visibility="hidden"><rect width="10" height="10"/></g>"#,
+        );
+        let Node::SingleGroup(group) = svg.root() else {
+            panic!("only the translucent rect remains: {:?}", svg.root());
+        };
+        assert_eq!(group.opacity, 0.5);
+        assert!(matches!(group.child.as_ref(), Node::Path(_)));
+    }
+
+    #[test]
+    fn clip_paths_bake_their_children_and_clip_rule() {
+        let svg = parse(
+            r##"<defs>
+                <clipPath id="c"><rect x="1" y="1" width="4" height="4" transform="translate(2 0)" clip-rule="evenodd"/></clipPath>
+            </defs>
+            <rect width="10" height="10" clip-path="url(#c)"/>"##,
+        );
+        let Node::ClipGroup(clip) = svg.root() else {
+            panic!("clip-path wraps the element: {:?}", svg.root());
+        };
+        assert_eq!(clip.commands[0], PathCommand::MoveTo(Vec2(3.0, 1.0)));
+        assert_eq!(clip.fill_rule, FillRule::EvenOdd);
+        assert!(matches!(clip.child.as_ref(), Node::Path(_)));
+    }
+
+    #[test]
+    fn clip_path_children_each_keep_their_clip_rule() {
+        let svg = parse(
+            r##"<defs>
+                <clipPath id="c" clip-rule="evenodd">
+                    <rect width="4" height="4"/>
+                    <rect x="2" width="4" height="4" clip-rule="nonzero"/>
+                </clipPath>
+            </defs>
+            <rect width="10" height="10" clip-path="url(#c)"/>"##,
+        );
+        let Node::Group(group) = svg.root() else {
+            panic!("each clip child clips its own copy: {:?}", svg.root());
+        };
+        let rules: Vec<_> = group
+            .children
+            .iter()
+            .map(|child| match child {
+                Node::ClipGroup(clip) => {
+                    assert!(matches!(clip.child.as_ref(), Node::Path(_)));
+                    (clip.commands[0], clip.fill_rule)
+                }
+                other => panic!("expected a clip, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            rules,
+            [
+                (PathCommand::MoveTo(Vec2(0.0, 0.0)), FillRule::EvenOdd),
+                (PathCommand::MoveTo(Vec2(2.0, 0.0)), FillRule::NonZero),
+            ]
+        );
+    }
+
+    #[test]
+    fn gradients_resolve_units_and_inherit_stops_through_href() {
+        let svg = parse(
+            r##"<defs>
+                <linearGradient id="stops"><stop offset="0" stop-color="red"/><stop offset="100%" style="stop-color: blue; stop-opacity: 0.5"/></linearGradient>
+                <linearGradient id="g" href="#stops" x1="0" x2="1"/>
+                <radialGradient id="r" xlink:href="#stops" xmlns:xlink="http://www.w3.org/1999/xlink" gradientUnits="userSpaceOnUse" cx="50" cy="25" r="10"/>
+            </defs>
+            <rect x="10" y="0" width="20" height="10" fill="url(#g)"/>
+            <circle cx="50" cy="25" r="10" fill="url(#r)"/>"##,
+        );
+        let Node::Group(group) = svg.root() else {
+            panic!("two painted shapes: {:?}", svg.root());
+        };
+        let paints: Vec<Paint> = group
+            .children
+            .iter()
+            .map(|child| match child {
+                Node::Path(path) => path.fill.clone().expect("filled").paint,
+                other => panic!("expected a path, got {other:?}"),
+            })
+            .collect();
+
+        let Paint::LinearGradient(linear) = &paints[0] else {
+            panic!("expected a linear gradient, got {:?}", paints[0]);
+        };
+        assert_eq!(linear.stops.len(), 2);
+        assert_eq!(
+            linear.stops[1].color,
+            Color::rgb_u8(0, 0, 255).with_alpha(0.5)
+        );
+        // Bounding-box units map 0..1 onto the rect.
+        assert_eq!(
+            linear.transform.transform_point(linear.start),
+            Vec2(10.0, 0.0)
+        );
+        assert_eq!(
+            linear.transform.transform_point(linear.end),
+            Vec2(30.0, 0.0)
+        );
+
+        let Paint::RadialGradient(radial) = &paints[1] else {
+            panic!("expected a radial gradient, got {:?}", paints[1]);
+        };
+        assert_eq!(radial.center, Vec2(50.0, 25.0));
+        assert_eq!(radial.focal, Vec2(50.0, 25.0));
+        assert_eq!(radial.radius, 10.0);
+        assert_eq!(radial.transform, Transform::IDENTITY);
+    }
+
+    #[test]
+    fn use_elements_instantiate_their_target() {
+        let svg = parse(
+            r##"<defs><symbol id="s"><rect width="4" height="4"/></symbol></defs>
+            <use href="#s" x="10" y="20" fill="lime"/>"##,
+        );
+        let path = only_path(&svg);
+        assert_eq!(path.transform, Transform::translate(Vec2(10.0, 20.0)));
+        assert_eq!(path.fill, solid(0, 255, 0));
+    }
+
+    #[test]
+    fn unsupported_features_are_reported_not_fatal() {
+        let svg = parse(
+            r##"<text x="0" y="10">hi</text>
+            <rect width="10" height="10" filter="url(#blur)" fill="url(#missing)" stroke="blue"/>
+            <path d="M0 0 L"/>"##,
+        );
+        let warnings = svg.warnings();
+        assert!(warnings.contains(&SvgWarning::UnsupportedElement {
+            element: "text".to_owned()
+        }));
+        assert!(warnings.contains(&SvgWarning::UnsupportedAttribute {
+            element: "rect".to_owned(),
+            attribute: "filter".to_owned(),
+        }));
+        assert!(warnings.contains(&SvgWarning::UnresolvedReference {
+            element: "rect".to_owned(),
+            reference: "missing".to_owned(),
+        }));
+        assert!(warnings.iter().any(|warning| matches!(
+            warning,
+            SvgWarning::InvalidValue { attribute, .. } if attribute == "d"
+        )));
+        // The rect still imports with its stroke.
+        let path = only_path(&svg);
+        assert_eq!(path.fill, None);
+        assert!(path.stroke.is_some());
+    }
+
+    #[test]
+    fn rejects_non_svg_and_malformed_documents() {
+        assert!(matches!(
+            Svg::parse("<html/>"),
+            Err(SvgError::NotSvg(root)) if root == "html"
+        ));
+        assert!(matches!(Svg::parse("<svg"), Err(SvgError::Xml(_))));
+        assert!(matches!(
+            Svg::load("/definitely/not/here.svg"),
+            Err(SvgError::Io { .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_documents_whose_references_expand_too_far() {
+        // Each level instantiates the one below it ten times: 10^6 rects.
+        let mut defs = String::from(r#"<rect id="l0" width="1" height="1"/>"#);
+        for level in 1..=6 {
+            defs.push_str(&format!(r#"<g id="l{level}">"#));
+            for _ in 0..10 {
+                defs.push_str(&format!(r##"<use href="#l{}"/>"##, level - 1));
+            }
+            defs.push_str("</g>");
+        }
+        let document = format!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg"><defs>{defs}</defs><use href="#l6"/></svg>"##
+        );
+        assert!(matches!(
+            Svg::parse(&document),
+            Err(SvgError::TooManyElements(MAX_EXPANDED_ELEMENTS))
+        ));
+        // Four levels stay within the budget.
+        let document = document.replace("#l6\"/></svg>", "#l4\"/></svg>");
+        assert!(Svg::parse(&document).is_ok());
+    }
+
+    #[test]
+    fn imported_artwork_composes_with_vector_effects() {
+        let svg =
+            parse(r#"<rect x="10" y="10" width="30" height="30" fill="red" stroke="black"/>"#);
+        let size = svg.layout(Constraints::UNBOUNDED);
+
+        let written = svg.clone().write_on(Phase::HALF);
+        assert!(!written.render(size).root.is_empty());
+
+        let outlined = svg.clone().outlined(2.0, Color::rgb_u8(0, 0, 255));
+        assert!(matches!(outlined.render(size).root, Node::Path(_)));
+
+        let clipped = Clip::builder()
+            .region(Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(20.0, 20.0),
+            })
+            .child(svg)
+            .build();
+        assert!(matches!(clipped.render(size).root, Node::ClipGroup(_)));
+    }
+
+    #[test]
+    fn paint_bounds_include_strokes_that_spill_past_the_view_box() {
+        let svg = parse(
+            r#"<rect width="100" height="50" fill="none" stroke="black" stroke-width="4" stroke-linejoin="round"/>"#,
+        );
+        assert_eq!(
+            svg.paint_bounds(Vec2(100.0, 50.0)),
+            Rect {
+                origin: Vec2(-2.0, -2.0),
+                size: Vec2(104.0, 54.0),
+            }
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! SVG interchange for vector graphics.
+//!
+//! [`Svg`] imports a document into the same [`Node`](crate::vector::Node)
+//! tree native shapes render to, so imported artwork composes with `Write`,
+//! `Outlined`, `Clip` and every other vector effect. [`export_svg`] goes the
+//! other way, writing any rendered
+//! [`VectorGraphic`](crate::vector::VectorGraphic) as a document.
+
+pub mod export;
+pub mod import;
+
+pub use export::{export_svg, to_svg_string, SvgExportError};
+pub use import::{Svg, SvgError, SvgWarning};
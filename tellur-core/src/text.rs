@@ -35,7 +35,7 @@ use crate::geometry::{Constraints, Rect, Transform, Vec2};
 use crate::placement::{Positioned, VectorPlacement};
 use crate::span::{ShapedSpan, Span, SpanContext};
 use crate::vector::{
-    Fill, Group, Node, Paint, Path as VPath, PathCommand, VectorComponent, VectorGraphic,
+    Fill, FillRule, Group, Node, Paint, Path as VPath, PathCommand, VectorComponent, VectorGraphic,
 };
 use crate::Keyable;
 
@@ -1002,6 +1002,7 @@ impl VectorComponent for TextSpanGraphic {
                     fill: Some(Fill {
                         paint: fill.clone(),
                     }),
+                    fill_rule: FillRule::NonZero,
                     stroke: None,
                     transform: Transform::IDENTITY,
                 })
@@ -1036,6 +1037,7 @@ impl VectorComponent for Text {
                 Node::Path(VPath {
                     commands,
                     fill: Some(Fill { paint: fill }),
+                    fill_rule: FillRule::NonZero,
                     stroke: None,
                     transform: Transform::IDENTITY,
                 })
@@ -7,6 +7,7 @@
 
 use std::hash::{Hash, Hasher};
 
+use crate::composite::BlendMode;
 use crate::geometry::Vec2;
 use crate::raster::{RasterImage, RasterResidency, Resolution};
 use crate::render_context::RenderContext;
@@ -224,6 +225,10 @@ where
         self.child.frame(clock, canvas, target, residency, ctx)
     }
 
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
     fn render_audio_block(&self, mut block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
         if self.identity {
             self.child.render_audio_block(block, ctx);
@@ -0,0 +1,118 @@
+//! Per-child blend modes for timeline overlays.
+//!
+//! A [`Blended`] component draws exactly what its child draws; it only
+//! changes how a containing [`Timeline`](crate::timeline_container::Timeline)
+//! or [`Sequence`](crate::timeline_container::Sequence) composites that frame
+//! over the children beneath it. Placement, trim, and effect wrappers forward
+//! the mode, so `.blended(..)` may sit anywhere inside a `.at(..)`.
+
+use std::hash::Hash;
+
+use crate::composite::BlendMode;
+use crate::geometry::Vec2;
+use crate::raster::{RasterImage, RasterResidency, Resolution};
+use crate::render_context::RenderContext;
+
+use super::{
+    Arrangement, AudioBlockMut, AudioRenderContext, Clock, Cue, ResolveCtx, TimelineBuilder,
+    TimelineComponent,
+};
+
+/// Composites `child`'s frames with `mode` instead of its own blend mode.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Blended<C> {
+    child: C,
+    mode: BlendMode,
+}
+
+impl<C> Blended<C> {
+    /// Wraps `child` so it composites with `mode`.
+    pub fn new(child: C, mode: BlendMode) -> Self {
+        Self { child, mode }
+    }
+
+    /// The wrapped child.
+    pub fn child(&self) -> &C {
+        &self.child
+    }
+
+    /// The blend mode applied to the child's frames.
+    pub fn mode(&self) -> BlendMode {
+        self.mode
+    }
+}
+
+impl<C> TimelineComponent for Blended<C>
+where
+    C: TimelineComponent + Clone + PartialEq + Hash + 'static,
+{
+    fn duration(&self) -> Option<f64> {
+        self.child.duration()
+    }
+
+    fn measure(&self) -> Option<f64> {
+        self.child.measure()
+    }
+
+    fn resolve(&self, abs_start: f64, out: &mut ResolveCtx) -> f64 {
+        self.child.resolve(abs_start, out)
+    }
+
+    fn frame(
+        &self,
+        clock: Clock<'_>,
+        canvas: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> Option<RasterImage> {
+        self.child.frame(clock, canvas, target, residency, ctx)
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.mode
+    }
+
+    fn render_audio_block(&self, block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
+        self.child.render_audio_block(block, ctx);
+    }
+
+    fn cues(&self, offset: f64) -> Vec<Cue> {
+        self.child.cues(offset)
+    }
+
+    fn arrangement(&self, offset: f64) -> Arrangement {
+        self.child.arrangement(offset)
+    }
+}
+
+/// Lets a blended component drop directly into timeline containers.
+impl<C> From<Blended<C>> for Box<dyn TimelineComponent + Send>
+where
+    C: TimelineComponent + Clone + PartialEq + Hash + Send + 'static,
+{
+    fn from(blended: Blended<C>) -> Self {
+        Box::new(blended)
+    }
+}
+
+/// Blend-mode verb for already-built timeline components.
+pub trait BlendEffects: TimelineComponent + PartialEq + Hash + Sized + 'static {
+    /// Composites this component's frames over earlier siblings with `mode`.
+    fn blended(self, mode: BlendMode) -> Blended<Self> {
+        Blended::new(self, mode)
+    }
+}
+
+impl<C> BlendEffects for C where C: TimelineComponent + PartialEq + Hash + Sized + 'static {}
+
+/// Builder-side blend-mode verb, so complete builders never need an explicit
+/// `.build()` first.
+pub trait BlendEffectsBuilder: TimelineBuilder {
+    /// Builds immediately, then applies [`BlendEffects::blended`].
+    fn blended(self, mode: BlendMode) -> Blended<Self::Output> {
+        Blended::new(self.build_component(), mode)
+    }
+}
+
+impl<B: TimelineBuilder> BlendEffectsBuilder for B {}
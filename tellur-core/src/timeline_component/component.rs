@@ -3,6 +3,7 @@
 
 use std::hash::Hash;
 
+use crate::composite::BlendMode;
 use crate::dyn_compare::{DynEq, DynHash};
 use crate::geometry::{Rect, Vec2};
 use crate::layer::composite_children;
@@ -111,6 +112,14 @@ pub trait TimelineComponent: TimelineComponentClone + DynEq + DynHash {
         None
     }
 
+    /// How this component's frame combines with the frames beneath it when a
+    /// container overlays its children. Wrappers that leave the visual
+    /// channel alone forward their child's mode; defaults to
+    /// [`BlendMode::Normal`].
+    fn blend_mode(&self) -> BlendMode {
+        BlendMode::Normal
+    }
+
     /// Renders one interleaved f32 audio block on this component's local clock.
     ///
     /// The root request is identified by an integer output-frame range. Temporal
@@ -285,6 +294,10 @@ where
         ))
     }
 
+    fn blend_mode(&self) -> BlendMode {
+        RasterComponent::blend_mode(self)
+    }
+
     fn cues(&self, _offset: f64) -> Vec<Cue> {
         Vec::new()
     }
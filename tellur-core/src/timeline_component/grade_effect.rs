@@ -0,0 +1,231 @@
+//! Ordered color-grading wrappers for timeline components.
+//!
+//! A [`Graded`] component grades every frame its child draws — typically a
+//! `VideoFile` — and keeps the audio, subtitle, and arrangement channels
+//! transparent. Like [`GainEnvelope`](super::GainEnvelope), its position in
+//! the builder tree is its execution order, so a LUT applied after an
+//! exposure correction sees the corrected frame.
+
+use std::hash::{Hash, Hasher};
+
+use crate::composite::BlendMode;
+use crate::geometry::Vec2;
+use crate::grade::{ColorAdjustment, CubeLut, Grade};
+use crate::interpolate::Interpolate;
+use crate::phase::Phase;
+use crate::raster::{RasterImage, RasterResidency, Resolution};
+use crate::render_context::RenderContext;
+
+use super::{
+    Arrangement, AudioBlockMut, AudioRenderContext, Clock, Cue, ResolveCtx, TimelineBuilder,
+    TimelineComponent,
+};
+
+/// A grade that ramps linearly from `from` to `to` across the child's
+/// resolved window.
+///
+/// Both ends are always the same kind of [`Grade`] (the verbs below only
+/// build such pairs); a LUT ramp blends its `mix` and keeps one table. An
+/// open-ended placement has no window to ramp across and holds `from`.
+#[derive(Debug, Clone)]
+pub struct Graded<C> {
+    child: C,
+    from: Grade,
+    to: Grade,
+}
+
+impl<C> Graded<C> {
+    /// Grades `child` with `grade` on every frame.
+    pub fn new(child: C, grade: Grade) -> Self {
+        Self {
+            child,
+            from: grade.clone(),
+            to: grade,
+        }
+    }
+
+    /// Ramps `child`'s color adjustment from `from` to `to`.
+    pub fn adjust_ramp(child: C, from: ColorAdjustment, to: ColorAdjustment) -> Self {
+        Self {
+            child,
+            from: Grade::Adjust(from),
+            to: Grade::Adjust(to),
+        }
+    }
+
+    /// Ramps how strongly `lut` is mixed into `child` from `from_mix` to
+    /// `to_mix`.
+    pub fn lut_ramp(child: C, lut: CubeLut, from_mix: f32, to_mix: f32) -> Self {
+        Self {
+            child,
+            from: Grade::Lut {
+                lut: lut.clone(),
+                mix: from_mix,
+            },
+            to: Grade::Lut { lut, mix: to_mix },
+        }
+    }
+
+    /// The wrapped child.
+    pub fn child(&self) -> &C {
+        &self.child
+    }
+
+    /// The grade at the start and at the end of the child's window.
+    pub fn grades(&self) -> (&Grade, &Grade) {
+        (&self.from, &self.to)
+    }
+
+    /// The grade at `phase` through the child's window.
+    pub fn grade_at(&self, phase: Phase) -> Grade {
+        match (&self.from, &self.to) {
+            (Grade::Adjust(from), Grade::Adjust(to)) => Grade::Adjust(from.interpolate(*to, phase)),
+            (Grade::Lut { lut, mix: from }, Grade::Lut { mix: to, .. }) => Grade::Lut {
+                lut: lut.clone(),
+                mix: from.interpolate(*to, phase),
+            },
+            (from, _) => from.clone(),
+        }
+    }
+}
+
+impl<C: PartialEq> PartialEq for Graded<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.child == other.child && self.from == other.from && self.to == other.to
+    }
+}
+
+impl<C: Eq> Eq for Graded<C> {}
+
+impl<C: Hash> Hash for Graded<C> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.child.hash(state);
+        self.from.hash(state);
+        self.to.hash(state);
+    }
+}
+
+impl<C> TimelineComponent for Graded<C>
+where
+    C: TimelineComponent + Clone + PartialEq + Hash + 'static,
+{
+    fn duration(&self) -> Option<f64> {
+        self.child.duration()
+    }
+
+    fn measure(&self) -> Option<f64> {
+        self.child.measure()
+    }
+
+    fn resolve(&self, abs_start: f64, out: &mut ResolveCtx) -> f64 {
+        self.child.resolve(abs_start, out)
+    }
+
+    fn frame(
+        &self,
+        clock: Clock<'_>,
+        canvas: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> Option<RasterImage> {
+        let grade = match clock.window() {
+            Some(window) => self.grade_at(window.phase()),
+            None => self.from.clone(),
+        };
+        if grade.is_identity() {
+            return self.child.frame(clock, canvas, target, residency, ctx);
+        }
+        // Render the child where the grade will run: the GPU pass takes a
+        // GPU-resident frame, the CPU fallback reads it back anyway.
+        let child_residency = if ctx.prefers_gpu() && ctx.gpu_backend().is_some() {
+            RasterResidency::Gpu
+        } else {
+            RasterResidency::Cpu
+        };
+        let image = self
+            .child
+            .frame(clock, canvas, target, child_residency, ctx)?;
+        Some(grade.apply(image, residency, ctx))
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
+    fn render_audio_block(&self, block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
+        self.child.render_audio_block(block, ctx);
+    }
+
+    fn cues(&self, offset: f64) -> Vec<Cue> {
+        self.child.cues(offset)
+    }
+
+    fn arrangement(&self, offset: f64) -> Arrangement {
+        self.child.arrangement(offset)
+    }
+}
+
+/// Lets a graded component drop directly into timeline containers.
+impl<C> From<Graded<C>> for Box<dyn TimelineComponent + Send>
+where
+    C: TimelineComponent + Clone + PartialEq + Hash + Send + 'static,
+{
+    fn from(graded: Graded<C>) -> Self {
+        Box::new(graded)
+    }
+}
+
+/// Color-grading verbs for already-built timeline components.
+///
+/// Each call immediately wraps `self`, making the last call the outermost
+/// grade, the same source-order rule as [`AudioEffects`](super::AudioEffects).
+pub trait GradeEffects: TimelineComponent + PartialEq + Hash + Sized + 'static {
+    /// Applies `adjustment` to every frame.
+    fn color_adjust(self, adjustment: ColorAdjustment) -> Graded<Self> {
+        Graded::new(self, Grade::Adjust(adjustment))
+    }
+
+    /// Ramps the color adjustment from `from` to `to` across the window.
+    fn color_adjust_ramp(self, from: ColorAdjustment, to: ColorAdjustment) -> Graded<Self> {
+        Graded::adjust_ramp(self, from, to)
+    }
+
+    /// Looks every frame up in `lut`, mixed with the original by `mix`.
+    fn lut3d(self, lut: CubeLut, mix: f32) -> Graded<Self> {
+        Graded::new(self, Grade::Lut { lut, mix })
+    }
+
+    /// Ramps the LUT mix from `from_mix` to `to_mix` across the window.
+    fn lut3d_ramp(self, lut: CubeLut, from_mix: f32, to_mix: f32) -> Graded<Self> {
+        Graded::lut_ramp(self, lut, from_mix, to_mix)
+    }
+}
+
+impl<C> GradeEffects for C where C: TimelineComponent + PartialEq + Hash + Sized + 'static {}
+
+/// Builder-side grading verbs, so complete builders never need an explicit
+/// `.build()` before grading.
+pub trait GradeEffectsBuilder: TimelineBuilder {
+    /// Builds immediately, then applies [`GradeEffects::color_adjust`].
+    fn color_adjust(self, adjustment: ColorAdjustment) -> Graded<Self::Output> {
+        Graded::new(self.build_component(), Grade::Adjust(adjustment))
+    }
+
+    /// Builds immediately, then applies [`GradeEffects::color_adjust_ramp`].
+    fn color_adjust_ramp(self, from: ColorAdjustment, to: ColorAdjustment) -> Graded<Self::Output> {
+        Graded::adjust_ramp(self.build_component(), from, to)
+    }
+
+    /// Builds immediately, then applies [`GradeEffects::lut3d`].
+    fn lut3d(self, lut: CubeLut, mix: f32) -> Graded<Self::Output> {
+        Graded::new(self.build_component(), Grade::Lut { lut, mix })
+    }
+
+    /// Builds immediately, then applies [`GradeEffects::lut3d_ramp`].
+    fn lut3d_ramp(self, lut: CubeLut, from_mix: f32, to_mix: f32) -> Graded<Self::Output> {
+        Graded::lut_ramp(self.build_component(), lut, from_mix, to_mix)
+    }
+}
+
+impl<B: TimelineBuilder> GradeEffectsBuilder for B {}
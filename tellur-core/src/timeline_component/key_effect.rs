@@ -0,0 +1,132 @@
+//! Chroma-key wrapper for timeline components.
+//!
+//! A [`Keyed`] component keys a backdrop color out of every frame its child
+//! draws — typically green-screen `VideoFile` footage — so the subject can
+//! be overlaid on the timeline's other children. Audio, subtitle, and
+//! arrangement channels pass through, like [`Graded`](super::Graded).
+
+use std::hash::Hash;
+
+use crate::chroma_key::ChromaKeySettings;
+use crate::composite::BlendMode;
+use crate::geometry::Vec2;
+use crate::raster::{RasterImage, RasterResidency, Resolution};
+use crate::render_context::RenderContext;
+
+use super::{
+    Arrangement, AudioBlockMut, AudioRenderContext, Clock, Cue, ResolveCtx, TimelineBuilder,
+    TimelineComponent,
+};
+
+/// Keys `settings.key_color` out of `child`'s frames.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Keyed<C> {
+    child: C,
+    settings: ChromaKeySettings,
+}
+
+impl<C> Keyed<C> {
+    /// Wraps `child` so its frames are keyed with `settings`.
+    pub fn new(child: C, settings: ChromaKeySettings) -> Self {
+        Self { child, settings }
+    }
+
+    /// The wrapped child.
+    pub fn child(&self) -> &C {
+        &self.child
+    }
+
+    /// The key applied to the child's frames.
+    pub fn settings(&self) -> &ChromaKeySettings {
+        &self.settings
+    }
+}
+
+impl<C> TimelineComponent for Keyed<C>
+where
+    C: TimelineComponent + Clone + PartialEq + Hash + 'static,
+{
+    fn duration(&self) -> Option<f64> {
+        self.child.duration()
+    }
+
+    fn measure(&self) -> Option<f64> {
+        self.child.measure()
+    }
+
+    fn resolve(&self, abs_start: f64, out: &mut ResolveCtx) -> f64 {
+        self.child.resolve(abs_start, out)
+    }
+
+    fn frame(
+        &self,
+        clock: Clock<'_>,
+        canvas: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> Option<RasterImage> {
+        let child_residency = if ctx.prefers_gpu() && ctx.gpu_backend().is_some() {
+            RasterResidency::Gpu
+        } else {
+            RasterResidency::Cpu
+        };
+        let image = self
+            .child
+            .frame(clock, canvas, target, child_residency, ctx)?;
+        // Frames cover the canvas, so the choke scales with it.
+        let scale = if canvas.0 > 0.0 {
+            target.width as f32 / canvas.0
+        } else {
+            1.0
+        };
+        Some(self.settings.apply(image, scale, residency, ctx))
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
+    fn render_audio_block(&self, block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
+        self.child.render_audio_block(block, ctx);
+    }
+
+    fn cues(&self, offset: f64) -> Vec<Cue> {
+        self.child.cues(offset)
+    }
+
+    fn arrangement(&self, offset: f64) -> Arrangement {
+        self.child.arrangement(offset)
+    }
+}
+
+/// Lets a keyed component drop directly into timeline containers.
+impl<C> From<Keyed<C>> for Box<dyn TimelineComponent + Send>
+where
+    C: TimelineComponent + Clone + PartialEq + Hash + Send + 'static,
+{
+    fn from(keyed: Keyed<C>) -> Self {
+        Box::new(keyed)
+    }
+}
+
+/// Chroma-key verb for already-built timeline components.
+pub trait KeyEffects: TimelineComponent + PartialEq + Hash + Sized + 'static {
+    /// Keys `settings.key_color` out of every frame.
+    fn chroma_key(self, settings: ChromaKeySettings) -> Keyed<Self> {
+        Keyed::new(self, settings)
+    }
+}
+
+impl<C> KeyEffects for C where C: TimelineComponent + PartialEq + Hash + Sized + 'static {}
+
+/// Builder-side chroma-key verb, so complete builders never need an explicit
+/// `.build()` first.
+pub trait KeyEffectsBuilder: TimelineBuilder {
+    /// Builds immediately, then applies [`KeyEffects::chroma_key`].
+    fn chroma_key(self, settings: ChromaKeySettings) -> Keyed<Self::Output> {
+        Keyed::new(self.build_component(), settings)
+    }
+}
+
+impl<B: TimelineBuilder> KeyEffectsBuilder for B {}
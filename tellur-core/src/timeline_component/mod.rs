@@ -23,8 +23,11 @@
 
 mod audio_effect;
 mod audio_render;
+mod blend_effect;
 mod clock;
 mod component;
+mod grade_effect;
+mod key_effect;
 mod output;
 mod placed;
 mod resolve;
@@ -33,8 +36,11 @@ mod trim;
 
 pub use audio_effect::{AudioEffects, AudioEffectsBuilder, EnvelopePoint, GainEnvelope};
 pub use audio_render::{AudioBlockMut, AudioRenderContext, AudioRenderRequest};
+pub use blend_effect::{BlendEffects, BlendEffectsBuilder, Blended};
 pub use clock::Clock;
 pub use component::{TimelineBuilder, TimelineComponent, TimelineComponentClone};
+pub use grade_effect::{GradeEffects, GradeEffectsBuilder, Graded};
+pub use key_effect::{KeyEffects, KeyEffectsBuilder, Keyed};
 pub use output::{Arrangement, AudioBuffer, Cue, NodeKind, SourceLoc, TriggerMark};
 pub use placed::{Placed, Placement, Timed, TimedBuilder};
 pub use resolve::{
@@ -3,6 +3,7 @@
 
 use std::ops::Range;
 
+use crate::composite::BlendMode;
 use crate::geometry::Vec2;
 use crate::raster::{RasterImage, RasterResidency, Resolution};
 use crate::render_context::RenderContext;
@@ -415,6 +416,10 @@ impl TimelineComponent for Placed {
         self.frame_with_fill_length(clock, None, canvas, target, residency, ctx)
     }
 
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
     fn render_audio_block(&self, block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
         self.render_audio_block_with_fill_length(block, None, ctx);
     }
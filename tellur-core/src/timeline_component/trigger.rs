@@ -6,6 +6,7 @@ use std::hash::Hash;
 use std::ops::Range;
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use crate::composite::BlendMode;
 use crate::geometry::Vec2;
 use crate::phase::Phase;
 use crate::raster::{RasterImage, RasterResidency, Resolution};
@@ -250,6 +251,10 @@ impl<T: TimelineComponent + Clone + PartialEq + Hash + 'static> TimelineComponen
         self.child.frame(clock, canvas, target, residency, ctx)
     }
 
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
     fn render_audio_block(&self, block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
         self.child.render_audio_block(block, ctx);
     }
@@ -391,6 +396,10 @@ impl TimelineComponent for Sourced {
         self.inner.frame(clock, canvas, target, residency, ctx)
     }
 
+    fn blend_mode(&self) -> BlendMode {
+        self.inner.blend_mode()
+    }
+
     fn render_audio_block(&self, block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
         self.inner.render_audio_block(block, ctx);
     }
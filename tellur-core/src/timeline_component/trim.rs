@@ -10,6 +10,7 @@
 use std::hash::{Hash, Hasher};
 use std::ops::{Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeTo};
 
+use crate::composite::BlendMode;
 use crate::geometry::Vec2;
 use crate::raster::{RasterImage, RasterResidency, Resolution};
 use crate::render_context::RenderContext;
@@ -236,6 +237,10 @@ where
             .frame(child_clock, canvas, target, residency, ctx)
     }
 
+    fn blend_mode(&self) -> BlendMode {
+        self.child.blend_mode()
+    }
+
     fn render_audio_block(&self, mut block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
         let range = self.resolved_range();
         let duration = range.end - range.start;
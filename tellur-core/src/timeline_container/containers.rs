@@ -1,6 +1,6 @@
 //! The overlay [`Timeline`] and the one-after-another [`Sequence`].
 
-use crate::composite::composite_frames_over;
+use crate::composite::composite_frames;
 use crate::geometry::Vec2;
 use crate::raster::{RasterImage, RasterResidency, Resolution};
 use crate::render_context::RenderContext;
@@ -240,6 +240,10 @@ impl TimelineComponent for Timeline {
         };
         let mut frames = Vec::new();
         for view in self.classify() {
+            let mode = match view {
+                ChildView::Fill(placed, _) | ChildView::PlacedAt(placed, _) => placed.blend_mode(),
+                ChildView::Bare(child) => child.blend_mode(),
+            };
             let image = match view {
                 ChildView::Fill(placed, _) => match length {
                     Some(fill_length) => {
@@ -253,10 +257,10 @@ impl TimelineComponent for Timeline {
                 ChildView::Bare(child) => child.frame(clock, canvas, target, child_residency, ctx),
             };
             if let Some(img) = image {
-                frames.push(img);
+                frames.push((img, mode));
             }
         }
-        composite_frames_over(frames, target, residency, ctx)
+        composite_frames(frames, target, residency, ctx)
     }
 
     fn render_audio_block(&self, mut block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
@@ -543,13 +547,13 @@ impl TimelineComponent for Sequence {
             if active {
                 let child_clock = clock.with_local_window(LocalTime::new(local_t - cursor), slot);
                 if let Some(img) = child.frame(child_clock, canvas, target, child_residency, ctx) {
-                    frames.push(img);
+                    frames.push((img, child.blend_mode()));
                 }
             }
             cursor += slot.unwrap_or(0.0);
             placed_any = true;
         }
-        composite_frames_over(frames, target, residency, ctx)
+        composite_frames(frames, target, residency, ctx)
     }
 
     fn render_audio_block(&self, mut block: AudioBlockMut<'_>, ctx: &mut AudioRenderContext) {
@@ -1,8 +1,9 @@
-//! The media / subtitle leaves: [`VideoFile`], [`AudioFile`], [`Subtitle`].
+//! The media / subtitle leaves: [`VideoFile`], [`ImageSequence`], [`AudioFile`],
+//! [`Subtitle`].
 
 use crate::audio;
 use crate::geometry::Vec2;
-use crate::raster::{RasterImage, RasterResidency, Resolution};
+use crate::raster::{resample_rgba8, RasterImage, RasterResidency, Resolution};
 use crate::render_context::RenderContext;
 use crate::time::Time;
 use crate::timeline_component::{
@@ -105,6 +106,123 @@ impl TimelineComponent for VideoFile {
     }
 }
 
+/// What an [`ImageSequence`] shows once its frames run out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SequenceEnd {
+    /// Nothing: past its last frame the leaf renders `None`.
+    #[default]
+    Stop,
+    /// Starts over from the first frame.
+    Loop,
+    /// Keeps showing the last frame.
+    Hold,
+}
+
+/// Numbered stills or an animated image, played as a clip — the visual channel.
+/// Built with `ImageSequence::builder().pattern("fx/fx_%04d.png")`.
+///
+/// `pattern` is a printf-style (`fx_%04d.png`) or glob (`fx_*.png`) file name,
+/// resolved against its directory and played at `frame_rate`, or a single
+/// animated GIF, APNG or WebP played at its own frame delays. Its intrinsic
+/// length comes from the frame count (`probe`); past it, [`end`](Self::end)
+/// stops, loops or holds the last frame. Like any timed leaf it stretches to
+/// an `.at(a..b)` window, so a looping or holding sequence is lengthened with
+/// an injected `duration` instead, which keeps the frames at native speed.
+///
+/// DECODE is lazy: each frame is decoded when first shown and kept in a
+/// bounded process-global cache ([`image_sequence`](crate::image_sequence)),
+/// so, like `VideoFile`, the leaf stays `Clone + Keyable` pure data.
+#[crate::component(timeline)]
+// `Clone`: see `VideoFile` — a leaf may be a `#[component(timeline)]` field that
+// the macro clones to build the body.
+#[derive(Clone, crate::Keyable)]
+pub struct ImageSequence {
+    #[builder(into)]
+    pub pattern: String,
+    /// Frames per second for numbered files. Animated images keep their own
+    /// frame delays.
+    #[builder(default = 24.0)]
+    pub frame_rate: f64,
+    /// What shows after the last frame.
+    #[builder(default)]
+    pub end: SequenceEnd,
+    /// Optional override for the probed duration — how long a looping or
+    /// holding sequence runs. `None` counts the resolved frames (with the stub
+    /// as fallback).
+    #[builder(into)]
+    pub duration: Option<f64>,
+}
+
+impl ImageSequence {
+    /// Wraps this source in the generic ordered trim component.
+    pub fn trim<R: TrimBounds>(self, bounds: R) -> Trim<Self> {
+        Trim::new(self, bounds)
+    }
+
+    /// Duration probe (`.sketch/02 §12`). An injected `duration` wins; otherwise
+    /// one pass over the resolved frames. If the pattern matches nothing, falls
+    /// back to [`STUB_PROBE_SECONDS`].
+    fn probe(&self) -> f64 {
+        if let Some(d) = self.duration {
+            return d;
+        }
+        crate::image_sequence::probe(&self.pattern)
+            .map(|frames| frames.duration(self.frame_rate))
+            .unwrap_or(STUB_PROBE_SECONDS)
+    }
+}
+
+impl TimelineComponent for ImageSequence {
+    fn duration(&self) -> Option<f64> {
+        Some(self.probe())
+    }
+
+    fn frame(
+        &self,
+        clock: Clock<'_>,
+        canvas: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> Option<RasterImage> {
+        // As for `VideoFile`: `local` is already rebased + speed-scaled to this
+        // source's clock, and frames scale to the pixel `target` regardless of
+        // `canvas`.
+        let _ = canvas;
+        let frames = crate::image_sequence::probe(&self.pattern)?;
+        let length = frames.duration(self.frame_rate);
+        let t = clock.local().seconds();
+        let index = match self.end {
+            SequenceEnd::Loop if length > 0.0 => {
+                frames.index_at(t.rem_euclid(length), self.frame_rate)
+            }
+            SequenceEnd::Hold if t >= length => Some(frames.len() - 1),
+            _ => frames.index_at(t, self.frame_rate),
+        }?;
+        let image = crate::image_sequence::decode_frame(&self.pattern, index)?;
+        let image = if image.width == target.width && image.height == target.height {
+            image
+        } else {
+            resample_rgba8(&image, target)
+        };
+        Some(ctx.ensure_residency(RasterImage::Cpu(image), residency))
+    }
+
+    fn arrangement(&self, offset: f64) -> Arrangement {
+        Arrangement {
+            kind: NodeKind::Video,
+            label: self.pattern.clone(),
+            name: None,
+            source: None,
+            start: offset,
+            end: offset + self.probe(),
+            trim: None,
+            triggers: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
 /// Decoded audio — the audio channel. Built with
 /// `AudioFile::builder().path("v.wav").gain(0.25).fade_out(0.4)`.
 ///
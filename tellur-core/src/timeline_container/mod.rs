@@ -17,8 +17,8 @@
 //! `impl TimelineComponent`, exactly as raster `Flex` is a
 //! `#[component(raster)] struct` + hand-written `impl RasterComponent`.
 //!
-//! The leaves ([`VideoFile`], [`AudioFile`], [`Subtitle`]) are buildless
-//! builders. Media DECODE is steps 8/9; here their length comes from a stubbed
+//! The leaves ([`VideoFile`], [`ImageSequence`], [`AudioFile`], [`Subtitle`])
+//! are buildless builders. Media DECODE is steps 8/9; here their length comes from a stubbed
 //! `VideoFile::probe` seam (a caller-injectable `duration`), and
 //! `frame` stays `None` and audio block rendering stays silent.
 
@@ -547,6 +547,91 @@ fn timeline_overlays_two_solids_source_over() {
     assert_eq!(px[2], 0, "no blue contributes");
 }
 
+// A `.blended(..)` child composites over the earlier siblings with its mode,
+// through the placement that wraps it.
+#[test]
+fn timeline_overlays_a_blended_child_with_its_mode() {
+    use crate::composite::BlendMode;
+    use crate::timeline_component::BlendEffects;
+
+    let tl = Timeline::builder()
+        .child(
+            SolidColor {
+                rgba: [200, 100, 0, 255],
+            }
+            .at(0.0..2.0),
+        )
+        .child(
+            SolidColor {
+                rgba: [100, 100, 255, 255],
+            }
+            .blended(BlendMode::Screen)
+            .at(0.0..2.0),
+        )
+        .build();
+    let resolved = resolve_root(tl).expect("windowed");
+
+    let mut ctx = crate::render_context::PassThrough;
+    let frame = resolved
+        .frame(
+            TimelineTime::new(0.5),
+            Resolution::new(2, 2),
+            RasterResidency::Cpu,
+            &mut ctx,
+        )
+        .expect("both solids contribute");
+    assert_eq!(first_pixel(&frame), [222, 161, 255, 255]);
+}
+
+// A `.chroma_key(..)` green-screen layer disappears where it matches the key,
+// leaving the backdrop beneath it in the timeline.
+#[test]
+fn keyed_green_screen_reveals_the_backdrop() {
+    use crate::chroma_key::ChromaKeySettings;
+    use crate::color::Color;
+    use crate::timeline_component::{KeyEffects, KeyEffectsBuilder, Keyed};
+
+    let _: Keyed<Trim<VideoFile>> = VideoFile::builder()
+        .path("missing.mp4")
+        .trim(0.5..)
+        .chroma_key(ChromaKeySettings::default());
+    let _: Trim<Keyed<VideoFile>> = VideoFile::builder()
+        .path("missing.mp4")
+        .chroma_key(ChromaKeySettings::default())
+        .trim(0.5..);
+
+    let tl = Timeline::builder()
+        .child(
+            SolidColor {
+                rgba: [200, 30, 30, 255],
+            }
+            .at(0.0..2.0),
+        )
+        .child(
+            SolidColor {
+                rgba: [30, 210, 50, 255],
+            }
+            .chroma_key(ChromaKeySettings {
+                key_color: Color::rgb_u8(20, 200, 40),
+                ..ChromaKeySettings::default()
+            })
+            .at(0.0..2.0),
+        )
+        .build();
+    let resolved = resolve_root(tl).expect("windowed");
+
+    let mut ctx = crate::render_context::PassThrough;
+    let frame = resolved
+        .frame(
+            TimelineTime::new(0.5),
+            Resolution::new(2, 2),
+            RasterResidency::Cpu,
+            &mut ctx,
+        )
+        .expect("the backdrop is visible");
+    assert_eq!(first_pixel(&frame), [200, 30, 30, 255]);
+}
+
 // A `#[component(timeline)]` with `#[clock]` that bakes `clock.local()`
 // (seconds, quantized to an integer) into the red channel — proving the
 // rebased local clock reaches the visual through `frame`.
@@ -1512,6 +1597,51 @@ fn ordered_effect_builder_types_preserve_call_order() {
     let _: Trim<VideoFile> = VideoFile::builder().path("missing.mp4").trim(..-0.5);
 }
 
+// A graded ramp runs across the window its placement gives it: halfway
+// through, the exposure ramp is half a stop.
+#[test]
+fn graded_ramp_follows_the_placed_window() {
+    use crate::grade::ColorAdjustment;
+    use crate::timeline_component::{GradeEffects, GradeEffectsBuilder, Graded};
+
+    let _: Graded<Trim<VideoFile>> = VideoFile::builder()
+        .path("missing.mp4")
+        .trim(0.5..)
+        .color_adjust(ColorAdjustment::default());
+    let _: Trim<Graded<VideoFile>> = VideoFile::builder()
+        .path("missing.mp4")
+        .color_adjust(ColorAdjustment::default())
+        .trim(0.5..);
+
+    let ramp = SolidColor {
+        rgba: [100, 50, 20, 255],
+    }
+    .color_adjust_ramp(
+        ColorAdjustment::default(),
+        ColorAdjustment {
+            exposure: 1.0,
+            ..ColorAdjustment::default()
+        },
+    );
+    let tl = Timeline::builder().child(ramp.at(0.0..2.0)).build();
+    let resolved = resolve_root(tl).expect("windowed");
+
+    let mut ctx = crate::render_context::PassThrough;
+    let mut pixel_at = |seconds: f64| {
+        let frame = resolved
+            .frame(
+                TimelineTime::new(seconds),
+                Resolution::new(2, 2),
+                RasterResidency::Cpu,
+                &mut ctx,
+            )
+            .expect("graded solid is visible");
+        first_pixel(&frame)
+    };
+    assert_eq!(pixel_at(0.0), [100, 50, 20, 255]);
+    assert_eq!(pixel_at(1.0), [141, 71, 28, 255]);
+}
+
 #[test]
 fn negative_trim_bounds_are_relative_to_the_immediate_child_end() {
     let source = AudioFile::builder()
@@ -2014,3 +2144,169 @@ fn videofile_decodes_plausible_frames() {
 
     let _ = std::fs::remove_file(&path);
 }
+
+// ── Image sequences ──────────────────────────────────────────────────────
+//
+// Numbered PNGs written to a per-test temp dir, one flat color per frame, so
+// the first pixel names the frame that was decoded.
+
+/// The red channel of frame `i` in [`write_png_sequence`].
+fn sequence_red(i: usize) -> u8 {
+    (i as u8 + 1) * 40
+}
+
+/// Writes `count` 2×2 flat PNGs named `fx_0001.png`… into a fresh temp dir
+/// and returns the dir. The caller removes it when done.
+fn write_png_sequence(name: &str, count: usize) -> std::path::PathBuf {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("tellur_sequence_{}_{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).expect("create sequence dir");
+    for i in 0..count {
+        let pixels = [sequence_red(i), 0, 0, 255].repeat(4);
+        let image = CpuRasterImage::new(2, 2, PixelFormat::Rgba8, pixels);
+        let file =
+            std::fs::File::create(dir.join(format!("fx_{:04}.png", i + 1))).expect("create frame");
+        image.export_png(file).expect("write frame");
+    }
+    dir
+}
+
+fn sequence_red_at(resolved: &crate::timeline_component::ResolvedTimeline, t: f64) -> Option<u8> {
+    let mut ctx = crate::render_context::PassThrough;
+    resolved
+        .frame(
+            TimelineTime::new(t),
+            Resolution::new(2, 2),
+            RasterResidency::Cpu,
+            &mut ctx,
+        )
+        .map(|frame| first_pixel(&frame)[0])
+}
+
+#[test]
+fn image_sequence_resolves_printf_and_glob_patterns() {
+    let dir = write_png_sequence("patterns", 4);
+    let printf = format!("{}/fx_%04d.png", dir.display());
+    let glob = format!("{}/fx_*.png", dir.display());
+    for pattern in [printf, glob] {
+        let seq = ImageSequence::builder()
+            .pattern(pattern.as_str())
+            .frame_rate(10.0)
+            .build();
+        assert_eq!(seq.duration(), Some(0.4), "{pattern}");
+        let resolved = resolve_root(Timeline::builder().child(seq).build()).expect("sized");
+        assert_eq!(sequence_red_at(&resolved, 0.0), Some(sequence_red(0)));
+        assert_eq!(sequence_red_at(&resolved, 0.25), Some(sequence_red(2)));
+        assert_eq!(sequence_red_at(&resolved, 0.35), Some(sequence_red(3)));
+    }
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn image_sequence_stops_loops_or_holds_past_its_frames() {
+    let dir = write_png_sequence("end", 3);
+    let pattern = format!("{}/fx_%04d.png", dir.display());
+    let sequence = |end| {
+        ImageSequence::builder()
+            .pattern(pattern.as_str())
+            .frame_rate(10.0)
+            .end(end)
+            .duration(1.0)
+    };
+    let render = |end, t| {
+        let resolved = resolve_root(Timeline::builder().child(sequence(end)).build()).unwrap();
+        sequence_red_at(&resolved, t)
+    };
+    assert_eq!(render(SequenceEnd::Stop, 0.5), None);
+    assert_eq!(render(SequenceEnd::Loop, 0.45), Some(sequence_red(1)));
+    assert_eq!(render(SequenceEnd::Hold, 0.95), Some(sequence_red(2)));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn image_sequence_follows_trim_and_placed_speed() {
+    let dir = write_png_sequence("trim", 4);
+    let pattern = format!("{}/fx_%04d.png", dir.display());
+    let seq = || {
+        ImageSequence::builder()
+            .pattern(pattern.as_str())
+            .frame_rate(10.0)
+            .build()
+    };
+
+    // Trimming off the first two frames starts on the third.
+    let trimmed = seq().trim(0.2..);
+    assert_eq!(trimmed.duration(), Some(0.2));
+    let resolved = resolve_root(Timeline::builder().child(trimmed).build()).unwrap();
+    assert_eq!(sequence_red_at(&resolved, 0.0), Some(sequence_red(2)));
+
+    // Squeezing the 0.4 s of frames into a 0.2 s window plays them at 2×.
+    let fast = Timeline::builder().child(seq().at(0.0..0.2)).build();
+    let resolved = resolve_root(fast).unwrap();
+    assert_eq!(sequence_red_at(&resolved, 0.1), Some(sequence_red(2)));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn image_sequence_picks_up_frames_changed_on_disk() {
+    let dir = write_png_sequence("edits", 2);
+    let pattern = format!("{}/fx_%04d.png", dir.display());
+    let seq = || {
+        ImageSequence::builder()
+            .pattern(pattern.as_str())
+            .frame_rate(10.0)
+            .build()
+    };
+    let red_at = |t| sequence_red_at(&resolve_root(seq()).unwrap(), t);
+    assert_eq!(seq().duration(), Some(0.2));
+    assert_eq!(red_at(0.0), Some(sequence_red(0)));
+
+    // A frame added to the directory lengthens the sequence, and a frame
+    // rewritten in place shows its new pixels.
+    let frame = |i: usize, red: u8| {
+        let image = CpuRasterImage::new(2, 2, PixelFormat::Rgba8, [red, 0, 0, 255].repeat(4));
+        let path = dir.join(format!("fx_{:04}.png", i + 1));
+        image
+            .export_png(std::fs::File::create(&path).expect("create frame"))
+            .expect("write frame");
+        // Past any coarse filesystem timestamp, so the edit is visible.
+        let later = std::time::SystemTime::now() + std::time::Duration::from_secs(10);
+        std::fs::File::options()
+            .write(true)
+            .open(&path)
+            .and_then(|file| file.set_modified(later))
+            .expect("touch frame");
+    };
+    frame(2, sequence_red(2));
+    frame(0, 7);
+    assert_eq!(seq().duration(), Some(0.3));
+    assert_eq!(red_at(0.0), Some(7));
+    assert_eq!(red_at(0.25), Some(sequence_red(2)));
+    let _ = std::fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn image_sequence_plays_an_animated_gif_at_its_delays() {
+    // Two frames with no delay of their own, so each shows for 0.1 s.
+    let gif = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/images/sample.gif"
+    );
+    let seq = ImageSequence::builder().pattern(gif).build();
+    assert_eq!(seq.duration(), Some(0.2));
+    let resolved = resolve_root(Timeline::builder().child(seq).build()).unwrap();
+    let mut ctx = crate::render_context::PassThrough;
+    let frame = |t, ctx: &mut crate::render_context::PassThrough| {
+        resolved
+            .frame(
+                TimelineTime::new(t),
+                Resolution::new(2, 1),
+                RasterResidency::Cpu,
+                ctx,
+            )
+            .expect("frame")
+    };
+    assert_eq!(first_pixel(&frame(0.05, &mut ctx)), [255, 0, 0, 255]);
+    assert_eq!(first_pixel(&frame(0.15, &mut ctx))[..2], [0, 255]);
+}
@@ -4,6 +4,7 @@ use std::hash::{Hash, Hasher};
 use crate::color::Color;
 use crate::dyn_compare::{DynEq, DynHash};
 use crate::geometry::{Anchor, Constraints, Rect, Transform, Vec2};
+use crate::raster::{CpuRasterImage, PixelFormat};
 use crate::scalar::clamp_unit;
 use crate::Keyable;
 
@@ -268,6 +269,8 @@ fn union_rect(a: Rect, b: Rect) -> Rect {
     }
 }
 
+// Paths are most of any tree; boxing them would cost an allocation each.
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Node {
     Group(Group),
@@ -325,6 +328,8 @@ pub struct SingleGroup {
 #[derive(Debug, Clone, Keyable)]
 pub struct ClipGroup {
     pub commands: Vec<PathCommand>,
+    /// How `commands` decide which points are inside the clip.
+    pub fill_rule: FillRule,
     pub transform: Transform,
     pub child: Box<Node>,
 }
@@ -333,10 +338,47 @@ pub struct ClipGroup {
 pub struct Path {
     pub commands: Vec<PathCommand>,
     pub fill: Option<Fill>,
+    /// How `commands` decide which points the fill covers. Strokes ignore it.
+    pub fill_rule: FillRule,
     pub stroke: Option<Stroke>,
     pub transform: Transform,
 }
 
+impl Path {
+    /// The stroke's expanded outline when it has a [`WidthProfile`], as
+    /// commands in the path's own coordinate space to be filled (nonzero)
+    /// with the stroke paint. `None` for strokes without a profile.
+    ///
+    /// `to_device` maps those coordinates to output pixels, including
+    /// `self.transform`; it only sets the flattening tolerance, so both
+    /// renderers get the same outline at the same zoom.
+    pub fn width_profile_outline(&self, to_device: Transform) -> Option<Vec<PathCommand>> {
+        let stroke = self.stroke.as_ref()?;
+        let profile = stroke.width_profile.as_ref()?;
+        Some(crate::effect::outline::width_profile_commands(
+            &self.commands,
+            stroke,
+            profile,
+            to_device,
+        ))
+    }
+}
+
+/// Which points a (possibly self-intersecting) closed path encloses, the
+/// same choice as SVG's `fill-rule`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    /// A point is inside when the path winds around it a non-zero number of
+    /// times. Contours drawn in opposite directions cut holes; contours drawn
+    /// in the same direction merge.
+    #[default]
+    NonZero,
+    /// A point is inside when a ray from it crosses the path an odd number of
+    /// times, so every nested contour alternates between filled and hole
+    /// regardless of direction.
+    EvenOdd,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PathCommand {
     MoveTo(Vec2),
@@ -404,6 +446,11 @@ pub struct Stroke {
     /// Optional dash pattern (SVG `stroke-dasharray`/`stroke-dashoffset`
     /// equivalent). `None` strokes solid.
     pub dash: Option<DashPattern>,
+    /// Optional width that varies along each subpath. When set, renderers
+    /// draw the stroke as its expanded outline (see
+    /// [`Path::width_profile_outline`]) and `width` holds the profile's
+    /// maximum so bounds stay conservative.
+    pub width_profile: Option<WidthProfile>,
 }
 
 impl Stroke {
@@ -415,6 +462,7 @@ impl Stroke {
             join: StrokeJoin::default(),
             miter_limit: DEFAULT_STROKE_MITER_LIMIT,
             dash: None,
+            width_profile: None,
         }
     }
 
@@ -472,6 +520,16 @@ impl Stroke {
         self.miter_limit = normalize_miter_limit(miter_limit);
         self
     }
+
+    /// Returns this stroke with a width that varies along each subpath.
+    ///
+    /// `width` becomes the profile's maximum width. Variable-width strokes
+    /// always join round; `cap` still shapes open ends and dash ends.
+    pub fn with_width_profile(mut self, profile: WidthProfile) -> Self {
+        self.width = profile.max_width();
+        self.width_profile = Some(profile);
+        self
+    }
 }
 
 fn normalize_miter_limit(miter_limit: f32) -> f32 {
@@ -527,9 +585,98 @@ impl DashPattern {
     }
 }
 
+/// One stop of a [`WidthProfile`]: the stroke is `width` logical units wide
+/// at `t` along the subpath.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct WidthStop {
+    pub t: f32,
+    pub width: f32,
+}
+
+/// Stroke width along a subpath, as `(t, width)` stops.
+///
+/// `t` runs from `0.0` at the start of each subpath to `1.0` at its end,
+/// measured by arc length, and widths are in logical units. The width
+/// interpolates linearly between stops and holds the nearest stop's width
+/// before the first and after the last.
+#[derive(Debug, Clone, Keyable)]
+pub struct WidthProfile {
+    stops: Vec<WidthStop>,
+}
+
+impl WidthProfile {
+    /// Builds a profile from `(t, width)` stops. Stops are sorted by `t`,
+    /// `t` is clamped to `0.0..=1.0`, negative widths become `0.0`, and stops
+    /// with non-finite values are dropped.
+    pub fn new(stops: impl IntoIterator<Item = (f32, f32)>) -> Self {
+        let mut stops: Vec<WidthStop> = stops
+            .into_iter()
+            .filter(|(t, width)| t.is_finite() && width.is_finite())
+            .map(|(t, width)| WidthStop {
+                t: t.clamp(0.0, 1.0),
+                width: width.max(0.0),
+            })
+            .collect();
+        stops.sort_by(|a, b| a.t.total_cmp(&b.t));
+        Self { stops }
+    }
+
+    /// A profile that swells from nothing to `width` over the first `taper`
+    /// of the subpath and shrinks back to nothing over the last `taper`.
+    pub fn tapered(width: f32, taper: f32) -> Self {
+        let taper = if taper.is_finite() {
+            taper.clamp(0.0, 0.5)
+        } else {
+            0.0
+        };
+        Self::new([(0.0, 0.0), (taper, width), (1.0 - taper, width), (1.0, 0.0)])
+    }
+
+    /// The sorted stops.
+    pub fn stops(&self) -> &[WidthStop] {
+        &self.stops
+    }
+
+    /// The stroke width at `t` along a subpath.
+    pub fn width_at(&self, t: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.stops.first(), self.stops.last()) else {
+            return 0.0;
+        };
+        if t <= first.t {
+            return first.width;
+        }
+        if t >= last.t {
+            return last.width;
+        }
+        let next = self.stops.partition_point(|stop| stop.t <= t);
+        let (a, b) = (self.stops[next - 1], self.stops[next]);
+        let span = b.t - a.t;
+        if span <= f32::EPSILON {
+            return b.width;
+        }
+        a.width + (b.width - a.width) * ((t - a.t) / span)
+    }
+
+    /// The widest point of the profile, or `0.0` without stops.
+    pub fn max_width(&self) -> f32 {
+        self.stops.iter().map(|stop| stop.width).fold(0.0, f32::max)
+    }
+}
+
+/// What fills or strokes a [`Path`].
+///
+/// Gradient and image geometry is expressed in the path's local coordinate
+/// space (the same space as its [`PathCommand`]s), after the paint's own
+/// `transform` maps gradient or image space into it.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Paint {
     Solid(Color),
+    LinearGradient(LinearGradient),
+    RadialGradient(RadialGradient),
+    SweepGradient(SweepGradient),
+    /// Boxed: the image handle would otherwise make every `Paint` (and so
+    /// every path node) as large as the pattern.
+    Image(Box<ImagePattern>),
 }
 
 impl Paint {
@@ -541,8 +688,329 @@ impl Paint {
     pub fn is_visible(&self) -> bool {
         match self {
             Paint::Solid(c) => c.a > 0.0,
+            Paint::LinearGradient(g) => stops_are_visible(&g.stops),
+            Paint::RadialGradient(g) => g.radius > 0.0 && stops_are_visible(&g.stops),
+            Paint::SweepGradient(g) => stops_are_visible(&g.stops),
+            Paint::Image(pattern) => pattern.is_drawable(),
+        }
+    }
+}
+
+/// How a gradient paints outside the `[0, 1]` range between its first and
+/// last stop.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SpreadMode {
+    /// Extend the end stop colors indefinitely.
+    #[default]
+    Pad,
+    /// Restart the gradient from its first stop.
+    Repeat,
+    /// Mirror the gradient back and forth.
+    Reflect,
+}
+
+/// A color at a position along a gradient. `offset` is a fraction in
+/// `[0, 1]`; renderers read stops through
+/// [`LinearGradient::normalized_stops`] (and its siblings), which clamp
+/// offsets and order them.
+#[derive(Debug, Clone, Copy, Keyable)]
+pub struct GradientStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+impl GradientStop {
+    pub const fn new(offset: f32, color: Color) -> Self {
+        Self { offset, color }
+    }
+}
+
+/// Stops evenly spaced from `0` to `1`, one per color.
+fn even_stops(colors: impl IntoIterator<Item = Color>) -> Vec<GradientStop> {
+    let colors: Vec<Color> = colors.into_iter().collect();
+    let last = colors.len().saturating_sub(1).max(1) as f32;
+    colors
+        .into_iter()
+        .enumerate()
+        .map(|(i, color)| GradientStop::new(i as f32 / last, color))
+        .collect()
+}
+
+fn stops_are_visible(stops: &[GradientStop]) -> bool {
+    stops.iter().any(|stop| stop.color.a > 0.0)
+}
+
+/// `stops` with offsets clamped to `[0, 1]` (NaN reads as `0`) and stably
+/// sorted, so equal offsets keep their authored order and form a hard edge.
+fn normalize_stops(stops: &[GradientStop]) -> Vec<GradientStop> {
+    let mut normalized: Vec<GradientStop> = stops
+        .iter()
+        .map(|stop| GradientStop::new(clamp_unit(stop.offset), stop.color))
+        .collect();
+    normalized.sort_by(|a, b| a.offset.total_cmp(&b.offset));
+    normalized
+}
+
+/// A gradient varying along the line from `start` to `end`.
+#[derive(Debug, Clone, Keyable)]
+pub struct LinearGradient {
+    pub start: Vec2,
+    pub end: Vec2,
+    pub stops: Vec<GradientStop>,
+    pub spread: SpreadMode,
+    /// Maps gradient space into the path's local space.
+    pub transform: Transform,
+}
+
+impl LinearGradient {
+    pub fn new(start: Vec2, end: Vec2, stops: impl Into<Vec<GradientStop>>) -> Self {
+        Self {
+            start,
+            end,
+            stops: stops.into(),
+            spread: SpreadMode::default(),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    /// A gradient through `colors`, evenly spaced from `start` to `end`.
+    pub fn evenly(start: Vec2, end: Vec2, colors: impl IntoIterator<Item = Color>) -> Self {
+        Self::new(start, end, even_stops(colors))
+    }
+
+    /// Returns this gradient with the given spread mode.
+    pub fn with_spread(mut self, spread: SpreadMode) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Returns this gradient with the given gradient transform.
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// `stops` clamped to `[0, 1]` and ordered by offset.
+    pub fn normalized_stops(&self) -> Vec<GradientStop> {
+        normalize_stops(&self.stops)
+    }
+}
+
+/// A gradient radiating from `focal` (by default the `center`) out to the
+/// circle of `radius` around `center`.
+#[derive(Debug, Clone, Keyable)]
+pub struct RadialGradient {
+    pub center: Vec2,
+    pub radius: f32,
+    /// Where offset `0` sits. Must lie inside the end circle for the
+    /// conventional "spotlight" look.
+    pub focal: Vec2,
+    pub stops: Vec<GradientStop>,
+    pub spread: SpreadMode,
+    /// Maps gradient space into the path's local space.
+    pub transform: Transform,
+}
+
+impl RadialGradient {
+    pub fn new(center: Vec2, radius: f32, stops: impl Into<Vec<GradientStop>>) -> Self {
+        Self {
+            center,
+            radius,
+            focal: center,
+            stops: stops.into(),
+            spread: SpreadMode::default(),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    /// A gradient through `colors`, evenly spaced from `center` to `radius`.
+    pub fn evenly(center: Vec2, radius: f32, colors: impl IntoIterator<Item = Color>) -> Self {
+        Self::new(center, radius, even_stops(colors))
+    }
+
+    /// Returns this gradient with offset `0` moved to `focal`.
+    pub fn with_focal(mut self, focal: Vec2) -> Self {
+        self.focal = focal;
+        self
+    }
+
+    /// Returns this gradient with the given spread mode.
+    pub fn with_spread(mut self, spread: SpreadMode) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Returns this gradient with the given gradient transform.
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// `stops` clamped to `[0, 1]` and ordered by offset.
+    pub fn normalized_stops(&self) -> Vec<GradientStop> {
+        normalize_stops(&self.stops)
+    }
+}
+
+/// A gradient sweeping clockwise (in y-down screen space) around `center`,
+/// from `start_angle` to `end_angle` in radians. Angle `0` points along +x.
+#[derive(Debug, Clone, Keyable)]
+pub struct SweepGradient {
+    pub center: Vec2,
+    pub start_angle: f32,
+    pub end_angle: f32,
+    pub stops: Vec<GradientStop>,
+    pub spread: SpreadMode,
+    /// Maps gradient space into the path's local space.
+    pub transform: Transform,
+}
+
+impl SweepGradient {
+    /// A full-turn sweep starting at angle `0`.
+    pub fn new(center: Vec2, stops: impl Into<Vec<GradientStop>>) -> Self {
+        Self {
+            center,
+            start_angle: 0.0,
+            end_angle: std::f32::consts::TAU,
+            stops: stops.into(),
+            spread: SpreadMode::default(),
+            transform: Transform::IDENTITY,
+        }
+    }
+
+    /// A full-turn sweep through `colors`, evenly spaced.
+    pub fn evenly(center: Vec2, colors: impl IntoIterator<Item = Color>) -> Self {
+        Self::new(center, even_stops(colors))
+    }
+
+    /// Returns this gradient sweeping from `start_angle` to `end_angle`
+    /// (radians).
+    pub fn with_angles(mut self, start_angle: f32, end_angle: f32) -> Self {
+        self.start_angle = start_angle;
+        self.end_angle = end_angle;
+        self
+    }
+
+    /// Returns this gradient with the given spread mode.
+    pub fn with_spread(mut self, spread: SpreadMode) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Returns this gradient with the given gradient transform.
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// `stops` clamped to `[0, 1]` and ordered by offset.
+    pub fn normalized_stops(&self) -> Vec<GradientStop> {
+        normalize_stops(&self.stops)
+    }
+}
+
+/// How an [`ImagePattern`] is sampled between its pixels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ImageQuality {
+    /// Take the nearest pixel; keeps pixel art crisp when scaled up.
+    Nearest,
+    /// Blend the four nearest pixels.
+    #[default]
+    Bilinear,
+    /// Blend the sixteen nearest pixels with a cubic filter; sharper than
+    /// bilinear when the image is magnified.
+    Bicubic,
+}
+
+/// A raster image used as paint, e.g. a photo or video frame showing through
+/// the letters of a title.
+///
+/// The image occupies `(0, 0)..(width, height)` of pattern space, one unit per
+/// pixel, and `transform` maps pattern space into the path's local space.
+/// Outside that box the image continues according to `extend`:
+/// [`SpreadMode::Pad`] stretches the edge pixels, `Repeat` tiles and
+/// `Reflect` mirrors. Only [`PixelFormat::Rgba8`] images are drawn.
+#[derive(Debug, Clone, Keyable)]
+pub struct ImagePattern {
+    pub image: CpuRasterImage,
+    /// Maps pattern space into the path's local space.
+    pub transform: Transform,
+    pub extend: SpreadMode,
+    pub quality: ImageQuality,
+}
+
+impl ImagePattern {
+    pub fn new(image: CpuRasterImage) -> Self {
+        Self {
+            image,
+            transform: Transform::IDENTITY,
+            extend: SpreadMode::default(),
+            quality: ImageQuality::default(),
         }
     }
+
+    /// Returns this pattern with the image stretched to exactly cover `rect`
+    /// (in the path's local space).
+    pub fn fit_to(mut self, rect: Rect) -> Self {
+        let (width, height) = (self.image.width.max(1), self.image.height.max(1));
+        self.transform = Transform::translate(rect.origin).concat(Transform::scale(Vec2(
+            rect.size.0 / width as f32,
+            rect.size.1 / height as f32,
+        )));
+        self
+    }
+
+    /// Returns this pattern with the given pattern transform.
+    pub fn with_transform(mut self, transform: Transform) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Returns this pattern with the given extend mode.
+    pub fn with_extend(mut self, extend: SpreadMode) -> Self {
+        self.extend = extend;
+        self
+    }
+
+    /// Returns this pattern with the given sampling quality.
+    pub fn with_quality(mut self, quality: ImageQuality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    /// `true` iff the image has pixels a renderer can sample: non-empty,
+    /// RGBA8 and tightly packed.
+    pub fn is_drawable(&self) -> bool {
+        let image = &self.image;
+        image.width > 0
+            && image.height > 0
+            && image.format == PixelFormat::Rgba8
+            && image.pixels.len() == image.width as usize * image.height as usize * 4
+    }
+}
+
+impl From<ImagePattern> for Paint {
+    fn from(pattern: ImagePattern) -> Self {
+        Self::Image(Box::new(pattern))
+    }
+}
+
+impl From<LinearGradient> for Paint {
+    fn from(gradient: LinearGradient) -> Self {
+        Self::LinearGradient(gradient)
+    }
+}
+
+impl From<RadialGradient> for Paint {
+    fn from(gradient: RadialGradient) -> Self {
+        Self::RadialGradient(gradient)
+    }
+}
+
+impl From<SweepGradient> for Paint {
+    fn from(gradient: SweepGradient) -> Self {
+        Self::SweepGradient(gradient)
+    }
 }
 
 impl From<Color> for Paint {
@@ -591,6 +1059,7 @@ impl From<Paint> for Stroke {
             join: StrokeJoin::default(),
             miter_limit: DEFAULT_STROKE_MITER_LIMIT,
             dash: None,
+            width_profile: None,
         }
     }
 }
@@ -616,7 +1085,7 @@ impl From<Color> for Option<Stroke> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::shapes::Rectangle;
+    use crate::shapes::{Corners, Rectangle};
 
     #[test]
     fn color_converts_to_paint_fill_and_stroke() {
@@ -638,6 +1107,7 @@ mod tests {
     fn boxed_vector_component_clone_is_dyn_equal() {
         let original: Box<dyn VectorComponent> = Box::new(Rectangle {
             size: Vec2(10.0, 20.0),
+            corners: Corners::SQUARE,
             fill: Option::<Fill>::from(Color::rgb_u8(1, 2, 3)),
             stroke: None,
         });
@@ -666,14 +1136,62 @@ mod tests {
                 join: StrokeJoin::Round,
                 miter_limit: DEFAULT_STROKE_MITER_LIMIT,
                 dash: None,
+                width_profile: None,
             })
         );
     }
 
+    #[test]
+    fn width_profile_sorts_stops_and_interpolates_between_them() {
+        let profile = WidthProfile::new([(1.0, 2.0), (0.0, 6.0), (0.5, f32::NAN), (2.0, -1.0)]);
+
+        assert_eq!(profile.stops().len(), 3);
+        assert_eq!(profile.width_at(-1.0), 6.0);
+        assert_eq!(profile.width_at(0.5), 4.0);
+        // Stops past the end clamp onto it; the later one wins there.
+        assert_eq!(profile.width_at(1.0), 0.0);
+        assert_eq!(profile.max_width(), 6.0);
+    }
+
+    #[test]
+    fn with_width_profile_widens_the_stroke_to_the_profile_maximum() {
+        let stroke = Stroke::new(Color::rgb_u8(0, 0, 0), 1.0)
+            .with_width_profile(WidthProfile::tapered(8.0, 0.2));
+        let profile = stroke.width_profile.as_ref().unwrap();
+
+        assert_eq!(stroke.width, 8.0);
+        assert_eq!(profile.width_at(0.0), 0.0);
+        assert_eq!(profile.width_at(0.1), 4.0);
+        assert_eq!(profile.width_at(0.5), 8.0);
+        assert!(stroke.is_visible());
+    }
+
+    #[test]
+    fn width_profile_outline_is_only_produced_for_profiled_strokes() {
+        let mut path = Path {
+            commands: vec![
+                PathCommand::MoveTo(Vec2(0.0, 0.0)),
+                PathCommand::LineTo(Vec2(10.0, 0.0)),
+            ],
+            fill: None,
+            fill_rule: FillRule::NonZero,
+            stroke: Some(Stroke::new(Color::rgb_u8(0, 0, 0), 2.0)),
+            transform: Transform::IDENTITY,
+        };
+        assert_eq!(path.width_profile_outline(Transform::IDENTITY), None);
+
+        path.stroke = path
+            .stroke
+            .map(|stroke| stroke.with_width_profile(WidthProfile::new([(0.0, 2.0)])));
+        let outline = path.width_profile_outline(Transform::IDENTITY).unwrap();
+        assert!(matches!(outline.first(), Some(PathCommand::MoveTo(_))));
+    }
+
     #[test]
     fn transformed_layout_is_layout_neutral() {
         let transformed = Rectangle {
             size: Vec2(10.0, 20.0),
+            corners: Corners::SQUARE,
             fill: None,
             stroke: None,
         }
@@ -700,6 +1218,7 @@ mod tests {
     fn transformed_bounds_do_not_shrink_the_layout_box() {
         let transformed = Rectangle {
             size: Vec2(4.0, 20.0),
+            corners: Corners::SQUARE,
             fill: None,
             stroke: None,
         }
@@ -726,6 +1245,7 @@ mod tests {
         let angle = 0.5_f32;
         let transformed = Rectangle {
             size: Vec2(4.0, 2.0),
+            corners: Corners::SQUARE,
             fill: None,
             stroke: None,
         }
@@ -747,6 +1267,7 @@ mod tests {
         let transform = Transform::rotate(0.5);
         let transformed = Rectangle {
             size: Vec2(4.0, 2.0),
+            corners: Corners::SQUARE,
             fill: None,
             stroke: None,
         }
@@ -765,6 +1286,7 @@ mod tests {
         for opacity in [0.0, -1.0, f32::NAN] {
             let transformed = Rectangle {
                 size: Vec2(1.0, 1.0),
+                corners: Corners::SQUARE,
                 fill: Option::<Fill>::from(Color::rgb_u8(255, 0, 0)),
                 stroke: None,
             }
@@ -779,6 +1301,7 @@ mod tests {
         // to an empty node while keeping its layout box as the view box.
         let bare = Rectangle {
             size: Vec2(4.0, 2.0),
+            corners: Corners::SQUARE,
             fill: None,
             stroke: None,
         };
@@ -786,6 +1309,7 @@ mod tests {
 
         let ghost = Rectangle {
             size: Vec2(4.0, 2.0),
+            corners: Corners::SQUARE,
             fill: Option::<Fill>::from(Color::rgba_u8(255, 0, 0, 0)),
             stroke: None,
         };
@@ -798,6 +1322,7 @@ mod tests {
     fn invisible_fill_is_dropped_but_visible_stroke_keeps_painting() {
         let outlined = Rectangle {
             size: Vec2(4.0, 2.0),
+            corners: Corners::SQUARE,
             fill: Option::<Fill>::from(Color::rgba_u8(255, 0, 0, 0)),
             stroke: Option::<Stroke>::from(Color::rgb_u8(0, 0, 0)),
         };
@@ -942,4 +1467,80 @@ mod tests {
         let miter = round.with_join(StrokeJoin::Miter);
         assert_ne!(miter, miter.clone().with_miter_limit(8.0));
     }
+
+    #[test]
+    fn gradient_stops_normalize_by_clamping_and_stable_sorting() {
+        let red = Color::rgb_u8(255, 0, 0);
+        let blue = Color::rgb_u8(0, 0, 255);
+        let green = Color::rgb_u8(0, 255, 0);
+        let gradient = LinearGradient::new(
+            Vec2(0.0, 0.0),
+            Vec2(1.0, 0.0),
+            vec![
+                GradientStop::new(1.5, blue),
+                GradientStop::new(0.5, red),
+                GradientStop::new(0.5, green),
+                GradientStop::new(-1.0, red),
+            ],
+        );
+        assert_eq!(
+            gradient.normalized_stops(),
+            vec![
+                GradientStop::new(0.0, red),
+                GradientStop::new(0.5, red),
+                GradientStop::new(0.5, green),
+                GradientStop::new(1.0, blue),
+            ]
+        );
+    }
+
+    #[test]
+    fn gradient_visibility_follows_its_stops() {
+        let clear = Color::rgba_u8(255, 0, 0, 0);
+        let red = Color::rgb_u8(255, 0, 0);
+        let center = Vec2(5.0, 5.0);
+
+        assert!(!Paint::from(SweepGradient::new(center, vec![])).is_visible());
+        assert!(!Paint::from(SweepGradient::evenly(center, [clear, clear])).is_visible());
+        assert!(Paint::from(SweepGradient::evenly(center, [clear, red])).is_visible());
+        assert!(!Paint::from(RadialGradient::evenly(center, 0.0, [red, red])).is_visible());
+    }
+
+    #[test]
+    fn evenly_spaced_stops_span_zero_to_one() {
+        let colors = [Color::rgb_u8(0, 0, 0); 3];
+        let gradient = LinearGradient::evenly(Vec2(0.0, 0.0), Vec2(1.0, 0.0), colors);
+        let offsets: Vec<f32> = gradient.stops.iter().map(|s| s.offset).collect();
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0]);
+    }
+
+    #[test]
+    fn image_pattern_fit_maps_the_image_onto_the_rect() {
+        let image = CpuRasterImage::new(4, 2, PixelFormat::Rgba8, vec![255; 4 * 2 * 4]);
+        let pattern = ImagePattern::new(image).fit_to(Rect {
+            origin: Vec2(10.0, 20.0),
+            size: Vec2(40.0, 10.0),
+        });
+        assert_eq!(
+            pattern.transform.transform_point(Vec2::ZERO),
+            Vec2(10.0, 20.0)
+        );
+        assert_eq!(
+            pattern.transform.transform_point(Vec2(4.0, 2.0)),
+            Vec2(50.0, 30.0)
+        );
+    }
+
+    #[test]
+    fn image_pattern_draws_only_packed_rgba8_pixels() {
+        let packed = CpuRasterImage::new(2, 2, PixelFormat::Rgba8, vec![0; 16]);
+        assert!(Paint::from(ImagePattern::new(packed)).is_visible());
+
+        let short = CpuRasterImage::new(2, 2, PixelFormat::Rgba8, vec![0; 15]);
+        assert!(!ImagePattern::new(short).is_drawable());
+        let empty = CpuRasterImage::new(0, 2, PixelFormat::Rgba8, vec![]);
+        assert!(!ImagePattern::new(empty).is_drawable());
+        let hdr = CpuRasterImage::new(1, 1, PixelFormat::Rgba16Float, vec![0; 8]);
+        assert!(!ImagePattern::new(hdr).is_drawable());
+    }
 }
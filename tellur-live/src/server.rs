@@ -1685,7 +1685,7 @@ fn log_cache_metrics_delta(before: &CacheMetrics, after: &CacheMetrics) {
     let gpu_before = &before.gpu;
     let gpu_after = &after.gpu;
     println!(
-        "video-stream-cache-delta hits={} misses={} hit_rate={:.1}% cache_size={} evicted_delta={} pressure_skips_delta={} oversize_skips_delta={} budget_skips_delta={} gpu_ops={} gpu_composites={} gpu_shadows={} gpu_outlines={} gpu_rasterizes={} gpu_fills={} gpu_temporal_avg={} gpu_readbacks={} gpu_vram_failures={} gpu_cache={}/{} vram={}/{}",
+        "video-stream-cache-delta hits={} misses={} hit_rate={:.1}% cache_size={} evicted_delta={} pressure_skips_delta={} oversize_skips_delta={} budget_skips_delta={} gpu_ops={} gpu_composites={} gpu_shadows={} gpu_outlines={} gpu_blurs={} gpu_glows={} gpu_blooms={} gpu_grades={} gpu_mattes={} gpu_chroma_keys={} gpu_resamples={} gpu_projections={} gpu_depth_composites={} gpu_warps={} gpu_grains={} gpu_dithers={} gpu_patterns={} gpu_rasterizes={} gpu_fills={} gpu_temporal_avg={} gpu_readbacks={} gpu_vram_failures={} gpu_cache={}/{} vram={}/{}",
         hits,
         misses,
         hit_rate * 100.0,
@@ -1698,6 +1698,27 @@ fn log_cache_metrics_delta(before: &CacheMetrics, after: &CacheMetrics) {
         gpu_after.composites.saturating_sub(gpu_before.composites),
         gpu_after.drop_shadows.saturating_sub(gpu_before.drop_shadows),
         gpu_after.outlines.saturating_sub(gpu_before.outlines),
+        gpu_after.blurs.saturating_sub(gpu_before.blurs),
+        gpu_after.glows.saturating_sub(gpu_before.glows),
+        gpu_after.blooms.saturating_sub(gpu_before.blooms),
+        gpu_after
+            .color_grades
+            .saturating_sub(gpu_before.color_grades),
+        gpu_after.mattes.saturating_sub(gpu_before.mattes),
+        gpu_after
+            .chroma_keys
+            .saturating_sub(gpu_before.chroma_keys),
+        gpu_after.resamples.saturating_sub(gpu_before.resamples),
+        gpu_after
+            .projections
+            .saturating_sub(gpu_before.projections),
+        gpu_after
+            .depth_composites
+            .saturating_sub(gpu_before.depth_composites),
+        gpu_after.warps.saturating_sub(gpu_before.warps),
+        gpu_after.grains.saturating_sub(gpu_before.grains),
+        gpu_after.dithers.saturating_sub(gpu_before.dithers),
+        gpu_after.patterns.saturating_sub(gpu_before.patterns),
         gpu_after.rasterizes.saturating_sub(gpu_before.rasterizes),
         gpu_after.fills.saturating_sub(gpu_before.fills),
         gpu_after
@@ -0,0 +1,17 @@
+//! Helpers shared by the examples.
+
+use std::fs::File;
+
+use tellur_core::geometry::Vec2;
+use tellur_core::vector::VectorComponent;
+
+/// When the example runs with `--svg`, also writes `component`'s vector
+/// frame at `size` to `path` as SVG.
+pub fn write_svg_if_requested(component: &dyn VectorComponent, size: Vec2, path: &str) {
+    if !std::env::args().any(|arg| arg == "--svg") {
+        return;
+    }
+    let file = File::create(path).expect("create SVG output file");
+    component.render(size).export_svg(file).expect("export SVG");
+    println!("Wrote {}", path);
+}
@@ -5,6 +5,8 @@
 //! ```sh
 //! cargo run -p tellur-renderer --example math_to_png --features latex
 //! ```
+//!
+//! Pass `--svg` to also write `/tmp/math.svg`.
 
 use std::fs::File;
 
@@ -20,6 +22,8 @@ use tellur_core::text::{Text, SERIF};
 use tellur_core::vector::Paint;
 use tellur_renderer::Rasterizable;
 
+mod common;
+
 fn main() {
     let scene_size = Vec2(1400.0, 360.0);
     let scene = VectorLayer::builder()
@@ -44,6 +48,8 @@ fn main() {
         )
         .build();
 
+    common::write_svg_if_requested(&scene, scene_size, "/tmp/math.svg");
+
     let image = scene.rasterize().render(
         scene_size,
         Resolution::new(scene_size.0 as u32, scene_size.1 as u32),
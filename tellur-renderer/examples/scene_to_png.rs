@@ -1,4 +1,6 @@
 //! Compose a two-shape scene in a 16:9 layer and write it to PNG.
+//!
+//! Pass `--svg` to also write `/tmp/scene.svg`.
 
 use std::fs::File;
 
@@ -12,6 +14,8 @@ use tellur_core::shapes::{Circle, Rectangle};
 use tellur_core::vector::Paint;
 use tellur_renderer::Rasterizable;
 
+mod common;
+
 fn main() {
     let scene_size = Vec2(1280.0, 720.0);
     let scene = VectorLayer::builder()
@@ -38,6 +42,8 @@ fn main() {
         )
         .build();
 
+    common::write_svg_if_requested(&scene, scene_size, "/tmp/scene.svg");
+
     let image = scene.rasterize().render(
         scene_size,
         Resolution::new(1280, 720),
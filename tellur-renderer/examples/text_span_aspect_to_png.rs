@@ -1,4 +1,6 @@
 //! Render text spans with independent X/Y glyph scale, then write PNG output.
+//!
+//! Pass `--svg` to also write `/tmp/text_span_aspect.svg`.
 
 use std::fs::File;
 
@@ -13,6 +15,8 @@ use tellur_core::text::{Text, TextSpan, SANS_SERIF};
 use tellur_core::vector::Paint;
 use tellur_renderer::Rasterizable;
 
+mod common;
+
 fn main() {
     let scene_size = Vec2(1600.0, 900.0);
     let background = Paint::Solid(Color::rgb_u8(248, 248, 244));
@@ -63,6 +67,8 @@ fn main() {
         )
         .build();
 
+    common::write_svg_if_requested(&scene, scene_size, "/tmp/text_span_aspect.svg");
+
     let image = scene.rasterize().render(
         scene_size,
         Resolution::new(1600, 900),
@@ -1,6 +1,8 @@
 //! Render "Hello world!" with the middle span in red, centered on a
 //! 1920x1080 white canvas, then write the result to PNG. Uses the
 //! system default sans-serif font resolved via fontconfig.
+//!
+//! Pass `--svg` to also write `/tmp/text.svg`.
 
 use std::fs::File;
 
@@ -15,6 +17,8 @@ use tellur_core::text::{Text, TextSpan, SANS_SERIF};
 use tellur_core::vector::Paint;
 use tellur_renderer::Rasterizable;
 
+mod common;
+
 fn main() {
     let scene_size = Vec2(1920.0, 1080.0);
     let scene = VectorLayer::builder()
@@ -42,6 +46,8 @@ fn main() {
         )
         .build();
 
+    common::write_svg_if_requested(&scene, scene_size, "/tmp/text.svg");
+
     let image = scene.rasterize().render(
         scene_size,
         Resolution::new(1920, 1080),
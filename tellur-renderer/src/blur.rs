@@ -0,0 +1,471 @@
+//! Gaussian blur effect for raster components.
+//!
+//! Wraps a `RasterComponent` and blurs its full-color pixels, either evenly
+//! in every direction or along a single direction for a motion-style streak.
+//! The blur runs on premultiplied color so transparent surroundings do not
+//! bleed dark fringes into the result. `paint_bounds` expands by the blur's
+//! reach so the surrounding `Layer` allocates enough pixels; `layout_box` is
+//! left unchanged so blurring does not disturb layout.
+
+use tellur_core::composite::composite_at;
+use tellur_core::geometry::{Constraints, Rect, Vec2};
+use tellur_core::raster::{
+    CpuRasterImage, PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution,
+};
+use tellur_core::render_context::{BlurInput, RenderContext};
+use tellur_core::Keyable;
+
+use crate::shadow::{blank_image, BLUR_EXTENT_MULTIPLIER};
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Blur {
+    /// Gaussian-equivalent blur radius (logical units). `0.0` leaves the
+    /// child untouched, and the blur grows smoothly from there.
+    pub radius: f32,
+    /// Blur only along this direction, for a directional or motion-style
+    /// blur. Only the angle matters; `None` blurs in every direction.
+    pub direction: Option<Vec2>,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl Blur {
+    /// Logical-unit pass vectors; see [`BlurInput::axes`].
+    fn axes(&self) -> [Vec2; 2] {
+        let radius = if self.radius.is_finite() {
+            self.radius.max(0.0)
+        } else {
+            0.0
+        };
+        match self.direction {
+            None => [Vec2(radius, 0.0), Vec2(0.0, radius)],
+            Some(Vec2(dx, dy)) => {
+                let length = (dx * dx + dy * dy).sqrt();
+                if length > f32::EPSILON && length.is_finite() {
+                    [Vec2(dx / length * radius, dy / length * radius), Vec2::ZERO]
+                } else {
+                    [Vec2::ZERO, Vec2::ZERO]
+                }
+            }
+        }
+    }
+}
+
+impl RasterComponent for Blur {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        let inner = self.child.paint_bounds(size);
+        let [a, b] = self.axes();
+        let extent_x = (a.0.abs() + b.0.abs()) * BLUR_EXTENT_MULTIPLIER;
+        let extent_y = (a.1.abs() + b.1.abs()) * BLUR_EXTENT_MULTIPLIER;
+        Rect {
+            origin: Vec2(inner.origin.0 - extent_x, inner.origin.1 - extent_y),
+            size: Vec2(inner.size.0 + 2.0 * extent_x, inner.size.1 + 2.0 * extent_y),
+        }
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let paint = self.paint_bounds(size);
+        let child_paint = self.child.paint_bounds(size);
+        if paint.size.0 <= 0.0 || paint.size.1 <= 0.0 {
+            return ctx.ensure_residency(blank_image(target), residency);
+        }
+        let sx = target.width as f32 / paint.size.0;
+        let sy = target.height as f32 / paint.size.1;
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+
+        // Render the child through the context so its output is memoized
+        // independently of the blur; animating the radius then only
+        // re-runs the blur passes.
+        let child_px_w = (child_paint.size.0 * sx).round().max(1.0) as u32;
+        let child_px_h = (child_paint.size.1 * sy).round().max(1.0) as u32;
+        let child_image = ctx.render(
+            self.child.as_ref(),
+            size,
+            Resolution::new(child_px_w, child_px_h),
+            if gpu_available {
+                RasterResidency::Gpu
+            } else {
+                RasterResidency::Cpu
+            },
+        );
+
+        let child_px_x = ((child_paint.origin.0 - paint.origin.0) * sx).round() as i32;
+        let child_px_y = ((child_paint.origin.1 - paint.origin.1) * sy).round() as i32;
+        // Pass vectors in pixels. Radii stay fractional: rounding them
+        // would make an animated radius step instead of glide.
+        let axes = self.axes().map(|axis| Vec2(axis.0 * sx, axis.1 * sy));
+
+        if gpu_available {
+            let input = BlurInput {
+                child: &child_image,
+                target,
+                child_offset_x: child_px_x,
+                child_offset_y: child_px_y,
+                axes,
+            };
+            if let Some(gpu) = ctx.gpu_backend() {
+                if let Some(image) = gpu.blur(input) {
+                    return ctx.ensure_residency(image, residency);
+                }
+            }
+        }
+
+        let child_image = ctx.readback(child_image);
+        let image = blur_image(&child_image, target, child_px_x, child_px_y, axes);
+        ctx.ensure_residency(RasterImage::Cpu(image), residency)
+    }
+}
+
+/// CPU counterpart of [`GpuRasterBackend::blur`](tellur_core::render_context::GpuRasterBackend::blur):
+/// places `image` at the offset in a `target`-sized transparent image and
+/// blurs it with the three rounds of box passes along `axes`.
+pub(crate) fn blur_image(
+    image: &CpuRasterImage,
+    target: Resolution,
+    offset_x: i32,
+    offset_y: i32,
+    axes: [Vec2; 2],
+) -> CpuRasterImage {
+    assert_eq!(image.format, PixelFormat::Rgba8);
+    if axes.iter().all(|axis| axis.0 == 0.0 && axis.1 == 0.0) {
+        let mut out = vec![0u8; (target.width as usize) * (target.height as usize) * 4];
+        composite_at(&mut out, target, image, offset_x, offset_y);
+        return CpuRasterImage::new(target.width, target.height, PixelFormat::Rgba8, out);
+    }
+
+    let plane = premultiplied_plane(image, target, offset_x, offset_y);
+    let plane = blur_plane(plane, target, axes);
+
+    let mut out = Vec::with_capacity(plane.len() * 4);
+    for texel in &plane {
+        out.extend_from_slice(&unpremultiply(*texel));
+    }
+    CpuRasterImage::new(target.width, target.height, PixelFormat::Rgba8, out)
+}
+
+/// Runs the three rounds of box passes along `axes` over a premultiplied
+/// `target`-sized plane, skipping zero vectors.
+pub(crate) fn blur_plane(
+    mut plane: Vec<[f32; 4]>,
+    target: Resolution,
+    axes: [Vec2; 2],
+) -> Vec<[f32; 4]> {
+    let width = target.width as usize;
+    let height = target.height as usize;
+    let mut scratch = vec![[0.0f32; 4]; plane.len()];
+    for _ in 0..3 {
+        for axis in axes {
+            if axis.0 == 0.0 && axis.1 == 0.0 {
+                continue;
+            }
+            box_pass(&plane, &mut scratch, width, height, axis);
+            std::mem::swap(&mut plane, &mut scratch);
+        }
+    }
+    plane
+}
+
+/// Places the straight-alpha `image` at the offset in a premultiplied
+/// `target`-sized plane.
+pub(crate) fn premultiplied_plane(
+    image: &CpuRasterImage,
+    target: Resolution,
+    offset_x: i32,
+    offset_y: i32,
+) -> Vec<[f32; 4]> {
+    let width = target.width as usize;
+    let mut plane = vec![[0.0f32; 4]; width * target.height as usize];
+    let pixels = image.pixels.as_ref();
+    for y in 0..image.height as i32 {
+        let dst_y = y + offset_y;
+        if dst_y < 0 || dst_y >= target.height as i32 {
+            continue;
+        }
+        for x in 0..image.width as i32 {
+            let dst_x = x + offset_x;
+            if dst_x < 0 || dst_x >= target.width as i32 {
+                continue;
+            }
+            let src = ((y as usize) * image.width as usize + x as usize) * 4;
+            let alpha = pixels[src + 3] as f32 / 255.0;
+            plane[dst_y as usize * width + dst_x as usize] = [
+                pixels[src] as f32 / 255.0 * alpha,
+                pixels[src + 1] as f32 / 255.0 * alpha,
+                pixels[src + 2] as f32 / 255.0 * alpha,
+                alpha,
+            ];
+        }
+    }
+    plane
+}
+
+pub(crate) fn unpremultiply(texel: [f32; 4]) -> [u8; 4] {
+    let alpha = texel[3].clamp(0.0, 1.0);
+    let a = (alpha * 255.0).round() as u8;
+    if a == 0 {
+        return [0; 4];
+    }
+    let channel = |c: f32| ((c / alpha).clamp(0.0, 1.0) * 255.0).round() as u8;
+    [channel(texel[0]), channel(texel[1]), channel(texel[2]), a]
+}
+
+/// One box pass of radius `|axis|` pixels along `axis`. Taps fall at whole
+/// steps along the axis and are sampled bilinearly; pixels outside the
+/// plane count as transparent. The fractional part of the radius weights the
+/// outermost pair of taps.
+fn box_pass(src: &[[f32; 4]], dst: &mut [[f32; 4]], width: usize, height: usize, axis: Vec2) {
+    let radius = (axis.0 * axis.0 + axis.1 * axis.1).sqrt();
+    let step = Vec2(axis.0 / radius, axis.1 / radius);
+    let whole = radius.floor();
+    let taps = whole as i32;
+    let edge = radius - whole;
+    let norm = 1.0 / (2.0 * whole + 1.0 + 2.0 * edge);
+
+    if step.1 == 0.0 || step.0 == 0.0 {
+        let horizontal = step.1 == 0.0;
+        let (len, lines) = if horizontal {
+            (width, height)
+        } else {
+            (height, width)
+        };
+        let index = |line: usize, i: usize| {
+            if horizontal {
+                line * width + i
+            } else {
+                i * width + line
+            }
+        };
+        let at = |line: usize, i: i64| -> [f64; 4] {
+            if i < 0 || i >= len as i64 {
+                [0.0; 4]
+            } else {
+                src[index(line, i as usize)].map(f64::from)
+            }
+        };
+        for line in 0..lines {
+            // Sliding window over the whole taps; the edge taps are added
+            // per pixel.
+            let mut sum = [0.0f64; 4];
+            for i in -(taps as i64)..=taps as i64 {
+                add(&mut sum, at(line, i), 1.0);
+            }
+            for i in 0..len as i64 {
+                let mut total = sum;
+                if edge > 0.0 {
+                    add(&mut total, at(line, i - taps as i64 - 1), edge as f64);
+                    add(&mut total, at(line, i + taps as i64 + 1), edge as f64);
+                }
+                dst[index(line, i as usize)] = total.map(|c| (c * norm as f64) as f32);
+                add(&mut sum, at(line, i + taps as i64 + 1), 1.0);
+                add(&mut sum, at(line, i - taps as i64), -1.0);
+            }
+        }
+        return;
+    }
+
+    let sample = |x: f32, y: f32| -> [f32; 4] {
+        let (fx, fy) = (x.floor(), y.floor());
+        let (tx, ty) = (x - fx, y - fy);
+        let (x0, y0) = (fx as i64, fy as i64);
+        let texel = |x: i64, y: i64| {
+            if x < 0 || y < 0 || x >= width as i64 || y >= height as i64 {
+                [0.0; 4]
+            } else {
+                src[y as usize * width + x as usize]
+            }
+        };
+        let top = mix(texel(x0, y0), texel(x0 + 1, y0), tx);
+        let bottom = mix(texel(x0, y0 + 1), texel(x0 + 1, y0 + 1), tx);
+        mix(top, bottom, ty)
+    };
+    for y in 0..height {
+        for x in 0..width {
+            let mut sum = [0.0f32; 4];
+            for k in -taps..=taps {
+                let k = k as f32;
+                let texel = sample(x as f32 + k * step.0, y as f32 + k * step.1);
+                for c in 0..4 {
+                    sum[c] += texel[c];
+                }
+            }
+            if edge > 0.0 {
+                let k = (taps + 1) as f32;
+                let before = sample(x as f32 - k * step.0, y as f32 - k * step.1);
+                let after = sample(x as f32 + k * step.0, y as f32 + k * step.1);
+                for c in 0..4 {
+                    sum[c] += edge * (before[c] + after[c]);
+                }
+            }
+            dst[y * width + x] = sum.map(|c| c * norm);
+        }
+    }
+}
+
+fn add(sum: &mut [f64; 4], texel: [f64; 4], weight: f64) {
+    for c in 0..4 {
+        sum[c] += texel[c] * weight;
+    }
+}
+
+fn mix(a: [f32; 4], b: [f32; 4], t: f32) -> [f32; 4] {
+    [
+        a[0] * (1.0 - t) + b[0] * t,
+        a[1] * (1.0 - t) + b[1] * t,
+        a[2] * (1.0 - t) + b[2] * t,
+        a[3] * (1.0 - t) + b[3] * t,
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{pixel, square};
+    use tellur_core::render_context::PassThrough;
+
+    fn render(blur: &Blur, scale: f32) -> CpuRasterImage {
+        let size = Vec2(8.0, 8.0);
+        let paint = blur.paint_bounds(size);
+        let target = Resolution::new(
+            (paint.size.0 * scale).round() as u32,
+            (paint.size.1 * scale).round() as u32,
+        );
+        let image = blur.render(size, target, RasterResidency::Cpu, &mut PassThrough);
+        image.as_cpu().expect("CPU render").clone()
+    }
+
+    #[test]
+    fn paint_bounds_grow_by_the_blur_reach() {
+        let even = Blur::builder().radius(2.0).child(square([0; 4])).build();
+        let sideways = Blur::builder()
+            .radius(2.0)
+            .direction(Vec2(-3.0, 0.0))
+            .child(square([0; 4]))
+            .build();
+
+        let even = even.paint_bounds(Vec2(8.0, 8.0));
+        let sideways = sideways.paint_bounds(Vec2(8.0, 8.0));
+
+        assert_eq!((even.origin.0, even.origin.1), (-6.0, -6.0));
+        assert_eq!((even.size.0, even.size.1), (20.0, 20.0));
+        assert_eq!((sideways.origin.0, sideways.origin.1), (-6.0, 0.0));
+        assert_eq!((sideways.size.0, sideways.size.1), (20.0, 8.0));
+    }
+
+    #[test]
+    fn zero_radius_returns_the_child_unchanged() {
+        let blur = Blur::builder()
+            .radius(0.0)
+            .child(square([10, 20, 30, 200]))
+            .build();
+
+        let image = render(&blur, 1.0);
+
+        assert_eq!((image.width, image.height), (8, 8));
+        assert!(image
+            .pixels
+            .chunks_exact(4)
+            .all(|px| px == [10, 20, 30, 200]));
+    }
+
+    #[test]
+    fn blur_spreads_color_without_darkening_edges() {
+        let blur = Blur::builder()
+            .radius(1.0)
+            .child(square([200, 40, 10, 255]))
+            .build();
+
+        // The square spans 3..11 of a 14-pixel target and the blur reaches
+        // three pixels, so the center stays untouched.
+        let image = render(&blur, 1.0);
+        let center = pixel(&image, 7, 7);
+        let edge = pixel(&image, 3, 7);
+        let outside = pixel(&image, 1, 7);
+
+        assert_eq!(center, [200, 40, 10, 255]);
+        // Premultiplied blurring fades alpha but keeps the straight color.
+        assert!(edge[3] > 64 && edge[3] < 192, "{edge:?}");
+        assert_eq!(&edge[..3], &[200, 40, 10]);
+        assert!(outside[3] > 0 && outside[3] < edge[3], "{outside:?}");
+        assert_eq!(pixel(&image, 0, 0), [0; 4]);
+    }
+
+    #[test]
+    fn directional_blur_only_spreads_along_its_direction() {
+        let blur = Blur::builder()
+            .radius(2.0)
+            .direction(Vec2(1.0, 0.0))
+            .child(square([255, 255, 255, 255]))
+            .build();
+
+        let image = render(&blur, 1.0);
+
+        assert_eq!((image.width, image.height), (20, 8));
+        // Every row matches: nothing spreads vertically.
+        for y in 1..8 {
+            for x in 0..20 {
+                assert_eq!(pixel(&image, x, y), pixel(&image, x, 0));
+            }
+        }
+        assert!(pixel(&image, 3, 4)[3] > 0);
+    }
+
+    #[test]
+    fn diagonal_blur_matches_its_mirror_image() {
+        let blur = Blur::builder()
+            .radius(1.5)
+            .direction(Vec2(1.0, 1.0))
+            .child(square([255, 255, 255, 255]))
+            .build();
+
+        let image = render(&blur, 1.0);
+
+        assert_eq!(image.width, image.height);
+        let n = image.width;
+        for y in 0..n {
+            for x in 0..n {
+                let a = pixel(&image, x, y)[3] as i32;
+                let b = pixel(&image, y, x)[3] as i32;
+                assert!((a - b).abs() <= 1, "({x}, {y}): {a} vs {b}");
+            }
+        }
+        // Spread along the diagonal, not across it.
+        assert!(pixel(&image, 1, 1)[3] > pixel(&image, n - 2, 1)[3]);
+    }
+
+    #[test]
+    fn fractional_radius_grows_smoothly_from_zero() {
+        let edge_alpha = |radius: f32| {
+            let blur = Blur::builder()
+                .radius(radius)
+                .direction(Vec2(1.0, 0.0))
+                .child(square([255, 255, 255, 255]))
+                .build();
+            let paint = blur.paint_bounds(Vec2(8.0, 8.0));
+            let image = render(&blur, 1.0);
+            // The pixel just inside the square's left edge.
+            let x = (-paint.origin.0).round() as u32;
+            pixel(&image, x, 4)[3]
+        };
+
+        let alphas: Vec<u8> = [0.0, 0.01, 0.1, 0.2].into_iter().map(edge_alpha).collect();
+
+        assert_eq!(alphas[0], 255);
+        assert!(alphas[1] >= 245, "{alphas:?}");
+        assert!(
+            alphas.windows(2).all(|pair| pair[1] < pair[0]),
+            "{alphas:?}"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! Chroma-key effect for raster components.
+//!
+//! [`ChromaKey`] removes a backdrop color — a green or blue screen — from its
+//! child: pixels near the key in chroma become transparent over a soft ramp,
+//! the key's color spill is suppressed on what remains, and the opaque edge
+//! can be choked inward. It is per-pixel apart from the choke, so `layout`
+//! and `paint_bounds` forward to the child. The keying itself is
+//! [`ChromaKeySettings::apply`], shared with the timeline's
+//! [`Keyed`](tellur_core::timeline_component::Keyed) wrapper for video.
+
+use tellur_core::chroma_key::ChromaKeySettings;
+use tellur_core::color::Color;
+use tellur_core::geometry::{Constraints, Rect, Vec2};
+use tellur_core::raster::{RasterComponent, RasterImage, RasterResidency, Resolution};
+use tellur_core::render_context::RenderContext;
+use tellur_core::Keyable;
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct ChromaKey {
+    /// The backdrop color to remove.
+    #[builder(default = Color::rgb_u8(0, 255, 0))]
+    pub key_color: Color,
+    /// Chroma distance from the key within which pixels are fully removed.
+    #[builder(default = 0.1)]
+    pub tolerance: f32,
+    /// Chroma distance beyond `tolerance` over which alpha ramps back up.
+    #[builder(default = 0.1)]
+    pub softness: f32,
+    /// How much of the key's hue is removed from the kept pixels (`0`–`1`).
+    #[builder(default = 1.0)]
+    pub spill: f32,
+    /// How far the opaque edge is pulled inward (logical units).
+    #[builder(default = 0.0)]
+    pub choke: f32,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl ChromaKey {
+    /// The fields as one [`ChromaKeySettings`].
+    pub fn settings(&self) -> ChromaKeySettings {
+        ChromaKeySettings {
+            key_color: self.key_color,
+            tolerance: self.tolerance,
+            softness: self.softness,
+            spill: self.spill,
+            choke: self.choke,
+        }
+    }
+}
+
+impl RasterComponent for ChromaKey {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.child.paint_bounds(size)
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+        let image = ctx.render(
+            self.child.as_ref(),
+            size,
+            target,
+            if gpu_available {
+                RasterResidency::Gpu
+            } else {
+                RasterResidency::Cpu
+            },
+        );
+        let paint = self.paint_bounds(size);
+        let scale = if paint.size.0 > 0.0 {
+            target.width as f32 / paint.size.0
+        } else {
+            1.0
+        };
+        self.settings().apply(image, scale, residency, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Square;
+    use tellur_core::raster::CpuRasterImage;
+    use tellur_core::render_context::PassThrough;
+
+    const SCREEN: [u8; 4] = [24, 190, 60, 255];
+    const SUBJECT: [u8; 4] = [180, 120, 90, 255];
+
+    /// A synthetic 6×6 green-screen shot: a 2×2 subject in the middle of an
+    /// unevenly lit screen.
+    fn green_screen() -> Box<dyn RasterComponent> {
+        let mut pixels = Vec::new();
+        for y in 0..6 {
+            for x in 0..6 {
+                if (2..4).contains(&x) && (2..4).contains(&y) {
+                    pixels.extend_from_slice(&SUBJECT);
+                } else {
+                    // Falloff toward the corners darkens the screen.
+                    let shade = 1.0 - 0.03 * (x + y) as f32;
+                    let [r, g, b, _] = SCREEN.map(|v| (f32::from(v) * shade) as u8);
+                    pixels.extend_from_slice(&[r, g, b, 255]);
+                }
+            }
+        }
+        Box::new(Square::from_pixels(6, 6, pixels))
+    }
+
+    fn render(component: &dyn RasterComponent) -> CpuRasterImage {
+        let mut ctx = PassThrough;
+        match component.render(
+            Vec2(6.0, 6.0),
+            Resolution::new(6, 6),
+            RasterResidency::Cpu,
+            &mut ctx,
+        ) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        }
+    }
+
+    fn alpha_grid(image: &CpuRasterImage) -> Vec<u8> {
+        image.pixels.chunks_exact(4).map(|px| px[3]).collect()
+    }
+
+    fn key(choke: f32) -> ChromaKey {
+        ChromaKey::builder()
+            .key_color(Color::rgb_u8(SCREEN[0], SCREEN[1], SCREEN[2]))
+            .tolerance(0.15)
+            .choke(choke)
+            .child(green_screen())
+            .build()
+    }
+
+    #[test]
+    fn keys_out_an_unevenly_lit_screen_and_keeps_the_subject() {
+        let image = render(&key(0.0));
+        let alpha = alpha_grid(&image);
+        for (i, a) in alpha.iter().enumerate() {
+            let (x, y) = (i % 6, i / 6);
+            let subject = (2..4).contains(&x) && (2..4).contains(&y);
+            assert_eq!(*a, if subject { 255 } else { 0 }, "pixel ({x}, {y})");
+        }
+        // The subject leans away from green, so spill suppression leaves it.
+        assert_eq!(&image.pixels[(2 * 6 + 2) * 4..][..4], &SUBJECT);
+    }
+
+    #[test]
+    fn choke_is_measured_in_logical_units() {
+        // One logical unit of choke erodes the 2×2 subject away entirely at
+        // one pixel per unit.
+        assert!(alpha_grid(&render(&key(1.0))).iter().all(|&a| a == 0));
+    }
+}
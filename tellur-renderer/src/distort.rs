@@ -0,0 +1,362 @@
+//! Distortion effects for raster components.
+//!
+//! [`Distort`] warps its child with one of the procedural
+//! [`Distortion`]s — ripple, wave, twirl or bulge — and [`Displace`] pushes
+//! it around by another component's luminance, for liquid transitions and
+//! heat haze. Both are UV remaps: every output pixel shows some other point
+//! of the child, and points pulled from outside the child read as
+//! transparent. The child keeps its layout, while the paint bounds grow by
+//! as far as the warp can push content, so an edge moved outward still
+//! shows. The displacement map is laid out at the child's size and rendered
+//! through the context, like a [`Matte`](crate::Matte)'s.
+
+use tellur_core::distort::{displace_pixels, distort_pixels, Distortion, WarpPlacement};
+use tellur_core::geometry::{Constraints, Rect, Vec2};
+use tellur_core::raster::{PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution};
+use tellur_core::render_context::{RenderContext, WarpField, WarpInput};
+use tellur_core::vector::ImageQuality;
+use tellur_core::Keyable;
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Distort {
+    /// The warp applied to the child.
+    #[builder(into)]
+    pub distortion: Distortion,
+    /// How the child is filtered at the warped points.
+    #[builder(default)]
+    pub quality: ImageQuality,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl RasterComponent for Distort {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.distortion
+            .paint_bounds(self.child.paint_bounds(size), size)
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let paint = self.paint_bounds(size);
+        if paint.size.0 <= 0.0 || paint.size.1 <= 0.0 {
+            return ctx.render(self.child.as_ref(), size, target, residency);
+        }
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+        let (image, placement) =
+            render_child(self.child.as_ref(), size, paint, target, gpu_available, ctx);
+        if gpu_available {
+            let input = WarpInput {
+                image: &image,
+                placement,
+                size,
+                field: WarpField::Distort(self.distortion),
+                quality: self.quality,
+            };
+            if let Some(gpu) = ctx.gpu_backend() {
+                if let Some(warped) = gpu.warp(input) {
+                    return ctx.ensure_residency(warped, residency);
+                }
+            }
+        }
+
+        let image = ctx.readback(image);
+        let pixels = distort_pixels(&image, placement, size, &self.distortion, self.quality);
+        let image = RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, pixels);
+        ctx.ensure_residency(image, residency)
+    }
+}
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Displace {
+    /// The component whose luminance pushes the child: mid gray stays put,
+    /// white shows the point `amount` away and black the point `-amount`
+    /// away. Transparent areas do not displace.
+    #[builder(into)]
+    pub map: Box<dyn RasterComponent>,
+    /// Displacement at full white (logical units).
+    pub amount: Vec2,
+    /// How the child is filtered at the displaced points.
+    #[builder(default)]
+    pub quality: ImageQuality,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl RasterComponent for Displace {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        let inner = self.child.paint_bounds(size);
+        let reach = Vec2(self.amount.0.abs(), self.amount.1.abs());
+        Rect {
+            origin: Vec2(inner.origin.0 - reach.0, inner.origin.1 - reach.1),
+            size: Vec2(inner.size.0 + 2.0 * reach.0, inner.size.1 + 2.0 * reach.1),
+        }
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let paint = self.paint_bounds(size);
+        if paint.size.0 <= 0.0 || paint.size.1 <= 0.0 {
+            return ctx.render(self.child.as_ref(), size, target, residency);
+        }
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+        let child_residency = if gpu_available {
+            RasterResidency::Gpu
+        } else {
+            RasterResidency::Cpu
+        };
+        let (image, placement) =
+            render_child(self.child.as_ref(), size, paint, target, gpu_available, ctx);
+        // The map shares the child's layout box but keeps its own paint
+        // bounds, rendered at the child's pixel density.
+        let sx = target.width as f32 / paint.size.0;
+        let sy = target.height as f32 / paint.size.1;
+        let map_paint = self.map.paint_bounds(size);
+        let map_image = ctx.render(
+            self.map.as_ref(),
+            size,
+            Resolution::new(
+                (map_paint.size.0 * sx).round().max(1.0) as u32,
+                (map_paint.size.1 * sy).round().max(1.0) as u32,
+            ),
+            child_residency,
+        );
+        let map_offset = [
+            ((map_paint.origin.0 - paint.origin.0) * sx).round() as i32,
+            ((map_paint.origin.1 - paint.origin.1) * sy).round() as i32,
+        ];
+
+        if gpu_available {
+            let input = WarpInput {
+                image: &image,
+                placement,
+                size,
+                field: WarpField::Displace {
+                    map: &map_image,
+                    map_offset,
+                    amount: self.amount,
+                },
+                quality: self.quality,
+            };
+            if let Some(gpu) = ctx.gpu_backend() {
+                if let Some(warped) = gpu.warp(input) {
+                    return ctx.ensure_residency(warped, residency);
+                }
+            }
+        }
+
+        let image = ctx.readback(image);
+        let map_image = ctx.readback(map_image);
+        assert_eq!(
+            map_image.format,
+            PixelFormat::Rgba8,
+            "displacement maps only support straight-alpha Rgba8 images",
+        );
+        let pixels = displace_pixels(
+            &image,
+            placement,
+            &map_image,
+            map_offset,
+            self.amount,
+            self.quality,
+        );
+        let image = RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, pixels);
+        ctx.ensure_residency(image, residency)
+    }
+}
+
+/// Renders `child` over its own paint bounds at the density `target` gives
+/// the warp's wider `paint`, and where that image sits in the output.
+fn render_child(
+    child: &dyn RasterComponent,
+    size: Vec2,
+    paint: Rect,
+    target: Resolution,
+    gpu_available: bool,
+    ctx: &mut dyn RenderContext,
+) -> (RasterImage, WarpPlacement) {
+    let child_paint = child.paint_bounds(size);
+    let sx = target.width as f32 / paint.size.0;
+    let sy = target.height as f32 / paint.size.1;
+    let image = ctx.render(
+        child,
+        size,
+        Resolution::new(
+            (child_paint.size.0 * sx).round().max(1.0) as u32,
+            (child_paint.size.1 * sy).round().max(1.0) as u32,
+        ),
+        if gpu_available {
+            RasterResidency::Gpu
+        } else {
+            RasterResidency::Cpu
+        },
+    );
+    let placement = WarpPlacement {
+        paint,
+        target,
+        offset: [
+            ((child_paint.origin.0 - paint.origin.0) * sx).round() as i32,
+            ((child_paint.origin.1 - paint.origin.1) * sy).round() as i32,
+        ],
+    };
+    (image, placement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Square;
+    use tellur_core::distort::{Twirl, Wave};
+    use tellur_core::raster::CpuRasterImage;
+    use tellur_core::render_context::PassThrough;
+
+    /// Four vertical stripes in a 4×4 box.
+    fn stripes() -> Box<dyn RasterComponent> {
+        let row = [0, 60, 120, 180].map(|red| [red, 0, 0, 255]).concat();
+        Box::new(Square::from_pixels(4, 4, row.repeat(4)))
+    }
+
+    /// A flat map of one gray level, covering the same box.
+    fn flat(level: u8) -> Box<dyn RasterComponent> {
+        Box::new(Square::solid([level, level, level, 255], 4, 4))
+    }
+
+    /// Renders `component` in a 4×4 layout box at one pixel per unit.
+    fn render(component: &dyn RasterComponent) -> CpuRasterImage {
+        let mut ctx = PassThrough;
+        let paint = component.paint_bounds(Vec2(4.0, 4.0));
+        match component.render(
+            Vec2(4.0, 4.0),
+            Resolution::new(paint.size.0.round() as u32, paint.size.1.round() as u32),
+            RasterResidency::Cpu,
+            &mut ctx,
+        ) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        }
+    }
+
+    fn reds(image: &CpuRasterImage) -> Vec<u8> {
+        image.pixels.chunks_exact(4).map(|px| px[0]).collect()
+    }
+
+    #[test]
+    fn zero_strength_distortions_leave_the_child_unchanged() {
+        let flat = Distort::builder()
+            .distortion(Wave {
+                amplitude: 0.0,
+                ..Default::default()
+            })
+            .child(stripes())
+            .build();
+        let still = Distort::builder()
+            .distortion(Twirl {
+                angle: 0.0,
+                ..Default::default()
+            })
+            .child(stripes())
+            .build();
+        let original = render(&*stripes());
+        assert_eq!(render(&flat).pixels, original.pixels);
+        assert_eq!(render(&still).pixels, original.pixels);
+    }
+
+    #[test]
+    fn paint_bounds_grow_by_how_far_content_can_move() {
+        let wave = Distort::builder()
+            .distortion(Wave {
+                amplitude: -2.0,
+                ..Default::default()
+            })
+            .child(stripes())
+            .build();
+        let displace = Displace::builder()
+            .map(flat(255))
+            .amount(Vec2(1.0, -3.0))
+            .child(stripes())
+            .build();
+        let wave = wave.paint_bounds(Vec2(4.0, 4.0));
+        let displace = displace.paint_bounds(Vec2(4.0, 4.0));
+        assert_eq!((wave.origin.0, wave.origin.1), (-2.0, -2.0));
+        assert_eq!((wave.size.0, wave.size.1), (8.0, 8.0));
+        assert_eq!((displace.origin.0, displace.origin.1), (-1.0, -3.0));
+        assert_eq!((displace.size.0, displace.size.1), (6.0, 10.0));
+    }
+
+    #[test]
+    fn content_pushed_past_the_child_edge_stays_visible() {
+        let wave = Distort::builder()
+            .distortion(Wave {
+                amplitude: 2.0,
+                wavelength: 4.0,
+                ..Default::default()
+            })
+            .quality(ImageQuality::Nearest)
+            .child(stripes())
+            .build();
+        let image = render(&wave);
+        assert_eq!((image.width, image.height), (8, 8));
+        // Pixel (5, 6) is centered at (3.5, 4.5), half a unit below the
+        // child, where the wave lifts the lookup 1.4 units back into its
+        // last stripe.
+        let i = (6 * 8 + 5) * 4;
+        assert_eq!(image.pixels[i..i + 4], [180, 0, 0, 255]);
+        // A trough column pushes the other way, so below it stays clear.
+        let i = (6 * 8 + 3) * 4;
+        assert_eq!(image.pixels[i + 3], 0);
+    }
+
+    #[test]
+    fn white_map_shifts_the_child_by_amount() {
+        let displaced = Displace::builder()
+            .map(flat(255))
+            .amount(Vec2(1.0, 0.0))
+            .quality(ImageQuality::Nearest)
+            .child(stripes())
+            .build();
+        let image = render(&displaced);
+        // The bounds grow a pixel each side. Within the child each pixel
+        // shows its right neighbor, and the last column pulls from outside
+        // the child and is transparent; off the map nothing moves.
+        assert_eq!(image.width, 6);
+        assert_eq!(&reds(&image)[..6], &[0, 60, 120, 180, 0, 0]);
+        assert_eq!(image.pixels[4 * 4 + 3], 0);
+
+        let neutral = Displace::builder()
+            .map(flat(128))
+            .amount(Vec2(1.0, 0.0))
+            .quality(ImageQuality::Nearest)
+            .child(stripes())
+            .build();
+        // Gray leaves the child in place, inside a transparent margin.
+        let neutral = render(&neutral);
+        let original = render(&*stripes());
+        for row in 0..4 {
+            assert_eq!(
+                neutral.pixels[(row * 6 + 1) * 4..][..16],
+                original.pixels[row * 16..][..16]
+            );
+        }
+    }
+}
@@ -0,0 +1,434 @@
+//! Glow effects for raster components.
+//!
+//! [`OuterGlow`] paints a colored halo behind the child: its alpha shape is
+//! grown by `spread`, blurred and tinted, the way [`Outline`](crate::Outline)
+//! and [`DropShadow`](crate::DropShadow) build their layers. [`Bloom`] makes
+//! the child's bright areas bleed light: pixels above a luminance threshold
+//! are blurred with the full-color [`Blur`](crate::Blur) passes and added
+//! back on top. Both widen `paint_bounds` by the reach of the light and leave
+//! `layout_box` alone.
+
+use tellur_core::color::Color;
+use tellur_core::composite::composite_at;
+use tellur_core::geometry::{Constraints, Rect, Vec2};
+use tellur_core::raster::{
+    CpuRasterImage, PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution,
+};
+use tellur_core::render_context::{BloomInput, OuterGlowInput, RenderContext};
+use tellur_core::Keyable;
+
+use crate::blur::{blur_plane, premultiplied_plane, unpremultiply};
+use crate::outline::dilate_ellipse_antialiased;
+use crate::shadow::{blank_image, box_blur_3pass, BLUR_EXTENT_MULTIPLIER};
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct OuterGlow {
+    /// Gaussian-equivalent blur radius of the halo (logical units).
+    pub blur: f32,
+    /// How far the child's silhouette grows before it is blurred, in
+    /// logical units. Larger spreads give a denser, wider core.
+    #[builder(default = 0.0)]
+    pub spread: f32,
+    /// Glow color (its alpha is multiplied with the halo alpha).
+    pub color: Color,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl RasterComponent for OuterGlow {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        let inner = self.child.paint_bounds(size);
+        let extent = self.spread.max(0.0) + self.blur.max(0.0) * BLUR_EXTENT_MULTIPLIER;
+        Rect {
+            origin: Vec2(inner.origin.0 - extent, inner.origin.1 - extent),
+            size: Vec2(inner.size.0 + 2.0 * extent, inner.size.1 + 2.0 * extent),
+        }
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let paint = self.paint_bounds(size);
+        let child_paint = self.child.paint_bounds(size);
+        if paint.size.0 <= 0.0 || paint.size.1 <= 0.0 {
+            return ctx.ensure_residency(blank_image(target), residency);
+        }
+        let sx = target.width as f32 / paint.size.0;
+        let sy = target.height as f32 / paint.size.1;
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+
+        let child_px_w = (child_paint.size.0 * sx).round().max(1.0) as u32;
+        let child_px_h = (child_paint.size.1 * sy).round().max(1.0) as u32;
+        let child_image = ctx.render(
+            self.child.as_ref(),
+            size,
+            Resolution::new(child_px_w, child_px_h),
+            if gpu_available {
+                RasterResidency::Gpu
+            } else {
+                RasterResidency::Cpu
+            },
+        );
+
+        // Spread is rounded per axis like `Outline`'s width; the blur radius
+        // uses the larger scale like `DropShadow`.
+        let spread_px_x = (self.spread.max(0.0) * sx).round() as u32;
+        let spread_px_y = (self.spread.max(0.0) * sy).round() as u32;
+        let blur_px = (self.blur * sx.max(sy)).round().max(0.0) as u32;
+        let child_px_x = ((child_paint.origin.0 - paint.origin.0) * sx).round() as i32;
+        let child_px_y = ((child_paint.origin.1 - paint.origin.1) * sy).round() as i32;
+
+        if gpu_available {
+            let input = OuterGlowInput {
+                child: &child_image,
+                target,
+                child_offset_x: child_px_x,
+                child_offset_y: child_px_y,
+                spread_x: spread_px_x,
+                spread_y: spread_px_y,
+                blur_radius: blur_px,
+                color: self.color,
+            };
+            if let Some(gpu) = ctx.gpu_backend() {
+                if let Some(image) = gpu.outer_glow(input) {
+                    return ctx.ensure_residency(image, residency);
+                }
+            }
+        }
+
+        let child_image = ctx.readback(child_image);
+        let glow_image = make_glow(
+            &child_image,
+            target,
+            (child_px_x, child_px_y),
+            (spread_px_x, spread_px_y),
+            blur_px,
+            self.color,
+        );
+
+        let mut accum = vec![0u8; (target.width as usize) * (target.height as usize) * 4];
+        composite_at(&mut accum, target, &glow_image, 0, 0);
+        composite_at(&mut accum, target, &child_image, child_px_x, child_px_y);
+
+        let image = RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, accum);
+        ctx.ensure_residency(image, residency)
+    }
+}
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Bloom {
+    /// Luminance (0–1) above which pixels start to bloom; light ramps up
+    /// linearly from here to full strength at white.
+    #[builder(default = 0.8)]
+    pub threshold: f32,
+    /// Gaussian-equivalent blur radius of the bloomed light (logical units).
+    pub radius: f32,
+    /// Multiplier on the light added back over the child.
+    #[builder(default = 1.0)]
+    pub intensity: f32,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl RasterComponent for Bloom {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        let inner = self.child.paint_bounds(size);
+        let extent = self.radius.max(0.0) * BLUR_EXTENT_MULTIPLIER;
+        Rect {
+            origin: Vec2(inner.origin.0 - extent, inner.origin.1 - extent),
+            size: Vec2(inner.size.0 + 2.0 * extent, inner.size.1 + 2.0 * extent),
+        }
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let paint = self.paint_bounds(size);
+        let child_paint = self.child.paint_bounds(size);
+        if paint.size.0 <= 0.0 || paint.size.1 <= 0.0 {
+            return ctx.ensure_residency(blank_image(target), residency);
+        }
+        let sx = target.width as f32 / paint.size.0;
+        let sy = target.height as f32 / paint.size.1;
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+
+        let child_px_w = (child_paint.size.0 * sx).round().max(1.0) as u32;
+        let child_px_h = (child_paint.size.1 * sy).round().max(1.0) as u32;
+        let child_image = ctx.render(
+            self.child.as_ref(),
+            size,
+            Resolution::new(child_px_w, child_px_h),
+            if gpu_available {
+                RasterResidency::Gpu
+            } else {
+                RasterResidency::Cpu
+            },
+        );
+
+        let radius = self.radius.max(0.0);
+        let axes = [Vec2(radius * sx, 0.0), Vec2(0.0, radius * sy)];
+        let threshold = self.threshold.clamp(0.0, 1.0);
+        let intensity = self.intensity.max(0.0);
+        let child_px_x = ((child_paint.origin.0 - paint.origin.0) * sx).round() as i32;
+        let child_px_y = ((child_paint.origin.1 - paint.origin.1) * sy).round() as i32;
+
+        if gpu_available {
+            let input = BloomInput {
+                child: &child_image,
+                target,
+                child_offset_x: child_px_x,
+                child_offset_y: child_px_y,
+                threshold,
+                intensity,
+                axes,
+            };
+            if let Some(gpu) = ctx.gpu_backend() {
+                if let Some(image) = gpu.bloom(input) {
+                    return ctx.ensure_residency(image, residency);
+                }
+            }
+        }
+
+        let child_image = ctx.readback(child_image);
+        let image = bloom_image(
+            &child_image,
+            target,
+            (child_px_x, child_px_y),
+            threshold,
+            intensity,
+            axes,
+        );
+        ctx.ensure_residency(RasterImage::Cpu(image), residency)
+    }
+}
+
+/// CPU counterpart of the halo layer of
+/// [`GpuRasterBackend::outer_glow`](tellur_core::render_context::GpuRasterBackend::outer_glow):
+/// a `target`-sized image holding the dilated, blurred and tinted alpha of
+/// `image` placed at `offset`.
+pub(crate) fn make_glow(
+    image: &CpuRasterImage,
+    target: Resolution,
+    offset: (i32, i32),
+    spread: (u32, u32),
+    blur_radius: u32,
+    color: Color,
+) -> CpuRasterImage {
+    assert_eq!(image.format, PixelFormat::Rgba8);
+    let w = target.width as usize;
+    let h = target.height as usize;
+    let mut alpha = vec![0u8; w * h];
+    let pixels = image.pixels.as_ref();
+    for y in 0..image.height as i32 {
+        let dy = y + offset.1;
+        if dy < 0 || dy >= h as i32 {
+            continue;
+        }
+        for x in 0..image.width as i32 {
+            let dx = x + offset.0;
+            if dx < 0 || dx >= w as i32 {
+                continue;
+            }
+            let src = ((y as usize) * image.width as usize + x as usize) * 4 + 3;
+            alpha[dy as usize * w + dx as usize] = pixels[src];
+        }
+    }
+
+    let mut alpha = dilate_ellipse_antialiased(&alpha, w, h, spread.0 as usize, spread.1 as usize);
+    if blur_radius > 0 {
+        box_blur_3pass(&mut alpha, w, h, blur_radius as usize);
+    }
+
+    let r = (color.r * 255.0).round().clamp(0.0, 255.0) as u8;
+    let g = (color.g * 255.0).round().clamp(0.0, 255.0) as u8;
+    let b = (color.b * 255.0).round().clamp(0.0, 255.0) as u8;
+    let alpha_scale = color.a.clamp(0.0, 1.0);
+    let mut out = Vec::with_capacity(w * h * 4);
+    for &alpha_value in &alpha {
+        let a = ((alpha_value as f32) * alpha_scale)
+            .round()
+            .clamp(0.0, 255.0) as u8;
+        out.extend_from_slice(&[r, g, b, a]);
+    }
+    CpuRasterImage::new(target.width, target.height, PixelFormat::Rgba8, out)
+}
+
+/// CPU counterpart of [`GpuRasterBackend::bloom`](tellur_core::render_context::GpuRasterBackend::bloom).
+pub(crate) fn bloom_image(
+    image: &CpuRasterImage,
+    target: Resolution,
+    offset: (i32, i32),
+    threshold: f32,
+    intensity: f32,
+    axes: [Vec2; 2],
+) -> CpuRasterImage {
+    assert_eq!(image.format, PixelFormat::Rgba8);
+    let plane = premultiplied_plane(image, target, offset.0, offset.1);
+    let bright = plane
+        .iter()
+        .map(|texel| {
+            if texel[3] <= 0.0 {
+                return [0.0; 4];
+            }
+            let luma = (0.2126 * texel[0] + 0.7152 * texel[1] + 0.0722 * texel[2]) / texel[3];
+            let weight = bloom_weight(luma, threshold);
+            texel.map(|c| c * weight)
+        })
+        .collect();
+    let bright = blur_plane(bright, target, axes);
+
+    let mut out = Vec::with_capacity(plane.len() * 4);
+    for (texel, light) in plane.iter().zip(&bright) {
+        let sum = [0, 1, 2, 3].map(|c| texel[c] + intensity * light[c]);
+        out.extend_from_slice(&unpremultiply(sum));
+    }
+    CpuRasterImage::new(target.width, target.height, PixelFormat::Rgba8, out)
+}
+
+/// How strongly a pixel of straight-color luminance `luma` blooms: zero at
+/// or below `threshold`, ramping linearly to one at white.
+fn bloom_weight(luma: f32, threshold: f32) -> f32 {
+    if threshold >= 1.0 {
+        return 0.0;
+    }
+    ((luma - threshold) / (1.0 - threshold)).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{pixel, square};
+    use tellur_core::render_context::PassThrough;
+
+    fn render(component: &dyn RasterComponent) -> CpuRasterImage {
+        let size = Vec2(8.0, 8.0);
+        let paint = component.paint_bounds(size);
+        let target = Resolution::new(paint.size.0.round() as u32, paint.size.1.round() as u32);
+        let image = component.render(size, target, RasterResidency::Cpu, &mut PassThrough);
+        image.as_cpu().expect("CPU render").clone()
+    }
+
+    #[test]
+    fn glow_paint_bounds_include_spread_and_blur_reach() {
+        let glow = OuterGlow::builder()
+            .blur(2.0)
+            .spread(1.5)
+            .color(Color::rgba_u8(255, 255, 255, 255))
+            .child(square([0; 4]))
+            .build();
+
+        let bounds = glow.paint_bounds(Vec2(8.0, 8.0));
+
+        assert_eq!((bounds.origin.0, bounds.origin.1), (-7.5, -7.5));
+        assert_eq!((bounds.size.0, bounds.size.1), (23.0, 23.0));
+    }
+
+    #[test]
+    fn unblurred_glow_is_a_solid_ring_of_the_spread() {
+        let glow = OuterGlow::builder()
+            .blur(0.0)
+            .spread(2.0)
+            .color(Color::rgba_u8(0, 255, 0, 255))
+            .child(square([200, 0, 0, 255]))
+            .build();
+
+        let image = render(&glow);
+
+        assert_eq!((image.width, image.height), (12, 12));
+        assert_eq!(pixel(&image, 6, 6), [200, 0, 0, 255]);
+        assert_eq!(pixel(&image, 1, 6), [0, 255, 0, 255]);
+        assert_eq!(pixel(&image, 6, 10), [0, 255, 0, 255]);
+        // The outermost ring pixel sits on the antialiased edge.
+        assert_eq!(pixel(&image, 0, 6), [0, 255, 0, 128]);
+    }
+
+    #[test]
+    fn glow_fades_away_from_the_child() {
+        let glow = OuterGlow::builder()
+            .blur(2.0)
+            .spread(1.0)
+            .color(Color::rgba_u8(0, 128, 255, 255))
+            .child(square([255, 255, 255, 255]))
+            .build();
+
+        let image = render(&glow);
+        let center = image.height / 2;
+        // The child starts at x = 7; walk left through the halo.
+        let alphas: Vec<u8> = (0..7).map(|x| pixel(&image, x, center)[3]).collect();
+
+        assert_eq!(pixel(&image, 10, center), [255, 255, 255, 255]);
+        assert_eq!(&pixel(&image, 5, center)[..3], &[0, 128, 255]);
+        assert!(
+            alphas.windows(2).all(|pair| pair[0] <= pair[1]),
+            "{alphas:?}"
+        );
+        assert!(alphas[6] > 64 && alphas[0] < 16, "{alphas:?}");
+    }
+
+    #[test]
+    fn bloom_leaves_pixels_below_the_threshold_alone() {
+        let bloom = Bloom::builder()
+            .threshold(0.8)
+            .radius(2.0)
+            .child(square([100, 100, 100, 255]))
+            .build();
+
+        let image = render(&bloom);
+
+        assert_eq!(pixel(&image, 10, 10), [100, 100, 100, 255]);
+        assert_eq!(pixel(&image, 2, 10), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn bloom_spreads_bright_light_past_the_child() {
+        let bloom = Bloom::builder()
+            .threshold(0.5)
+            .radius(2.0)
+            .child(square([255, 240, 200, 255]))
+            .build();
+
+        let image = render(&bloom);
+        let halo = pixel(&image, 4, 10);
+
+        assert_eq!(pixel(&image, 10, 10), [255, 255, 255, 255]);
+        assert!(halo[3] > 0 && halo[3] < 255, "{halo:?}");
+        assert!(halo[0] >= halo[2], "{halo:?}");
+        assert!(pixel(&image, 0, 10)[3] < 8);
+    }
+
+    #[test]
+    fn zero_intensity_bloom_returns_the_child() {
+        let bloom = Bloom::builder()
+            .threshold(0.0)
+            .radius(1.0)
+            .intensity(0.0)
+            .child(square([250, 250, 250, 255]))
+            .build();
+
+        let image = render(&bloom);
+
+        assert_eq!((image.width, image.height), (14, 14));
+        assert_eq!(pixel(&image, 3, 3), [250, 250, 250, 255]);
+        assert_eq!(pixel(&image, 2, 3), [0, 0, 0, 0]);
+    }
+}
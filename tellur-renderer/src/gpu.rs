@@ -3,23 +3,36 @@ use std::sync::Arc;
 
 use tellur_core::cache_budget::{try_reserve_vram, BudgetReservation};
 use tellur_core::color::Color;
-use tellur_core::geometry::{Transform, Vec2};
+use tellur_core::composite::BlendMode;
+use tellur_core::distort::{Distortion, WarpPlacement};
+use tellur_core::geometry::{Homography, Transform, Vec2};
+use tellur_core::grade::CubeLut;
+use tellur_core::grain::{blue_noise_ranks, Dither};
+use tellur_core::matte::MatteMode;
+use tellur_core::pattern::{NoiseKind, Pattern, MAX_OCTAVES};
+use tellur_core::perspective::NEAR_PLANE;
 use tellur_core::raster::{CpuRasterImage, GpuSurface, PixelFormat, RasterImage, Resolution};
 use tellur_core::render_context::{
-    CompositeInput, DropShadowInput, GpuRasterBackend, OutlineInput,
+    BloomInput, BlurInput, ChromaKeyInput, ColorAdjustInput, CompositeInput, DepthCompositeInput,
+    DitherInput, DropShadowInput, GpuRasterBackend, GrainInput, Lut3dInput, MatteInput,
+    OuterGlowInput, OutlineInput, PatternInput, ProjectInput, ResampleInput, WarpField, WarpInput,
 };
 use tellur_core::vector::{
-    ClipGroup as TellurClipGroup, DashPattern, Node, Paint, Path as TellurPath, PathCommand,
-    Stroke as TellurStroke, StrokeCap, StrokeJoin, VectorGraphic,
+    ClipGroup as TellurClipGroup, DashPattern, FillRule, GradientStop, ImagePattern, ImageQuality,
+    Node, Paint, Path as TellurPath, PathCommand, SpreadMode, Stroke as TellurStroke, StrokeCap,
+    StrokeJoin, VectorGraphic,
 };
 use vello::kurbo::{
-    Affine, BezPath, Cap as VelloCap, Join as VelloJoin, PathEl, Rect as VelloRect,
+    Affine, BezPath, Cap as VelloCap, Join as VelloJoin, PathEl, Rect as VelloRect, Shape,
     Stroke as VelloStroke,
 };
 use wgpu::util::DeviceExt;
 
 const BACKEND: &str = "tellur-wgpu-buffer-v1";
 const WORKGROUP: u32 = 16;
+/// The most layers `DEPTH_COMPOSITE_SHADER` sorts at one pixel; deeper
+/// scenes composite on the CPU.
+const MAX_DEPTH_LAYERS: usize = 16;
 // Matches Vello 0.2's own CPU stroke-expansion fallback tolerance.
 const VELLO_STROKE_TOLERANCE: f64 = 0.01;
 
@@ -69,6 +82,23 @@ pub struct GpuRenderer {
     blur_pipeline: wgpu::ComputePipeline,
     shadow_pipeline: wgpu::ComputePipeline,
     outline_pipeline: wgpu::ComputePipeline,
+    premultiply_pipeline: wgpu::ComputePipeline,
+    color_blur_pipeline: wgpu::ComputePipeline,
+    unpremultiply_pipeline: wgpu::ComputePipeline,
+    dilate_pipeline: wgpu::ComputePipeline,
+    bright_pass_pipeline: wgpu::ComputePipeline,
+    add_plane_pipeline: wgpu::ComputePipeline,
+    color_adjust_pipeline: wgpu::ComputePipeline,
+    lut_pipeline: wgpu::ComputePipeline,
+    matte_pipeline: wgpu::ComputePipeline,
+    chroma_choke_pipeline: wgpu::ComputePipeline,
+    chroma_key_pipeline: wgpu::ComputePipeline,
+    resample_pipeline: wgpu::ComputePipeline,
+    depth_composite_pipeline: wgpu::ComputePipeline,
+    warp_pipeline: wgpu::ComputePipeline,
+    grain_pipeline: wgpu::ComputePipeline,
+    dither_pipeline: wgpu::ComputePipeline,
+    pattern_pipeline: wgpu::ComputePipeline,
     texture_to_buffer_pipeline: wgpu::ComputePipeline,
     fill_pipeline: wgpu::ComputePipeline,
     motion_accum_pipeline: wgpu::ComputePipeline,
@@ -81,6 +111,11 @@ pub struct GpuRenderer {
     // their size has to match (recreated when the resolution changes).
     vello_target: Option<(u32, u32, wgpu::Texture, BudgetReservation)>,
     readback_staging: Option<(wgpu::Buffer, BudgetReservation)>,
+    // The last 3D LUT uploaded, keyed by its content fingerprint: a graded
+    // clip looks the same table up every frame.
+    lut_table: Option<(u64, wgpu::Buffer, BudgetReservation)>,
+    // The blue-noise dither tile, uploaded on the first dithered frame.
+    blue_noise_table: Option<(wgpu::Buffer, BudgetReservation)>,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -88,6 +123,19 @@ pub struct GpuRenderStats {
     pub composites: u64,
     pub drop_shadows: u64,
     pub outlines: u64,
+    pub blurs: u64,
+    pub glows: u64,
+    pub blooms: u64,
+    pub color_grades: u64,
+    pub mattes: u64,
+    pub chroma_keys: u64,
+    pub resamples: u64,
+    pub projections: u64,
+    pub depth_composites: u64,
+    pub warps: u64,
+    pub grains: u64,
+    pub dithers: u64,
+    pub patterns: u64,
     pub rasterizes: u64,
     pub fills: u64,
     pub temporal_averages: u64,
@@ -100,6 +148,19 @@ impl GpuRenderStats {
         self.composites
             + self.drop_shadows
             + self.outlines
+            + self.blurs
+            + self.glows
+            + self.blooms
+            + self.color_grades
+            + self.mattes
+            + self.chroma_keys
+            + self.resamples
+            + self.projections
+            + self.depth_composites
+            + self.warps
+            + self.grains
+            + self.dithers
+            + self.patterns
             + self.rasterizes
             + self.fills
             + self.temporal_averages
@@ -115,6 +176,14 @@ struct GpuBufferImage {
     _reservation: BudgetReservation,
 }
 
+/// Premultiplied `vec4<f32>` scratch plane of the full-color blur.
+struct ColorPlane {
+    width: u32,
+    height: u32,
+    buffer: wgpu::Buffer,
+    _reservation: BudgetReservation,
+}
+
 fn pixel_stride(format: PixelFormat) -> usize {
     match format {
         PixelFormat::Rgba8 => 4,
@@ -131,13 +200,152 @@ struct CompositeParams {
     src_h: u32,
     offset_x: i32,
     offset_y: i32,
-    _pad0: u32,
+    mode: u32,
     _pad1: u32,
 }
 
 unsafe impl bytemuck::Zeroable for CompositeParams {}
 unsafe impl bytemuck::Pod for CompositeParams {}
 
+/// The `mode` selector `COMPOSITE_SHADER` switches on.
+fn matte_mode_code(mode: MatteMode) -> u32 {
+    match mode {
+        MatteMode::Alpha => 0,
+        MatteMode::AlphaInverted => 1,
+        MatteMode::Luma => 2,
+        MatteMode::LumaInverted => 3,
+    }
+}
+
+fn image_quality_code(quality: ImageQuality) -> u32 {
+    match quality {
+        ImageQuality::Nearest => 0,
+        ImageQuality::Bilinear => 1,
+        ImageQuality::Bicubic => 2,
+    }
+}
+
+/// The `kind` selector `DITHER_SHADER` switches on.
+fn dither_code(dither: Dither) -> u32 {
+    match dither {
+        Dither::Ordered => 0,
+        Dither::BlueNoise => 1,
+    }
+}
+
+/// The pattern shader's parameters for `input`, derived exactly as
+/// `pattern_pixels` derives them, or `None` for a degenerate scale the CPU
+/// path fills flat.
+fn pattern_params(input: &PatternInput<'_>) -> Option<PatternParams> {
+    let scale = input.pattern.scale();
+    if !scale.is_finite() || scale <= 0.0 {
+        return None;
+    }
+    let target = input.target;
+    let unit = [
+        input.size.0 / target.width as f32,
+        input.size.1 / target.height as f32,
+    ];
+    let step = unit.map(|u| u / scale);
+    let premultiplied = |c: Color| [c.r * c.a, c.g * c.a, c.b * c.a, c.a];
+    let [low, high] = input.pattern.colors().map(premultiplied);
+    let mut params = PatternParams {
+        width: target.width,
+        height: target.height,
+        kind: 0,
+        octaves: 0,
+        seed: 0,
+        step_x: step[0],
+        step_y: step[1],
+        shift: 0.0,
+        p0: 0.0,
+        p1: 0.0,
+        p2: 0.0,
+        _pad0: 0,
+        low,
+        high,
+    };
+    match input.pattern {
+        Pattern::Noise(noise) => {
+            params.kind = match noise.kind {
+                NoiseKind::Perlin => 0,
+                NoiseKind::Simplex => 1,
+            };
+            params.octaves = noise.octaves.clamp(1, MAX_OCTAVES);
+            params.seed = noise.seed;
+            params.shift = noise.evolution;
+        }
+        Pattern::Checker(checker) => {
+            params.kind = 2;
+            params.shift = 2.0 * checker.evolution;
+        }
+        Pattern::Stripes(stripes) => {
+            let (sin, cos) = stripes.angle.sin_cos();
+            params.kind = 3;
+            params.step_x = 2.0 * cos * step[0];
+            params.step_y = 2.0 * sin * step[1];
+            params.shift = 2.0 * stripes.evolution;
+            params.p0 = params.step_x.abs() + params.step_y.abs();
+        }
+        Pattern::DotGrid(grid) => {
+            params.kind = 4;
+            params.shift = grid.evolution;
+            params.p0 = scale;
+            params.p1 = grid.radius;
+            params.p2 = unit[0].max(unit[1]);
+        }
+    }
+    Some(params)
+}
+
+/// The warp shader's kind code and `p0`..`p4`, with anchors resolved on a
+/// child of `size`.
+fn warp_params(distortion: &Distortion, size: Vec2) -> (u32, [f32; 5]) {
+    match *distortion {
+        Distortion::Ripple(ripple) => {
+            let c = ripple.center.point(size);
+            let p = [
+                c.0,
+                c.1,
+                ripple.amplitude,
+                ripple.wavelength,
+                ripple.phase.get(),
+            ];
+            (0, p)
+        }
+        Distortion::Wave(wave) => {
+            let (sin, cos) = wave.angle.sin_cos();
+            (
+                1,
+                [cos, sin, wave.amplitude, wave.wavelength, wave.phase.get()],
+            )
+        }
+        Distortion::Twirl(twirl) => {
+            let c = twirl.center.point(size);
+            (2, [c.0, c.1, twirl.radius, twirl.angle, 0.0])
+        }
+        Distortion::Bulge(bulge) => {
+            let c = bulge.center.point(size);
+            (3, [c.0, c.1, bulge.radius, bulge.strength, 0.0])
+        }
+    }
+}
+
+fn blend_mode_code(mode: BlendMode) -> u32 {
+    match mode {
+        BlendMode::Normal => 0,
+        BlendMode::Multiply => 1,
+        BlendMode::Screen => 2,
+        BlendMode::Overlay => 3,
+        BlendMode::Add => 4,
+        BlendMode::Darken => 5,
+        BlendMode::Lighten => 6,
+        BlendMode::Difference => 7,
+        BlendMode::ColorDodge => 8,
+        BlendMode::SoftLight => 9,
+    }
+}
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 struct CopyAlphaParams {
@@ -166,6 +374,278 @@ struct BlurParams {
 unsafe impl bytemuck::Zeroable for BlurParams {}
 unsafe impl bytemuck::Pod for BlurParams {}
 
+/// One premultiplied box pass of `radius` pixels along the unit `step`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ColorBlurParams {
+    width: u32,
+    height: u32,
+    radius: f32,
+    step_x: f32,
+    step_y: f32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+}
+
+unsafe impl bytemuck::Zeroable for ColorBlurParams {}
+unsafe impl bytemuck::Pod for ColorBlurParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct PlaneParams {
+    width: u32,
+    height: u32,
+    _pad0: u32,
+    _pad1: u32,
+}
+
+unsafe impl bytemuck::Zeroable for PlaneParams {}
+unsafe impl bytemuck::Pod for PlaneParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct DilateParams {
+    width: u32,
+    height: u32,
+    radius_x: u32,
+    radius_y: u32,
+}
+
+unsafe impl bytemuck::Zeroable for DilateParams {}
+unsafe impl bytemuck::Pod for DilateParams {}
+
+/// Placement of the child into the bloom's bright-pass plane.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct BrightPassParams {
+    src_w: u32,
+    src_h: u32,
+    out_w: u32,
+    out_h: u32,
+    offset_x: i32,
+    offset_y: i32,
+    threshold: f32,
+    _pad0: u32,
+}
+
+unsafe impl bytemuck::Zeroable for BrightPassParams {}
+unsafe impl bytemuck::Pod for BrightPassParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct AddPlaneParams {
+    width: u32,
+    height: u32,
+    scale: f32,
+    _pad0: u32,
+}
+
+unsafe impl bytemuck::Zeroable for AddPlaneParams {}
+unsafe impl bytemuck::Pod for AddPlaneParams {}
+
+/// [`ColorAdjustment`](tellur_core::grade::ColorAdjustment) with the
+/// exposure, hue and gamma terms precomputed on the CPU.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ColorAdjustParams {
+    width: u32,
+    height: u32,
+    exposure_scale: f32,
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+    inverse_gamma: f32,
+    tint_amount: f32,
+    tint_r: f32,
+    tint_g: f32,
+    tint_b: f32,
+    _pad0: u32,
+    hue: [[f32; 4]; 3],
+}
+
+unsafe impl bytemuck::Zeroable for ColorAdjustParams {}
+unsafe impl bytemuck::Pod for ColorAdjustParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct LutParams {
+    width: u32,
+    height: u32,
+    size: u32,
+    mix: f32,
+    domain_min: [f32; 4],
+    domain_max: [f32; 4],
+}
+
+unsafe impl bytemuck::Zeroable for LutParams {}
+unsafe impl bytemuck::Pod for LutParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct MatteParams {
+    width: u32,
+    height: u32,
+    matte_width: u32,
+    matte_height: u32,
+    offset_x: i32,
+    offset_y: i32,
+    mode: u32,
+    _pad0: u32,
+}
+
+unsafe impl bytemuck::Zeroable for MatteParams {}
+unsafe impl bytemuck::Pod for MatteParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ChromaKeyParams {
+    width: u32,
+    height: u32,
+    choke_radius: u32,
+    tolerance: f32,
+    softness: f32,
+    spill: f32,
+    key_cb: f32,
+    key_cr: f32,
+}
+
+unsafe impl bytemuck::Zeroable for ChromaKeyParams {}
+unsafe impl bytemuck::Pod for ChromaKeyParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct ResampleParams {
+    width: u32,
+    height: u32,
+    src_width: u32,
+    src_height: u32,
+    m: [[f32; 3]; 3],
+    quality: u32,
+    near: f32,
+    _pad0: u32,
+}
+
+unsafe impl bytemuck::Zeroable for ResampleParams {}
+unsafe impl bytemuck::Pod for ResampleParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct DepthCompositeParams {
+    width: u32,
+    height: u32,
+    count: u32,
+    _pad0: u32,
+    origin: [f32; 2],
+    scale: [f32; 2],
+}
+
+unsafe impl bytemuck::Zeroable for DepthCompositeParams {}
+unsafe impl bytemuck::Pod for DepthCompositeParams {}
+
+/// One layer of a depth composite, as `DEPTH_COMPOSITE_SHADER` reads it.
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct DepthLayerParams {
+    width: u32,
+    height: u32,
+    offset_x: i32,
+    offset_y: i32,
+    /// Index of the layer's first pixel in the packed pixel buffer.
+    base: u32,
+    mode: u32,
+    _pad0: [u32; 2],
+    /// The inverse homography's rows, each padded to a `vec4`.
+    inverse: [[f32; 4]; 3],
+    depth: [f32; 4],
+}
+
+unsafe impl bytemuck::Zeroable for DepthLayerParams {}
+unsafe impl bytemuck::Pod for DepthLayerParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct WarpParams {
+    width: u32,
+    height: u32,
+    kind: u32,
+    quality: u32,
+    origin_x: f32,
+    origin_y: f32,
+    scale_x: f32,
+    scale_y: f32,
+    p: [f32; 5],
+    map_width: u32,
+    map_height: u32,
+    map_offset_x: i32,
+    map_offset_y: i32,
+    src_width: u32,
+    src_height: u32,
+    src_offset_x: i32,
+    src_offset_y: i32,
+    _pad: [u32; 3],
+}
+
+unsafe impl bytemuck::Zeroable for WarpParams {}
+unsafe impl bytemuck::Pod for WarpParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct GrainParams {
+    width: u32,
+    height: u32,
+    seed: u32,
+    frame: u32,
+    monochrome: u32,
+    cells_per_pixel: f32,
+    /// The peak deviation in code values.
+    amount: f32,
+    _pad0: u32,
+}
+
+unsafe impl bytemuck::Zeroable for GrainParams {}
+unsafe impl bytemuck::Pod for GrainParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct DitherParams {
+    width: u32,
+    height: u32,
+    kind: u32,
+    offset_x: u32,
+    offset_y: u32,
+    _pad: [u32; 3],
+}
+
+unsafe impl bytemuck::Zeroable for DitherParams {}
+unsafe impl bytemuck::Pod for DitherParams {}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct PatternParams {
+    width: u32,
+    height: u32,
+    kind: u32,
+    octaves: u32,
+    seed: u32,
+    /// Pattern coordinates per pixel along each axis.
+    step_x: f32,
+    step_y: f32,
+    /// Subtracted from the pattern coordinate along x (or across stripes),
+    /// or the noise's third coordinate.
+    shift: f32,
+    p0: f32,
+    p1: f32,
+    p2: f32,
+    _pad0: u32,
+    /// Premultiplied.
+    low: [f32; 4],
+    high: [f32; 4],
+}
+
+unsafe impl bytemuck::Zeroable for PatternParams {}
+unsafe impl bytemuck::Pod for PatternParams {}
+
 #[repr(C)]
 #[derive(Clone, Copy)]
 struct ColorCompositeParams {
@@ -254,7 +734,7 @@ impl GpuRenderer {
             composite_pipeline: compute_pipeline(
                 &device,
                 "tellur-composite",
-                &format!("{COMMON_WGSL}{COMPOSITE_SHADER}"),
+                &format!("{COMMON_WGSL}{COMPOSITE_SHADER}{BLEND_MODE_WGSL}"),
             ),
             copy_alpha_pipeline: compute_pipeline(&device, "tellur-copy-alpha", COPY_ALPHA_SHADER),
             blur_pipeline: compute_pipeline(&device, "tellur-box-blur", BLUR_SHADER),
@@ -266,7 +746,84 @@ impl GpuRenderer {
             outline_pipeline: compute_pipeline(
                 &device,
                 "tellur-outline-composite",
-                &format!("{COMMON_WGSL}{OUTLINE_SHADER}"),
+                &format!("{COMMON_WGSL}{OUTLINE_SHADER}{DILATE_WGSL}"),
+            ),
+            premultiply_pipeline: compute_pipeline(
+                &device,
+                "tellur-premultiply",
+                &format!("{COMMON_WGSL}{PREMULTIPLY_SHADER}"),
+            ),
+            color_blur_pipeline: compute_pipeline(&device, "tellur-color-blur", COLOR_BLUR_SHADER),
+            unpremultiply_pipeline: compute_pipeline(
+                &device,
+                "tellur-unpremultiply",
+                &format!("{COMMON_WGSL}{UNPREMULTIPLY_SHADER}"),
+            ),
+            dilate_pipeline: compute_pipeline(
+                &device,
+                "tellur-dilate-alpha",
+                &format!("{DILATE_SHADER}{DILATE_WGSL}"),
+            ),
+            bright_pass_pipeline: compute_pipeline(
+                &device,
+                "tellur-bloom-bright-pass",
+                &format!("{COMMON_WGSL}{BRIGHT_PASS_SHADER}"),
+            ),
+            add_plane_pipeline: compute_pipeline(&device, "tellur-add-plane", ADD_PLANE_SHADER),
+            color_adjust_pipeline: compute_pipeline(
+                &device,
+                "tellur-color-adjust",
+                &format!("{COMMON_WGSL}{COLOR_ADJUST_SHADER}"),
+            ),
+            lut_pipeline: compute_pipeline(
+                &device,
+                "tellur-lut3d",
+                &format!("{COMMON_WGSL}{LUT_SHADER}"),
+            ),
+            matte_pipeline: compute_pipeline(
+                &device,
+                "tellur-matte",
+                &format!("{COMMON_WGSL}{MATTE_SHADER}"),
+            ),
+            chroma_choke_pipeline: compute_pipeline(
+                &device,
+                "tellur-chroma-choke",
+                &format!("{COMMON_WGSL}{CHROMA_CHOKE_SHADER}{CHROMA_KEY_WGSL}"),
+            ),
+            chroma_key_pipeline: compute_pipeline(
+                &device,
+                "tellur-chroma-key",
+                &format!("{COMMON_WGSL}{CHROMA_KEY_SHADER}{CHROMA_KEY_WGSL}"),
+            ),
+            resample_pipeline: compute_pipeline(
+                &device,
+                "tellur-resample",
+                &format!("{COMMON_WGSL}{SAMPLE_WGSL}{RESAMPLE_SHADER}"),
+            ),
+            depth_composite_pipeline: compute_pipeline(
+                &device,
+                "tellur-depth-composite",
+                &format!("{COMMON_WGSL}{DEPTH_COMPOSITE_SHADER}{BLEND_MODE_WGSL}"),
+            ),
+            warp_pipeline: compute_pipeline(
+                &device,
+                "tellur-warp",
+                &format!("{COMMON_WGSL}{SAMPLE_WGSL}{WARP_SHADER}"),
+            ),
+            grain_pipeline: compute_pipeline(
+                &device,
+                "tellur-grain",
+                &format!("{COMMON_WGSL}{GRAIN_SHADER}"),
+            ),
+            dither_pipeline: compute_pipeline(
+                &device,
+                "tellur-dither",
+                &format!("{COMMON_WGSL}{DITHER_SHADER}"),
+            ),
+            pattern_pipeline: compute_pipeline(
+                &device,
+                "tellur-pattern",
+                &format!("{COMMON_WGSL}{PATTERN_SHADER}"),
             ),
             texture_to_buffer_pipeline: compute_pipeline(
                 &device,
@@ -290,6 +847,8 @@ impl GpuRenderer {
             stats: GpuRenderStats::default(),
             vello_target: None,
             readback_staging: None,
+            lut_table: None,
+            blue_noise_table: None,
         })
     }
 
@@ -303,6 +862,8 @@ impl GpuRenderer {
     pub fn release_cached_resources(&mut self) {
         self.vello_target = None;
         self.readback_staging = None;
+        self.lut_table = None;
+        self.blue_noise_table = None;
     }
 
     fn reserve_render_vram(&mut self, bytes: usize) -> Option<BudgetReservation> {
@@ -346,6 +907,95 @@ impl GpuRenderer {
         }))
     }
 
+    /// Uploads `lut` as `vec4<f32>` entries into `lut_table` unless it is the
+    /// table already resident.
+    fn upload_lut(&mut self, lut: &CubeLut) -> Option<()> {
+        let resident = matches!(&self.lut_table, Some((fingerprint, _, _)) if *fingerprint == lut.fingerprint());
+        if !resident {
+            self.lut_table = None;
+            let entries: Vec<[f32; 4]> = lut
+                .table()
+                .iter()
+                .map(|&[r, g, b]| [r, g, b, 0.0])
+                .collect();
+            let bytes: &[u8] = bytemuck::cast_slice(&entries);
+            let reservation = self.reserve_render_vram(bytes.len())?;
+            let buffer = self
+                .device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("tellur-gpu-lut3d"),
+                    contents: bytes,
+                    usage: wgpu::BufferUsages::STORAGE,
+                });
+            self.lut_table = Some((lut.fingerprint(), buffer, reservation));
+        }
+        Some(())
+    }
+
+    /// Uploads the blue-noise dither tile as `u32` ranks into
+    /// `blue_noise_table` unless it is already resident.
+    fn upload_blue_noise(&mut self) -> Option<()> {
+        if self.blue_noise_table.is_none() {
+            let ranks: Vec<u32> = blue_noise_ranks().iter().map(|&r| r as u32).collect();
+            let bytes: &[u8] = bytemuck::cast_slice(&ranks);
+            let reservation = self.reserve_render_vram(bytes.len())?;
+            let buffer = self
+                .device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some("tellur-gpu-blue-noise"),
+                    contents: bytes,
+                    usage: wgpu::BufferUsages::STORAGE,
+                });
+            self.blue_noise_table = Some((buffer, reservation));
+        }
+        Some(())
+    }
+
+    /// Runs the resample pass shared by affine resampling and projection:
+    /// `inverse` maps target pixels back to `image`, and `near` is the
+    /// smallest `w` a source point may have (`0` for affine maps).
+    fn dispatch_resample(
+        &mut self,
+        image: &RasterImage,
+        target: Resolution,
+        inverse: Homography,
+        near: f32,
+        quality: ImageQuality,
+    ) -> Option<Arc<GpuBufferImage>> {
+        let src = self.image_ref(image)?;
+        if src.format != PixelFormat::Rgba8 || target.width == 0 || target.height == 0 {
+            return None;
+        }
+        let output = self.empty_image(target)?;
+        let params = ResampleParams {
+            width: target.width,
+            height: target.height,
+            src_width: src.width,
+            src_height: src.height,
+            m: inverse.m,
+            quality: image_quality_code(quality),
+            near,
+            _pad0: 0,
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-resample"),
+            });
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.resample_pipeline,
+            [&src.buffer, &output.buffer],
+            &params,
+            DispatchSize::new(target.width, target.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        Some(output)
+    }
+
     fn image_ref(&self, image: &RasterImage) -> Option<Arc<GpuBufferImage>> {
         match image {
             RasterImage::Gpu(surface) if surface.backend() == BACKEND => {
@@ -426,6 +1076,25 @@ impl GpuRenderer {
         })
     }
 
+    /// Allocates a premultiplied `vec4<f32>` working plane, 16 bytes per
+    /// pixel, for the full-color blur.
+    fn color_plane(&mut self, width: u32, height: u32) -> Option<ColorPlane> {
+        let len = (width as usize) * (height as usize) * 16;
+        let reservation = self.reserve_render_vram(len)?;
+        let buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("tellur-gpu-color-plane"),
+            size: len as u64,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+        Some(ColorPlane {
+            width,
+            height,
+            buffer,
+            _reservation: reservation,
+        })
+    }
+
     fn composite_one(
         &self,
         encoder: &mut wgpu::CommandEncoder,
@@ -433,6 +1102,7 @@ impl GpuRenderer {
         src: &GpuBufferImage,
         offset_x: i32,
         offset_y: i32,
+        mode: BlendMode,
     ) {
         let params = CompositeParams {
             dst_w: dst.width,
@@ -441,7 +1111,7 @@ impl GpuRenderer {
             src_h: src.height,
             offset_x,
             offset_y,
-            _pad0: 0,
+            mode: blend_mode_code(mode),
             _pad1: 0,
         };
         dispatch_three_buffer(
@@ -522,17 +1192,45 @@ impl GpuRenderer {
         );
     }
 
-    fn composite_shadow_alpha(
+    fn color_blur_pass(
         &self,
         encoder: &mut wgpu::CommandEncoder,
-        dst: &GpuBufferImage,
-        alpha: &GpuBufferImage,
-        offset_x: i32,
-        offset_y: i32,
-        color: Color,
+        src: &ColorPlane,
+        dst: &ColorPlane,
+        axis: Vec2,
     ) {
-        let [r, g, b, a] = color_u8(color);
-        let params = ColorCompositeParams {
+        let radius = (axis.0 * axis.0 + axis.1 * axis.1).sqrt();
+        let params = ColorBlurParams {
+            width: src.width,
+            height: src.height,
+            radius,
+            step_x: axis.0 / radius,
+            step_y: axis.1 / radius,
+            _pad0: 0,
+            _pad1: 0,
+            _pad2: 0,
+        };
+        dispatch_three_buffer(
+            &self.device,
+            encoder,
+            &self.color_blur_pipeline,
+            [&src.buffer, &dst.buffer],
+            &params,
+            DispatchSize::new(src.width, src.height),
+        );
+    }
+
+    fn composite_shadow_alpha(
+        &self,
+        encoder: &mut wgpu::CommandEncoder,
+        dst: &GpuBufferImage,
+        alpha: &GpuBufferImage,
+        offset_x: i32,
+        offset_y: i32,
+        color: Color,
+    ) {
+        let [r, g, b, a] = color_u8(color);
+        let params = ColorCompositeParams {
             dst_w: dst.width,
             dst_h: dst.height,
             src_w: alpha.width,
@@ -796,7 +1494,7 @@ impl GpuRasterBackend for GpuRenderer {
             if src.format != PixelFormat::Rgba8 {
                 return None;
             }
-            sources.push((src, input.offset_x, input.offset_y));
+            sources.push((src, input.offset_x, input.offset_y, input.blend));
         }
 
         let fills_target = |src: &GpuBufferImage, offset_x: i32, offset_y: i32| {
@@ -808,10 +1506,10 @@ impl GpuRasterBackend for GpuRenderer {
         };
         let first_fills_target = sources
             .first()
-            .is_some_and(|(src, offset_x, offset_y)| fills_target(src, *offset_x, *offset_y));
+            .is_some_and(|(src, offset_x, offset_y, _)| fills_target(src, *offset_x, *offset_y));
         let known_opaque = sources
             .iter()
-            .any(|(src, offset_x, offset_y)| fills_target(src, *offset_x, *offset_y));
+            .any(|(src, offset_x, offset_y, _)| fills_target(src, *offset_x, *offset_y));
 
         let target_image = self.empty_image_with_opacity(target, known_opaque)?;
         let mut encoder = self
@@ -820,8 +1518,9 @@ impl GpuRasterBackend for GpuRenderer {
                 label: Some("tellur-gpu-composite"),
             });
 
+        // Over the transparent target every blend mode reduces to a copy.
         let start = if first_fills_target {
-            let (src, _, _) = &sources[0];
+            let (src, _, _, _) = &sources[0];
             let len = (target.width as u64) * (target.height as u64) * 4;
             encoder.copy_buffer_to_buffer(&src.buffer, 0, &target_image.buffer, 0, len);
             1
@@ -829,8 +1528,15 @@ impl GpuRasterBackend for GpuRenderer {
             0
         };
 
-        for (src, offset_x, offset_y) in sources.iter().skip(start) {
-            self.composite_one(&mut encoder, &target_image, src, *offset_x, *offset_y);
+        for (src, offset_x, offset_y, mode) in sources.iter().skip(start) {
+            self.composite_one(
+                &mut encoder,
+                &target_image,
+                src,
+                *offset_x,
+                *offset_y,
+                *mode,
+            );
         }
 
         self.queue.submit(Some(encoder.finish()));
@@ -871,6 +1577,7 @@ impl GpuRasterBackend for GpuRenderer {
             &child,
             input.child_offset_x,
             input.child_offset_y,
+            BlendMode::Normal,
         );
 
         self.queue.submit(Some(encoder.finish()));
@@ -912,6 +1619,7 @@ impl GpuRasterBackend for GpuRenderer {
             &child,
             input.child_offset_x,
             input.child_offset_y,
+            BlendMode::Normal,
         );
 
         self.queue.submit(Some(encoder.finish()));
@@ -919,166 +1627,850 @@ impl GpuRasterBackend for GpuRenderer {
         Some(self.raster_image(target))
     }
 
-    fn rasterize(&mut self, graphic: &VectorGraphic, target: Resolution) -> Option<RasterImage> {
-        let target_image = self.render_vello_graphic(graphic, target)?;
-        self.stats.rasterizes = self.stats.rasterizes.saturating_add(1);
-        Some(self.raster_image(target_image))
-    }
-
-    fn solid_fill(&mut self, target: Resolution, color: Color) -> Option<RasterImage> {
-        let [r, g, b, a] = color_u8(color);
-        let packed = r | (g << 8) | (b << 16) | (a << 24);
-        let image = self.filled_image(target, packed)?;
-        self.stats.fills = self.stats.fills.saturating_add(1);
-        Some(self.raster_image(image))
-    }
-
-    fn temporal_average(
-        &mut self,
-        target: Resolution,
-        frames: &[&RasterImage],
-        total: u32,
-    ) -> Option<RasterImage> {
-        if total == 0 || frames.is_empty() || frames.len() as u32 > total {
+    fn blur(&mut self, input: BlurInput<'_>) -> Option<RasterImage> {
+        let child = self.image_ref(input.child)?;
+        if child.format != PixelFormat::Rgba8 || input.target.width == 0 || input.target.height == 0
+        {
             return None;
         }
-        // Resolve every source up front so an unsupported frame bails before
-        // any GPU work is encoded.
-        let mut sources = Vec::with_capacity(frames.len());
-        for frame in frames {
-            if frame.width() != target.width || frame.height() != target.height {
-                return None;
-            }
-            let src = self.image_ref(frame)?;
-            if src.format != PixelFormat::Rgba8 {
-                return None;
+        let (width, height) = (input.target.width, input.target.height);
+        let plane_a = self.color_plane(width, height)?;
+        let plane_b = self.color_plane(width, height)?;
+        let target = self.empty_image(input.target)?;
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-blur"),
+            });
+        let place = CopyAlphaParams {
+            src_w: child.width,
+            src_h: child.height,
+            out_w: width,
+            out_h: height,
+            offset_x: input.child_offset_x,
+            offset_y: input.child_offset_y,
+            _pad0: 0,
+            _pad1: 0,
+        };
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.premultiply_pipeline,
+            [&child.buffer, &plane_a.buffer],
+            &place,
+            DispatchSize::new(width, height),
+        );
+        let (mut src, mut dst) = (&plane_a, &plane_b);
+        for _ in 0..3 {
+            for axis in input.axes {
+                if axis.0 == 0.0 && axis.1 == 0.0 {
+                    continue;
+                }
+                self.color_blur_pass(&mut encoder, src, dst, axis);
+                std::mem::swap(&mut src, &mut dst);
             }
-            sources.push(src);
         }
-
-        // Premultiplied u32 channel sums, 4 words per pixel. A fresh wgpu
-        // buffer is zero-initialized, so accumulation starts from zero
-        // without an explicit clear pass.
-        let acc_len = (target.width as u64) * (target.height as u64) * 16;
-        let _acc_reservation = self.reserve_render_vram(acc_len as usize)?;
-        let acc = self.device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("tellur-gpu-motion-acc"),
-            size: acc_len,
-            usage: wgpu::BufferUsages::STORAGE,
-            mapped_at_creation: false,
-        });
-        let out = self.empty_image(target)?;
-
-        let params = MotionPassParams {
-            width: target.width,
-            height: target.height,
-            total,
+        let resolve = PlaneParams {
+            width,
+            height,
             _pad0: 0,
+            _pad1: 0,
         };
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.unpremultiply_pipeline,
+            [&src.buffer, &target.buffer],
+            &resolve,
+            DispatchSize::new(width, height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.blurs = self.stats.blurs.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn outer_glow(&mut self, input: OuterGlowInput<'_>) -> Option<RasterImage> {
+        let child = self.image_ref(input.child)?;
+        if child.format != PixelFormat::Rgba8 {
+            return None;
+        }
+        let (width, height) = (input.target.width, input.target.height);
+        let alpha_a = self.alpha_image(width, height)?;
+        let alpha_b = self.alpha_image(width, height)?;
+        let target = self.empty_image(input.target)?;
+
         let mut encoder = self
             .device
             .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                label: Some("tellur-gpu-temporal-average"),
+                label: Some("tellur-gpu-outer-glow"),
             });
-        for src in &sources {
+        self.copy_alpha(
+            &mut encoder,
+            &child,
+            &alpha_a,
+            input.child_offset_x,
+            input.child_offset_y,
+        );
+        let (mut glow, mut scratch) = (&alpha_a, &alpha_b);
+        if input.spread_x > 0 || input.spread_y > 0 {
+            let params = DilateParams {
+                width,
+                height,
+                radius_x: input.spread_x,
+                radius_y: input.spread_y,
+            };
             dispatch_three_buffer(
                 &self.device,
                 &mut encoder,
-                &self.motion_accum_pipeline,
-                [&acc, &src.buffer],
+                &self.dilate_pipeline,
+                [&glow.buffer, &scratch.buffer],
                 &params,
-                DispatchSize::new(target.width, target.height),
+                DispatchSize::new(width, height),
             );
+            std::mem::swap(&mut glow, &mut scratch);
         }
-        dispatch_three_buffer(
-            &self.device,
+        self.blur_alpha(&mut encoder, glow, scratch, input.blur_radius);
+        self.composite_shadow_alpha(&mut encoder, &target, glow, 0, 0, input.color);
+        self.composite_one(
             &mut encoder,
-            &self.motion_resolve_pipeline,
-            [&acc, &out.buffer],
-            &params,
-            DispatchSize::new(target.width, target.height),
+            &target,
+            &child,
+            input.child_offset_x,
+            input.child_offset_y,
+            BlendMode::Normal,
         );
+
         self.queue.submit(Some(encoder.finish()));
-        self.stats.temporal_averages = self.stats.temporal_averages.saturating_add(1);
-        Some(self.raster_image(out))
+        self.stats.glows = self.stats.glows.saturating_add(1);
+        Some(self.raster_image(target))
     }
 
-    fn readback(&mut self, image: RasterImage) -> Option<CpuRasterImage> {
-        match image {
-            RasterImage::Cpu(image) => Some(image),
-            RasterImage::Gpu(surface) if surface.backend() == BACKEND => {
-                let image = Arc::downcast::<GpuBufferImage>(surface.handle_arc()).ok()?;
-                let byte_len = Self::buffer_image_bytes(&image);
-                // Reuse a persisted MAP_READ staging buffer across frames (the
-                // resolution is stable), so this allocates once instead of every
-                // readback. The copy below fully overwrites it each time.
-                if self
-                    .readback_staging
-                    .as_ref()
-                    .map(|(buffer, _)| buffer.size())
-                    != Some(byte_len as u64)
-                {
-                    self.readback_staging = None;
-                    let reservation = self.reserve_render_vram(byte_len).or_else(|| {
-                        self.release_cached_resources();
-                        self.reserve_render_vram(byte_len)
-                    })?;
-                    self.readback_staging = Some((
-                        self.device.create_buffer(&wgpu::BufferDescriptor {
-                            label: Some("tellur-gpu-readback"),
-                            size: byte_len as u64,
-                            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
-                            mapped_at_creation: false,
-                        }),
-                        reservation,
-                    ));
-                }
-                let staging = &self.readback_staging.as_ref()?.0;
-                let mut encoder =
-                    self.device
-                        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
-                            label: Some("tellur-gpu-readback"),
-                        });
-                encoder.copy_buffer_to_buffer(&image.buffer, 0, staging, 0, byte_len as u64);
-                self.queue.submit(Some(encoder.finish()));
-
-                let slice = staging.slice(..);
-                let (tx, rx) = std::sync::mpsc::channel();
-                slice.map_async(wgpu::MapMode::Read, move |result| {
-                    let _ = tx.send(result);
-                });
-                self.device.poll(wgpu::Maintain::Wait);
-                rx.recv().ok()?.ok()?;
+    fn bloom(&mut self, input: BloomInput<'_>) -> Option<RasterImage> {
+        let child = self.image_ref(input.child)?;
+        if child.format != PixelFormat::Rgba8 || input.target.width == 0 || input.target.height == 0
+        {
+            return None;
+        }
+        let (width, height) = (input.target.width, input.target.height);
+        let plane_a = self.color_plane(width, height)?;
+        let plane_b = self.color_plane(width, height)?;
+        let target = self.empty_image(input.target)?;
 
-                let data = {
-                    let mapped = slice.get_mapped_range();
-                    mapped.to_vec()
-                };
-                staging.unmap();
-                self.stats.readbacks = self.stats.readbacks.saturating_add(1);
-                Some(CpuRasterImage::new(
-                    image.width,
-                    image.height,
-                    image.format,
-                    data,
-                ))
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-bloom"),
+            });
+        let bright = BrightPassParams {
+            src_w: child.width,
+            src_h: child.height,
+            out_w: width,
+            out_h: height,
+            offset_x: input.child_offset_x,
+            offset_y: input.child_offset_y,
+            threshold: input.threshold,
+            _pad0: 0,
+        };
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.bright_pass_pipeline,
+            [&child.buffer, &plane_a.buffer],
+            &bright,
+            DispatchSize::new(width, height),
+        );
+        let (mut src, mut dst) = (&plane_a, &plane_b);
+        for _ in 0..3 {
+            for axis in input.axes {
+                if axis.0 == 0.0 && axis.1 == 0.0 {
+                    continue;
+                }
+                self.color_blur_pass(&mut encoder, src, dst, axis);
+                std::mem::swap(&mut src, &mut dst);
             }
-            RasterImage::Gpu(_) => None,
         }
+        // The free plane takes the child itself; the blurred light is added
+        // on top before resolving.
+        let place = CopyAlphaParams {
+            src_w: child.width,
+            src_h: child.height,
+            out_w: width,
+            out_h: height,
+            offset_x: input.child_offset_x,
+            offset_y: input.child_offset_y,
+            _pad0: 0,
+            _pad1: 0,
+        };
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.premultiply_pipeline,
+            [&child.buffer, &dst.buffer],
+            &place,
+            DispatchSize::new(width, height),
+        );
+        let add = AddPlaneParams {
+            width,
+            height,
+            scale: input.intensity,
+            _pad0: 0,
+        };
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.add_plane_pipeline,
+            [&src.buffer, &dst.buffer],
+            &add,
+            DispatchSize::new(width, height),
+        );
+        let resolve = PlaneParams {
+            width,
+            height,
+            _pad0: 0,
+            _pad1: 0,
+        };
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.unpremultiply_pipeline,
+            [&dst.buffer, &target.buffer],
+            &resolve,
+            DispatchSize::new(width, height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.blooms = self.stats.blooms.saturating_add(1);
+        Some(self.raster_image(target))
     }
-}
 
-fn compute_pipeline(
-    device: &wgpu::Device,
-    label: &'static str,
-    source: &str,
-) -> wgpu::ComputePipeline {
-    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
-        label: Some(label),
-        source: wgpu::ShaderSource::Wgsl(Cow::Owned(source.to_owned())),
-    });
-    device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
-        label: Some(label),
+    fn color_adjust(&mut self, input: ColorAdjustInput<'_>) -> Option<RasterImage> {
+        let src = self.image_ref(input.image)?;
+        if src.format != PixelFormat::Rgba8 {
+            return None;
+        }
+        let resolution = Resolution::new(src.width, src.height);
+        let target = self.empty_image(resolution)?;
+        let adjustment = input.adjustment;
+        let hue = adjustment.hue_matrix().map(|[a, b, c]| [a, b, c, 0.0]);
+        let params = ColorAdjustParams {
+            width: src.width,
+            height: src.height,
+            exposure_scale: adjustment.exposure.exp2(),
+            brightness: adjustment.brightness,
+            contrast: adjustment.contrast,
+            saturation: adjustment.saturation,
+            inverse_gamma: 1.0 / adjustment.gamma.max(1e-3),
+            tint_amount: adjustment.tint.a.clamp(0.0, 1.0),
+            tint_r: adjustment.tint.r,
+            tint_g: adjustment.tint.g,
+            tint_b: adjustment.tint.b,
+            _pad0: 0,
+            hue,
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-color-adjust"),
+            });
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.color_adjust_pipeline,
+            [&src.buffer, &target.buffer],
+            &params,
+            DispatchSize::new(src.width, src.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.color_grades = self.stats.color_grades.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn lut3d(&mut self, input: Lut3dInput<'_>) -> Option<RasterImage> {
+        let src = self.image_ref(input.image)?;
+        if src.format != PixelFormat::Rgba8 {
+            return None;
+        }
+        self.upload_lut(input.lut)?;
+        let resolution = Resolution::new(src.width, src.height);
+        let target = self.empty_image(resolution)?;
+        let (domain_min, domain_max) = input.lut.domain();
+        let params = LutParams {
+            width: src.width,
+            height: src.height,
+            size: input.lut.size(),
+            mix: input.mix.clamp(0.0, 1.0),
+            domain_min: [domain_min[0], domain_min[1], domain_min[2], 0.0],
+            domain_max: [domain_max[0], domain_max[1], domain_max[2], 1.0],
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-lut3d"),
+            });
+        // The pass grades `target` in place, so it starts as a copy of the
+        // source; that leaves the LUT table the second binding.
+        let len = (src.width as u64) * (src.height as u64) * 4;
+        encoder.copy_buffer_to_buffer(&src.buffer, 0, &target.buffer, 0, len);
+        let (_, table, _) = self.lut_table.as_ref()?;
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.lut_pipeline,
+            [&target.buffer, table],
+            &params,
+            DispatchSize::new(src.width, src.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.color_grades = self.stats.color_grades.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn matte(&mut self, input: MatteInput<'_>) -> Option<RasterImage> {
+        let src = self.image_ref(input.content)?;
+        let matte = self.image_ref(input.matte)?;
+        if src.format != PixelFormat::Rgba8 || matte.format != PixelFormat::Rgba8 {
+            return None;
+        }
+        let target = self.empty_image(Resolution::new(src.width, src.height))?;
+        let params = MatteParams {
+            width: src.width,
+            height: src.height,
+            matte_width: matte.width,
+            matte_height: matte.height,
+            offset_x: input.matte_offset_x,
+            offset_y: input.matte_offset_y,
+            mode: matte_mode_code(input.mode),
+            _pad0: 0,
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-matte"),
+            });
+        // Masked in place, like the LUT pass, so the matte can be the second
+        // binding.
+        let len = (src.width as u64) * (src.height as u64) * 4;
+        encoder.copy_buffer_to_buffer(&src.buffer, 0, &target.buffer, 0, len);
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.matte_pipeline,
+            [&target.buffer, &matte.buffer],
+            &params,
+            DispatchSize::new(src.width, src.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.mattes = self.stats.mattes.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn chroma_key(&mut self, input: ChromaKeyInput<'_>) -> Option<RasterImage> {
+        let src = self.image_ref(input.image)?;
+        if src.format != PixelFormat::Rgba8 {
+            return None;
+        }
+        let resolution = Resolution::new(src.width, src.height);
+        // Each pixel's smallest key along its row of the choke square, as
+        // f32 bits.
+        let row_keys = self.empty_image(resolution)?;
+        let target = self.empty_image(resolution)?;
+        let settings = input.settings;
+        let [key_cb, key_cr] = settings.key_chroma();
+        let params = ChromaKeyParams {
+            width: src.width,
+            height: src.height,
+            choke_radius: input.choke_radius,
+            tolerance: settings.tolerance,
+            softness: settings.softness,
+            spill: settings.spill.clamp(0.0, 1.0),
+            key_cb,
+            key_cr,
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-chroma-key"),
+            });
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.chroma_choke_pipeline,
+            [&src.buffer, &row_keys.buffer],
+            &params,
+            DispatchSize::new(src.width, src.height),
+        );
+        dispatch_buffers(
+            &self.device,
+            &mut encoder,
+            &self.chroma_key_pipeline,
+            &[&src.buffer, &row_keys.buffer, &target.buffer],
+            &params,
+            DispatchSize::new(src.width, src.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.chroma_keys = self.stats.chroma_keys.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn resample(&mut self, input: ResampleInput<'_>) -> Option<RasterImage> {
+        let target = self.dispatch_resample(
+            input.image,
+            input.target,
+            Homography::from(input.inverse),
+            0.0,
+            input.quality,
+        )?;
+        self.stats.resamples = self.stats.resamples.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn project(&mut self, input: ProjectInput<'_>) -> Option<RasterImage> {
+        let target = self.dispatch_resample(
+            input.image,
+            input.target,
+            input.inverse,
+            NEAR_PLANE,
+            input.quality,
+        )?;
+        self.stats.projections = self.stats.projections.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn depth_composite(&mut self, input: DepthCompositeInput<'_>) -> Option<RasterImage> {
+        let target = input.target;
+        if input.layers.len() > MAX_DEPTH_LAYERS || target.width == 0 || target.height == 0 {
+            return None;
+        }
+        let mut sources = Vec::with_capacity(input.layers.len());
+        for layer in input.layers {
+            let src = self.image_ref(layer.image)?;
+            if src.format != PixelFormat::Rgba8 {
+                return None;
+            }
+            sources.push(src);
+        }
+        let output = self.empty_image(target)?;
+        if sources.is_empty() {
+            self.stats.depth_composites = self.stats.depth_composites.saturating_add(1);
+            return Some(self.raster_image(output));
+        }
+
+        // The shader reads every layer through one binding, so pack their
+        // pixels back to back.
+        let mut descs = Vec::with_capacity(sources.len());
+        let mut base = 0u32;
+        for (layer, src) in input.layers.iter().zip(&sources) {
+            let row = |r: [f32; 3]| [r[0], r[1], r[2], 0.0];
+            descs.push(DepthLayerParams {
+                width: src.width,
+                height: src.height,
+                offset_x: layer.offset[0],
+                offset_y: layer.offset[1],
+                base,
+                mode: blend_mode_code(layer.blend),
+                _pad0: [0; 2],
+                inverse: layer.inverse.m.map(row),
+                depth: row(layer.depth),
+            });
+            base = base.checked_add(src.width.checked_mul(src.height)?)?;
+        }
+        let packed_len = base as usize * 4;
+        let _reservation = self.reserve_render_vram(packed_len)?;
+        let packed = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("tellur-gpu-depth-layers"),
+            size: packed_len as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let layers = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("tellur-gpu-depth-layer-params"),
+                contents: bytemuck::cast_slice(&descs),
+                usage: wgpu::BufferUsages::STORAGE,
+            });
+        let params = DepthCompositeParams {
+            width: target.width,
+            height: target.height,
+            count: descs.len() as u32,
+            _pad0: 0,
+            origin: [input.origin.0, input.origin.1],
+            scale: [input.scale.0, input.scale.1],
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-depth-composite"),
+            });
+        for (desc, src) in descs.iter().zip(&sources) {
+            encoder.copy_buffer_to_buffer(
+                &src.buffer,
+                0,
+                &packed,
+                u64::from(desc.base) * 4,
+                Self::buffer_image_bytes(src) as u64,
+            );
+        }
+        dispatch_buffers(
+            &self.device,
+            &mut encoder,
+            &self.depth_composite_pipeline,
+            &[&output.buffer, &packed, &layers],
+            &params,
+            DispatchSize::new(target.width, target.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.depth_composites = self.stats.depth_composites.saturating_add(1);
+        Some(self.raster_image(output))
+    }
+
+    fn warp(&mut self, input: WarpInput<'_>) -> Option<RasterImage> {
+        let src = self.image_ref(input.image)?;
+        let WarpPlacement {
+            paint,
+            target,
+            offset,
+        } = input.placement;
+        if src.format != PixelFormat::Rgba8 || paint.size.0 <= 0.0 || paint.size.1 <= 0.0 {
+            return None;
+        }
+        let (kind, p, map) = match input.field {
+            WarpField::Distort(distortion) => {
+                let (kind, p) = warp_params(&distortion, input.size);
+                (kind, p, None)
+            }
+            WarpField::Displace {
+                map,
+                map_offset,
+                amount,
+            } => {
+                let map = self.image_ref(map)?;
+                if map.format != PixelFormat::Rgba8 {
+                    return None;
+                }
+                (
+                    4,
+                    [amount.0, amount.1, 0.0, 0.0, 0.0],
+                    Some((map, map_offset)),
+                )
+            }
+        };
+        let output = self.empty_image(target)?;
+        let params = WarpParams {
+            width: target.width,
+            height: target.height,
+            kind,
+            quality: image_quality_code(input.quality),
+            origin_x: paint.origin.0,
+            origin_y: paint.origin.1,
+            scale_x: target.width as f32 / paint.size.0,
+            scale_y: target.height as f32 / paint.size.1,
+            p,
+            map_width: map.as_ref().map_or(0, |(map, _)| map.width),
+            map_height: map.as_ref().map_or(0, |(map, _)| map.height),
+            map_offset_x: map.as_ref().map_or(0, |(_, offset)| offset[0]),
+            map_offset_y: map.as_ref().map_or(0, |(_, offset)| offset[1]),
+            src_width: src.width,
+            src_height: src.height,
+            src_offset_x: offset[0],
+            src_offset_y: offset[1],
+            _pad: [0; 3],
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-warp"),
+            });
+        // Procedural warps read no map; the source stands in for the binding.
+        let map_buffer = map.as_ref().map_or(&src.buffer, |(map, _)| &map.buffer);
+        dispatch_buffers(
+            &self.device,
+            &mut encoder,
+            &self.warp_pipeline,
+            &[&src.buffer, map_buffer, &output.buffer],
+            &params,
+            DispatchSize::new(target.width, target.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.warps = self.stats.warps.saturating_add(1);
+        Some(self.raster_image(output))
+    }
+
+    fn grain(&mut self, input: GrainInput<'_>) -> Option<RasterImage> {
+        let src = self.image_ref(input.image)?;
+        if src.format != PixelFormat::Rgba8 {
+            return None;
+        }
+        let target = self.empty_image(Resolution::new(src.width, src.height))?;
+        let noise = input.noise;
+        let params = GrainParams {
+            width: src.width,
+            height: src.height,
+            seed: noise.seed,
+            frame: noise.frame,
+            monochrome: noise.monochrome as u32,
+            cells_per_pixel: noise.cells_per_pixel,
+            amount: noise.amount * 255.0,
+            _pad0: 0,
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-grain"),
+            });
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.grain_pipeline,
+            [&src.buffer, &target.buffer],
+            &params,
+            DispatchSize::new(src.width, src.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.grains = self.stats.grains.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn dither(&mut self, input: DitherInput<'_>) -> Option<RasterImage> {
+        let src = self.image_ref(input.image)?;
+        if src.format != PixelFormat::Rgba8 {
+            return None;
+        }
+        self.upload_blue_noise()?;
+        let target = self.empty_image(Resolution::new(src.width, src.height))?;
+        let [offset_x, offset_y] = input.dither.offset(input.frame);
+        let params = DitherParams {
+            width: src.width,
+            height: src.height,
+            kind: dither_code(input.dither),
+            offset_x,
+            offset_y,
+            _pad: [0; 3],
+        };
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-dither"),
+            });
+        // Dithered in place, like the LUT pass, so the blue-noise tile can be
+        // the second binding.
+        let len = (src.width as u64) * (src.height as u64) * 4;
+        encoder.copy_buffer_to_buffer(&src.buffer, 0, &target.buffer, 0, len);
+        let (table, _) = self.blue_noise_table.as_ref()?;
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.dither_pipeline,
+            [&target.buffer, table],
+            &params,
+            DispatchSize::new(src.width, src.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.dithers = self.stats.dithers.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn pattern(&mut self, input: PatternInput<'_>) -> Option<RasterImage> {
+        let params = pattern_params(&input)?;
+        let target = self.empty_image(input.target)?;
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-pattern"),
+            });
+        dispatch_buffers(
+            &self.device,
+            &mut encoder,
+            &self.pattern_pipeline,
+            &[&target.buffer],
+            &params,
+            DispatchSize::new(target.width, target.height),
+        );
+
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.patterns = self.stats.patterns.saturating_add(1);
+        Some(self.raster_image(target))
+    }
+
+    fn rasterize(&mut self, graphic: &VectorGraphic, target: Resolution) -> Option<RasterImage> {
+        let target_image = self.render_vello_graphic(graphic, target)?;
+        self.stats.rasterizes = self.stats.rasterizes.saturating_add(1);
+        Some(self.raster_image(target_image))
+    }
+
+    fn solid_fill(&mut self, target: Resolution, color: Color) -> Option<RasterImage> {
+        let [r, g, b, a] = color_u8(color);
+        let packed = r | (g << 8) | (b << 16) | (a << 24);
+        let image = self.filled_image(target, packed)?;
+        self.stats.fills = self.stats.fills.saturating_add(1);
+        Some(self.raster_image(image))
+    }
+
+    fn temporal_average(
+        &mut self,
+        target: Resolution,
+        frames: &[&RasterImage],
+        total: u32,
+    ) -> Option<RasterImage> {
+        if total == 0 || frames.is_empty() || frames.len() as u32 > total {
+            return None;
+        }
+        // Resolve every source up front so an unsupported frame bails before
+        // any GPU work is encoded.
+        let mut sources = Vec::with_capacity(frames.len());
+        for frame in frames {
+            if frame.width() != target.width || frame.height() != target.height {
+                return None;
+            }
+            let src = self.image_ref(frame)?;
+            if src.format != PixelFormat::Rgba8 {
+                return None;
+            }
+            sources.push(src);
+        }
+
+        // Premultiplied u32 channel sums, 4 words per pixel. A fresh wgpu
+        // buffer is zero-initialized, so accumulation starts from zero
+        // without an explicit clear pass.
+        let acc_len = (target.width as u64) * (target.height as u64) * 16;
+        let _acc_reservation = self.reserve_render_vram(acc_len as usize)?;
+        let acc = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("tellur-gpu-motion-acc"),
+            size: acc_len,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
+        let out = self.empty_image(target)?;
+
+        let params = MotionPassParams {
+            width: target.width,
+            height: target.height,
+            total,
+            _pad0: 0,
+        };
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("tellur-gpu-temporal-average"),
+            });
+        for src in &sources {
+            dispatch_three_buffer(
+                &self.device,
+                &mut encoder,
+                &self.motion_accum_pipeline,
+                [&acc, &src.buffer],
+                &params,
+                DispatchSize::new(target.width, target.height),
+            );
+        }
+        dispatch_three_buffer(
+            &self.device,
+            &mut encoder,
+            &self.motion_resolve_pipeline,
+            [&acc, &out.buffer],
+            &params,
+            DispatchSize::new(target.width, target.height),
+        );
+        self.queue.submit(Some(encoder.finish()));
+        self.stats.temporal_averages = self.stats.temporal_averages.saturating_add(1);
+        Some(self.raster_image(out))
+    }
+
+    fn readback(&mut self, image: RasterImage) -> Option<CpuRasterImage> {
+        match image {
+            RasterImage::Cpu(image) => Some(image),
+            RasterImage::Gpu(surface) if surface.backend() == BACKEND => {
+                let image = Arc::downcast::<GpuBufferImage>(surface.handle_arc()).ok()?;
+                let byte_len = Self::buffer_image_bytes(&image);
+                // Reuse a persisted MAP_READ staging buffer across frames (the
+                // resolution is stable), so this allocates once instead of every
+                // readback. The copy below fully overwrites it each time.
+                if self
+                    .readback_staging
+                    .as_ref()
+                    .map(|(buffer, _)| buffer.size())
+                    != Some(byte_len as u64)
+                {
+                    self.readback_staging = None;
+                    let reservation = self.reserve_render_vram(byte_len).or_else(|| {
+                        self.release_cached_resources();
+                        self.reserve_render_vram(byte_len)
+                    })?;
+                    self.readback_staging = Some((
+                        self.device.create_buffer(&wgpu::BufferDescriptor {
+                            label: Some("tellur-gpu-readback"),
+                            size: byte_len as u64,
+                            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                            mapped_at_creation: false,
+                        }),
+                        reservation,
+                    ));
+                }
+                let staging = &self.readback_staging.as_ref()?.0;
+                let mut encoder =
+                    self.device
+                        .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                            label: Some("tellur-gpu-readback"),
+                        });
+                encoder.copy_buffer_to_buffer(&image.buffer, 0, staging, 0, byte_len as u64);
+                self.queue.submit(Some(encoder.finish()));
+
+                let slice = staging.slice(..);
+                let (tx, rx) = std::sync::mpsc::channel();
+                slice.map_async(wgpu::MapMode::Read, move |result| {
+                    let _ = tx.send(result);
+                });
+                self.device.poll(wgpu::Maintain::Wait);
+                rx.recv().ok()?.ok()?;
+
+                let data = {
+                    let mapped = slice.get_mapped_range();
+                    mapped.to_vec()
+                };
+                staging.unmap();
+                self.stats.readbacks = self.stats.readbacks.saturating_add(1);
+                Some(CpuRasterImage::new(
+                    image.width,
+                    image.height,
+                    image.format,
+                    data,
+                ))
+            }
+            RasterImage::Gpu(_) => None,
+        }
+    }
+}
+
+fn compute_pipeline(
+    device: &wgpu::Device,
+    label: &'static str,
+    source: &str,
+) -> wgpu::ComputePipeline {
+    let shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some(label),
+        source: wgpu::ShaderSource::Wgsl(Cow::Owned(source.to_owned())),
+    });
+    device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some(label),
         layout: None,
         module: &shader,
         entry_point: "main",
@@ -1086,500 +2478,1954 @@ fn compute_pipeline(
     })
 }
 
-fn create_vello_renderer(device: &wgpu::Device) -> Option<vello::Renderer> {
-    vello::Renderer::new(
-        device,
-        vello::RendererOptions {
-            surface_format: None,
-            use_cpu: false,
-            antialiasing_support: vello::AaSupport::all(),
-            num_init_threads: std::num::NonZeroUsize::new(1),
-        },
-    )
-    .ok()
+fn create_vello_renderer(device: &wgpu::Device) -> Option<vello::Renderer> {
+    vello::Renderer::new(
+        device,
+        vello::RendererOptions {
+            surface_format: None,
+            use_cpu: false,
+            antialiasing_support: vello::AaSupport::all(),
+            num_init_threads: std::num::NonZeroUsize::new(1),
+        },
+    )
+    .ok()
+}
+
+#[derive(Clone, Copy)]
+struct DispatchSize {
+    width: u32,
+    height: u32,
+}
+
+impl DispatchSize {
+    fn new(width: u32, height: u32) -> Self {
+        Self { width, height }
+    }
+}
+
+fn dispatch_three_buffer<P: bytemuck::Pod>(
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    pipeline: &wgpu::ComputePipeline,
+    buffers: [&wgpu::Buffer; 2],
+    params: &P,
+    size: DispatchSize,
+) {
+    dispatch_buffers(device, encoder, pipeline, &buffers, params, size);
+}
+
+/// Binds `buffers` in order, then `params` after them, and dispatches one
+/// invocation per pixel.
+fn dispatch_buffers<P: bytemuck::Pod>(
+    device: &wgpu::Device,
+    encoder: &mut wgpu::CommandEncoder,
+    pipeline: &wgpu::ComputePipeline,
+    buffers: &[&wgpu::Buffer],
+    params: &P,
+    size: DispatchSize,
+) {
+    let params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("tellur-gpu-params"),
+        contents: bytemuck::bytes_of(params),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+    let layout = pipeline.get_bind_group_layout(0);
+    let entries: Vec<wgpu::BindGroupEntry<'_>> = buffers
+        .iter()
+        .chain(std::iter::once(&&params))
+        .enumerate()
+        .map(|(binding, buffer)| wgpu::BindGroupEntry {
+            binding: binding as u32,
+            resource: buffer.as_entire_binding(),
+        })
+        .collect();
+    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+        label: Some("tellur-gpu-bind-group"),
+        layout: &layout,
+        entries: &entries,
+    });
+
+    let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+        label: Some("tellur-gpu-pass"),
+        timestamp_writes: None,
+    });
+    pass.set_pipeline(pipeline);
+    pass.set_bind_group(0, &bind_group, &[]);
+    pass.dispatch_workgroups(
+        div_ceil(size.width, WORKGROUP),
+        div_ceil(size.height, WORKGROUP),
+        1,
+    );
+}
+
+fn div_ceil(n: u32, d: u32) -> u32 {
+    if n == 0 {
+        0
+    } else {
+        1 + (n - 1) / d
+    }
+}
+
+fn color_u8(color: Color) -> [u32; 4] {
+    [
+        (color.r * 255.0).round().clamp(0.0, 255.0) as u32,
+        (color.g * 255.0).round().clamp(0.0, 255.0) as u32,
+        (color.b * 255.0).round().clamp(0.0, 255.0) as u32,
+        (color.a * 255.0).round().clamp(0.0, 255.0) as u32,
+    ]
+}
+
+fn build_vello_scene(graphic: &VectorGraphic, target: Resolution) -> Option<vello::Scene> {
+    if target.width == 0
+        || target.height == 0
+        || graphic.view_box.size.0 <= 0.0
+        || graphic.view_box.size.1 <= 0.0
+    {
+        return None;
+    }
+
+    let sx = target.width as f32 / graphic.view_box.size.0;
+    let sy = target.height as f32 / graphic.view_box.size.1;
+    let view = Transform {
+        a: sx,
+        b: 0.0,
+        c: 0.0,
+        d: sy,
+        tx: -graphic.view_box.origin.0 * sx,
+        ty: -graphic.view_box.origin.1 * sy,
+    };
+    let clip = VelloRect::new(0.0, 0.0, target.width as f64, target.height as f64);
+    let mut scene = vello::Scene::new();
+    encode_vello_node(&mut scene, &graphic.root, view, &clip, 1.0)?;
+    Some(scene)
+}
+
+fn encode_vello_node(
+    scene: &mut vello::Scene,
+    node: &Node,
+    transform: Transform,
+    clip: &VelloRect,
+    opacity: f32,
+) -> Option<()> {
+    match node {
+        Node::Group(group) => {
+            let opacity = opacity * group.opacity.clamp(0.0, 1.0);
+            if opacity <= 0.0 {
+                return Some(());
+            }
+            let transform = concat_transform(transform, group.transform);
+            for child in &group.children {
+                encode_vello_node(scene, child, transform, clip, opacity)?;
+            }
+        }
+        Node::SingleGroup(group) => {
+            let opacity = opacity * group.opacity.clamp(0.0, 1.0);
+            if opacity <= 0.0 {
+                return Some(());
+            }
+            let transform = concat_transform(transform, group.transform);
+            encode_vello_node(scene, &group.child, transform, clip, opacity)?;
+        }
+        Node::ClipGroup(group) => encode_vello_clip_group(scene, group, transform, clip, opacity)?,
+        Node::Path(path) => encode_vello_path(scene, path, transform, clip, opacity)?,
+    }
+    Some(())
+}
+
+fn encode_vello_clip_group(
+    scene: &mut vello::Scene,
+    group: &TellurClipGroup,
+    transform: Transform,
+    outer_clip: &VelloRect,
+    opacity: f32,
+) -> Option<()> {
+    // Vello 0.2 always resolves layer clips with the nonzero rule, so an
+    // even-odd clip defers the whole graphic to the CPU rasterizer.
+    if group.fill_rule != FillRule::NonZero {
+        return None;
+    }
+    let Some(clip_path) = build_vello_path(&group.commands) else {
+        return Some(());
+    };
+    let clip_transform = concat_transform(transform, group.transform);
+    scene.push_layer(
+        vello::peniko::BlendMode::default(),
+        1.0,
+        to_vello_affine(clip_transform),
+        &clip_path,
+    );
+    encode_vello_node(scene, &group.child, transform, outer_clip, opacity)?;
+    scene.pop_layer();
+    Some(())
+}
+
+fn to_vello_fill(rule: FillRule) -> vello::peniko::Fill {
+    match rule {
+        FillRule::NonZero => vello::peniko::Fill::NonZero,
+        FillRule::EvenOdd => vello::peniko::Fill::EvenOdd,
+    }
+}
+
+fn encode_vello_path(
+    scene: &mut vello::Scene,
+    path: &TellurPath,
+    transform: Transform,
+    clip: &VelloRect,
+    opacity: f32,
+) -> Option<()> {
+    if path.fill.is_none() && path.stroke.is_none() {
+        return Some(());
+    }
+    let transform = concat_transform(transform, path.transform);
+    let Some(vello_path) = build_vello_path(&path.commands) else {
+        return Some(());
+    };
+    let profile_outline = path.width_profile_outline(transform);
+    let transform = to_vello_affine(transform);
+
+    if let Some(fill) = &path.fill {
+        let bounds = vello_path.bounding_box();
+        if let Some((brush, brush_transform)) =
+            to_vello_paint(&fill.paint, opacity, transform, bounds, clip)
+        {
+            scene.fill(
+                to_vello_fill(path.fill_rule),
+                transform,
+                &brush,
+                brush_transform,
+                &vello_path,
+            );
+        }
+    }
+
+    if let Some(stroke) = &path.stroke {
+        if let Some(outline) = profile_outline {
+            if let Some(outline) = build_vello_path(&outline) {
+                let bounds = outline.bounding_box();
+                if let Some((brush, brush_transform)) =
+                    to_vello_paint(&stroke.paint, opacity, transform, bounds, clip)
+                {
+                    scene.fill(
+                        vello::peniko::Fill::NonZero,
+                        transform,
+                        &brush,
+                        brush_transform,
+                        &outline,
+                    );
+                }
+            }
+        } else if stroke.width > 0.0 {
+            let outset = stroke.width as f64
+                * 0.5
+                * (stroke.miter_limit() as f64).max(std::f64::consts::SQRT_2);
+            let bounds = vello_path.bounding_box().inflate(outset, outset);
+            if let Some((brush, brush_transform)) =
+                to_vello_paint(&stroke.paint, opacity, transform, bounds, clip)
+            {
+                let vello_stroke = to_vello_stroke(stroke);
+                if has_zero_length_closing_segment(&vello_path) {
+                    // Vello 0.2's GPU stroker can misinterpret its synthetic
+                    // closed-subpath marker when the final segment already ends
+                    // at the subpath start, producing a detached full-width band.
+                    // Expanding only this case on the CPU preserves the closed
+                    // join semantics; dropping Close would turn the join into caps.
+                    let outline = vello::kurbo::stroke(
+                        vello_path.iter(),
+                        &vello_stroke,
+                        &Default::default(),
+                        VELLO_STROKE_TOLERANCE,
+                    );
+                    scene.fill(
+                        vello::peniko::Fill::NonZero,
+                        transform,
+                        &brush,
+                        brush_transform,
+                        &outline,
+                    );
+                } else {
+                    scene.stroke(
+                        &vello_stroke,
+                        transform,
+                        &brush,
+                        brush_transform,
+                        &vello_path,
+                    );
+                }
+            }
+        }
+    }
+
+    Some(())
+}
+
+fn to_vello_stroke(stroke: &TellurStroke) -> VelloStroke {
+    let stroke_style = VelloStroke::new(stroke.width as f64)
+        .with_caps(to_vello_cap(stroke.cap))
+        .with_join(to_vello_join(stroke.join))
+        .with_miter_limit(stroke.miter_limit() as f64);
+    apply_dash(stroke_style, stroke.dash.as_ref())
+}
+
+fn to_vello_cap(cap: StrokeCap) -> VelloCap {
+    match cap {
+        StrokeCap::Butt => VelloCap::Butt,
+        StrokeCap::Square => VelloCap::Square,
+        StrokeCap::Round => VelloCap::Round,
+    }
+}
+
+fn to_vello_join(join: StrokeJoin) -> VelloJoin {
+    match join {
+        StrokeJoin::Bevel => VelloJoin::Bevel,
+        StrokeJoin::Miter => VelloJoin::Miter,
+        StrokeJoin::Round => VelloJoin::Round,
+    }
+}
+
+/// Applies a [`DashPattern`] to a [`VelloStroke`], mirroring
+/// `rasterize.rs`'s `to_skia_dash` so the GPU (vello) and CPU (tiny-skia)
+/// backends draw identical dashes. `None`/an unusable pattern (see
+/// [`DashPattern::normalized_lengths`]) leaves the stroke solid.
+fn apply_dash(stroke: VelloStroke, dash: Option<&DashPattern>) -> VelloStroke {
+    let Some(dash) = dash else {
+        return stroke;
+    };
+    let Some(lengths) = dash.normalized_lengths() else {
+        return stroke;
+    };
+    stroke.with_dashes(dash.offset as f64, lengths.into_iter().map(f64::from))
+}
+
+fn build_vello_path(commands: &[PathCommand]) -> Option<BezPath> {
+    let mut path = BezPath::new();
+    let mut has_open_subpath = false;
+    for command in commands {
+        match *command {
+            PathCommand::MoveTo(p) => {
+                path.move_to(to_vello_point(p));
+                has_open_subpath = true;
+            }
+            PathCommand::LineTo(p) => {
+                if has_open_subpath {
+                    path.line_to(to_vello_point(p));
+                }
+            }
+            PathCommand::QuadTo { control, to } => {
+                if has_open_subpath {
+                    path.quad_to(to_vello_point(control), to_vello_point(to));
+                }
+            }
+            PathCommand::CubicTo { c1, c2, to } => {
+                if has_open_subpath {
+                    path.curve_to(to_vello_point(c1), to_vello_point(c2), to_vello_point(to));
+                }
+            }
+            PathCommand::Close => {
+                if has_open_subpath {
+                    path.close_path();
+                    has_open_subpath = false;
+                }
+            }
+        }
+    }
+    (!path.elements().is_empty()).then_some(path)
+}
+
+fn has_zero_length_closing_segment(path: &BezPath) -> bool {
+    let mut subpath_start = None;
+    let mut current = None;
+    let mut has_segment = false;
+
+    for element in path.iter() {
+        match element {
+            PathEl::MoveTo(point) => {
+                subpath_start = Some(point);
+                current = Some(point);
+                has_segment = false;
+            }
+            PathEl::LineTo(point) => {
+                current = Some(point);
+                has_segment = true;
+            }
+            PathEl::QuadTo(_, point) | PathEl::CurveTo(_, _, point) => {
+                current = Some(point);
+                has_segment = true;
+            }
+            PathEl::ClosePath => {
+                if has_segment && current == subpath_start {
+                    return true;
+                }
+                subpath_start = None;
+                current = None;
+                has_segment = false;
+            }
+        }
+    }
+
+    false
+}
+
+fn to_vello_point(p: Vec2) -> (f64, f64) {
+    (p.0 as f64, p.1 as f64)
+}
+
+/// The brush (and brush transform) for painting a path whose local space maps
+/// to device pixels through `transform`. `local_bounds` covers everything the
+/// path can ink in local space and `clip` is the target rectangle; image
+/// patterns use both to bake only the pixels the path can touch.
+fn to_vello_paint(
+    paint: &Paint,
+    opacity: f32,
+    transform: Affine,
+    local_bounds: VelloRect,
+    clip: &VelloRect,
+) -> Option<(vello::peniko::Brush, Option<Affine>)> {
+    if let Paint::Image(pattern) = paint {
+        return bake_vello_image_pattern(pattern, opacity, transform, local_bounds, clip);
+    }
+    let (brush, brush_transform) = to_vello_brush(paint, opacity)?;
+    let brush_transform = sample_brush_at_pixel_centers(&brush, transform, brush_transform);
+    Some((brush, brush_transform))
+}
+
+/// Resolves an image pattern into a device-aligned image covering the path's
+/// pixels. Vello 0.2's image brush clips to the image bounds (no pad, repeat
+/// or reflect) and only filters bilinearly, so the pattern is sampled through
+/// the CPU rasterizer's own shader and vello draws it texel-for-texel: its
+/// fine shader samples at pixel corners, so an integer brush offset reads
+/// each baked texel exactly. Coverage and compositing stay on the GPU.
+fn bake_vello_image_pattern(
+    pattern: &ImagePattern,
+    opacity: f32,
+    transform: Affine,
+    local_bounds: VelloRect,
+    clip: &VelloRect,
+) -> Option<(vello::peniko::Brush, Option<Affine>)> {
+    if opacity <= 0.0 || transform.determinant() == 0.0 {
+        return None;
+    }
+    // One pixel of slack covers antialiased edge coverage.
+    let device = transform
+        .transform_rect_bbox(local_bounds)
+        .inflate(1.0, 1.0)
+        .intersect(*clip);
+    let (x0, y0) = (device.x0.floor(), device.y0.floor());
+    let (x1, y1) = (device.x1.ceil(), device.y1.ceil());
+    if !(x1 > x0 && y1 > y0) {
+        return None;
+    }
+    let (width, height) = ((x1 - x0) as u32, (y1 - y0) as u32);
+
+    let source = crate::rasterize::to_skia_pixmap(&pattern.image)?;
+    let mut baked = tiny_skia::Pixmap::new(width, height)?;
+    let device_from_pattern =
+        Affine::translate((-x0, -y0)) * transform * to_vello_affine(pattern.transform);
+    let [a, b, c, d, tx, ty] = device_from_pattern.as_coeffs().map(|v| v as f32);
+    let paint = tiny_skia::Paint {
+        shader: crate::rasterize::image_pattern_shader(
+            &source,
+            pattern,
+            tiny_skia::Transform::from_row(a, b, c, d, tx, ty),
+            opacity.clamp(0.0, 1.0),
+        ),
+        ..Default::default()
+    };
+    baked.fill_rect(
+        tiny_skia::Rect::from_xywh(0.0, 0.0, width as f32, height as f32)?,
+        &paint,
+        tiny_skia::Transform::identity(),
+        None,
+    );
+
+    // Vello premultiplies image texels itself.
+    let mut straight = Vec::with_capacity(baked.data().len());
+    for p in baked.pixels() {
+        let c = p.demultiply();
+        straight.extend_from_slice(&[c.red(), c.green(), c.blue(), c.alpha()]);
+    }
+    let image = vello::peniko::Image::new(
+        vello::peniko::Blob::new(Arc::new(straight)),
+        vello::peniko::Format::Rgba8,
+        width,
+        height,
+    );
+    let brush_transform = transform.inverse() * Affine::translate((x0, y0));
+    Some((vello::peniko::Brush::Image(image), Some(brush_transform)))
+}
+
+/// Converts a solid or gradient [`Paint`] into a vello brush plus the brush
+/// transform that maps gradient space into path space. `opacity` (the
+/// accumulated group opacity) is folded into every color. `None` when the
+/// paint draws nothing; image patterns go through
+/// [`bake_vello_image_pattern`] instead.
+fn to_vello_brush(paint: &Paint, opacity: f32) -> Option<(vello::peniko::Brush, Option<Affine>)> {
+    use vello::peniko::{Brush, Gradient};
+
+    let (gradient, stops, spread, transform) = match paint {
+        Paint::Solid(color) => return to_vello_color(*color, opacity).map(|c| (c.into(), None)),
+        Paint::LinearGradient(g) => {
+            let (start, end) = (to_vello_point(g.start), to_vello_point(g.end));
+            let length = (g.end.0 - g.start.0).hypot(g.end.1 - g.start.1);
+            if !length.is_finite() {
+                return None;
+            }
+            if length <= DEGENERATE_GRADIENT_THRESHOLD {
+                let stops = g.normalized_stops();
+                let color = degenerate_gradient_color(&stops, g.spread)?;
+                return to_vello_color(color, opacity).map(|c| (c.into(), None));
+            }
+            let gradient = Gradient::new_linear(start, end);
+            (gradient, g.normalized_stops(), g.spread, g.transform)
+        }
+        Paint::RadialGradient(g) => {
+            if g.radius.is_nan() || g.radius <= 0.0 {
+                return None;
+            }
+            let gradient = if g.focal == g.center {
+                Gradient::new_radial(to_vello_point(g.center), g.radius)
+            } else {
+                Gradient::new_two_point_radial(
+                    to_vello_point(g.focal),
+                    0.0,
+                    to_vello_point(g.center),
+                    g.radius,
+                )
+            };
+            (gradient, g.normalized_stops(), g.spread, g.transform)
+        }
+        Paint::SweepGradient(g) => {
+            if !g.start_angle.is_finite() || !g.end_angle.is_finite() {
+                return None;
+            }
+            let gradient =
+                Gradient::new_sweep(to_vello_point(g.center), g.start_angle, g.end_angle);
+            (gradient, g.normalized_stops(), g.spread, g.transform)
+        }
+        Paint::Image(_) => return None,
+    };
+    match stops.as_slice() {
+        [] => return None,
+        [stop] => return to_vello_color(stop.color, opacity).map(|c| (c.into(), None)),
+        _ => {}
+    }
+    if stops.iter().all(|stop| stop.color.a * opacity <= 0.0) {
+        return None;
+    }
+    let stops: Vec<vello::peniko::ColorStop> = stops
+        .iter()
+        .map(|stop| (stop.offset, to_vello_color_unculled(stop.color, opacity)).into())
+        .collect();
+    let gradient = gradient
+        .with_extend(to_vello_extend(spread))
+        .with_stops(stops.as_slice());
+    let brush_transform = (transform != Transform::IDENTITY).then(|| to_vello_affine(transform));
+    Some((Brush::Gradient(gradient), brush_transform))
+}
+
+/// Vello's fine shader evaluates gradients at each pixel's top-left corner,
+/// while tiny-skia (and every other image pipeline) samples pixel centers.
+/// Shift the brush half a device pixel so both backends see the same ramp:
+/// the brush's full matrix `transform * brush_transform` is pre-multiplied by
+/// a `(-0.5, -0.5)` device translation, re-expressed relative to `transform`.
+fn sample_brush_at_pixel_centers(
+    brush: &vello::peniko::Brush,
+    transform: Affine,
+    brush_transform: Option<Affine>,
+) -> Option<Affine> {
+    if !matches!(brush, vello::peniko::Brush::Gradient(_)) || transform.determinant() == 0.0 {
+        return brush_transform;
+    }
+    let shifted = transform.inverse()
+        * Affine::translate((-0.5, -0.5))
+        * transform
+        * brush_transform.unwrap_or(Affine::IDENTITY);
+    Some(shifted)
+}
+
+/// Start/end separation below which a linear gradient is treated as a solid,
+/// matching tiny-skia's degenerate-gradient fallback on the CPU path.
+const DEGENERATE_GRADIENT_THRESHOLD: f32 = 1.0 / (1 << 15) as f32;
+
+/// The solid a zero-length gradient collapses to: its last stop when padded,
+/// otherwise the average color of the infinitely repeated ramp.
+fn degenerate_gradient_color(stops: &[GradientStop], spread: SpreadMode) -> Option<Color> {
+    let last = stops.last()?;
+    if spread == SpreadMode::Pad || stops.len() == 1 {
+        return Some(last.color);
+    }
+    let mut sum = [0.0f32; 4];
+    let mut add = |color: Color, weight: f32| {
+        sum[0] += color.r * weight;
+        sum[1] += color.g * weight;
+        sum[2] += color.b * weight;
+        sum[3] += color.a * weight;
+    };
+    let first = stops[0];
+    add(first.color, first.offset);
+    for pair in stops.windows(2) {
+        let weight = (pair[1].offset - pair[0].offset) * 0.5;
+        add(pair[0].color, weight);
+        add(pair[1].color, weight);
+    }
+    add(last.color, 1.0 - last.offset);
+    Some(Color {
+        r: sum[0],
+        g: sum[1],
+        b: sum[2],
+        a: sum[3],
+    })
+}
+
+fn to_vello_extend(spread: SpreadMode) -> vello::peniko::Extend {
+    match spread {
+        SpreadMode::Pad => vello::peniko::Extend::Pad,
+        SpreadMode::Repeat => vello::peniko::Extend::Repeat,
+        SpreadMode::Reflect => vello::peniko::Extend::Reflect,
+    }
+}
+
+fn to_vello_color(color: Color, opacity: f32) -> Option<vello::peniko::Color> {
+    if color.multiply_alpha(opacity).a <= 0.0 {
+        return None;
+    }
+    Some(to_vello_color_unculled(color, opacity))
+}
+
+fn to_vello_color_unculled(color: Color, opacity: f32) -> vello::peniko::Color {
+    let [r, g, b, a] = color_u8(color.multiply_alpha(opacity));
+    vello::peniko::Color::rgba8(r as u8, g as u8, b as u8, a as u8)
+}
+
+fn concat_transform(a: Transform, b: Transform) -> Transform {
+    Transform {
+        a: a.a * b.a + a.c * b.b,
+        b: a.b * b.a + a.d * b.b,
+        c: a.a * b.c + a.c * b.d,
+        d: a.b * b.c + a.d * b.d,
+        tx: a.a * b.tx + a.c * b.ty + a.tx,
+        ty: a.b * b.tx + a.d * b.ty + a.ty,
+    }
+}
+
+fn to_vello_affine(t: Transform) -> Affine {
+    Affine::new([
+        t.a as f64,
+        t.b as f64,
+        t.c as f64,
+        t.d as f64,
+        t.tx as f64,
+        t.ty as f64,
+    ])
+}
+
+const COMMON_WGSL: &str = r#"
+fn unpack_rgba(px: u32) -> vec4<u32> {
+    return vec4<u32>(
+        px & 255u,
+        (px >> 8u) & 255u,
+        (px >> 16u) & 255u,
+        (px >> 24u) & 255u,
+    );
+}
+
+fn pack_rgba(c: vec4<u32>) -> u32 {
+    return (c.x & 255u) | ((c.y & 255u) << 8u) | ((c.z & 255u) << 16u) | ((c.w & 255u) << 24u);
+}
+
+fn blend_over(dst_px: u32, src_px: u32) -> u32 {
+    let s = unpack_rgba(src_px);
+    let sa = s.w;
+    if (sa == 0u) {
+        return dst_px;
+    }
+    if (sa == 255u) {
+        return src_px;
+    }
+
+    let d = unpack_rgba(dst_px);
+    let inv_sa = 255u - sa;
+    let out_a_x255 = sa * 255u + d.w * inv_sa;
+    let half = out_a_x255 / 2u;
+    let out_r = (s.x * sa * 255u + d.x * d.w * inv_sa + half) / out_a_x255;
+    let out_g = (s.y * sa * 255u + d.y * d.w * inv_sa + half) / out_a_x255;
+    let out_b = (s.z * sa * 255u + d.z * d.w * inv_sa + half) / out_a_x255;
+    let out_a = (out_a_x255 + 127u) / 255u;
+    return pack_rgba(vec4<u32>(out_r, out_g, out_b, out_a));
+}
+"#;
+
+const COMPOSITE_SHADER: &str = r#"
+struct Params {
+    dst_w: u32,
+    dst_h: u32,
+    src_w: u32,
+    src_h: u32,
+    offset_x: i32,
+    offset_y: i32,
+    mode: u32,
+    pad1: u32,
+}
+
+@group(0) @binding(0) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(1) var<storage, read> src: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.src_w || y >= params.src_h) {
+        return;
+    }
+    let dx = i32(x) + params.offset_x;
+    let dy = i32(y) + params.offset_y;
+    if (dx < 0 || dy < 0 || dx >= i32(params.dst_w) || dy >= i32(params.dst_h)) {
+        return;
+    }
+    let sidx = y * params.src_w + x;
+    let didx = u32(dy) * params.dst_w + u32(dx);
+    if (params.mode == 0u) {
+        dst[didx] = blend_over(dst[didx], src[sidx]);
+    } else {
+        dst[didx] = blend_with_mode(dst[didx], src[sidx], params.mode);
+    }
+}
+"#;
+
+// Follows `tellur_core::composite::blend_pixel`: the source color is mixed
+// toward `B(backdrop, source)` by the backdrop alpha, then composited
+// source-over.
+const BLEND_MODE_WGSL: &str = r#"
+fn blend_channel(cb: f32, cs: f32, mode: u32) -> f32 {
+    switch mode {
+        case 1u: { return cb * cs; }
+        case 2u: { return cb + cs - cb * cs; }
+        case 3u: {
+            if (cb <= 0.5) {
+                return cs * 2.0 * cb;
+            }
+            let b = 2.0 * cb - 1.0;
+            return cs + b - cs * b;
+        }
+        case 4u: { return min(cb + cs, 1.0); }
+        case 5u: { return min(cb, cs); }
+        case 6u: { return max(cb, cs); }
+        case 7u: { return abs(cb - cs); }
+        case 8u: {
+            if (cb <= 0.0) {
+                return 0.0;
+            }
+            if (cs >= 1.0) {
+                return 1.0;
+            }
+            return min(cb / (1.0 - cs), 1.0);
+        }
+        case 9u: {
+            if (cs <= 0.5) {
+                return cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb);
+            }
+            var d = sqrt(cb);
+            if (cb <= 0.25) {
+                d = ((16.0 * cb - 12.0) * cb + 4.0) * cb;
+            }
+            return cb + (2.0 * cs - 1.0) * (d - cb);
+        }
+        default: { return cs; }
+    }
+}
+
+fn blend_with_mode(dst_px: u32, src_px: u32, mode: u32) -> u32 {
+    let s = vec4<f32>(unpack_rgba(src_px)) / 255.0;
+    if (s.w <= 0.0) {
+        return dst_px;
+    }
+    let d = vec4<f32>(unpack_rgba(dst_px)) / 255.0;
+    let out_a = s.w + d.w * (1.0 - s.w);
+    let mixed = vec3<f32>(
+        (1.0 - d.w) * s.x + d.w * blend_channel(d.x, s.x, mode),
+        (1.0 - d.w) * s.y + d.w * blend_channel(d.y, s.y, mode),
+        (1.0 - d.w) * s.z + d.w * blend_channel(d.z, s.z, mode),
+    );
+    let c = (s.w * mixed + d.w * d.xyz * (1.0 - s.w)) / out_a;
+    let out = clamp(round(vec4<f32>(c, out_a) * 255.0), vec4<f32>(0.0), vec4<f32>(255.0));
+    return pack_rgba(vec4<u32>(out));
+}
+"#;
+
+// Keep in lockstep with `tellur_core::perspective::depth_composite_pixels`.
+const DEPTH_COMPOSITE_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    count: u32,
+    pad0: u32,
+    origin_x: f32,
+    origin_y: f32,
+    scale_x: f32,
+    scale_y: f32,
+}
+
+struct Layer {
+    width: u32,
+    height: u32,
+    offset_x: i32,
+    offset_y: i32,
+    base: u32,
+    mode: u32,
+    pad0: u32,
+    pad1: u32,
+    inv0: vec4<f32>,
+    inv1: vec4<f32>,
+    inv2: vec4<f32>,
+    depth: vec4<f32>,
+}
+
+@group(0) @binding(0) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(1) var<storage, read> pixels: array<u32>;
+@group(0) @binding(2) var<storage, read> layers: array<Layer>;
+@group(0) @binding(3) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let px = params.origin_x + (f32(x) + 0.5) / params.scale_x;
+    let py = params.origin_y + (f32(y) + 0.5) / params.scale_y;
+
+    // The covering layers, insertion-sorted by depth as they are found so
+    // layers at equal depth keep their order.
+    var depths: array<f32, 16>;
+    var colors: array<u32, 16>;
+    var modes: array<u32, 16>;
+    var n = 0u;
+    for (var i = 0u; i < params.count; i = i + 1u) {
+        let layer = layers[i];
+        let lx = i32(x) - layer.offset_x;
+        let ly = i32(y) - layer.offset_y;
+        if (lx < 0 || ly < 0 || lx >= i32(layer.width) || ly >= i32(layer.height)) {
+            continue;
+        }
+        let color = pixels[layer.base + u32(ly) * layer.width + u32(lx)];
+        let q = layer.inv2.x * px + layer.inv2.y * py + layer.inv2.z;
+        if (unpack_rgba(color).w == 0u || q <= 0.0) {
+            continue;
+        }
+        let u = (layer.inv0.x * px + layer.inv0.y * py + layer.inv0.z) / q;
+        let v = (layer.inv1.x * px + layer.inv1.y * py + layer.inv1.z) / q;
+        let depth = layer.depth.x * u + layer.depth.y * v + layer.depth.z;
+        var j = n;
+        while (j > 0u && depths[j - 1u] > depth) {
+            depths[j] = depths[j - 1u];
+            colors[j] = colors[j - 1u];
+            modes[j] = modes[j - 1u];
+            j = j - 1u;
+        }
+        depths[j] = depth;
+        colors[j] = color;
+        modes[j] = layer.mode;
+        n = n + 1u;
+    }
+
+    var out = 0u;
+    for (var k = 0u; k < n; k = k + 1u) {
+        out = blend_with_mode(out, colors[k], modes[k]);
+    }
+    dst[y * params.width + x] = out;
+}
+"#;
+
+const COPY_ALPHA_SHADER: &str = r#"
+struct Params {
+    src_w: u32,
+    src_h: u32,
+    out_w: u32,
+    out_h: u32,
+    offset_x: i32,
+    offset_y: i32,
+    pad0: u32,
+    pad1: u32,
+}
+
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read_write> alpha: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.out_w || y >= params.out_h) {
+        return;
+    }
+    let out_idx = y * params.out_w + x;
+    let sx_i = i32(x) - params.offset_x;
+    let sy_i = i32(y) - params.offset_y;
+    if (sx_i < 0 || sy_i < 0) {
+        alpha[out_idx] = 0u;
+        return;
+    }
+    let sx = u32(sx_i);
+    let sy = u32(sy_i);
+    if (sx >= params.src_w || sy >= params.src_h) {
+        alpha[out_idx] = 0u;
+        return;
+    }
+    let px = src[sy * params.src_w + sx];
+    alpha[out_idx] = (px >> 24u) & 255u;
+}
+"#;
+
+const BLUR_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    radius: u32,
+    horizontal: u32,
+}
+
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+
+    var sum = 0u;
+    var count = 0u;
+    if (params.horizontal != 0u) {
+        let start = select(0u, x - params.radius, x >= params.radius);
+        let end = min(params.width - 1u, x + params.radius);
+        var ix = start;
+        loop {
+            sum = sum + src[y * params.width + ix];
+            count = count + 1u;
+            if (ix >= end) {
+                break;
+            }
+            ix = ix + 1u;
+        }
+    } else {
+        let start = select(0u, y - params.radius, y >= params.radius);
+        let end = min(params.height - 1u, y + params.radius);
+        var iy = start;
+        loop {
+            sum = sum + src[iy * params.width + x];
+            count = count + 1u;
+            if (iy >= end) {
+                break;
+            }
+            iy = iy + 1u;
+        }
+    }
+    dst[y * params.width + x] = sum / count;
+}
+"#;
+
+// Places the straight-alpha child into a premultiplied `vec4<f32>` plane.
+// Keep the arithmetic in lockstep with the CPU blur in `blur.rs`.
+const PREMULTIPLY_SHADER: &str = r#"
+struct Params {
+    src_w: u32,
+    src_h: u32,
+    out_w: u32,
+    out_h: u32,
+    offset_x: i32,
+    offset_y: i32,
+    pad0: u32,
+    pad1: u32,
 }
 
-#[derive(Clone, Copy)]
-struct DispatchSize {
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read_write> plane: array<vec4<f32>>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.out_w || y >= params.out_h) {
+        return;
+    }
+    let out_idx = y * params.out_w + x;
+    let sx = i32(x) - params.offset_x;
+    let sy = i32(y) - params.offset_y;
+    if (sx < 0 || sy < 0 || sx >= i32(params.src_w) || sy >= i32(params.src_h)) {
+        plane[out_idx] = vec4<f32>(0.0);
+        return;
+    }
+    let c = vec4<f32>(unpack_rgba(src[u32(sy) * params.src_w + u32(sx)])) / 255.0;
+    plane[out_idx] = vec4<f32>(c.xyz * c.w, c.w);
+}
+"#;
+
+// One box pass along `step`: whole taps at unit steps, sampled bilinearly,
+// with the radius' fractional part weighting the outermost pair. Pixels
+// outside the plane count as transparent.
+const COLOR_BLUR_SHADER: &str = r#"
+struct Params {
     width: u32,
     height: u32,
+    radius: f32,
+    step_x: f32,
+    step_y: f32,
+    pad0: u32,
+    pad1: u32,
+    pad2: u32,
 }
 
-impl DispatchSize {
-    fn new(width: u32, height: u32) -> Self {
-        Self { width, height }
+@group(0) @binding(0) var<storage, read> src: array<vec4<f32>>;
+@group(0) @binding(1) var<storage, read_write> dst: array<vec4<f32>>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+fn texel(x: i32, y: i32) -> vec4<f32> {
+    if (x < 0 || y < 0 || x >= i32(params.width) || y >= i32(params.height)) {
+        return vec4<f32>(0.0);
     }
+    return src[u32(y) * params.width + u32(x)];
 }
 
-fn dispatch_three_buffer<P: bytemuck::Pod>(
-    device: &wgpu::Device,
-    encoder: &mut wgpu::CommandEncoder,
-    pipeline: &wgpu::ComputePipeline,
-    buffers: [&wgpu::Buffer; 2],
-    params: &P,
-    size: DispatchSize,
-) {
-    let params = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-        label: Some("tellur-gpu-params"),
-        contents: bytemuck::bytes_of(params),
-        usage: wgpu::BufferUsages::STORAGE,
-    });
-    let layout = pipeline.get_bind_group_layout(0);
-    let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
-        label: Some("tellur-gpu-bind-group"),
-        layout: &layout,
-        entries: &[
-            wgpu::BindGroupEntry {
-                binding: 0,
-                resource: buffers[0].as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 1,
-                resource: buffers[1].as_entire_binding(),
-            },
-            wgpu::BindGroupEntry {
-                binding: 2,
-                resource: params.as_entire_binding(),
-            },
-        ],
-    });
+fn sample(px: f32, py: f32) -> vec4<f32> {
+    let fx = floor(px);
+    let fy = floor(py);
+    let tx = px - fx;
+    let ty = py - fy;
+    let x0 = i32(fx);
+    let y0 = i32(fy);
+    let top = texel(x0, y0) * (1.0 - tx) + texel(x0 + 1, y0) * tx;
+    let bottom = texel(x0, y0 + 1) * (1.0 - tx) + texel(x0 + 1, y0 + 1) * tx;
+    return top * (1.0 - ty) + bottom * ty;
+}
 
-    let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
-        label: Some("tellur-gpu-pass"),
-        timestamp_writes: None,
-    });
-    pass.set_pipeline(pipeline);
-    pass.set_bind_group(0, &bind_group, &[]);
-    pass.dispatch_workgroups(
-        div_ceil(size.width, WORKGROUP),
-        div_ceil(size.height, WORKGROUP),
-        1,
-    );
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let whole = floor(params.radius);
+    let taps = i32(whole);
+    let edge = params.radius - whole;
+    let fx = f32(x);
+    let fy = f32(y);
+
+    var sum = vec4<f32>(0.0);
+    var k = -taps;
+    loop {
+        if (k > taps) {
+            break;
+        }
+        let kf = f32(k);
+        sum = sum + sample(fx + kf * params.step_x, fy + kf * params.step_y);
+        k = k + 1;
+    }
+    if (edge > 0.0) {
+        let kf = f32(taps + 1);
+        let before = sample(fx - kf * params.step_x, fy - kf * params.step_y);
+        let after = sample(fx + kf * params.step_x, fy + kf * params.step_y);
+        sum = sum + edge * (before + after);
+    }
+    dst[y * params.width + x] = sum * (1.0 / (2.0 * whole + 1.0 + 2.0 * edge));
 }
+"#;
 
-fn div_ceil(n: u32, d: u32) -> u32 {
-    if n == 0 {
-        0
-    } else {
-        1 + (n - 1) / d
+// Resolves a premultiplied plane back into straight-alpha RGBA8.
+const UNPREMULTIPLY_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    pad0: u32,
+    pad1: u32,
+}
+
+@group(0) @binding(0) var<storage, read> plane: array<vec4<f32>>;
+@group(0) @binding(1) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let idx = y * params.width + x;
+    let texel = plane[idx];
+    let alpha = clamp(texel.w, 0.0, 1.0);
+    let a = u32(round(alpha * 255.0));
+    if (a == 0u) {
+        dst[idx] = 0u;
+        return;
     }
+    let c = vec3<u32>(round(clamp(texel.xyz / alpha, vec3<f32>(0.0), vec3<f32>(1.0)) * 255.0));
+    dst[idx] = pack_rgba(vec4<u32>(c, a));
 }
+"#;
 
-fn color_u8(color: Color) -> [u32; 4] {
-    [
-        (color.r * 255.0).round().clamp(0.0, 255.0) as u32,
-        (color.g * 255.0).round().clamp(0.0, 255.0) as u32,
-        (color.b * 255.0).round().clamp(0.0, 255.0) as u32,
-        (color.a * 255.0).round().clamp(0.0, 255.0) as u32,
-    ]
+const DILATE_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    radius_x: u32,
+    radius_y: u32,
+}
+
+@group(0) @binding(0) var<storage, read> alpha: array<u32>;
+@group(0) @binding(1) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    dst[y * params.width + x] =
+        dilated_alpha(x, y, params.width, params.height, params.radius_x, params.radius_y);
+}
+"#;
+
+// Places the child into a premultiplied plane weighted by how far its
+// luminance rises above the threshold. Keep in lockstep with `bloom_image`
+// in `glow.rs`.
+const BRIGHT_PASS_SHADER: &str = r#"
+struct Params {
+    src_w: u32,
+    src_h: u32,
+    out_w: u32,
+    out_h: u32,
+    offset_x: i32,
+    offset_y: i32,
+    threshold: f32,
+    pad0: u32,
+}
+
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read_write> plane: array<vec4<f32>>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.out_w || y >= params.out_h) {
+        return;
+    }
+    let out_idx = y * params.out_w + x;
+    let sx = i32(x) - params.offset_x;
+    let sy = i32(y) - params.offset_y;
+    if (sx < 0 || sy < 0 || sx >= i32(params.src_w) || sy >= i32(params.src_h)
+        || params.threshold >= 1.0) {
+        plane[out_idx] = vec4<f32>(0.0);
+        return;
+    }
+    let c = vec4<f32>(unpack_rgba(src[u32(sy) * params.src_w + u32(sx)])) / 255.0;
+    let luma = dot(c.xyz, vec3<f32>(0.2126, 0.7152, 0.0722));
+    let weight = clamp((luma - params.threshold) / (1.0 - params.threshold), 0.0, 1.0);
+    plane[out_idx] = vec4<f32>(c.xyz * c.w, c.w) * weight;
+}
+"#;
+
+// dst += scale * src over two premultiplied planes.
+const ADD_PLANE_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    scale: f32,
+    pad0: u32,
+}
+
+@group(0) @binding(0) var<storage, read> src: array<vec4<f32>>;
+@group(0) @binding(1) var<storage, read_write> dst: array<vec4<f32>>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let idx = y * params.width + x;
+    dst[idx] = dst[idx] + params.scale * src[idx];
+}
+"#;
+
+// Keep in lockstep with `ColorAdjustment::apply` in tellur-core.
+const COLOR_ADJUST_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    exposure_scale: f32,
+    brightness: f32,
+    contrast: f32,
+    saturation: f32,
+    inverse_gamma: f32,
+    tint_amount: f32,
+    tint: vec3<f32>,
+    hue0: vec3<f32>,
+    hue1: vec3<f32>,
+    hue2: vec3<f32>,
+}
+
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+const LUMA = vec3<f32>(0.2126, 0.7152, 0.0722);
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let idx = y * params.width + x;
+    let px = unpack_rgba(src[idx]);
+    var c = vec3<f32>(px.xyz) / 255.0;
+    c = (c * params.exposure_scale + params.brightness - 0.5) * params.contrast + 0.5;
+    let luma = dot(c, LUMA);
+    c = luma + (c - luma) * params.saturation;
+    c = vec3<f32>(dot(params.hue0, c), dot(params.hue1, c), dot(params.hue2, c));
+    c = c + (dot(c, LUMA) * params.tint - c) * params.tint_amount;
+    c = pow(clamp(c, vec3<f32>(0.0), vec3<f32>(1.0)), vec3<f32>(params.inverse_gamma));
+    dst[idx] = pack_rgba(vec4<u32>(vec3<u32>(round(c * 255.0)), px.w));
+}
+"#;
+
+// Trilinear 3D LUT lookup, graded in place. Keep in lockstep with
+// `CubeLut::sample` in tellur-core.
+const LUT_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    size: u32,
+    mix: f32,
+    domain_min: vec4<f32>,
+    domain_max: vec4<f32>,
+}
+
+@group(0) @binding(0) var<storage, read_write> image: array<u32>;
+@group(0) @binding(1) var<storage, read> table: array<vec4<f32>>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+fn entry(r: u32, g: u32, b: u32) -> vec3<f32> {
+    return table[r + g * params.size + b * params.size * params.size].xyz;
+}
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let idx = y * params.width + x;
+    let px = unpack_rgba(image[idx]);
+    let c = vec3<f32>(px.xyz) / 255.0;
+    let last = f32(params.size - 1u);
+    let unit = clamp(
+        (c - params.domain_min.xyz) / (params.domain_max.xyz - params.domain_min.xyz),
+        vec3<f32>(0.0),
+        vec3<f32>(1.0),
+    );
+    let scaled = unit * last;
+    let base = min(floor(scaled), vec3<f32>(last - 1.0));
+    let t = scaled - base;
+    let i = vec3<u32>(base);
+    let near0 = mix(entry(i.x, i.y, i.z), entry(i.x + 1u, i.y, i.z), t.x);
+    let far0 = mix(entry(i.x, i.y + 1u, i.z), entry(i.x + 1u, i.y + 1u, i.z), t.x);
+    let near1 = mix(entry(i.x, i.y, i.z + 1u), entry(i.x + 1u, i.y, i.z + 1u), t.x);
+    let far1 = mix(entry(i.x, i.y + 1u, i.z + 1u), entry(i.x + 1u, i.y + 1u, i.z + 1u), t.x);
+    let looked_up = mix(mix(near0, far0, t.y), mix(near1, far1, t.y), t.z);
+    let graded = clamp(mix(c, looked_up, params.mix), vec3<f32>(0.0), vec3<f32>(1.0));
+    image[idx] = pack_rgba(vec4<u32>(vec3<u32>(round(graded * 255.0)), px.w));
+}
+"#;
+
+// Integer track matte. Keep in lockstep with `MatteMode::coverage` and
+// `apply_matte_pixels` in tellur-core.
+const MATTE_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    matte_width: u32,
+    matte_height: u32,
+    offset_x: i32,
+    offset_y: i32,
+    mode: u32,
+    _pad0: u32,
 }
 
-fn build_vello_scene(graphic: &VectorGraphic, target: Resolution) -> Option<vello::Scene> {
-    if target.width == 0
-        || target.height == 0
-        || graphic.view_box.size.0 <= 0.0
-        || graphic.view_box.size.1 <= 0.0
-    {
-        return None;
-    }
+@group(0) @binding(0) var<storage, read_write> image: array<u32>;
+@group(0) @binding(1) var<storage, read> matte: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
 
-    let sx = target.width as f32 / graphic.view_box.size.0;
-    let sy = target.height as f32 / graphic.view_box.size.1;
-    let view = Transform {
-        a: sx,
-        b: 0.0,
-        c: 0.0,
-        d: sy,
-        tx: -graphic.view_box.origin.0 * sx,
-        ty: -graphic.view_box.origin.1 * sy,
-    };
-    let clip = VelloRect::new(0.0, 0.0, target.width as f64, target.height as f64);
-    let mut scene = vello::Scene::new();
-    encode_vello_node(&mut scene, &graphic.root, view, &clip, 1.0)?;
-    Some(scene)
+fn mul_div_255(a: u32, b: u32) -> u32 {
+    return (a * b + 127u) / 255u;
 }
 
-fn encode_vello_node(
-    scene: &mut vello::Scene,
-    node: &Node,
-    transform: Transform,
-    clip: &VelloRect,
-    opacity: f32,
-) -> Option<()> {
-    match node {
-        Node::Group(group) => {
-            let opacity = opacity * group.opacity.clamp(0.0, 1.0);
-            if opacity <= 0.0 {
-                return Some(());
-            }
-            let transform = concat_transform(transform, group.transform);
-            for child in &group.children {
-                encode_vello_node(scene, child, transform, clip, opacity)?;
-            }
-        }
-        Node::SingleGroup(group) => {
-            let opacity = opacity * group.opacity.clamp(0.0, 1.0);
-            if opacity <= 0.0 {
-                return Some(());
-            }
-            let transform = concat_transform(transform, group.transform);
-            encode_vello_node(scene, &group.child, transform, clip, opacity)?;
-        }
-        Node::ClipGroup(group) => encode_vello_clip_group(scene, group, transform, clip, opacity)?,
-        Node::Path(path) => encode_vello_path(scene, path, transform, opacity)?,
+fn coverage(px: vec4<u32>) -> u32 {
+    var value = px.w;
+    if (params.mode >= 2u) {
+        let luma = (54u * px.x + 183u * px.y + 19u * px.z + 128u) >> 8u;
+        value = mul_div_255(luma, px.w);
     }
-    Some(())
+    if (params.mode == 1u || params.mode == 3u) {
+        value = 255u - value;
+    }
+    return value;
 }
 
-fn encode_vello_clip_group(
-    scene: &mut vello::Scene,
-    group: &TellurClipGroup,
-    transform: Transform,
-    outer_clip: &VelloRect,
-    opacity: f32,
-) -> Option<()> {
-    let Some(clip_path) = build_vello_path(&group.commands) else {
-        return Some(());
-    };
-    let clip_transform = concat_transform(transform, group.transform);
-    scene.push_layer(
-        vello::peniko::BlendMode::default(),
-        1.0,
-        to_vello_affine(clip_transform),
-        &clip_path,
-    );
-    encode_vello_node(scene, &group.child, transform, outer_clip, opacity)?;
-    scene.pop_layer();
-    Some(())
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let idx = y * params.width + x;
+    let mx = i32(x) - params.offset_x;
+    let my = i32(y) - params.offset_y;
+    var m = vec4<u32>(0u);
+    if (mx >= 0 && my >= 0 && mx < i32(params.matte_width) && my < i32(params.matte_height)) {
+        m = unpack_rgba(matte[u32(my) * params.matte_width + u32(mx)]);
+    }
+    let px = unpack_rgba(image[idx]);
+    image[idx] = pack_rgba(vec4<u32>(px.xyz, mul_div_255(px.w, coverage(m))));
 }
+"#;
 
-fn encode_vello_path(
-    scene: &mut vello::Scene,
-    path: &TellurPath,
-    transform: Transform,
-    opacity: f32,
-) -> Option<()> {
-    if path.fill.is_none() && path.stroke.is_none() {
-        return Some(());
-    }
-    let transform = concat_transform(transform, path.transform);
-    let Some(vello_path) = build_vello_path(&path.commands) else {
-        return Some(());
-    };
-    let transform = to_vello_affine(transform);
+// Keying shared by the two chroma key passes. It goes after each pass's
+// source, since some drivers only bind `src` and `params` when they are
+// declared before the functions that read them. Keep in lockstep with
+// `ChromaKeySettings::apply_pixels` in tellur-core: the choke square is a
+// row pass, then a column pass that also suppresses spill and writes the
+// result.
+const CHROMA_KEY_WGSL: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    choke_radius: u32,
+    tolerance: f32,
+    softness: f32,
+    spill: f32,
+    key_cb: f32,
+    key_cr: f32,
+}
 
-    if let Some(fill) = &path.fill {
-        if let Some(paint) = to_vello_color(&fill.paint, opacity) {
-            scene.fill(
-                vello::peniko::Fill::NonZero,
-                transform,
-                paint,
-                None,
-                &vello_path,
-            );
-        }
-    }
+const LUMA = vec3<f32>(0.2126, 0.7152, 0.0722);
+const CB_SCALE: f32 = 1.8556;
+const CR_SCALE: f32 = 1.5748;
 
-    if let Some(stroke) = &path.stroke {
-        if stroke.width > 0.0 {
-            if let Some(paint) = to_vello_color(&stroke.paint, opacity) {
-                let vello_stroke = to_vello_stroke(stroke);
-                if has_zero_length_closing_segment(&vello_path) {
-                    // Vello 0.2's GPU stroker can misinterpret its synthetic
-                    // closed-subpath marker when the final segment already ends
-                    // at the subpath start, producing a detached full-width band.
-                    // Expanding only this case on the CPU preserves the closed
-                    // join semantics; dropping Close would turn the join into caps.
-                    let outline = vello::kurbo::stroke(
-                        vello_path.iter(),
-                        &vello_stroke,
-                        &Default::default(),
-                        VELLO_STROKE_TOLERANCE,
-                    );
-                    scene.fill(
-                        vello::peniko::Fill::NonZero,
-                        transform,
-                        paint,
-                        None,
-                        &outline,
-                    );
-                } else {
-                    scene.stroke(&vello_stroke, transform, paint, None, &vello_path);
-                }
-            }
-        }
-    }
+fn chroma(c: vec3<f32>) -> vec2<f32> {
+    let y = dot(c, LUMA);
+    return vec2<f32>((c.z - y) / CB_SCALE, (c.x - y) / CR_SCALE);
+}
 
-    Some(())
+fn rgb_at(idx: u32) -> vec3<f32> {
+    return vec3<f32>(unpack_rgba(src[idx]).xyz) / 255.0;
 }
 
-fn to_vello_stroke(stroke: &TellurStroke) -> VelloStroke {
-    let stroke_style = VelloStroke::new(stroke.width as f64)
-        .with_caps(to_vello_cap(stroke.cap))
-        .with_join(to_vello_join(stroke.join))
-        .with_miter_limit(stroke.miter_limit() as f64);
-    apply_dash(stroke_style, stroke.dash.as_ref())
+fn key_alpha(c: vec3<f32>) -> f32 {
+    let distance = length(chroma(c) - vec2<f32>(params.key_cb, params.key_cr));
+    let over = distance - params.tolerance;
+    if (params.softness <= 0.0) {
+        return select(0.0, 1.0, over > 0.0);
+    }
+    return clamp(over / params.softness, 0.0, 1.0);
 }
 
-fn to_vello_cap(cap: StrokeCap) -> VelloCap {
-    match cap {
-        StrokeCap::Butt => VelloCap::Butt,
-        StrokeCap::Square => VelloCap::Square,
-        StrokeCap::Round => VelloCap::Round,
+fn suppress_spill(c: vec3<f32>) -> vec3<f32> {
+    let key = vec2<f32>(params.key_cb, params.key_cr);
+    let len = length(key);
+    if (len <= 1.1920929e-7 || params.spill <= 0.0) {
+        return c;
+    }
+    let dir = key / len;
+    let y = dot(c, LUMA);
+    var ch = chroma(c);
+    let along = dot(ch, dir);
+    if (along <= 0.0) {
+        return c;
     }
+    ch = ch - params.spill * along * dir;
+    let r = y + CR_SCALE * ch.y;
+    let b = y + CB_SCALE * ch.x;
+    let g = (y - LUMA.x * r - LUMA.z * b) / LUMA.y;
+    return clamp(vec3<f32>(r, g, b), vec3<f32>(0.0), vec3<f32>(1.0));
 }
+"#;
 
-fn to_vello_join(join: StrokeJoin) -> VelloJoin {
-    match join {
-        StrokeJoin::Bevel => VelloJoin::Bevel,
-        StrokeJoin::Miter => VelloJoin::Miter,
-        StrokeJoin::Round => VelloJoin::Round,
+const CHROMA_CHOKE_SHADER: &str = r#"
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read_write> row_keys: array<f32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let r = params.choke_radius;
+    let row = y * params.width;
+    var key = 1.0;
+    for (var nx = x - min(x, r); nx <= min(x + r, params.width - 1u); nx = nx + 1u) {
+        key = min(key, key_alpha(rgb_at(row + nx)));
     }
+    row_keys[row + x] = key;
 }
+"#;
 
-/// Applies a [`DashPattern`] to a [`VelloStroke`], mirroring
-/// `rasterize.rs`'s `to_skia_dash` so the GPU (vello) and CPU (tiny-skia)
-/// backends draw identical dashes. `None`/an unusable pattern (see
-/// [`DashPattern::normalized_lengths`]) leaves the stroke solid.
-fn apply_dash(stroke: VelloStroke, dash: Option<&DashPattern>) -> VelloStroke {
-    let Some(dash) = dash else {
-        return stroke;
-    };
-    let Some(lengths) = dash.normalized_lengths() else {
-        return stroke;
-    };
-    stroke.with_dashes(dash.offset as f64, lengths.into_iter().map(f64::from))
+const CHROMA_KEY_SHADER: &str = r#"
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read> row_keys: array<f32>;
+@group(0) @binding(2) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(3) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let idx = y * params.width + x;
+    let r = params.choke_radius;
+    var key = 1.0;
+    for (var ny = y - min(y, r); ny <= min(y + r, params.height - 1u); ny = ny + 1u) {
+        key = min(key, row_keys[ny * params.width + x]);
+    }
+    let px = unpack_rgba(src[idx]);
+    let c = suppress_spill(vec3<f32>(px.xyz) / 255.0);
+    let alpha = u32(round(f32(px.w) * key));
+    dst[idx] = pack_rgba(vec4<u32>(vec3<u32>(round(c * 255.0)), alpha));
 }
+"#;
 
-fn build_vello_path(commands: &[PathCommand]) -> Option<BezPath> {
-    let mut path = BezPath::new();
-    let mut has_open_subpath = false;
-    for command in commands {
-        match *command {
-            PathCommand::MoveTo(p) => {
-                path.move_to(to_vello_point(p));
-                has_open_subpath = true;
-            }
-            PathCommand::LineTo(p) => {
-                if has_open_subpath {
-                    path.line_to(to_vello_point(p));
-                }
-            }
-            PathCommand::QuadTo { control, to } => {
-                if has_open_subpath {
-                    path.quad_to(to_vello_point(control), to_vello_point(to));
-                }
-            }
-            PathCommand::CubicTo { c1, c2, to } => {
-                if has_open_subpath {
-                    path.curve_to(to_vello_point(c1), to_vello_point(c2), to_vello_point(to));
-                }
-            }
-            PathCommand::Close => {
-                if has_open_subpath {
-                    path.close_path();
-                    has_open_subpath = false;
-                }
-            }
-        }
+// Filtering shared by the resample and warp passes; keep in lockstep with
+// `sample` in tellur-core's resample module. Shaders including it declare
+// the `src` binding.
+const SAMPLE_WGSL: &str = r#"
+// The premultiplied source pixel at (x, y), transparent off the image.
+fn premultiplied(dims: vec2<u32>, x: f32, y: f32) -> vec4<f32> {
+    if (x < 0.0 || y < 0.0 || x >= f32(dims.x) || y >= f32(dims.y)) {
+        return vec4<f32>(0.0);
     }
-    (!path.elements().is_empty()).then_some(path)
+    let px = vec4<f32>(unpack_rgba(src[u32(y) * dims.x + u32(x)])) / 255.0;
+    return vec4<f32>(px.xyz * px.w, px.w);
 }
 
-fn has_zero_length_closing_segment(path: &BezPath) -> bool {
-    let mut subpath_start = None;
-    let mut current = None;
-    let mut has_segment = false;
+fn catmull_rom(t: f32) -> vec4<f32> {
+    return vec4<f32>(
+        ((-0.5 * t + 1.0) * t - 0.5) * t,
+        (1.5 * t - 2.5) * t * t + 1.0,
+        ((-1.5 * t + 2.0) * t + 0.5) * t,
+        (0.5 * t - 0.5) * t * t,
+    );
+}
 
-    for element in path.iter() {
-        match element {
-            PathEl::MoveTo(point) => {
-                subpath_start = Some(point);
-                current = Some(point);
-                has_segment = false;
-            }
-            PathEl::LineTo(point) => {
-                current = Some(point);
-                has_segment = true;
-            }
-            PathEl::QuadTo(_, point) | PathEl::CurveTo(_, _, point) => {
-                current = Some(point);
-                has_segment = true;
+// Filters the source at (u, v), where integer coordinates are pixel
+// centers, and returns the packed straight-alpha result.
+fn sample_source(dims: vec2<u32>, u: f32, v: f32, quality: u32) -> u32 {
+    var acc = vec4<f32>(0.0);
+    if (quality == 0u) {
+        acc = premultiplied(dims, floor(u + 0.5), floor(v + 0.5));
+    } else if (quality == 1u) {
+        let x0 = floor(u);
+        let y0 = floor(v);
+        let fx = u - x0;
+        let fy = v - y0;
+        let wx = vec2<f32>(1.0 - fx, fx);
+        let wy = vec2<f32>(1.0 - fy, fy);
+        for (var j = 0; j < 2; j = j + 1) {
+            for (var i = 0; i < 2; i = i + 1) {
+                acc = acc + wx[i] * wy[j] * premultiplied(dims, x0 + f32(i), y0 + f32(j));
             }
-            PathEl::ClosePath => {
-                if has_segment && current == subpath_start {
-                    return true;
-                }
-                subpath_start = None;
-                current = None;
-                has_segment = false;
+        }
+    } else {
+        let x0 = floor(u);
+        let y0 = floor(v);
+        let wx = catmull_rom(u - x0);
+        let wy = catmull_rom(v - y0);
+        for (var j = 0; j < 4; j = j + 1) {
+            for (var i = 0; i < 4; i = i + 1) {
+                acc = acc
+                    + wx[i] * wy[j] * premultiplied(dims, x0 - 1.0 + f32(i), y0 - 1.0 + f32(j));
             }
         }
     }
-
-    false
-}
-
-fn to_vello_point(p: Vec2) -> (f64, f64) {
-    (p.0 as f64, p.1 as f64)
+    let a = clamp(acc.w, 0.0, 1.0);
+    if (a <= 0.0) {
+        return 0u;
+    }
+    let rgb = clamp(acc.xyz, vec3<f32>(0.0), vec3<f32>(a)) / a;
+    return pack_rgba(vec4<u32>(vec3<u32>(round(rgb * 255.0)), u32(round(a * 255.0))));
 }
+"#;
 
-fn to_vello_color(paint: &Paint, opacity: f32) -> Option<vello::peniko::Color> {
-    let Paint::Solid(color) = paint;
-    let color = color.multiply_alpha(opacity);
-    if color.a <= 0.0 {
-        return None;
-    }
-    let [r, g, b, a] = color_u8(color);
-    Some(vello::peniko::Color::rgba8(
-        r as u8, g as u8, b as u8, a as u8,
-    ))
+// Keep in lockstep with `resample_pixels` and `project_pixels` in
+// tellur-core. Matrix rows are stored as scalars, since WGSL pads vec3s.
+const RESAMPLE_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    src_width: u32,
+    src_height: u32,
+    m00: f32,
+    m01: f32,
+    m02: f32,
+    m10: f32,
+    m11: f32,
+    m12: f32,
+    m20: f32,
+    m21: f32,
+    m22: f32,
+    quality: u32,
+    near: f32,
+    _pad0: u32,
 }
 
-fn concat_transform(a: Transform, b: Transform) -> Transform {
-    Transform {
-        a: a.a * b.a + a.c * b.b,
-        b: a.b * b.a + a.d * b.b,
-        c: a.a * b.c + a.c * b.d,
-        d: a.b * b.c + a.d * b.d,
-        tx: a.a * b.tx + a.c * b.ty + a.tx,
-        ty: a.b * b.tx + a.d * b.ty + a.ty,
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
+    }
+    let idx = y * params.width + x;
+    let px = f32(x) + 0.5;
+    let py = f32(y) + 0.5;
+    // `q` is `1 / w` of the source point; affine maps keep it at 1.
+    let q = params.m20 * px + params.m21 * py + params.m22;
+    if (!(q > 0.0 && q * params.near <= 1.0)) {
+        dst[idx] = 0u;
+        return;
     }
+    let u = (params.m00 * px + params.m01 * py + params.m02) / q - 0.5;
+    let v = (params.m10 * px + params.m11 * py + params.m12) / q - 0.5;
+    let dims = vec2<u32>(params.src_width, params.src_height);
+    dst[idx] = sample_source(dims, u, v, params.quality);
 }
+"#;
 
-fn to_vello_affine(t: Transform) -> Affine {
-    Affine::new([
-        t.a as f64,
-        t.b as f64,
-        t.c as f64,
-        t.d as f64,
-        t.tx as f64,
-        t.ty as f64,
-    ])
+// Keep in lockstep with `Distortion::source_point`, `displacement` and
+// `remap` in tellur-core's distort module. `p0`..`p4` hold the warp's
+// parameters in logical units, as laid out by `warp_params`.
+const WARP_SHADER: &str = r#"
+struct Params {
+    width: u32,
+    height: u32,
+    kind: u32,
+    quality: u32,
+    origin_x: f32,
+    origin_y: f32,
+    scale_x: f32,
+    scale_y: f32,
+    p0: f32,
+    p1: f32,
+    p2: f32,
+    p3: f32,
+    p4: f32,
+    map_width: u32,
+    map_height: u32,
+    map_offset_x: i32,
+    map_offset_y: i32,
+    src_width: u32,
+    src_height: u32,
+    src_offset_x: i32,
+    src_offset_y: i32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
 }
 
-const COMMON_WGSL: &str = r#"
-fn unpack_rgba(px: u32) -> vec4<u32> {
-    return vec4<u32>(
-        px & 255u,
-        (px >> 8u) & 255u,
-        (px >> 16u) & 255u,
-        (px >> 24u) & 255u,
-    );
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read> displacement_map: array<u32>;
+@group(0) @binding(2) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(3) var<storage, read> params: Params;
+
+const TAU: f32 = 6.283185307179586;
+const LUMA: vec3<f32> = vec3<f32>(0.2126, 0.7152, 0.0722);
+
+fn displacement(x: u32, y: u32) -> f32 {
+    let mx = i32(x) - params.map_offset_x;
+    let my = i32(y) - params.map_offset_y;
+    if (mx < 0 || my < 0 || mx >= i32(params.map_width) || my >= i32(params.map_height)) {
+        return 0.0;
+    }
+    let px = vec4<f32>(unpack_rgba(displacement_map[u32(my) * params.map_width + u32(mx)])) / 255.0;
+    return (dot(px.xyz, LUMA) * 2.0 - 1.0) * px.w;
 }
 
-fn pack_rgba(c: vec4<u32>) -> u32 {
-    return (c.x & 255u) | ((c.y & 255u) << 8u) | ((c.z & 255u) << 16u) | ((c.w & 255u) << 24u);
+fn source_point(x: u32, y: u32, at: vec2<f32>) -> vec2<f32> {
+    let center = vec2<f32>(params.p0, params.p1);
+    let d = at - center;
+    let r = length(d);
+    switch (params.kind) {
+        // Ripple: amplitude, wavelength, phase.
+        case 0u: {
+            if (params.p3 <= 0.0 || r <= 1.1920929e-7) {
+                return at;
+            }
+            let push = params.p2 * sin(TAU * (r / params.p3 - params.p4));
+            return at + d / r * push;
+        }
+        // Wave: (cos, sin) of the angle, amplitude, wavelength, phase.
+        case 1u: {
+            if (params.p3 <= 0.0) {
+                return at;
+            }
+            let along = at.x * params.p0 + at.y * params.p1;
+            let push = params.p2 * sin(TAU * (along / params.p3 - params.p4));
+            return vec2<f32>(at.x - params.p1 * push, at.y + params.p0 * push);
+        }
+        // Twirl: radius, angle.
+        case 2u: {
+            if (r >= params.p2) {
+                return at;
+            }
+            let t = 1.0 - r / params.p2;
+            let turn = params.p3 * t * t;
+            let s = sin(turn);
+            let c = cos(turn);
+            return center + vec2<f32>(c * d.x + s * d.y, -s * d.x + c * d.y);
+        }
+        // Bulge: radius, strength.
+        case 3u: {
+            if (r >= params.p2) {
+                return at;
+            }
+            let falloff = 1.0 - r / params.p2;
+            return center + d * (1.0 - params.p3 * falloff * falloff);
+        }
+        // Displacement map: amount.
+        default: {
+            return at + displacement(x, y) * vec2<f32>(params.p0, params.p1);
+        }
+    }
 }
 
-fn blend_over(dst_px: u32, src_px: u32) -> u32 {
-    let s = unpack_rgba(src_px);
-    let sa = s.w;
-    if (sa == 0u) {
-        return dst_px;
-    }
-    if (sa == 255u) {
-        return src_px;
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.width || y >= params.height) {
+        return;
     }
-
-    let d = unpack_rgba(dst_px);
-    let inv_sa = 255u - sa;
-    let out_a_x255 = sa * 255u + d.w * inv_sa;
-    let half = out_a_x255 / 2u;
-    let out_r = (s.x * sa * 255u + d.x * d.w * inv_sa + half) / out_a_x255;
-    let out_g = (s.y * sa * 255u + d.y * d.w * inv_sa + half) / out_a_x255;
-    let out_b = (s.z * sa * 255u + d.z * d.w * inv_sa + half) / out_a_x255;
-    let out_a = (out_a_x255 + 127u) / 255u;
-    return pack_rgba(vec4<u32>(out_r, out_g, out_b, out_a));
+    let origin = vec2<f32>(params.origin_x, params.origin_y);
+    let scale = vec2<f32>(params.scale_x, params.scale_y);
+    let at = origin + (vec2<f32>(f32(x), f32(y)) + 0.5) / scale;
+    let src_offset = vec2<f32>(f32(params.src_offset_x), f32(params.src_offset_y));
+    let uv = (source_point(x, y, at) - origin) * scale - 0.5 - src_offset;
+    let dims = vec2<u32>(params.src_width, params.src_height);
+    dst[y * params.width + x] = sample_source(dims, uv.x, uv.y, params.quality);
 }
 "#;
 
-const COMPOSITE_SHADER: &str = r#"
+// Film grain. Keep in lockstep with `grain_pixels` in tellur-core: the same
+// hash chain, and only correctly rounded float operations.
+const GRAIN_SHADER: &str = r#"
 struct Params {
-    dst_w: u32,
-    dst_h: u32,
-    src_w: u32,
-    src_h: u32,
-    offset_x: i32,
-    offset_y: i32,
-    pad0: u32,
-    pad1: u32,
+    width: u32,
+    height: u32,
+    seed: u32,
+    frame: u32,
+    monochrome: u32,
+    cells_per_pixel: f32,
+    amount: f32,
+    _pad0: u32,
 }
 
-@group(0) @binding(0) var<storage, read_write> dst: array<u32>;
-@group(0) @binding(1) var<storage, read> src: array<u32>;
+@group(0) @binding(0) var<storage, read> src: array<u32>;
+@group(0) @binding(1) var<storage, read_write> dst: array<u32>;
 @group(0) @binding(2) var<storage, read> params: Params;
 
+fn pcg(v: u32) -> u32 {
+    let state = v * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+    return (word >> 22u) ^ word;
+}
+
+fn cell(i: u32) -> u32 {
+    return u32(floor((f32(i) + 0.5) * params.cells_per_pixel));
+}
+
+fn triangular(hash: u32) -> f32 {
+    let unit = 1.0 / 65536.0;
+    let a = f32(hash & 0xffffu) * unit;
+    let b = f32(hash >> 16u) * unit;
+    return a + b - 1.0 + unit;
+}
+
 @compute @workgroup_size(16, 16)
 fn main(@builtin(global_invocation_id) id: vec3<u32>) {
     let x = id.x;
     let y = id.y;
-    if (x >= params.src_w || y >= params.src_h) {
+    if (x >= params.width || y >= params.height) {
         return;
     }
-    let dx = i32(x) + params.offset_x;
-    let dy = i32(y) + params.offset_y;
-    if (dx < 0 || dy < 0 || dx >= i32(params.dst_w) || dy >= i32(params.dst_h)) {
+    let idx = y * params.width + x;
+    let px = unpack_rgba(src[idx]);
+    if (px.w == 0u) {
+        dst[idx] = src[idx];
         return;
     }
-    let sidx = y * params.src_w + x;
-    let didx = u32(dy) * params.dst_w + u32(dx);
-    dst[didx] = blend_over(dst[didx], src[sidx]);
+    let frame_seed = pcg(params.frame + pcg(params.seed));
+    let row_seed = pcg(cell(y) + frame_seed);
+    let base = pcg(cell(x) + row_seed);
+    var out = px;
+    for (var channel = 0u; channel < 3u; channel = channel + 1u) {
+        let hash = select(pcg(base + channel), base, params.monochrome != 0u);
+        let grained = f32(px[channel]) + triangular(hash) * params.amount;
+        out[channel] = u32(floor(clamp(grained, 0.0, 255.0) + 0.5));
+    }
+    dst[idx] = pack_rgba(out);
 }
 "#;
 
-const COPY_ALPHA_SHADER: &str = r#"
+// Output dithering. Keep in lockstep with `Dither::step` in tellur-core.
+const DITHER_SHADER: &str = r#"
 struct Params {
-    src_w: u32,
-    src_h: u32,
-    out_w: u32,
-    out_h: u32,
-    offset_x: i32,
-    offset_y: i32,
-    pad0: u32,
-    pad1: u32,
+    width: u32,
+    height: u32,
+    kind: u32,
+    offset_x: u32,
+    offset_y: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
 }
 
-@group(0) @binding(0) var<storage, read> src: array<u32>;
-@group(0) @binding(1) var<storage, read_write> alpha: array<u32>;
+@group(0) @binding(0) var<storage, read_write> image: array<u32>;
+@group(0) @binding(1) var<storage, read> blue_noise: array<u32>;
 @group(0) @binding(2) var<storage, read> params: Params;
 
+// `tellur_core::grain::BLUE_NOISE_SIZE`.
+const BLUE_NOISE_SIZE: u32 = 64u;
+
+fn bayer_rank(x: u32, y: u32) -> u32 {
+    var rank = 0u;
+    for (var bit = 0u; bit < 3u; bit = bit + 1u) {
+        let xb = (x >> bit) & 1u;
+        let yb = (y >> bit) & 1u;
+        rank = rank | ((((xb ^ yb) << 1u) | yb) << (2u * (2u - bit)));
+    }
+    return rank;
+}
+
 @compute @workgroup_size(16, 16)
 fn main(@builtin(global_invocation_id) id: vec3<u32>) {
     let x = id.x;
     let y = id.y;
-    if (x >= params.out_w || y >= params.out_h) {
+    if (x >= params.width || y >= params.height) {
         return;
     }
-    let out_idx = y * params.out_w + x;
-    let sx_i = i32(x) - params.offset_x;
-    let sy_i = i32(y) - params.offset_y;
-    if (sx_i < 0 || sy_i < 0) {
-        alpha[out_idx] = 0u;
-        return;
+    let sx = x + params.offset_x;
+    let sy = y + params.offset_y;
+    var rank: u32;
+    var count: u32;
+    if (params.kind == 0u) {
+        rank = bayer_rank(sx, sy);
+        count = 64u;
+    } else {
+        let tx = sx % BLUE_NOISE_SIZE;
+        let ty = sy % BLUE_NOISE_SIZE;
+        rank = blue_noise[ty * BLUE_NOISE_SIZE + tx];
+        count = BLUE_NOISE_SIZE * BLUE_NOISE_SIZE;
     }
-    let sx = u32(sx_i);
-    let sy = u32(sy_i);
-    if (sx >= params.src_w || sy >= params.src_h) {
-        alpha[out_idx] = 0u;
+    var step = 0;
+    if (rank * 4u < count) {
+        step = -1;
+    } else if (rank * 4u >= count * 3u) {
+        step = 1;
+    }
+    if (step == 0) {
         return;
     }
-    let px = src[sy * params.src_w + sx];
-    alpha[out_idx] = (px >> 24u) & 255u;
+    let idx = y * params.width + x;
+    let px = unpack_rgba(image[idx]);
+    let c = clamp(vec3<i32>(px.xyz) + vec3<i32>(step), vec3<i32>(0), vec3<i32>(255));
+    image[idx] = pack_rgba(vec4<u32>(vec3<u32>(c), px.w));
 }
 "#;
 
-const BLUR_SHADER: &str = r#"
+// Procedural patterns. Keep in lockstep with `pattern_pixels` in
+// tellur-core: the same hashes, noise and box filters, so both are continuous
+// in position and agree to within one code value.
+const PATTERN_SHADER: &str = r#"
 struct Params {
     width: u32,
     height: u32,
-    radius: u32,
-    horizontal: u32,
+    kind: u32,
+    octaves: u32,
+    seed: u32,
+    step_x: f32,
+    step_y: f32,
+    shift: f32,
+    p0: f32,
+    p1: f32,
+    p2: f32,
+    _pad0: u32,
+    low: vec4<f32>,
+    high: vec4<f32>,
 }
 
-@group(0) @binding(0) var<storage, read> src: array<u32>;
-@group(0) @binding(1) var<storage, read_write> dst: array<u32>;
-@group(0) @binding(2) var<storage, read> params: Params;
+@group(0) @binding(0) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(1) var<storage, read> params: Params;
+
+fn pcg(v: u32) -> u32 {
+    let state = v * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+    return (word >> 22u) ^ word;
+}
+
+fn lattice(seed: u32, c: vec3<i32>) -> u32 {
+    let h = pcg(bitcast<u32>(c.z) + seed);
+    let h2 = pcg(bitcast<u32>(c.y) + h);
+    return pcg(bitcast<u32>(c.x) + h2);
+}
+
+fn gradient(hash: u32, d: vec3<f32>) -> f32 {
+    let h = hash & 15u;
+    let u = select(d.y, d.x, h < 8u);
+    var v = d.z;
+    if (h < 4u) {
+        v = d.y;
+    } else if (h == 12u || h == 14u) {
+        v = d.x;
+    }
+    return select(-u, u, (h & 1u) == 0u) + select(-v, v, (h & 2u) == 0u);
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    return a + (b - a) * t;
+}
+
+fn fade(t: f32) -> f32 {
+    return t * t * t * (t * (t * 6.0 - 15.0) + 10.0);
+}
+
+fn perlin_corner(seed: u32, cell: vec3<i32>, d: vec3<f32>, offset: vec3<i32>) -> f32 {
+    return gradient(lattice(seed, cell + offset), d - vec3<f32>(offset));
+}
+
+fn perlin(p: vec3<f32>, seed: u32) -> f32 {
+    let floored = floor(p);
+    let c = vec3<i32>(floored);
+    let d = p - floored;
+    let u = fade(d.x);
+    let v = fade(d.y);
+    let w = fade(d.z);
+    let near = lerp(
+        lerp(perlin_corner(seed, c, d, vec3<i32>(0, 0, 0)), perlin_corner(seed, c, d, vec3<i32>(1, 0, 0)), u),
+        lerp(perlin_corner(seed, c, d, vec3<i32>(0, 1, 0)), perlin_corner(seed, c, d, vec3<i32>(1, 1, 0)), u),
+        v,
+    );
+    let far = lerp(
+        lerp(perlin_corner(seed, c, d, vec3<i32>(0, 0, 1)), perlin_corner(seed, c, d, vec3<i32>(1, 0, 1)), u),
+        lerp(perlin_corner(seed, c, d, vec3<i32>(0, 1, 1)), perlin_corner(seed, c, d, vec3<i32>(1, 1, 1)), u),
+        v,
+    );
+    return lerp(near, far, w);
+}
+
+const SIMPLEX_UNSKEW: f32 = 1.0 / 6.0;
+const SIMPLEX_GAIN: f32 = 76.0;
+
+fn simplex_corner(seed: u32, cell: vec3<i32>, d0: vec3<f32>, offset: vec3<i32>, unskew: f32) -> f32 {
+    let d = d0 - vec3<f32>(offset) + vec3<f32>(unskew);
+    let falloff = 0.5 - d.x * d.x - d.y * d.y - d.z * d.z;
+    if (falloff <= 0.0) {
+        return 0.0;
+    }
+    let f2 = falloff * falloff;
+    return f2 * f2 * gradient(lattice(seed, cell + offset), d);
+}
+
+fn simplex(p: vec3<f32>, seed: u32) -> f32 {
+    let s = (p.x + p.y + p.z) * (1.0 / 3.0);
+    let floored = floor(p + vec3<f32>(s));
+    let t = (floored.x + floored.y + floored.z) * SIMPLEX_UNSKEW;
+    let d0 = p - (floored - vec3<f32>(t));
+    var c1 = vec3<i32>(0, 1, 0);
+    var c2 = vec3<i32>(1, 1, 0);
+    if (d0.x >= d0.y) {
+        if (d0.y >= d0.z) {
+            c1 = vec3<i32>(1, 0, 0);
+            c2 = vec3<i32>(1, 1, 0);
+        } else if (d0.x >= d0.z) {
+            c1 = vec3<i32>(1, 0, 0);
+            c2 = vec3<i32>(1, 0, 1);
+        } else {
+            c1 = vec3<i32>(0, 0, 1);
+            c2 = vec3<i32>(1, 0, 1);
+        }
+    } else if (d0.y < d0.z) {
+        c1 = vec3<i32>(0, 0, 1);
+        c2 = vec3<i32>(0, 1, 1);
+    } else if (d0.x < d0.z) {
+        c1 = vec3<i32>(0, 1, 0);
+        c2 = vec3<i32>(0, 1, 1);
+    }
+    let c = vec3<i32>(floored);
+    return SIMPLEX_GAIN * (
+        simplex_corner(seed, c, d0, vec3<i32>(0, 0, 0), 0.0)
+        + simplex_corner(seed, c, d0, c1, SIMPLEX_UNSKEW)
+        + simplex_corner(seed, c, d0, c2, 2.0 * SIMPLEX_UNSKEW)
+        + simplex_corner(seed, c, d0, vec3<i32>(1, 1, 1), 3.0 * SIMPLEX_UNSKEW)
+    );
+}
+
+fn fractal_noise(p: vec3<f32>) -> f32 {
+    var sum = 0.0;
+    var total = 0.0;
+    var amplitude = 1.0;
+    var frequency = 1.0;
+    for (var octave = 0u; octave < params.octaves; octave = octave + 1u) {
+        let seed = pcg(params.seed + octave);
+        let q = p * frequency;
+        var n = 0.0;
+        if (params.kind == 0u) {
+            n = perlin(q, seed);
+        } else {
+            n = simplex(q, seed);
+        }
+        sum = sum + amplitude * n;
+        total = total + amplitude;
+        amplitude = amplitude * 0.5;
+        frequency = frequency * 2.0;
+    }
+    return clamp(0.5 + 0.5 * sum / total, 0.0, 1.0);
+}
+
+fn triangle(v: f32) -> f32 {
+    return abs(fract(v * 0.5) - 0.5);
+}
+
+fn square_wave(p: f32, width: f32) -> f32 {
+    return clamp(2.0 * (triangle(p - 0.5 * width) - triangle(p + 0.5 * width)) / width, -1.0, 1.0);
+}
+
+fn code(v: f32) -> u32 {
+    return u32(floor(clamp(v * 255.0, 0.0, 255.0) + 0.5));
+}
 
 @compute @workgroup_size(16, 16)
 fn main(@builtin(global_invocation_id) id: vec3<u32>) {
@@ -1588,35 +4434,40 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
     if (x >= params.width || y >= params.height) {
         return;
     }
-
-    var sum = 0u;
-    var count = 0u;
-    if (params.horizontal != 0u) {
-        let start = select(0u, x - params.radius, x >= params.radius);
-        let end = min(params.width - 1u, x + params.radius);
-        var ix = start;
-        loop {
-            sum = sum + src[y * params.width + ix];
-            count = count + 1u;
-            if (ix >= end) {
-                break;
-            }
-            ix = ix + 1u;
+    let px = f32(x) + 0.5;
+    let py = f32(y) + 0.5;
+    var t = 0.0;
+    switch params.kind {
+        case 0u, 1u: {
+            t = fractal_noise(vec3<f32>(px * params.step_x, py * params.step_y, params.shift));
         }
-    } else {
-        let start = select(0u, y - params.radius, y >= params.radius);
-        let end = min(params.height - 1u, y + params.radius);
-        var iy = start;
-        loop {
-            sum = sum + src[iy * params.width + x];
-            count = count + 1u;
-            if (iy >= end) {
-                break;
-            }
-            iy = iy + 1u;
+        case 2u: {
+            let u = square_wave(px * params.step_x - params.shift, params.step_x);
+            let v = square_wave(py * params.step_y, params.step_y);
+            t = 0.5 - 0.5 * u * v;
+        }
+        case 3u: {
+            let across = px * params.step_x + py * params.step_y - params.shift;
+            t = 0.5 - 0.5 * square_wave(across, params.p0);
+        }
+        default: {
+            let lx = (fract(px * params.step_x - params.shift) - 0.5) * params.p0;
+            let ly = (fract(py * params.step_y) - 0.5) * params.p0;
+            let edge = sqrt(lx * lx + ly * ly) - params.p1;
+            t = clamp(0.5 - edge / params.p2, 0.0, 1.0);
         }
     }
-    dst[y * params.width + x] = sum / count;
+    let mixed = params.low + (params.high - params.low) * t;
+    var out = vec4<u32>(0u);
+    if (mixed.w > 0.0) {
+        out = vec4<u32>(
+            code(mixed.x / mixed.w),
+            code(mixed.y / mixed.w),
+            code(mixed.z / mixed.w),
+            code(mixed.w),
+        );
+    }
+    dst[y * params.width + x] = pack_rgba(out);
 }
 "#;
 
@@ -1655,33 +4506,18 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
     let a = (alpha[y * params.src_w + x] * params.a + 127u) / 255u;
     if (a == 0u) {
         return;
-    }
-    let src = pack_rgba(vec4<u32>(params.r, params.g, params.b, a));
-    let didx = u32(dy) * params.dst_w + u32(dx);
-    dst[didx] = blend_over(dst[didx], src);
-}
-"#;
-
-const OUTLINE_SHADER: &str = r#"
-struct Params {
-    dst_w: u32,
-    dst_h: u32,
-    src_w: u32,
-    src_h: u32,
-    offset_x: i32,
-    offset_y: i32,
-    r: u32,
-    g: u32,
-    b: u32,
-    a: u32,
-    radius_x: u32,
-    radius_y: u32,
+    }
+    let src = pack_rgba(vec4<u32>(params.r, params.g, params.b, a));
+    let didx = u32(dy) * params.dst_w + u32(dx);
+    dst[didx] = blend_over(dst[didx], src);
 }
+"#;
 
-@group(0) @binding(0) var<storage, read_write> dst: array<u32>;
-@group(0) @binding(1) var<storage, read> alpha: array<u32>;
-@group(0) @binding(2) var<storage, read> params: Params;
-
+// Antialiased elliptical dilation of the `alpha` binding declared by the
+// including shader. Append it after that shader: some backends miscompile
+// functions that reference a binding declared further down. Keep in
+// lockstep with `dilate_ellipse_antialiased` in `outline.rs`.
+const DILATE_WGSL: &str = r#"
 fn line_coverage(delta: i32, radius: u32) -> f32 {
     let edge_distance = f32(abs(delta)) - f32(radius);
     return clamp(0.5 - edge_distance, 0.0, 1.0);
@@ -1719,27 +4555,20 @@ fn ellipse_coverage(dx: i32, dy: i32, rx: u32, ry: u32) -> f32 {
     return clamp(0.5 - edge_distance, 0.0, 1.0);
 }
 
-@compute @workgroup_size(16, 16)
-fn main(@builtin(global_invocation_id) id: vec3<u32>) {
-    let x = id.x;
-    let y = id.y;
-    if (x >= params.src_w || y >= params.src_h) {
-        return;
-    }
-
-    let rx = i32(params.radius_x);
-    let ry = i32(params.radius_y);
+fn dilated_alpha(x: u32, y: u32, width: u32, height: u32, radius_x: u32, radius_y: u32) -> u32 {
+    let rx = i32(radius_x);
+    let ry = i32(radius_y);
     var m = 0.0;
     var oy = -(ry + 1);
     loop {
         var ox = -(rx + 1);
         loop {
-            let coverage = ellipse_coverage(ox, oy, params.radius_x, params.radius_y);
+            let coverage = ellipse_coverage(ox, oy, radius_x, radius_y);
             if (coverage > 0.0) {
                 let sx = i32(x) + ox;
                 let sy = i32(y) + oy;
-                if (sx >= 0 && sy >= 0 && sx < i32(params.src_w) && sy < i32(params.src_h)) {
-                    m = max(m, f32(alpha[u32(sy) * params.src_w + u32(sx)]) * coverage);
+                if (sx >= 0 && sy >= 0 && sx < i32(width) && sy < i32(height)) {
+                    m = max(m, f32(alpha[u32(sy) * width + u32(sx)]) * coverage);
                 }
             }
             if (ox >= rx + 1) {
@@ -1752,8 +4581,39 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
         }
         oy = oy + 1;
     }
+    return u32(round(clamp(m, 0.0, 255.0)));
+}
+"#;
+
+const OUTLINE_SHADER: &str = r#"
+struct Params {
+    dst_w: u32,
+    dst_h: u32,
+    src_w: u32,
+    src_h: u32,
+    offset_x: i32,
+    offset_y: i32,
+    r: u32,
+    g: u32,
+    b: u32,
+    a: u32,
+    radius_x: u32,
+    radius_y: u32,
+}
+
+@group(0) @binding(0) var<storage, read_write> dst: array<u32>;
+@group(0) @binding(1) var<storage, read> alpha: array<u32>;
+@group(0) @binding(2) var<storage, read> params: Params;
+
+@compute @workgroup_size(16, 16)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let x = id.x;
+    let y = id.y;
+    if (x >= params.src_w || y >= params.src_h) {
+        return;
+    }
 
-    let dilated = u32(round(clamp(m, 0.0, 255.0)));
+    let dilated = dilated_alpha(x, y, params.src_w, params.src_h, params.radius_x, params.radius_y);
     let a = (dilated * params.a + 127u) / 255u;
     if (a == 0u) {
         return;
@@ -1892,11 +4752,21 @@ fn main(@builtin(global_invocation_id) id: vec3<u32>) {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tellur_core::chroma_key::ChromaKeySettings;
     use tellur_core::composite::composite_at;
+    use tellur_core::distort::{displace_pixels, distort_pixels, Bulge, Ripple, Twirl, Wave};
     use tellur_core::geometry::Rect;
+    use tellur_core::grade::{ColorAdjustment, Grade};
+    use tellur_core::grain::{dither_pixels, grain_pixels, GrainNoise};
+    use tellur_core::matte::apply_matte_pixels;
+    use tellur_core::pattern::{pattern_pixels, Checker, DotGrid, NoiseField, Stripes};
+    use tellur_core::perspective::{depth_composite_pixels, project_pixels};
+    use tellur_core::phase::Phase;
     use tellur_core::render_context::{
-        CompositeInput, DropShadowInput, GpuRasterBackend, OutlineInput,
+        ChromaKeyInput, CompositeInput, DepthCompositeInput, DepthLayer, DropShadowInput,
+        GpuRasterBackend, OutlineInput, PatternInput, ProjectInput, ResampleInput,
     };
+    use tellur_core::resample::resample_pixels;
     use tellur_core::vector::{ClipGroup, Fill, Group, Path, PathCommand, Stroke};
 
     fn gpu_or_skip() -> Option<GpuRenderer> {
@@ -1990,6 +4860,7 @@ mod tests {
             image: &cpu,
             offset_x: 0,
             offset_y: 0,
+            blend: BlendMode::Normal,
         };
 
         assert!(
@@ -2061,6 +4932,7 @@ mod tests {
             image: &gpu_src,
             offset_x: 1,
             offset_y: 1,
+            blend: BlendMode::Normal,
         };
 
         let rendered = GpuRasterBackend::composite(&mut gpu, target, &[input]).unwrap();
@@ -2071,6 +4943,73 @@ mod tests {
         assert_eq!(rendered.pixels.as_ref(), expected.as_slice());
     }
 
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn composite_blend_modes_match_blend_pixel() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        // Every backdrop/source pair from a small channel and alpha grid,
+        // laid out so each source pixel lands on its backdrop pixel.
+        let levels = [0u8, 40, 128, 200, 255];
+        let alphas = [0u8, 90, 255];
+        let mut backdrop = Vec::new();
+        let mut source = Vec::new();
+        for &da in &alphas {
+            for &sa in &alphas[1..] {
+                for &cb in &levels {
+                    for &cs in &levels {
+                        backdrop.extend_from_slice(&[cb, 255 - cb, cb / 2, da]);
+                        source.extend_from_slice(&[cs, cs / 3, 255 - cs, sa]);
+                    }
+                }
+            }
+        }
+        let width = (backdrop.len() / 4) as u32;
+        let target = Resolution::new(width, 1);
+        let backdrop = image(width, 1, &backdrop);
+        let source = image(width, 1, &source);
+
+        for mode in BlendMode::ALL {
+            let gpu_backdrop = upload(&mut gpu, &backdrop);
+            let gpu_source = upload(&mut gpu, &source);
+            let inputs = [
+                CompositeInput {
+                    image: &gpu_backdrop,
+                    offset_x: 0,
+                    offset_y: 0,
+                    blend: BlendMode::Normal,
+                },
+                CompositeInput {
+                    image: &gpu_source,
+                    offset_x: 0,
+                    offset_y: 0,
+                    blend: mode,
+                },
+            ];
+            let rendered = GpuRasterBackend::composite(&mut gpu, target, &inputs).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+
+            for (i, ((gpu_px, d), s)) in rendered
+                .pixels
+                .chunks_exact(4)
+                .zip(backdrop.pixels.chunks_exact(4))
+                .zip(source.pixels.chunks_exact(4))
+                .enumerate()
+            {
+                let d = [d[0], d[1], d[2], d[3]];
+                let s = [s[0], s[1], s[2], s[3]];
+                let expected = tellur_core::composite::blend_pixel(d, s, mode);
+                for c in 0..4 {
+                    assert!(
+                        gpu_px[c].abs_diff(expected[c]) <= 1,
+                        "{mode:?} at {i}: {gpu_px:?} vs {expected:?} (d={d:?} s={s:?})"
+                    );
+                }
+            }
+        }
+    }
+
     #[test]
     #[ignore = "requires a GPU adapter"]
     fn composite_matches_cpu_with_opaque_full_frame_base() {
@@ -2094,11 +5033,13 @@ mod tests {
                 image: &base,
                 offset_x: 0,
                 offset_y: 0,
+                blend: BlendMode::Normal,
             },
             CompositeInput {
                 image: &gpu_src,
                 offset_x: 1,
                 offset_y: 1,
+                blend: BlendMode::Normal,
             },
         ];
 
@@ -2145,6 +5086,784 @@ mod tests {
         );
     }
 
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn blur_matches_cpu_blur() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        // A two-tone, partly transparent child exercises premultiplication.
+        let mut pixels = Vec::new();
+        for y in 0..6u8 {
+            for x in 0..8u8 {
+                let px = if x < 4 {
+                    [240, 20, 10, 255]
+                } else {
+                    [10, 40, 220, 60 + y * 20]
+                };
+                pixels.extend_from_slice(&px);
+            }
+        }
+        let child = image(8, 6, &pixels);
+        let target = Resolution::new(24, 22);
+        let cases = [
+            [Vec2(2.5, 0.0), Vec2(0.0, 1.75)],
+            [Vec2(2.0, 1.5), Vec2::ZERO],
+        ];
+
+        for axes in cases {
+            let expected = crate::blur::blur_image(&child, target, 8, 8, axes);
+            let uploaded = upload(&mut gpu, &child);
+            let input = BlurInput {
+                child: &uploaded,
+                target,
+                child_offset_x: 8,
+                child_offset_y: 8,
+                axes,
+            };
+            let rendered = GpuRasterBackend::blur(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+
+            for (i, (gpu_px, cpu_px)) in rendered
+                .pixels
+                .chunks_exact(4)
+                .zip(expected.pixels.chunks_exact(4))
+                .enumerate()
+            {
+                assert!(gpu_px[3].abs_diff(cpu_px[3]) <= 1, "{axes:?} alpha at {i}");
+                // Straight color of nearly transparent pixels is ill-conditioned.
+                if cpu_px[3] >= 16 {
+                    for c in 0..3 {
+                        assert!(
+                            gpu_px[c].abs_diff(cpu_px[c]) <= 2,
+                            "{axes:?} at {i}: {gpu_px:?} vs {cpu_px:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn outer_glow_matches_cpu_glow() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let mut pixels = Vec::new();
+        for y in 0..5u8 {
+            for x in 0..7u8 {
+                pixels.extend_from_slice(&[200, 30, 10, if x > y { 255 } else { 90 }]);
+            }
+        }
+        let child = image(7, 5, &pixels);
+        let target = Resolution::new(23, 21);
+        let color = Color::rgba_u8(40, 220, 255, 200);
+
+        for (spread, blur_radius) in [((0, 0), 2), ((2, 1), 0), ((3, 2), 2)] {
+            let mut expected = vec![0u8; 23 * 21 * 4];
+            let glow = crate::glow::make_glow(&child, target, (8, 8), spread, blur_radius, color);
+            composite_at(&mut expected, target, &glow, 0, 0);
+            composite_at(&mut expected, target, &child, 8, 8);
+            let uploaded = upload(&mut gpu, &child);
+            let input = OuterGlowInput {
+                child: &uploaded,
+                target,
+                child_offset_x: 8,
+                child_offset_y: 8,
+                spread_x: spread.0,
+                spread_y: spread.1,
+                blur_radius,
+                color,
+            };
+            let rendered = GpuRasterBackend::outer_glow(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+
+            for (i, (gpu_px, cpu_px)) in rendered
+                .pixels
+                .chunks_exact(4)
+                .zip(expected.chunks_exact(4))
+                .enumerate()
+            {
+                for c in 0..4 {
+                    assert!(
+                        gpu_px[c].abs_diff(cpu_px[c]) <= 1,
+                        "{spread:?}/{blur_radius} at {i}: {gpu_px:?} vs {cpu_px:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn bloom_matches_cpu_bloom() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        // Bright, dim and translucent regions straddle the threshold.
+        let mut pixels = Vec::new();
+        for y in 0..6u8 {
+            for x in 0..8u8 {
+                let px = match x {
+                    0..=2 => [255, 250, 220, 255],
+                    3..=5 => [90, 100, 120, 255],
+                    _ => [240, 240, 255, 80 + y * 30],
+                };
+                pixels.extend_from_slice(&px);
+            }
+        }
+        let child = image(8, 6, &pixels);
+        let target = Resolution::new(24, 22);
+        let cases = [
+            (0.6, 1.0, [Vec2(2.5, 0.0), Vec2(0.0, 2.5)]),
+            (0.3, 1.5, [Vec2(1.0, 0.0), Vec2::ZERO]),
+        ];
+
+        for (threshold, intensity, axes) in cases {
+            let expected =
+                crate::glow::bloom_image(&child, target, (8, 8), threshold, intensity, axes);
+            let uploaded = upload(&mut gpu, &child);
+            let input = BloomInput {
+                child: &uploaded,
+                target,
+                child_offset_x: 8,
+                child_offset_y: 8,
+                threshold,
+                intensity,
+                axes,
+            };
+            let rendered = GpuRasterBackend::bloom(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+
+            for (i, (gpu_px, cpu_px)) in rendered
+                .pixels
+                .chunks_exact(4)
+                .zip(expected.pixels.chunks_exact(4))
+                .enumerate()
+            {
+                assert!(
+                    gpu_px[3].abs_diff(cpu_px[3]) <= 1,
+                    "{threshold} alpha at {i}"
+                );
+                if cpu_px[3] >= 16 {
+                    for c in 0..3 {
+                        assert!(
+                            gpu_px[c].abs_diff(cpu_px[c]) <= 2,
+                            "{threshold} at {i}: {gpu_px:?} vs {cpu_px:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Every channel value in steps of 17, with a varying alpha.
+    fn grading_ramp() -> CpuRasterImage {
+        let mut pixels = Vec::new();
+        for b in 0..4u8 {
+            for g in 0..16u8 {
+                for r in 0..16u8 {
+                    pixels.extend_from_slice(&[r * 17, g * 17, b * 85, 255 - r]);
+                }
+            }
+        }
+        image(16, 64, &pixels)
+    }
+
+    fn assert_rgb_within_one(rendered: &CpuRasterImage, expected: &[u8], what: &str) {
+        for (i, (gpu_px, cpu_px)) in rendered
+            .pixels
+            .chunks_exact(4)
+            .zip(expected.chunks_exact(4))
+            .enumerate()
+        {
+            assert_eq!(gpu_px[3], cpu_px[3], "{what} alpha at {i}");
+            for c in 0..3 {
+                assert!(
+                    gpu_px[c].abs_diff(cpu_px[c]) <= 1,
+                    "{what} at {i}: {gpu_px:?} vs {cpu_px:?}"
+                );
+            }
+        }
+    }
+
+    /// Compares premultiplied channels, so colors under near-zero alpha,
+    /// which filtering leaves imprecise, do not count.
+    fn assert_premultiplied_within_one(rendered: &CpuRasterImage, expected: &[u8], what: &str) {
+        let premul = |px: &[u8], c: usize| u32::from(px[c]) * u32::from(px[3]) / 255;
+        for (i, (gpu_px, cpu_px)) in rendered
+            .pixels
+            .chunks_exact(4)
+            .zip(expected.chunks_exact(4))
+            .enumerate()
+        {
+            assert!(
+                gpu_px[3].abs_diff(cpu_px[3]) <= 1
+                    && (0..3).all(|c| premul(gpu_px, c).abs_diff(premul(cpu_px, c)) <= 1),
+                "{what} at {i}: {gpu_px:?} vs {cpu_px:?}"
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn color_adjust_matches_cpu_grade() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let child = grading_ramp();
+        let cases = [
+            ColorAdjustment {
+                exposure: 0.5,
+                contrast: 1.3,
+                saturation: 0.6,
+                ..ColorAdjustment::default()
+            },
+            ColorAdjustment {
+                brightness: -0.1,
+                hue: 75.0,
+                tint: Color::rgba_u8(255, 160, 60, 100),
+                gamma: 1.8,
+                ..ColorAdjustment::default()
+            },
+        ];
+
+        for (case, adjustment) in cases.into_iter().enumerate() {
+            let mut expected = child.pixels.to_vec();
+            Grade::Adjust(adjustment).apply_pixels(&mut expected);
+            let uploaded = upload(&mut gpu, &child);
+            let input = ColorAdjustInput {
+                image: &uploaded,
+                adjustment,
+            };
+            let rendered = GpuRasterBackend::color_adjust(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+            assert_rgb_within_one(&rendered, &expected, &format!("case {case}"));
+        }
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn lut3d_matches_cpu_grade() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let child = grading_ramp();
+        // A warm, crushed look: not separable per channel, so every axis of
+        // the trilinear lookup is exercised.
+        let size = 5u32;
+        let last = (size - 1) as f32;
+        let mut table = Vec::new();
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    let [r, g, b] = [r, g, b].map(|v| v as f32 / last);
+                    table.push([
+                        (r * 0.8 + g * 0.2).powf(1.2),
+                        (g * 0.9 + b * 0.1) * 0.95,
+                        (b * 0.7 + r * 0.1).sqrt() * 0.9,
+                    ]);
+                }
+            }
+        }
+        let lut = CubeLut::new(size, [0.0; 3], [1.0; 3], table).unwrap();
+
+        for mix in [1.0, 0.35] {
+            let grade = Grade::Lut {
+                lut: lut.clone(),
+                mix,
+            };
+            let mut expected = child.pixels.to_vec();
+            grade.apply_pixels(&mut expected);
+            let uploaded = upload(&mut gpu, &child);
+            let input = Lut3dInput {
+                image: &uploaded,
+                lut: &lut,
+                mix,
+            };
+            let rendered = GpuRasterBackend::lut3d(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+            assert_rgb_within_one(&rendered, &expected, &format!("mix {mix}"));
+        }
+        assert_eq!(gpu.stats.color_grades, 2);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn matte_matches_cpu_reference_for_every_mode() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let content = grading_ramp();
+        // A smaller matte, offset so content pixels on every side fall
+        // outside it.
+        let mut matte_pixels = Vec::new();
+        for y in 0..40u32 {
+            for x in 0..12u32 {
+                let v = (x * 21 + y * 5) as u8;
+                matte_pixels.extend_from_slice(&[v, 255 - v, v / 2, (y * 6) as u8]);
+            }
+        }
+        let matte = image(12, 40, &matte_pixels);
+
+        for mode in MatteMode::ALL {
+            let mut expected = content.pixels.to_vec();
+            apply_matte_pixels(&mut expected, content.width, &matte, 3, -2, mode);
+            let uploaded_content = upload(&mut gpu, &content);
+            let uploaded_matte = upload(&mut gpu, &matte);
+            let input = MatteInput {
+                content: &uploaded_content,
+                matte: &uploaded_matte,
+                matte_offset_x: 3,
+                matte_offset_y: -2,
+                mode,
+            };
+            let rendered = GpuRasterBackend::matte(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+            assert_eq!(&rendered.pixels[..], &expected[..], "{mode:?}");
+        }
+        assert_eq!(gpu.stats.mattes, MatteMode::ALL.len() as u64);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn chroma_key_matches_cpu_reference() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        // The ramp sweeps through greens, so it holds keyed, ramped, spilled
+        // and untouched pixels alike.
+        let source = grading_ramp();
+        let settings = ChromaKeySettings {
+            key_color: Color::rgb_u8(40, 220, 60),
+            tolerance: 0.12,
+            softness: 0.15,
+            spill: 0.8,
+            choke: 0.0,
+        };
+        for choke_radius in [0, 2, 9] {
+            let mut expected = source.pixels.to_vec();
+            settings.apply_pixels(&mut expected, source.width, choke_radius);
+            let uploaded = upload(&mut gpu, &source);
+            let input = ChromaKeyInput {
+                image: &uploaded,
+                settings,
+                choke_radius,
+            };
+            let rendered = GpuRasterBackend::chroma_key(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+            for (i, (gpu_px, cpu_px)) in rendered
+                .pixels
+                .chunks_exact(4)
+                .zip(expected.chunks_exact(4))
+                .enumerate()
+            {
+                // The soft ramp's float rounding may land either side of a
+                // half step.
+                for c in 0..4 {
+                    assert!(
+                        gpu_px[c].abs_diff(cpu_px[c]) <= 1,
+                        "choke {choke_radius} at {i}: {gpu_px:?} vs {cpu_px:?}"
+                    );
+                }
+            }
+            assert!(expected.chunks_exact(4).any(|px| px[3] == 0));
+        }
+        assert_eq!(gpu.stats.chroma_keys, 3);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn resample_matches_cpu_reference_for_every_quality() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let source = grading_ramp();
+        // A rotated, skewed, shrunk map whose samples straddle the source's
+        // edges, so transparent border taps are exercised too.
+        let inverse = Transform::around_point(
+            Vec2(8.0, 32.0),
+            Transform::rotate(0.4)
+                .then(Transform::skew(Vec2(0.15, 0.0)))
+                .then(Transform::scale(Vec2(1.3, 0.9))),
+        )
+        .then(Transform::translate(Vec2(-4.0, 2.5)));
+        let target = Resolution::new(24, 40);
+        for quality in [
+            ImageQuality::Nearest,
+            ImageQuality::Bilinear,
+            ImageQuality::Bicubic,
+        ] {
+            let expected = resample_pixels(&source, target, inverse, quality);
+            let uploaded = upload(&mut gpu, &source);
+            let input = ResampleInput {
+                image: &uploaded,
+                target,
+                inverse,
+                quality,
+            };
+            let rendered = GpuRasterBackend::resample(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+            let mut mismatched = 0;
+            for (gpu_px, cpu_px) in rendered
+                .pixels
+                .chunks_exact(4)
+                .zip(expected.chunks_exact(4))
+            {
+                // Unpremultiplying magnifies float noise at low alpha, so
+                // compare the premultiplied color.
+                let premul = |px: &[u8], c: usize| u32::from(px[c]) * u32::from(px[3]) / 255;
+                if gpu_px[3].abs_diff(cpu_px[3]) > 1
+                    || (0..3).any(|c| premul(gpu_px, c).abs_diff(premul(cpu_px, c)) > 1)
+                {
+                    mismatched += 1;
+                }
+            }
+            // Nearest sampling can pick the other neighbor where a sample
+            // lands exactly between two pixels.
+            assert!(
+                mismatched <= expected.len() / 4 / 100,
+                "{quality:?}: {mismatched} pixels differ"
+            );
+            assert!(expected.chunks_exact(4).any(|px| px[3] == 0));
+            assert!(expected.chunks_exact(4).any(|px| px[3] == 255));
+        }
+        assert_eq!(gpu.stats.resamples, 3);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn project_matches_cpu_reference() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let source = grading_ramp();
+        // A strongly foreshortened map whose lower rows fall behind the eye,
+        // so clipped, filtered and off-source pixels all occur.
+        let inverse = Homography {
+            m: [[0.6, 0.05, -2.0], [0.02, 1.4, -6.0], [0.004, -0.03, 1.2]],
+        };
+        let target = Resolution::new(24, 48);
+        let expected = project_pixels(&source, target, inverse, ImageQuality::Bilinear);
+        let uploaded = upload(&mut gpu, &source);
+        let input = ProjectInput {
+            image: &uploaded,
+            target,
+            inverse,
+            quality: ImageQuality::Bilinear,
+        };
+        let rendered = GpuRasterBackend::project(&mut gpu, input).unwrap();
+        let rendered = readback(&mut gpu, rendered);
+        assert_premultiplied_within_one(&rendered, &expected, "project");
+        assert!(expected.chunks_exact(4).any(|px| px[3] == 0));
+        assert!(expected.chunks_exact(4).any(|px| px[3] == 255));
+        assert_eq!(gpu.stats.projections, 1);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn depth_composite_matches_cpu_reference() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let card = |w: u32, h: u32, rgb: [u8; 3]| {
+            let mut pixels = Vec::new();
+            for y in 0..h {
+                for x in 0..w {
+                    let a = if (x + y) % 7 == 0 {
+                        0
+                    } else {
+                        128 + (x * 8) as u8 % 128
+                    };
+                    pixels.extend_from_slice(&[rgb[0], rgb[1], rgb[2], a]);
+                }
+            }
+            image(w, h, &pixels)
+        };
+        // Two planes that cross mid-frame, and a multiplied third whose rays
+        // partly miss it, placed at two pixels per layout unit.
+        fn layers(images: &[RasterImage]) -> Vec<DepthLayer<'_>> {
+            let offsets = [[0, 0], [6, 2], [-4, 12]];
+            let inverses = [
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+                [[0.9, 0.1, -1.0], [-0.05, 1.1, 0.5], [0.01, 0.0, 0.9]],
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [-0.06, 0.0, 0.8]],
+            ];
+            let depths = [[0.5, 0.0, -5.0], [-0.4, 0.1, 3.0], [0.0, 0.0, 10.0]];
+            let blends = [BlendMode::Normal, BlendMode::Normal, BlendMode::Multiply];
+            (0..images.len())
+                .map(|i| DepthLayer {
+                    image: &images[i],
+                    offset: offsets[i],
+                    inverse: Homography { m: inverses[i] },
+                    depth: depths[i],
+                    blend: blends[i],
+                })
+                .collect()
+        }
+        let sources = [
+            card(40, 40, [220, 40, 40]),
+            card(32, 36, [40, 60, 220]),
+            card(40, 20, [240, 220, 60]),
+        ];
+        let target = Resolution::new(40, 40);
+        let input = |layers| DepthCompositeInput {
+            target,
+            origin: Vec2(-1.0, 0.5),
+            scale: Vec2(2.0, 2.0),
+            layers,
+        };
+
+        let cpu_images: Vec<RasterImage> = sources
+            .iter()
+            .map(|source| RasterImage::Cpu(source.clone()))
+            .collect();
+        let cpu_layers = layers(&cpu_images);
+        let expected = depth_composite_pixels(&input(&cpu_layers));
+        let gpu_images: Vec<RasterImage> = sources
+            .iter()
+            .map(|source| upload(&mut gpu, source))
+            .collect();
+        let gpu_layers = layers(&gpu_images);
+        let rendered = GpuRasterBackend::depth_composite(&mut gpu, input(&gpu_layers)).unwrap();
+        let rendered = readback(&mut gpu, rendered);
+        assert_premultiplied_within_one(&rendered, &expected, "depth composite");
+        // The crossing planes each win on one side of the crossing.
+        let px = |x: usize, y: usize| &expected[(y * 40 + x) * 4..][..4];
+        assert!(px(10, 6)[2] > px(10, 6)[0]);
+        assert!(px(35, 6)[0] > px(35, 6)[2]);
+        assert_eq!(gpu.stats.depth_composites, 1);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn warp_matches_cpu_reference_for_every_field() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let source = grading_ramp();
+        // Two pixels per unit, with the paint bounds overhanging the layout
+        // box so anchors and pixel centers do not coincide, and a margin
+        // around the source for content pushed past it.
+        let placement = WarpPlacement {
+            paint: Rect {
+                origin: Vec2(-2.0, -3.5),
+                size: Vec2(10.0, 35.0),
+            },
+            target: Resolution::new(source.width + 4, source.height + 6),
+            offset: [2, 3],
+        };
+        let size = Vec2(6.0, 28.0);
+        let phase = Phase::saturating(0.3);
+        let distortions: [Distortion; 4] = [
+            Ripple {
+                amplitude: 1.5,
+                wavelength: 5.0,
+                phase,
+                ..Default::default()
+            }
+            .into(),
+            Wave {
+                amplitude: 1.0,
+                wavelength: 7.0,
+                angle: 0.4,
+                phase,
+            }
+            .into(),
+            Twirl {
+                radius: 6.0,
+                angle: 2.0,
+                ..Default::default()
+            }
+            .into(),
+            Bulge {
+                radius: 6.0,
+                strength: -0.7,
+                ..Default::default()
+            }
+            .into(),
+        ];
+        let uploaded = upload(&mut gpu, &source);
+        for distortion in distortions {
+            let expected =
+                distort_pixels(&source, placement, size, &distortion, ImageQuality::Bicubic);
+            let input = WarpInput {
+                image: &uploaded,
+                placement,
+                size,
+                field: WarpField::Distort(distortion),
+                quality: ImageQuality::Bicubic,
+            };
+            let rendered = GpuRasterBackend::warp(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+            assert_premultiplied_within_one(&rendered, &expected, &format!("{distortion:?}"));
+        }
+
+        // A map smaller than the source and offset into it, with varied luma
+        // and alpha.
+        let map_pixels: Vec<u8> = (0..8 * 40u32)
+            .flat_map(|i| {
+                [
+                    (i * 7 % 256) as u8,
+                    (i * 3 % 256) as u8,
+                    90,
+                    255 - (i % 64) as u8,
+                ]
+            })
+            .collect();
+        let map = image(8, 40, &map_pixels);
+        let amount = Vec2(2.0, -1.5);
+        let expected = displace_pixels(
+            &source,
+            placement,
+            &map,
+            [3, 10],
+            amount,
+            ImageQuality::Bilinear,
+        );
+        let uploaded_map = upload(&mut gpu, &map);
+        let input = WarpInput {
+            image: &uploaded,
+            placement,
+            size,
+            field: WarpField::Displace {
+                map: &uploaded_map,
+                map_offset: [3, 10],
+                amount,
+            },
+            quality: ImageQuality::Bilinear,
+        };
+        let rendered = GpuRasterBackend::warp(&mut gpu, input).unwrap();
+        let rendered = readback(&mut gpu, rendered);
+        assert_premultiplied_within_one(&rendered, &expected, "displace");
+        assert_eq!(gpu.stats.warps, 5);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn grain_matches_cpu_reference() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let source = grading_ramp();
+        let uploaded = upload(&mut gpu, &source);
+        for (cells_per_pixel, monochrome) in [(1.0, true), (0.3, false)] {
+            let noise = GrainNoise {
+                amount: 0.1,
+                cells_per_pixel,
+                seed: 7,
+                frame: 12,
+                monochrome,
+            };
+            let expected = grain_pixels(&source, noise);
+            let input = GrainInput {
+                image: &uploaded,
+                noise,
+            };
+            let rendered = GpuRasterBackend::grain(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+            assert_rgb_within_one(&rendered, &expected, &format!("{noise:?}"));
+        }
+        assert_eq!(gpu.stats.grains, 2);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn dither_matches_cpu_reference() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let source = grading_ramp();
+        let uploaded = upload(&mut gpu, &source);
+        for dither in [Dither::Ordered, Dither::BlueNoise] {
+            for frame in [0, 5] {
+                let expected = dither_pixels(&source, dither, frame);
+                let input = DitherInput {
+                    image: &uploaded,
+                    dither,
+                    frame,
+                };
+                let rendered = GpuRasterBackend::dither(&mut gpu, input).unwrap();
+                let rendered = readback(&mut gpu, rendered);
+                assert_eq!(
+                    rendered.pixels.as_ref(),
+                    expected.as_slice(),
+                    "{dither:?} frame {frame}"
+                );
+            }
+        }
+        assert_eq!(gpu.stats.dithers, 4);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn pattern_matches_cpu_reference_for_every_generator() {
+        let Some(mut gpu) = gpu_or_skip() else {
+            return;
+        };
+        let translucent = Color::rgba_u8(40, 160, 220, 120);
+        let noise = |kind| {
+            NoiseField::builder()
+                .scale(11.0)
+                .octaves(5)
+                .seed(9)
+                .evolution(2.7)
+                .kind(kind)
+                .high(translucent)
+                .build()
+        };
+        let perlin = noise(NoiseKind::Perlin);
+        let simplex = noise(NoiseKind::Simplex);
+        let checker = Checker::builder()
+            .scale(5.5)
+            .evolution(0.3)
+            .second(translucent)
+            .build();
+        let stripes = Stripes::builder()
+            .scale(7.0)
+            .angle(0.6)
+            .evolution(-0.4)
+            .first(translucent)
+            .build();
+        let grid = DotGrid::builder()
+            .scale(9.0)
+            .radius(3.2)
+            .evolution(1.3)
+            .build();
+        let patterns = [
+            Pattern::Noise(&perlin),
+            Pattern::Noise(&simplex),
+            Pattern::Checker(&checker),
+            Pattern::Stripes(&stripes),
+            Pattern::DotGrid(&grid),
+        ];
+        // Non-square pixels, so each axis's step is exercised separately.
+        let size = Vec2(48.0, 30.0);
+        let target = Resolution::new(64, 48);
+        for (i, pattern) in patterns.into_iter().enumerate() {
+            let expected = pattern_pixels(pattern, size, target);
+            let input = PatternInput {
+                target,
+                size,
+                pattern,
+            };
+            let rendered = GpuRasterBackend::pattern(&mut gpu, input).unwrap();
+            let rendered = readback(&mut gpu, rendered);
+            assert_premultiplied_within_one(&rendered, &expected, &format!("pattern {i}"));
+        }
+        assert_eq!(gpu.stats.patterns, 5);
+
+        // A degenerate scale is left to the CPU path.
+        let flat = Checker::builder().scale(0.0).build();
+        let input = PatternInput {
+            target,
+            size,
+            pattern: Pattern::Checker(&flat),
+        };
+        assert!(GpuRasterBackend::pattern(&mut gpu, input).is_none());
+    }
+
     #[test]
     #[ignore = "requires a GPU adapter"]
     fn outline_dilates_child_alpha() {
@@ -2198,6 +5917,7 @@ mod tests {
                 fill: Some(Fill {
                     paint: Paint::Solid(Color::rgba_u8(8, 9, 10, 255)),
                 }),
+                fill_rule: FillRule::NonZero,
                 stroke: None,
                 transform: Transform::IDENTITY,
             }),
@@ -2239,6 +5959,7 @@ mod tests {
                 fill: Some(Fill {
                     paint: Paint::Solid(Color::rgba_u8(80, 40, 20, 128)),
                 }),
+                fill_rule: FillRule::NonZero,
                 stroke: None,
                 transform: Transform::IDENTITY,
             }),
@@ -2272,6 +5993,7 @@ mod tests {
                 fill: Some(Fill {
                     paint: Paint::Solid(color),
                 }),
+                fill_rule: FillRule::NonZero,
                 stroke: None,
                 transform: Transform::IDENTITY,
             })
@@ -2331,6 +6053,7 @@ mod tests {
                     fill: Some(Fill {
                         paint: Paint::Solid(Color::rgba_u8(0, 0, 0, 255)),
                     }),
+                    fill_rule: FillRule::NonZero,
                     stroke: None,
                     transform: Transform::IDENTITY,
                 }),
@@ -2379,6 +6102,7 @@ mod tests {
                         fill: Some(Fill {
                             paint: Paint::Solid(Color::rgba_u8(255, 255, 255, 255)),
                         }),
+                        fill_rule: FillRule::NonZero,
                         stroke: None,
                         transform: Transform::IDENTITY,
                     }),
@@ -2390,6 +6114,7 @@ mod tests {
                             fill: Some(Fill {
                                 paint: Paint::Solid(Color::rgba_u8(0, 0, 0, 255)),
                             }),
+                            fill_rule: FillRule::NonZero,
                             stroke: None,
                             transform: Transform::IDENTITY,
                         }),
@@ -2435,6 +6160,7 @@ mod tests {
                         fill: Some(Fill {
                             paint: Paint::Solid(Color::rgba_u8(255, 255, 255, 255)),
                         }),
+                        fill_rule: FillRule::NonZero,
                         stroke: None,
                         transform: Transform::IDENTITY,
                     }),
@@ -2443,6 +6169,7 @@ mod tests {
                         0.25,
                         Node::ClipGroup(ClipGroup {
                             commands: rect.clone(),
+                            fill_rule: FillRule::NonZero,
                             transform: Transform::IDENTITY,
                             child: Box::new(Node::Path(Path {
                                 commands: vec![
@@ -2450,6 +6177,7 @@ mod tests {
                                     PathCommand::LineTo(Vec2(4.0, 2.0)),
                                 ],
                                 fill: None,
+                                fill_rule: FillRule::NonZero,
                                 stroke: Some(Stroke::new(
                                     Paint::Solid(Color::rgba_u8(0, 0, 0, 255)),
                                     4.0,
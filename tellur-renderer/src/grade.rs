@@ -0,0 +1,255 @@
+//! Color-grading effects for raster components.
+//!
+//! [`ColorAdjust`] runs the [`ColorAdjustment`] chain (exposure, brightness,
+//! contrast, saturation, hue, tint, gamma) over its child and [`Lut3d`] looks
+//! the child up in a `.cube` [`CubeLut`]. Both are per-pixel: `layout` and
+//! `paint_bounds` forward to the child, and alpha passes through unchanged.
+//! The grading itself is [`Grade::apply`], shared with the timeline's
+//! [`Graded`](tellur_core::timeline_component::Graded) wrapper for video.
+
+use tellur_core::color::Color;
+use tellur_core::geometry::{Constraints, Rect, Vec2};
+use tellur_core::grade::{ColorAdjustment, CubeLut, Grade};
+use tellur_core::raster::{RasterComponent, RasterImage, RasterResidency, Resolution};
+use tellur_core::render_context::{CachePolicy, RenderContext};
+use tellur_core::Keyable;
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct ColorAdjust {
+    /// Exposure in stops; each stop doubles the channel values.
+    #[builder(default = 0.0)]
+    pub exposure: f32,
+    /// Offset added to every channel.
+    #[builder(default = 0.0)]
+    pub brightness: f32,
+    /// Contrast multiplier around mid-gray.
+    #[builder(default = 1.0)]
+    pub contrast: f32,
+    /// Saturation multiplier; `0` is grayscale.
+    #[builder(default = 1.0)]
+    pub saturation: f32,
+    /// Hue rotation in degrees.
+    #[builder(default = 0.0)]
+    pub hue: f32,
+    /// Tint color, mixed in by its alpha.
+    #[builder(default = Color::rgba_u8(0, 0, 0, 0))]
+    pub tint: Color,
+    /// Gamma; values above `1` lift the midtones.
+    #[builder(default = 1.0)]
+    pub gamma: f32,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl ColorAdjust {
+    /// The fields as one [`ColorAdjustment`].
+    pub fn adjustment(&self) -> ColorAdjustment {
+        ColorAdjustment {
+            exposure: self.exposure,
+            brightness: self.brightness,
+            contrast: self.contrast,
+            saturation: self.saturation,
+            hue: self.hue,
+            tint: self.tint,
+            gamma: self.gamma,
+        }
+    }
+}
+
+impl RasterComponent for ColorAdjust {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.child.paint_bounds(size)
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        grade_cache_policy(&Grade::Adjust(self.adjustment()))
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        render_graded(
+            self.child.as_ref(),
+            &Grade::Adjust(self.adjustment()),
+            size,
+            target,
+            residency,
+            ctx,
+        )
+    }
+}
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Lut3d {
+    /// The lookup table, usually from [`CubeLut::load`].
+    pub lut: CubeLut,
+    /// Blend from the child's colors (`0`) to the looked-up colors (`1`).
+    #[builder(default = 1.0)]
+    pub mix: f32,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl Lut3d {
+    fn grade(&self) -> Grade {
+        Grade::Lut {
+            lut: self.lut.clone(),
+            mix: self.mix,
+        }
+    }
+}
+
+impl RasterComponent for Lut3d {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.child.paint_bounds(size)
+    }
+
+    fn cache_policy(&self) -> CachePolicy {
+        grade_cache_policy(&self.grade())
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        render_graded(
+            self.child.as_ref(),
+            &self.grade(),
+            size,
+            target,
+            residency,
+            ctx,
+        )
+    }
+}
+
+/// An identity grade returns the child's image unchanged, so it lets the
+/// child own the cache entry, like `Opacity` at full opacity.
+fn grade_cache_policy(grade: &Grade) -> CachePolicy {
+    if grade.is_identity() {
+        CachePolicy::Transparent
+    } else {
+        CachePolicy::Memoize
+    }
+}
+
+fn render_graded(
+    child: &dyn RasterComponent,
+    grade: &Grade,
+    size: Vec2,
+    target: Resolution,
+    residency: RasterResidency,
+    ctx: &mut dyn RenderContext,
+) -> RasterImage {
+    if grade.is_identity() {
+        return ctx.render(child, size, target, residency);
+    }
+    let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+    let image = ctx.render(
+        child,
+        size,
+        target,
+        if gpu_available {
+            RasterResidency::Gpu
+        } else {
+            RasterResidency::Cpu
+        },
+    );
+    grade.apply(image, residency, ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Square;
+    use tellur_core::raster::CpuRasterImage;
+    use tellur_core::render_context::PassThrough;
+
+    /// Two pixels with the given straight RGBA bytes.
+    fn pixels(rgba: &[u8]) -> Box<dyn RasterComponent> {
+        Box::new(Square::from_pixels(2, 1, rgba.to_vec()))
+    }
+
+    fn render(component: &dyn RasterComponent) -> CpuRasterImage {
+        let mut ctx = PassThrough;
+        match component.render(
+            Vec2(2.0, 1.0),
+            Resolution::new(2, 1),
+            RasterResidency::Cpu,
+            &mut ctx,
+        ) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        }
+    }
+
+    #[test]
+    fn color_adjust_grades_rgb_and_keeps_alpha() {
+        let adjust = ColorAdjust::builder()
+            .saturation(0.0)
+            .child(pixels(&[255, 0, 0, 200, 10, 20, 30, 0]))
+            .build();
+        let image = render(&adjust);
+        // Rec. 709 luma of pure red is 0.2126 → 54.
+        assert_eq!(&image.pixels[..], &[54, 54, 54, 200, 19, 19, 19, 0]);
+    }
+
+    #[test]
+    fn identity_adjustment_is_cache_transparent() {
+        let child = pixels(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        let identity = ColorAdjust::builder().child(child.clone()).build();
+        assert_eq!(identity.cache_policy(), CachePolicy::Transparent);
+        assert_eq!(&render(&identity).pixels[..], &[1, 2, 3, 4, 5, 6, 7, 8]);
+
+        let brighter = ColorAdjust::builder().exposure(1.0).child(child).build();
+        assert_eq!(brighter.cache_policy(), CachePolicy::Memoize);
+        assert_eq!(&render(&brighter).pixels[..], &[2, 4, 6, 4, 10, 12, 14, 8]);
+    }
+
+    #[test]
+    fn lut3d_looks_up_and_mixes() {
+        let inverted = CubeLut::parse(
+            "LUT_3D_SIZE 2\n\
+             1 1 1\n0 1 1\n1 0 1\n0 0 1\n\
+             1 1 0\n0 1 0\n1 0 0\n0 0 0\n",
+        )
+        .unwrap();
+        let full = Lut3d::builder()
+            .lut(inverted.clone())
+            .child(pixels(&[255, 0, 51, 255, 0, 0, 0, 9]))
+            .build();
+        assert_eq!(
+            &render(&full).pixels[..],
+            &[0, 255, 204, 255, 255, 255, 255, 9]
+        );
+
+        let half = Lut3d::builder()
+            .lut(inverted)
+            .mix(0.5)
+            .child(pixels(&[255, 0, 51, 255, 0, 0, 0, 9]))
+            .build();
+        assert_eq!(
+            &render(&half).pixels[..],
+            &[128, 128, 128, 255, 128, 128, 128, 9]
+        );
+    }
+}
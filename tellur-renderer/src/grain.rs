@@ -0,0 +1,170 @@
+//! Film grain for raster components.
+//!
+//! [`Grain`] adds seeded, animated noise to its child, for "cinematic"
+//! finishing and to break up gradient banding before encoding. The pattern
+//! is a pure function of `seed`, the noise `frame` and the pixel, so it lives
+//! in the component's cache key. The frame is quantized from the clock up
+//! front with [`GrainNoise::frame_at`], so every render within one noise
+//! frame reuses one entry and only a new noise frame is a new entry. The
+//! child is rendered once and memoizes on its own. It is per-pixel, so
+//! `layout` and `paint_bounds` forward to the child; the noise itself is
+//! [`grain_pixels`], or the GPU pass kept in lockstep with it.
+
+use tellur_core::geometry::{Constraints, Rect, Vec2};
+use tellur_core::grain::{grain_pixels, GrainNoise};
+use tellur_core::raster::{RasterComponent, RasterImage, RasterResidency, Resolution};
+use tellur_core::render_context::{GrainInput, RenderContext};
+use tellur_core::Keyable;
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Grain {
+    /// Peak deviation, as a fraction of full scale.
+    #[builder(default = 0.05)]
+    pub amount: f32,
+    /// Size of one grain (logical units).
+    #[builder(default = 1.0)]
+    pub size: f32,
+    /// Whether the channels move together, as luminance grain, rather than
+    /// each getting its own noise.
+    #[builder(default = true)]
+    pub monochrome: bool,
+    /// Picks one of many unrelated patterns.
+    #[builder(default)]
+    pub seed: u32,
+    /// The noise frame; each one is an independent pattern. Derive it from
+    /// the clock with [`GrainNoise::frame_at`], e.g.
+    /// `GrainNoise::frame_at(clock.local().seconds(), 24.0)` for grain that
+    /// changes 24 times a second; a constant frame freezes it.
+    #[builder(default)]
+    pub frame: u32,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl Grain {
+    /// The noise for a `target`-sized render of a child whose paint box is
+    /// `paint`.
+    fn noise(&self, paint: Rect, target: Resolution) -> GrainNoise {
+        let px_per_unit = if paint.size.0 > 0.0 {
+            target.width as f32 / paint.size.0
+        } else {
+            1.0
+        };
+        let grain_px = self.size * px_per_unit;
+        GrainNoise {
+            amount: self.amount,
+            cells_per_pixel: if grain_px > 0.0 { 1.0 / grain_px } else { 1.0 },
+            seed: self.seed,
+            frame: self.frame,
+            monochrome: self.monochrome,
+        }
+    }
+}
+
+impl RasterComponent for Grain {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.child.paint_bounds(size)
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+        let child_residency = if gpu_available {
+            RasterResidency::Gpu
+        } else {
+            RasterResidency::Cpu
+        };
+        let image = ctx.render(self.child.as_ref(), size, target, child_residency);
+        let noise = self.noise(self.paint_bounds(size), target);
+        if gpu_available {
+            let input = GrainInput {
+                image: &image,
+                noise,
+            };
+            if let Some(gpu) = ctx.gpu_backend() {
+                if let Some(grained) = gpu.grain(input) {
+                    return ctx.ensure_residency(grained, residency);
+                }
+            }
+        }
+
+        let mut image = ctx.readback(image);
+        image.pixels = grain_pixels(&image, noise).into();
+        ctx.ensure_residency(RasterImage::Cpu(image), residency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::square;
+    use tellur_core::raster::CpuRasterImage;
+    use tellur_core::render_context::PassThrough;
+
+    fn render(grain: &Grain) -> CpuRasterImage {
+        let mut ctx = PassThrough;
+        match grain.render(
+            Vec2(8.0, 8.0),
+            Resolution::new(8, 8),
+            RasterResidency::Cpu,
+            &mut ctx,
+        ) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        }
+    }
+
+    fn grain_at(time: f64) -> Grain {
+        Grain::builder()
+            .amount(0.2)
+            .seed(3)
+            .frame(GrainNoise::frame_at(time, 24.0))
+            .child(square([128, 128, 128, 255]))
+            .build()
+    }
+
+    #[test]
+    fn noise_changes_only_when_the_rate_ticks() {
+        let first = render(&grain_at(0.0));
+        assert_ne!(first.pixels.to_vec(), [128, 128, 128, 255].repeat(64));
+        // 24 noise frames a second: 10 ms later is the same frame, 50 ms
+        // later the next one.
+        assert_eq!(render(&grain_at(0.01)).pixels, first.pixels);
+        assert_ne!(render(&grain_at(0.05)).pixels, first.pixels);
+        // The key follows the noise frame rather than the time, so renders
+        // within one frame share a cache entry and a new frame misses it.
+        assert!(grain_at(0.0) == grain_at(0.01));
+        assert!(grain_at(0.0) != grain_at(0.05));
+    }
+
+    #[test]
+    fn grain_size_is_in_logical_units() {
+        let coarse = Grain::builder()
+            .amount(0.2)
+            .size(4.0)
+            .child(square([128, 128, 128, 255]))
+            .build();
+        let image = render(&coarse);
+        let first_row: Vec<u8> = image
+            .pixels
+            .chunks_exact(4)
+            .take(4)
+            .map(|px| px[0])
+            .collect();
+        assert!(
+            first_row.iter().all(|&v| v == first_row[0]),
+            "{first_row:?}"
+        );
+    }
+}
@@ -1,7 +1,17 @@
 mod cache;
+#[cfg(test)]
+mod test_support;
 
+pub mod blur;
+pub mod chroma_key;
+pub mod distort;
+pub mod glow;
 pub mod gpu;
+pub mod grade;
+pub mod grain;
 pub mod host_info;
+pub mod mask;
+pub mod matte;
 pub mod motion_blur;
 pub mod outline;
 pub mod rasterize;
@@ -10,8 +20,16 @@ pub mod shadow;
 pub mod subtitle;
 pub mod video;
 
+pub use blur::Blur;
+pub use chroma_key::ChromaKey;
+pub use distort::{Displace, Distort};
+pub use glow::{Bloom, OuterGlow};
 pub use gpu::{probe_adapter_info, GpuAdapterInfo};
+pub use grade::{ColorAdjust, Lut3d};
+pub use grain::Grain;
 pub use host_info::{host_cpu_summary, host_memory_total_bytes};
+pub use mask::Mask;
+pub use matte::Matte;
 pub use motion_blur::MotionBlur;
 pub use outline::Outline;
 pub use rasterize::{Rasterizable, RasterizableBuilder, Rasterize};
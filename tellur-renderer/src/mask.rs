@@ -0,0 +1,239 @@
+//! Soft-edged mask effect for raster components.
+//!
+//! [`Mask`] is the raster-side counterpart of the vector `Clip`: it keeps the
+//! part of its child inside a [`ClipRegion`], but the region's edge can be
+//! moved outward or inward by `expansion` and softened by `feather`. The
+//! region is rasterized into a coverage mask, grown or shrunk with a stroke
+//! of the outline, blurred, and applied as an alpha [`Matte`](crate::Matte).
+//! Every stage is an ordinary component rendered through the context, so an
+//! unchanged mask is memoized while the child underneath animates, and
+//! `ClipRegion` interpolation gives wipes whose shape morphs over a `Phase`.
+
+use tellur_core::clip::ClipRegion;
+use tellur_core::color::Color;
+use tellur_core::geometry::{Constraints, Rect, Transform, Vec2};
+use tellur_core::matte::MatteMode;
+use tellur_core::raster::{RasterComponent, RasterImage, RasterResidency, Resolution};
+use tellur_core::render_context::RenderContext;
+use tellur_core::vector::{
+    Fill, Node, Paint, Path, Stroke, StrokeCap, StrokeJoin, VectorComponent, VectorGraphic,
+};
+use tellur_core::Keyable;
+
+use crate::blur::Blur;
+use crate::matte::{render_matted, Matte};
+use crate::rasterize::Rasterizable;
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Mask {
+    /// The region of the child to keep, in the child's local coordinates.
+    #[builder(into)]
+    pub region: ClipRegion,
+    /// Gaussian-equivalent blur radius of the mask edge (logical units).
+    #[builder(default = 0.0)]
+    pub feather: f32,
+    /// Moves the region's edge outward (positive) or inward (negative), in
+    /// logical units, before feathering.
+    #[builder(default = 0.0)]
+    pub expansion: f32,
+    #[effect]
+    #[builder(into)]
+    pub child: Box<dyn RasterComponent>,
+}
+
+impl Mask {
+    /// The alpha matte the child is masked by.
+    fn coverage(&self) -> Box<dyn RasterComponent> {
+        let expansion = finite_or_zero(self.expansion);
+        let ink = |fill: bool, band: f32| RegionInk {
+            region: self.region.clone(),
+            fill,
+            band,
+        };
+        let shape: Box<dyn RasterComponent> = if expansion > 0.0 {
+            // A round-joined stroke centered on the outline reaches exactly
+            // `expansion` past it on every side.
+            ink(true, 2.0 * expansion).rasterize().into()
+        } else if expansion < 0.0 {
+            Matte::builder()
+                .mode(MatteMode::AlphaInverted)
+                .matte(ink(false, -2.0 * expansion).rasterize())
+                .content(ink(true, 0.0).rasterize())
+                .build()
+                .into()
+        } else {
+            ink(true, 0.0).rasterize().into()
+        };
+        let feather = finite_or_zero(self.feather);
+        if feather > 0.0 {
+            Blur::builder().radius(feather).child(shape).build().into()
+        } else {
+            shape
+        }
+    }
+}
+
+impl RasterComponent for Mask {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.child.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.child.paint_bounds(size)
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        render_matted(
+            self.child.as_ref(),
+            self.coverage().as_ref(),
+            MatteMode::Alpha,
+            size,
+            target,
+            residency,
+            ctx,
+        )
+    }
+}
+
+fn finite_or_zero(value: f32) -> f32 {
+    if value.is_finite() {
+        value
+    } else {
+        0.0
+    }
+}
+
+/// A mask region drawn as opaque ink: its fill, a round-joined stroke
+/// `band` wide centered on its outline, or both. It paints in the mask's
+/// coordinate space and takes no layout space of its own.
+#[derive(Clone, Keyable)]
+struct RegionInk {
+    region: ClipRegion,
+    fill: bool,
+    band: f32,
+}
+
+impl RegionInk {
+    fn stroke(&self) -> Option<Stroke> {
+        (self.band > 0.0).then(|| {
+            Stroke::new(Color::rgb_u8(255, 255, 255), self.band)
+                .with_join(StrokeJoin::Round)
+                .with_cap(StrokeCap::Round)
+        })
+    }
+}
+
+impl VectorComponent for RegionInk {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        constraints.constrain(Vec2::ZERO)
+    }
+
+    fn paint_bounds(&self, _size: Vec2) -> Rect {
+        let bounds = self.region.bounds();
+        // Round joins and caps never reach past half the stroke width.
+        let outset = self.band.max(0.0) / 2.0;
+        Rect {
+            origin: Vec2(bounds.origin.0 - outset, bounds.origin.1 - outset),
+            size: Vec2(bounds.size.0 + 2.0 * outset, bounds.size.1 + 2.0 * outset),
+        }
+    }
+
+    fn render(&self, size: Vec2) -> VectorGraphic {
+        VectorGraphic {
+            view_box: self.paint_bounds(size),
+            root: Node::Path(Path {
+                commands: self.region.to_commands(),
+                fill: self.fill.then(|| Fill {
+                    paint: Paint::Solid(Color::rgb_u8(255, 255, 255)),
+                }),
+                fill_rule: self.region.fill_rule(),
+                stroke: self.stroke(),
+                transform: Transform::IDENTITY,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::Square;
+    use tellur_core::raster::CpuRasterImage;
+    use tellur_core::render_context::PassThrough;
+
+    /// An opaque 8×1 strip.
+    fn strip() -> Box<dyn RasterComponent> {
+        Box::new(Square::solid([40, 80, 120, 255], 8, 1))
+    }
+
+    /// A tall region spanning `x0..x1`, so only its vertical edges cross the
+    /// strip.
+    fn columns(x0: f32, x1: f32) -> Rect {
+        Rect {
+            origin: Vec2(x0, -4.0),
+            size: Vec2(x1 - x0, 9.0),
+        }
+    }
+
+    fn alphas(mask: &Mask) -> Vec<u8> {
+        let mut ctx = PassThrough;
+        let image = match mask.render(
+            Vec2(8.0, 1.0),
+            Resolution::new(8, 1),
+            RasterResidency::Cpu,
+            &mut ctx,
+        ) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        };
+        let CpuRasterImage { pixels, .. } = image;
+        pixels.chunks_exact(4).map(|px| px[3]).collect()
+    }
+
+    #[test]
+    fn hard_mask_keeps_only_the_region() {
+        let mask = Mask::builder()
+            .region(columns(2.0, 6.0))
+            .child(strip())
+            .build();
+        assert_eq!(mask.layout(Constraints::UNBOUNDED), Vec2(8.0, 1.0));
+        assert_eq!(alphas(&mask), vec![0, 0, 255, 255, 255, 255, 0, 0]);
+    }
+
+    #[test]
+    fn expansion_grows_and_contraction_shrinks_the_region() {
+        let grown = Mask::builder()
+            .region(columns(2.0, 6.0))
+            .expansion(1.0)
+            .child(strip())
+            .build();
+        assert_eq!(alphas(&grown), vec![0, 255, 255, 255, 255, 255, 255, 0]);
+
+        let shrunk = Mask::builder()
+            .region(columns(2.0, 6.0))
+            .expansion(-1.0)
+            .child(strip())
+            .build();
+        assert_eq!(alphas(&shrunk), vec![0, 0, 0, 255, 255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn feather_ramps_the_edge() {
+        let mask = Mask::builder()
+            .region(columns(2.0, 6.0))
+            .feather(1.0)
+            .child(strip())
+            .build();
+        let alpha = alphas(&mask);
+        assert!(alpha[1] > 0 && alpha[1] < alpha[2], "{alpha:?}");
+        assert!(alpha[2] < alpha[3] && alpha[3] < 255, "{alpha:?}");
+        assert!(alpha[6] > 0 && alpha[6] < alpha[5], "{alpha:?}");
+    }
+}
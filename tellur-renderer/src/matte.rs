@@ -0,0 +1,252 @@
+//! Track-matte effect for raster components.
+//!
+//! [`Matte`] masks its `content` by a second component, the `matte`: text
+//! for a reveal wipe, a soft gradient for a vignette, or any animated layer.
+//! The matte is laid out at the content's size and rendered through the
+//! context, so it is memoized apart from the content and from the masked
+//! result. Like `Clip`, `Matte` is transparent to layout and only ever hides
+//! content, so `paint_bounds` is the content's own.
+
+use tellur_core::geometry::{Constraints, Rect, Vec2};
+use tellur_core::matte::{apply_matte_pixels, MatteMode};
+use tellur_core::raster::{PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution};
+use tellur_core::render_context::{MatteInput, RenderContext};
+use tellur_core::Keyable;
+
+#[tellur_core::component(raster)]
+#[derive(Clone, Keyable)]
+pub struct Matte {
+    /// Which channel of the matte masks the content.
+    #[builder(default)]
+    pub mode: MatteMode,
+    /// The component whose alpha or luma masks `content`.
+    #[builder(into)]
+    pub matte: Box<dyn RasterComponent>,
+    #[effect]
+    #[builder(into)]
+    pub content: Box<dyn RasterComponent>,
+}
+
+impl RasterComponent for Matte {
+    fn layout(&self, constraints: Constraints) -> Vec2 {
+        self.content.layout(constraints)
+    }
+
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        self.content.paint_bounds(size)
+    }
+
+    fn render(
+        &self,
+        size: Vec2,
+        target: Resolution,
+        residency: RasterResidency,
+        ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        render_matted(
+            self.content.as_ref(),
+            self.matte.as_ref(),
+            self.mode,
+            size,
+            target,
+            residency,
+            ctx,
+        )
+    }
+}
+
+/// Renders `content` masked by `matte`, both through `ctx`, into an image
+/// covering the content's paint bounds. Shared with [`Mask`](crate::Mask),
+/// whose feathered coverage is just another matte.
+pub(crate) fn render_matted(
+    content: &dyn RasterComponent,
+    matte: &dyn RasterComponent,
+    mode: MatteMode,
+    size: Vec2,
+    target: Resolution,
+    residency: RasterResidency,
+    ctx: &mut dyn RenderContext,
+) -> RasterImage {
+    let paint = content.paint_bounds(size);
+    if paint.size.0 <= 0.0 || paint.size.1 <= 0.0 {
+        let blank = RasterImage::cpu(
+            target.width,
+            target.height,
+            PixelFormat::Rgba8,
+            vec![0u8; (target.width as usize) * (target.height as usize) * 4],
+        );
+        return ctx.ensure_residency(blank, residency);
+    }
+    let sx = target.width as f32 / paint.size.0;
+    let sy = target.height as f32 / paint.size.1;
+    let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+    let child_residency = if gpu_available {
+        RasterResidency::Gpu
+    } else {
+        RasterResidency::Cpu
+    };
+
+    let content_image = ctx.render(content, size, target, child_residency);
+    // The matte shares the content's layout box but keeps its own paint
+    // bounds, rendered at the content's pixel density.
+    let matte_paint = matte.paint_bounds(size);
+    let matte_px_w = (matte_paint.size.0 * sx).round().max(1.0) as u32;
+    let matte_px_h = (matte_paint.size.1 * sy).round().max(1.0) as u32;
+    let matte_image = ctx.render(
+        matte,
+        size,
+        Resolution::new(matte_px_w, matte_px_h),
+        child_residency,
+    );
+    let matte_px_x = ((matte_paint.origin.0 - paint.origin.0) * sx).round() as i32;
+    let matte_px_y = ((matte_paint.origin.1 - paint.origin.1) * sy).round() as i32;
+
+    if gpu_available {
+        let input = MatteInput {
+            content: &content_image,
+            matte: &matte_image,
+            matte_offset_x: matte_px_x,
+            matte_offset_y: matte_px_y,
+            mode,
+        };
+        if let Some(gpu) = ctx.gpu_backend() {
+            if let Some(image) = gpu.matte(input) {
+                return ctx.ensure_residency(image, residency);
+            }
+        }
+    }
+
+    let mut content_image = ctx.readback(content_image);
+    let matte_image = ctx.readback(matte_image);
+    assert_eq!(
+        content_image.format,
+        PixelFormat::Rgba8,
+        "mattes only support straight-alpha Rgba8 images",
+    );
+    let mut pixels = content_image.pixels.to_vec();
+    apply_matte_pixels(
+        &mut pixels,
+        content_image.width,
+        &matte_image,
+        matte_px_x,
+        matte_px_y,
+        mode,
+    );
+    content_image.pixels = pixels.into();
+    ctx.ensure_residency(RasterImage::Cpu(content_image), residency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tellur_core::raster::CpuRasterImage;
+    use tellur_core::render_context::PassThrough;
+
+    /// A row of straight RGBA pixels in a 4×1 box, painting one logical unit
+    /// per pixel from `x`.
+    #[derive(Clone, PartialEq, Hash)]
+    struct Strip {
+        x: u32,
+        pixels: Vec<u8>,
+    }
+
+    impl RasterComponent for Strip {
+        fn layout(&self, _constraints: Constraints) -> Vec2 {
+            Vec2(4.0, 1.0)
+        }
+
+        fn paint_bounds(&self, _size: Vec2) -> Rect {
+            Rect {
+                origin: Vec2(self.x as f32, 0.0),
+                size: Vec2((self.pixels.len() / 4) as f32, 1.0),
+            }
+        }
+
+        fn render(
+            &self,
+            _size: Vec2,
+            target: Resolution,
+            _residency: RasterResidency,
+            _ctx: &mut dyn RenderContext,
+        ) -> RasterImage {
+            RasterImage::cpu(
+                target.width,
+                target.height,
+                PixelFormat::Rgba8,
+                self.pixels.clone(),
+            )
+        }
+    }
+
+    fn strip(x: u32, pixels: &[u8]) -> Box<dyn RasterComponent> {
+        Box::new(Strip {
+            x,
+            pixels: pixels.to_vec(),
+        })
+    }
+
+    fn render(component: &dyn RasterComponent) -> CpuRasterImage {
+        let mut ctx = PassThrough;
+        match component.render(
+            Vec2(4.0, 1.0),
+            Resolution::new(4, 1),
+            RasterResidency::Cpu,
+            &mut ctx,
+        ) {
+            RasterImage::Cpu(image) => image,
+            RasterImage::Gpu(_) => panic!("expected a CPU image"),
+        }
+    }
+
+    fn alphas(image: &CpuRasterImage) -> Vec<u8> {
+        image.pixels.chunks_exact(4).map(|px| px[3]).collect()
+    }
+
+    #[test]
+    fn alpha_matte_keeps_layout_and_masks_content() {
+        let content = strip(0, &[50, 60, 70, 255].repeat(4));
+        let matte = Matte::builder()
+            .matte(strip(
+                0,
+                &[0, 0, 0, 255, 0, 0, 0, 128, 9, 9, 9, 0, 0, 0, 0, 255],
+            ))
+            .content(content)
+            .build();
+        assert_eq!(matte.layout(Constraints::UNBOUNDED), Vec2(4.0, 1.0));
+        let image = render(&matte);
+        assert_eq!(alphas(&image), vec![255, 128, 0, 255]);
+        assert_eq!(&image.pixels[..4], &[50, 60, 70, 255]);
+    }
+
+    #[test]
+    fn every_mode_masks_by_its_channel() {
+        // Opaque white, opaque black, then transparent white.
+        let matte_pixels = [
+            255, 255, 255, 255, 0, 0, 0, 255, 255, 255, 255, 0, 0, 0, 0, 0,
+        ];
+        let expected = [
+            (MatteMode::Alpha, [255, 255, 0, 0]),
+            (MatteMode::AlphaInverted, [0, 0, 255, 255]),
+            (MatteMode::Luma, [255, 0, 0, 0]),
+            (MatteMode::LumaInverted, [0, 255, 255, 255]),
+        ];
+        for (mode, alpha) in expected {
+            let matte = Matte::builder()
+                .mode(mode)
+                .matte(strip(0, &matte_pixels))
+                .content(strip(0, &[1, 2, 3, 255].repeat(4)))
+                .build();
+            assert_eq!(alphas(&render(&matte)), alpha.to_vec(), "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn matte_is_placed_by_its_own_paint_bounds() {
+        // A two-pixel opaque matte painting at x = 1..3 of a four-pixel box.
+        let matte = Matte::builder()
+            .matte(strip(1, &[0, 0, 0, 255].repeat(2)))
+            .content(strip(0, &[1, 2, 3, 255].repeat(4)))
+            .build();
+        assert_eq!(alphas(&render(&matte)), vec![0, 255, 255, 0]);
+    }
+}
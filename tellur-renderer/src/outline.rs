@@ -18,6 +18,8 @@ use tellur_core::raster::{
 use tellur_core::render_context::{OutlineInput, RenderContext};
 use tellur_core::Keyable;
 
+use crate::shadow::blank_image;
+
 #[tellur_core::component(raster)]
 #[derive(Clone, Keyable)]
 pub struct Outline {
@@ -128,16 +130,6 @@ impl RasterComponent for Outline {
     }
 }
 
-fn blank_image(target: Resolution) -> RasterImage {
-    let bytes = (target.width as usize) * (target.height as usize) * 4;
-    RasterImage::cpu(
-        target.width,
-        target.height,
-        PixelFormat::Rgba8,
-        vec![0u8; bytes],
-    )
-}
-
 fn make_outline(
     image: &CpuRasterImage,
     target: Resolution,
@@ -220,7 +212,13 @@ fn make_outline(
 /// Not separable: the ellipse SE has to be applied as a 2-D
 /// neighborhood. Cost is O(W·H·|SE|) ≈ O(W·H·π·rx·ry), which is fine
 /// here because the outline is memoized on a per-subtree basis.
-fn dilate_ellipse_antialiased(src: &[u8], w: usize, h: usize, rx: usize, ry: usize) -> Vec<u8> {
+pub(crate) fn dilate_ellipse_antialiased(
+    src: &[u8],
+    w: usize,
+    h: usize,
+    rx: usize,
+    ry: usize,
+) -> Vec<u8> {
     let mut dst = vec![0u8; w * h];
     if w == 0 || h == 0 || (rx == 0 && ry == 0) {
         dst.copy_from_slice(src);
@@ -2,11 +2,13 @@ use std::hash::Hash;
 
 use tellur_core::color::Color;
 use tellur_core::geometry::{Constraints, Rect, Transform, Vec2};
-use tellur_core::raster::{PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution};
+use tellur_core::raster::{
+    CpuRasterImage, PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution,
+};
 use tellur_core::render_context::RenderContext;
 use tellur_core::vector::{
-    DashPattern, Node, Paint, Path, PathCommand, Stroke, StrokeCap, StrokeJoin, VectorComponent,
-    VectorGraphic,
+    DashPattern, FillRule, GradientStop, ImagePattern, ImageQuality, Node, Paint, Path,
+    PathCommand, SpreadMode, Stroke, StrokeCap, StrokeJoin, VectorComponent, VectorGraphic,
 };
 
 /// A `RasterComponent` that rasterizes a `VectorComponent`. The layout
@@ -208,7 +210,12 @@ fn render_clipped_node(
     let Some(mut mask) = tiny_skia::Mask::new(pixmap.width(), pixmap.height()) else {
         return;
     };
-    mask.fill_path(&clip_path, tiny_skia::FillRule::Winding, true, clip_xform);
+    mask.fill_path(
+        &clip_path,
+        to_skia_fill_rule(group.fill_rule),
+        true,
+        clip_xform,
+    );
 
     let mut layer = tiny_skia::Pixmap::new(pixmap.width(), pixmap.height())
         .expect("pixmap dimensions must be non-zero");
@@ -223,6 +230,13 @@ fn render_clipped_node(
     );
 }
 
+fn to_skia_fill_rule(rule: FillRule) -> tiny_skia::FillRule {
+    match rule {
+        FillRule::NonZero => tiny_skia::FillRule::Winding,
+        FillRule::EvenOdd => tiny_skia::FillRule::EvenOdd,
+    }
+}
+
 fn render_path(pixmap: &mut tiny_skia::Pixmap, path: &Path, xform: tiny_skia::Transform) {
     let Some(skia_path) = build_skia_path(&path.commands) else {
         return;
@@ -233,14 +247,16 @@ fn render_path(pixmap: &mut tiny_skia::Pixmap, path: &Path, xform: tiny_skia::Tr
             anti_alias: true,
             ..Default::default()
         };
-        apply_paint(&mut paint, &fill.paint);
-        pixmap.fill_path(
-            &skia_path,
-            &paint,
-            tiny_skia::FillRule::Winding,
-            xform,
-            None,
-        );
+        let mut pattern = None;
+        if apply_paint(&mut paint, &fill.paint, &mut pattern) {
+            pixmap.fill_path(
+                &skia_path,
+                &paint,
+                to_skia_fill_rule(path.fill_rule),
+                xform,
+                None,
+            );
+        }
     }
 
     if let Some(stroke) = &path.stroke {
@@ -248,9 +264,26 @@ fn render_path(pixmap: &mut tiny_skia::Pixmap, path: &Path, xform: tiny_skia::Tr
             anti_alias: true,
             ..Default::default()
         };
-        apply_paint(&mut paint, &stroke.paint);
-        let skia_stroke = to_skia_stroke(stroke);
-        pixmap.stroke_path(&skia_path, &paint, &skia_stroke, xform, None);
+        let mut pattern = None;
+        if apply_paint(&mut paint, &stroke.paint, &mut pattern) {
+            match path.width_profile_outline(from_skia_transform(xform)) {
+                Some(outline) => {
+                    if let Some(outline) = build_skia_path(&outline) {
+                        pixmap.fill_path(
+                            &outline,
+                            &paint,
+                            tiny_skia::FillRule::Winding,
+                            xform,
+                            None,
+                        );
+                    }
+                }
+                None => {
+                    let skia_stroke = to_skia_stroke(stroke);
+                    pixmap.stroke_path(&skia_path, &paint, &skia_stroke, xform, None);
+                }
+            }
+        }
     }
 }
 
@@ -303,11 +336,137 @@ fn build_skia_path(commands: &[PathCommand]) -> Option<tiny_skia::Path> {
     pb.finish()
 }
 
-fn apply_paint(paint: &mut tiny_skia::Paint, source: &Paint) {
-    match source {
+/// Installs `source` as the shader of `paint`. `false` when the paint cannot
+/// be expressed (e.g. a gradient without stops), in which case nothing should
+/// be drawn with it. An image pattern's pixels are converted into `pattern`,
+/// which the shader borrows.
+fn apply_paint<'a>(
+    paint: &mut tiny_skia::Paint<'a>,
+    source: &Paint,
+    pattern: &'a mut Option<tiny_skia::Pixmap>,
+) -> bool {
+    let shader = match source {
         Paint::Solid(color) => {
             paint.set_color(to_skia_color(color));
+            return true;
+        }
+        Paint::Image(image) => {
+            let Some(pixmap) = to_skia_pixmap(&image.image) else {
+                return false;
+            };
+            let pixmap = pattern.insert(pixmap);
+            Some(image_pattern_shader(
+                pixmap,
+                image,
+                to_skia_transform(&image.transform),
+                1.0,
+            ))
+        }
+        Paint::LinearGradient(gradient) => tiny_skia::LinearGradient::new(
+            to_skia_point(gradient.start),
+            to_skia_point(gradient.end),
+            to_skia_stops(&gradient.normalized_stops()),
+            to_skia_spread(gradient.spread),
+            to_skia_transform(&gradient.transform),
+        ),
+        Paint::RadialGradient(gradient) => tiny_skia::RadialGradient::new(
+            to_skia_point(gradient.focal),
+            0.0,
+            to_skia_point(gradient.center),
+            gradient.radius.max(0.0),
+            to_skia_stops(&gradient.normalized_stops()),
+            to_skia_spread(gradient.spread),
+            to_skia_transform(&gradient.transform),
+        ),
+        Paint::SweepGradient(gradient) => {
+            // tiny-skia takes degrees and insists on `start <= end`; a
+            // counter-clockwise sweep is the clockwise one with its stops
+            // mirrored.
+            let mut stops = gradient.normalized_stops();
+            let (mut start, mut end) = (gradient.start_angle, gradient.end_angle);
+            if start > end {
+                std::mem::swap(&mut start, &mut end);
+                stops = stops
+                    .into_iter()
+                    .rev()
+                    .map(|stop| GradientStop::new(1.0 - stop.offset, stop.color))
+                    .collect();
+            }
+            tiny_skia::SweepGradient::new(
+                to_skia_point(gradient.center),
+                start.to_degrees(),
+                end.to_degrees(),
+                to_skia_stops(&stops),
+                to_skia_spread(gradient.spread),
+                to_skia_transform(&gradient.transform),
+            )
+        }
+    };
+    match shader {
+        Some(shader) => {
+            paint.shader = shader;
+            true
         }
+        None => false,
+    }
+}
+
+/// The tiny-skia shader for an image pattern whose pattern space maps to
+/// device space through `transform`. Shared with the GPU backend, which
+/// bakes patterns through the same sampler (see `gpu.rs`).
+pub(crate) fn image_pattern_shader<'a>(
+    pixmap: &'a tiny_skia::Pixmap,
+    pattern: &ImagePattern,
+    transform: tiny_skia::Transform,
+    opacity: f32,
+) -> tiny_skia::Shader<'a> {
+    let quality = match pattern.quality {
+        ImageQuality::Nearest => tiny_skia::FilterQuality::Nearest,
+        ImageQuality::Bilinear => tiny_skia::FilterQuality::Bilinear,
+        ImageQuality::Bicubic => tiny_skia::FilterQuality::Bicubic,
+    };
+    tiny_skia::Pattern::new(
+        pixmap.as_ref(),
+        to_skia_spread(pattern.extend),
+        quality,
+        opacity,
+        transform,
+    )
+}
+
+/// Premultiplies a straight-alpha RGBA8 image into a tiny-skia pixmap.
+/// `None` for images [`ImagePattern::is_drawable`] rejects.
+pub(crate) fn to_skia_pixmap(image: &CpuRasterImage) -> Option<tiny_skia::Pixmap> {
+    if !ImagePattern::new(image.clone()).is_drawable() {
+        return None;
+    }
+    let mut pixmap = tiny_skia::Pixmap::new(image.width, image.height)?;
+    for (dst, src) in pixmap
+        .pixels_mut()
+        .iter_mut()
+        .zip(image.pixels.chunks_exact(4))
+    {
+        *dst = tiny_skia::ColorU8::from_rgba(src[0], src[1], src[2], src[3]).premultiply();
+    }
+    Some(pixmap)
+}
+
+fn to_skia_point(p: Vec2) -> tiny_skia::Point {
+    tiny_skia::Point::from_xy(p.0, p.1)
+}
+
+fn to_skia_stops(stops: &[GradientStop]) -> Vec<tiny_skia::GradientStop> {
+    stops
+        .iter()
+        .map(|stop| tiny_skia::GradientStop::new(stop.offset, to_skia_color(&stop.color)))
+        .collect()
+}
+
+fn to_skia_spread(spread: SpreadMode) -> tiny_skia::SpreadMode {
+    match spread {
+        SpreadMode::Pad => tiny_skia::SpreadMode::Pad,
+        SpreadMode::Repeat => tiny_skia::SpreadMode::Repeat,
+        SpreadMode::Reflect => tiny_skia::SpreadMode::Reflect,
     }
 }
 
@@ -328,6 +487,17 @@ fn to_skia_transform(t: &Transform) -> tiny_skia::Transform {
     tiny_skia::Transform::from_row(t.a, t.b, t.c, t.d, t.tx, t.ty)
 }
 
+fn from_skia_transform(t: tiny_skia::Transform) -> Transform {
+    Transform {
+        a: t.sx,
+        b: t.ky,
+        c: t.kx,
+        d: t.sy,
+        tx: t.tx,
+        ty: t.ty,
+    }
+}
+
 // The compile-time dyn-safety guarantee for `Rasterize` is covered by the
 // `const _: Option<&dyn RasterComponent> = None;` assertion in `RasterComponent`.
 
@@ -346,8 +516,10 @@ mod tests {
     use tellur_core::render_context::{
         CompositeInput, DropShadowInput, GpuPreference, GpuRasterBackend, OutlineInput, PassThrough,
     };
-    use tellur_core::shapes::Rectangle;
-    use tellur_core::vector::Stroke;
+    use tellur_core::shapes::{Corners, Rectangle};
+    use tellur_core::vector::{
+        LinearGradient, RadialGradient, Stroke, SweepGradient, WidthProfile,
+    };
 
     const TEST_GPU_BACKEND: &str = "tellur-rasterize-test";
 
@@ -558,6 +730,7 @@ mod tests {
                     PathCommand::Close,
                 ],
                 fill: None,
+                fill_rule: FillRule::NonZero,
                 stroke: Some(Stroke::new(Color::rgb_u8(255, 255, 255), 2.5)),
                 transform: Transform::IDENTITY,
             }),
@@ -626,6 +799,7 @@ mod tests {
                         PathCommand::LineTo(Vec2(104.0, 76.0)),
                     ],
                     fill: None,
+                    fill_rule: FillRule::NonZero,
                     stroke: Some(
                         Stroke::new(Color::rgb_u8(255, 255, 255), 14.0)
                             .with_cap(cap)
@@ -660,6 +834,74 @@ mod tests {
         }
     }
 
+    fn width_profile_graphic() -> VectorGraphic {
+        VectorGraphic {
+            view_box: Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(128.0, 32.0),
+            },
+            root: Node::Path(Path {
+                commands: vec![
+                    PathCommand::MoveTo(Vec2(8.0, 16.0)),
+                    PathCommand::LineTo(Vec2(120.0, 16.0)),
+                ],
+                fill: None,
+                fill_rule: FillRule::NonZero,
+                // Swells to 16 units at the midpoint and tapers to nothing
+                // at both ends.
+                stroke: Some(
+                    Stroke::new(Color::rgb_u8(255, 255, 255), 1.0)
+                        .with_width_profile(WidthProfile::tapered(16.0, 0.5)),
+                ),
+                transform: Transform::IDENTITY,
+            }),
+        }
+    }
+
+    #[test]
+    fn width_profile_stroke_follows_the_profile() {
+        let image = rasterize(&width_profile_graphic(), 128, 32);
+
+        assert_eq!(alpha_at(&image, 64, 10, 128), 255);
+        assert_eq!(alpha_at(&image, 64, 21, 128), 255);
+        assert_eq!(alpha_at(&image, 64, 26, 128), 0);
+        assert_eq!(alpha_at(&image, 12, 21, 128), 0);
+        assert_eq!(alpha_at(&image, 116, 11, 128), 0);
+        assert!(alpha_at(&image, 12, 16, 128) > 0);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn gpu_width_profile_stroke_matches_cpu_footprint() {
+        let Ok(mut renderer) = GpuRenderer::new() else {
+            eprintln!("skipping GPU width-profile test: no GPU adapter available");
+            return;
+        };
+        let graphic = width_profile_graphic();
+        let target = Resolution::new(128, 32);
+        let cpu = rasterize(&graphic, target.width, target.height);
+        let cpu = cpu.as_cpu().expect("CPU rasterization returns CPU pixels");
+        let gpu = GpuRasterBackend::rasterize(&mut renderer, &graphic, target)
+            .expect("GPU rasterization should succeed");
+        let gpu = GpuRasterBackend::readback(&mut renderer, gpu)
+            .expect("GPU rasterization should read back");
+
+        // Both renderers fill the same expanded outline, so only edge
+        // antialiasing may differ.
+        let unexpected = pixels_outside_alpha_footprint(&gpu, cpu, 1, 32);
+        assert!(
+            unexpected.is_empty(),
+            "GPU outline escaped the CPU footprint at {:?}",
+            &unexpected[..unexpected.len().min(8)]
+        );
+        let missing = pixels_outside_alpha_footprint(cpu, &gpu, 1, 32);
+        assert!(
+            missing.is_empty(),
+            "GPU outline missed the CPU footprint at {:?}",
+            &missing[..missing.len().min(8)]
+        );
+    }
+
     #[derive(Clone, PartialEq, Eq, Hash)]
     struct StaleViewBoxVector;
 
@@ -695,6 +937,7 @@ mod tests {
                         PathCommand::Close,
                     ],
                     fill: Some(Paint::Solid(Color::rgb_u8(0, 0, 0)).into()),
+                    fill_rule: FillRule::NonZero,
                     stroke: None,
                     transform: Transform::IDENTITY,
                 }),
@@ -740,6 +983,7 @@ mod tests {
         let component = Rasterize {
             vector: Rectangle {
                 size: Vec2(1.0, 1.0),
+                corners: Corners::SQUARE,
                 fill: None,
                 stroke: None,
             },
@@ -892,6 +1136,7 @@ mod tests {
                     PathCommand::LineTo(Vec2(20.0, 5.0)),
                 ],
                 fill: None,
+                fill_rule: FillRule::NonZero,
                 stroke: Some(stroke),
                 transform: Transform::IDENTITY,
             }),
@@ -927,6 +1172,7 @@ mod tests {
                     PathCommand::LineTo(Vec2(20.0, 5.0)),
                 ],
                 fill: None,
+                fill_rule: FillRule::NonZero,
                 stroke: Some(stroke),
                 transform: Transform::IDENTITY,
             }),
@@ -935,4 +1181,533 @@ mod tests {
         let image = rasterize(&graphic, 20, 10);
         assert!(alpha_at(&image, 6, 5, 20) > 0, "a solid stroke has no gaps");
     }
+
+    fn rgba_at(image: &RasterImage, x: u32, y: u32) -> [u8; 4] {
+        let cpu = image
+            .as_cpu()
+            .expect("rasterize() always returns a CPU image");
+        let i = ((y * cpu.width + x) * 4) as usize;
+        cpu.pixels[i..i + 4].try_into().unwrap()
+    }
+
+    /// A `width`x`height` rectangle filled with `paint`, 1 unit per pixel.
+    fn painted_rect(paint: impl Into<Paint>, width: f32, height: f32) -> VectorGraphic {
+        VectorGraphic {
+            view_box: Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(width, height),
+            },
+            root: Node::Path(Path {
+                commands: vec![
+                    PathCommand::MoveTo(Vec2::ZERO),
+                    PathCommand::LineTo(Vec2(width, 0.0)),
+                    PathCommand::LineTo(Vec2(width, height)),
+                    PathCommand::LineTo(Vec2(0.0, height)),
+                    PathCommand::Close,
+                ],
+                fill: Some(paint.into().into()),
+                fill_rule: FillRule::NonZero,
+                stroke: None,
+                transform: Transform::IDENTITY,
+            }),
+        }
+    }
+
+    const RED: Color = Color::rgb_u8(255, 0, 0);
+    const BLUE: Color = Color::rgb_u8(0, 0, 255);
+
+    #[test]
+    fn linear_gradient_runs_from_start_to_end() {
+        let gradient = LinearGradient::evenly(Vec2(0.0, 0.0), Vec2(100.0, 0.0), [RED, BLUE]);
+        let image = rasterize(&painted_rect(gradient, 100.0, 4.0), 100, 4);
+
+        let left = rgba_at(&image, 0, 2);
+        let middle = rgba_at(&image, 50, 2);
+        let right = rgba_at(&image, 99, 2);
+        assert!(left[0] > 250 && left[2] < 5, "left is red: {left:?}");
+        assert!(right[2] > 250 && right[0] < 5, "right is blue: {right:?}");
+        assert!(
+            middle[0].abs_diff(middle[2]) < 8,
+            "the middle is an even mix: {middle:?}"
+        );
+    }
+
+    #[test]
+    fn gradient_spread_modes_differ_past_the_last_stop() {
+        // The ramp spans x in 0..10; pixel 12 samples a quarter into the next period.
+        let ramp = LinearGradient::evenly(Vec2(0.0, 0.0), Vec2(10.0, 0.0), [RED, BLUE]);
+        let sample = |spread: SpreadMode| {
+            let graphic = painted_rect(ramp.clone().with_spread(spread), 20.0, 2.0);
+            rgba_at(&rasterize(&graphic, 20, 2), 12, 1)
+        };
+
+        let pad = sample(SpreadMode::Pad);
+        let repeat = sample(SpreadMode::Repeat);
+        let reflect = sample(SpreadMode::Reflect);
+        assert!(pad[2] > 250, "pad holds the last stop: {pad:?}");
+        assert!(
+            repeat[0] > repeat[2],
+            "repeat restarts from red: {repeat:?}"
+        );
+        assert!(
+            reflect[2] > reflect[0],
+            "reflect runs back from blue: {reflect:?}"
+        );
+    }
+
+    #[test]
+    fn gradient_transform_maps_gradient_space_into_path_space() {
+        // A horizontal ramp rotated a quarter turn runs top to bottom.
+        let gradient = LinearGradient::evenly(Vec2(0.0, 0.0), Vec2(40.0, 0.0), [RED, BLUE])
+            .with_transform(Transform::rotate(std::f32::consts::FRAC_PI_2));
+        let image = rasterize(&painted_rect(gradient, 40.0, 40.0), 40, 40);
+
+        let top = rgba_at(&image, 30, 1);
+        let bottom = rgba_at(&image, 30, 38);
+        assert!(top[0] > 240, "top is red: {top:?}");
+        assert!(bottom[2] > 240, "bottom is blue: {bottom:?}");
+        assert_eq!(rgba_at(&image, 2, 20), rgba_at(&image, 37, 20));
+    }
+
+    #[test]
+    fn radial_gradient_runs_from_center_to_radius() {
+        let gradient = RadialGradient::evenly(Vec2(20.0, 20.0), 20.0, [RED, BLUE]);
+        let image = rasterize(&painted_rect(gradient, 40.0, 40.0), 40, 40);
+
+        let center = rgba_at(&image, 20, 20);
+        let corner = rgba_at(&image, 0, 0);
+        assert!(center[0] > 240, "center is red: {center:?}");
+        assert!(corner[2] > 250, "past the radius pads to blue: {corner:?}");
+    }
+
+    #[test]
+    fn sweep_gradient_turns_clockwise_from_positive_x() {
+        let gradient = SweepGradient::evenly(Vec2(20.0, 20.0), [RED, BLUE]);
+        let image = rasterize(&painted_rect(gradient, 40.0, 40.0), 40, 40);
+
+        // Just below the +x axis is the start of the turn; a quarter turn
+        // clockwise (straight down in y-down space) is a quarter of the way.
+        let start = rgba_at(&image, 38, 21);
+        let quarter = rgba_at(&image, 20, 38);
+        let three_quarters = rgba_at(&image, 20, 1);
+        assert!(start[0] > 240, "the sweep starts red: {start:?}");
+        assert!(
+            quarter[0] > quarter[2] && three_quarters[2] > three_quarters[0],
+            "red fades to blue clockwise: {quarter:?} then {three_quarters:?}"
+        );
+    }
+
+    #[test]
+    fn counter_clockwise_sweep_mirrors_the_clockwise_one() {
+        let turn = std::f32::consts::TAU;
+        let clockwise = SweepGradient::evenly(Vec2(20.0, 20.0), [RED, BLUE]);
+        let counter = SweepGradient::evenly(Vec2(20.0, 20.0), [BLUE, RED]).with_angles(turn, 0.0);
+        let a = rasterize(&painted_rect(clockwise, 40.0, 40.0), 40, 40);
+        let b = rasterize(&painted_rect(counter, 40.0, 40.0), 40, 40);
+
+        for (x, y) in [(20, 38), (1, 20), (20, 1)] {
+            let (a, b) = (rgba_at(&a, x, y), rgba_at(&b, x, y));
+            assert!(
+                a.iter().zip(b).all(|(a, b)| a.abs_diff(b) <= 2),
+                "({x}, {y}): {a:?} vs {b:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn gradient_without_stops_paints_nothing() {
+        let gradient = LinearGradient::new(Vec2(0.0, 0.0), Vec2(10.0, 0.0), vec![]);
+        let image = rasterize(&painted_rect(gradient, 10.0, 10.0), 10, 10);
+        assert_eq!(alpha_at(&image, 5, 5, 10), 0);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn gpu_gradients_match_cpu_colors() {
+        let Ok(mut renderer) = GpuRenderer::new() else {
+            eprintln!("skipping GPU gradient test: no GPU adapter available");
+            return;
+        };
+        let center = Vec2(32.0, 32.0);
+        let cases: [(&str, Paint); 5] = [
+            (
+                "linear-reflect",
+                LinearGradient::evenly(Vec2(8.0, 0.0), Vec2(40.0, 16.0), [RED, BLUE])
+                    .with_spread(SpreadMode::Reflect)
+                    .into(),
+            ),
+            (
+                "linear-transformed",
+                LinearGradient::evenly(Vec2(0.0, 0.0), Vec2(64.0, 0.0), [RED, BLUE])
+                    .with_transform(Transform::around_point(center, Transform::rotate(0.7)))
+                    .into(),
+            ),
+            (
+                "radial-repeat",
+                RadialGradient::evenly(center, 12.0, [RED, Color::rgba_u8(0, 0, 255, 128)])
+                    .with_spread(SpreadMode::Repeat)
+                    .into(),
+            ),
+            (
+                "radial-focal",
+                RadialGradient::evenly(center, 30.0, [RED, BLUE])
+                    .with_focal(Vec2(24.0, 28.0))
+                    .into(),
+            ),
+            (
+                "sweep",
+                SweepGradient::evenly(center, [RED, Color::rgb_u8(0, 255, 0), BLUE])
+                    .with_angles(0.5, 5.0)
+                    .into(),
+            ),
+        ];
+
+        for (label, paint) in cases {
+            let graphic = painted_rect(paint, 64.0, 64.0);
+            let cpu = rasterize(&graphic, 64, 64);
+            let cpu = cpu.as_cpu().expect("CPU rasterization returns CPU pixels");
+            let gpu = GpuRasterBackend::rasterize(&mut renderer, &graphic, Resolution::new(64, 64))
+                .expect("GPU rasterization should succeed");
+            let gpu = GpuRasterBackend::readback(&mut renderer, gpu)
+                .expect("GPU rasterization should read back");
+
+            // Vello resolves ramps through a 512-entry lookup texture, so
+            // allow a few levels of quantization against tiny-skia's exact
+            // per-pixel evaluation.
+            let mut worst = 0;
+            for y in 0..64u32 {
+                for x in 0..64u32 {
+                    let i = ((y * 64 + x) * 4) as usize;
+                    for c in 0..4 {
+                        worst = worst.max(cpu.pixels[i + c].abs_diff(gpu.pixels[i + c]));
+                    }
+                }
+            }
+            assert!(
+                worst <= 6,
+                "{label}: GPU gradient differs by {worst} levels"
+            );
+        }
+    }
+
+    /// 2x2 image: red, green / blue, white.
+    fn quad_image() -> CpuRasterImage {
+        CpuRasterImage::new(
+            2,
+            2,
+            PixelFormat::Rgba8,
+            vec![
+                255, 0, 0, 255, 0, 255, 0, 255, //
+                0, 0, 255, 255, 255, 255, 255, 255,
+            ],
+        )
+    }
+
+    #[test]
+    fn image_pattern_maps_pixels_through_its_transform() {
+        let pattern = ImagePattern::new(quad_image())
+            .with_quality(ImageQuality::Nearest)
+            .fit_to(Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(20.0, 20.0),
+            });
+        let image = rasterize(&painted_rect(pattern, 20.0, 20.0), 20, 20);
+
+        assert_eq!(rgba_at(&image, 4, 4), [255, 0, 0, 255]);
+        assert_eq!(rgba_at(&image, 15, 4), [0, 255, 0, 255]);
+        assert_eq!(rgba_at(&image, 4, 15), [0, 0, 255, 255]);
+        assert_eq!(rgba_at(&image, 15, 15), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn image_pattern_extend_modes_continue_past_the_image() {
+        // The image covers x in 0..10; pixel 12 lies in the next tile.
+        let sample = |extend: SpreadMode| {
+            let pattern = ImagePattern::new(quad_image())
+                .with_quality(ImageQuality::Nearest)
+                .with_extend(extend)
+                .with_transform(Transform::scale(Vec2(5.0, 5.0)));
+            rgba_at(
+                &rasterize(&painted_rect(pattern, 20.0, 10.0), 20, 10),
+                12,
+                2,
+            )
+        };
+
+        assert_eq!(
+            sample(SpreadMode::Pad),
+            [0, 255, 0, 255],
+            "pad smears the edge"
+        );
+        assert_eq!(sample(SpreadMode::Repeat), [255, 0, 0, 255], "repeat tiles");
+        assert_eq!(
+            sample(SpreadMode::Reflect),
+            [0, 255, 0, 255],
+            "reflect mirrors"
+        );
+    }
+
+    #[test]
+    fn image_pattern_keeps_straight_alpha() {
+        let translucent = CpuRasterImage::new(1, 1, PixelFormat::Rgba8, vec![200, 100, 50, 128]);
+        let image = rasterize(
+            &painted_rect(ImagePattern::new(translucent), 4.0, 4.0),
+            4,
+            4,
+        );
+        let [r, g, b, a] = rgba_at(&image, 2, 2);
+        assert_eq!(a, 128);
+        assert!(r.abs_diff(200) <= 1 && g.abs_diff(100) <= 1 && b.abs_diff(50) <= 1);
+    }
+
+    #[test]
+    fn undrawable_image_pattern_paints_nothing() {
+        let hdr = CpuRasterImage::new(1, 1, PixelFormat::Rgba16Float, vec![0; 8]);
+        let image = rasterize(&painted_rect(ImagePattern::new(hdr), 4.0, 4.0), 4, 4);
+        assert_eq!(alpha_at(&image, 2, 2, 4), 0);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn gpu_image_patterns_match_cpu_colors() {
+        let Ok(mut renderer) = GpuRenderer::new() else {
+            eprintln!("skipping GPU image-pattern test: no GPU adapter available");
+            return;
+        };
+        let base = ImagePattern::new(quad_image());
+        let rotated = Transform::around_point(Vec2(32.0, 32.0), Transform::rotate(0.4))
+            .concat(Transform::scale(Vec2(7.0, 5.0)));
+        let cases = [
+            (
+                "nearest-repeat",
+                base.clone()
+                    .with_quality(ImageQuality::Nearest)
+                    .with_extend(SpreadMode::Repeat)
+                    .with_transform(Transform::scale(Vec2(9.0, 9.0))),
+            ),
+            (
+                "bilinear-reflect",
+                base.clone()
+                    .with_extend(SpreadMode::Reflect)
+                    .with_transform(rotated),
+            ),
+            (
+                "bicubic-pad",
+                base.with_quality(ImageQuality::Bicubic)
+                    .with_transform(rotated),
+            ),
+        ];
+
+        for (label, pattern) in cases {
+            // A filled octagon inside a stroked one, both painted with the
+            // pattern and kept apart so the translucent group's opacity
+            // (folded into the bake) never stacks on overlapping ink.
+            let octagon = |radius: f32| -> Vec<PathCommand> {
+                (0..8)
+                    .map(|i| {
+                        let angle = i as f32 * std::f32::consts::FRAC_PI_4;
+                        let p = Vec2(32.0 + radius * angle.cos(), 32.0 + radius * angle.sin());
+                        if i == 0 {
+                            PathCommand::MoveTo(p)
+                        } else {
+                            PathCommand::LineTo(p)
+                        }
+                    })
+                    .chain([PathCommand::Close])
+                    .collect()
+            };
+            let graphic = VectorGraphic {
+                view_box: Rect {
+                    origin: Vec2::ZERO,
+                    size: Vec2(64.0, 64.0),
+                },
+                root: Node::Group(tellur_core::vector::Group {
+                    transform: Transform::IDENTITY,
+                    opacity: 0.75,
+                    children: vec![
+                        Node::Path(Path {
+                            commands: octagon(16.0),
+                            fill: Some(Paint::from(pattern.clone()).into()),
+                            fill_rule: FillRule::NonZero,
+                            stroke: None,
+                            transform: Transform::IDENTITY,
+                        }),
+                        Node::Path(Path {
+                            commands: octagon(26.0),
+                            fill: None,
+                            fill_rule: FillRule::NonZero,
+                            stroke: Some(Stroke::new(pattern, 6.0)),
+                            transform: Transform::IDENTITY,
+                        }),
+                    ],
+                }),
+            };
+            let cpu = rasterize(&graphic, 64, 64);
+            let cpu = cpu.as_cpu().expect("CPU rasterization returns CPU pixels");
+            let gpu = GpuRasterBackend::rasterize(&mut renderer, &graphic, Resolution::new(64, 64))
+                .expect("GPU rasterization should succeed");
+            let gpu = GpuRasterBackend::readback(&mut renderer, gpu)
+                .expect("GPU rasterization should read back");
+
+            // Both backends sample through one shader, so pixels well inside
+            // the fill or the stroke band must agree closely; antialiased
+            // coverage along the edges legitimately differs.
+            let mut worst = 0;
+            for y in 0..64u32 {
+                for x in 0..64u32 {
+                    let r = (x as f32 + 0.5 - 32.0).hypot(y as f32 + 0.5 - 32.0);
+                    if !(r < 12.0 || (24.0..26.0).contains(&r)) {
+                        continue;
+                    }
+                    let i = ((y * 64 + x) * 4) as usize;
+                    for c in 0..4 {
+                        worst = worst.max(cpu.pixels[i + c].abs_diff(gpu.pixels[i + c]));
+                    }
+                }
+            }
+            assert!(
+                worst <= 4,
+                "{label}: GPU image pattern differs by {worst} levels"
+            );
+        }
+    }
+
+    /// Two concentric squares wound the same way: nonzero fills the inner
+    /// one, even-odd leaves it as a hole.
+    fn donut() -> Vec<PathCommand> {
+        let square = |min: f32, max: f32| {
+            [
+                PathCommand::MoveTo(Vec2(min, min)),
+                PathCommand::LineTo(Vec2(max, min)),
+                PathCommand::LineTo(Vec2(max, max)),
+                PathCommand::LineTo(Vec2(min, max)),
+                PathCommand::Close,
+            ]
+        };
+        square(2.0, 30.0)
+            .into_iter()
+            .chain(square(10.0, 22.0))
+            .collect()
+    }
+
+    /// A five-point star drawn as one self-intersecting pentagram, centred
+    /// in a 32x32 box.
+    fn pentagram() -> Vec<PathCommand> {
+        (0..5)
+            .map(|i| {
+                let angle =
+                    -std::f32::consts::FRAC_PI_2 + i as f32 * 4.0 * std::f32::consts::PI / 5.0;
+                let p = Vec2(16.0 + 15.0 * angle.cos(), 16.0 + 15.0 * angle.sin());
+                if i == 0 {
+                    PathCommand::MoveTo(p)
+                } else {
+                    PathCommand::LineTo(p)
+                }
+            })
+            .chain([PathCommand::Close])
+            .collect()
+    }
+
+    fn filled(commands: Vec<PathCommand>, fill_rule: FillRule) -> VectorGraphic {
+        VectorGraphic {
+            view_box: Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(32.0, 32.0),
+            },
+            root: Node::Path(Path {
+                commands,
+                fill: Some(Paint::from(RED).into()),
+                fill_rule,
+                stroke: None,
+                transform: Transform::IDENTITY,
+            }),
+        }
+    }
+
+    #[test]
+    fn donut_hole_depends_on_fill_rule() {
+        let nonzero = rasterize(&filled(donut(), FillRule::NonZero), 32, 32);
+        let even_odd = rasterize(&filled(donut(), FillRule::EvenOdd), 32, 32);
+
+        assert_eq!(rgba_at(&nonzero, 16, 16), [255, 0, 0, 255]);
+        assert_eq!(rgba_at(&even_odd, 16, 16), [0, 0, 0, 0]);
+        assert_eq!(rgba_at(&nonzero, 5, 16), [255, 0, 0, 255]);
+        assert_eq!(rgba_at(&even_odd, 5, 16), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn even_odd_star_leaves_its_pentagon_empty() {
+        let nonzero = rasterize(&filled(pentagram(), FillRule::NonZero), 32, 32);
+        let even_odd = rasterize(&filled(pentagram(), FillRule::EvenOdd), 32, 32);
+
+        assert_eq!(rgba_at(&nonzero, 16, 16), [255, 0, 0, 255]);
+        assert_eq!(rgba_at(&even_odd, 16, 16), [0, 0, 0, 0]);
+        // The top point is covered once, so both rules fill it.
+        assert_eq!(rgba_at(&nonzero, 16, 5), [255, 0, 0, 255]);
+        assert_eq!(rgba_at(&even_odd, 16, 5), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn even_odd_clip_masks_out_the_hole() {
+        let graphic = VectorGraphic {
+            view_box: Rect {
+                origin: Vec2::ZERO,
+                size: Vec2(32.0, 32.0),
+            },
+            root: Node::ClipGroup(tellur_core::vector::ClipGroup {
+                commands: donut(),
+                fill_rule: FillRule::EvenOdd,
+                transform: Transform::IDENTITY,
+                child: Box::new(painted_rect(RED, 32.0, 32.0).root),
+            }),
+        };
+        let image = rasterize(&graphic, 32, 32);
+
+        assert_eq!(rgba_at(&image, 16, 16), [0, 0, 0, 0]);
+        assert_eq!(rgba_at(&image, 5, 16), [255, 0, 0, 255]);
+        assert_eq!(rgba_at(&image, 0, 0), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    #[ignore = "requires a GPU adapter"]
+    fn gpu_fill_rules_match_cpu() {
+        let Ok(mut renderer) = GpuRenderer::new() else {
+            eprintln!("skipping GPU fill-rule test: no GPU adapter available");
+            return;
+        };
+        for commands in [donut(), pentagram()] {
+            for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+                let graphic = filled(commands.clone(), fill_rule);
+                let cpu = rasterize(&graphic, 32, 32);
+                let gpu =
+                    GpuRasterBackend::rasterize(&mut renderer, &graphic, Resolution::new(32, 32))
+                        .expect("GPU rasterization should succeed");
+                let gpu = GpuRasterBackend::readback(&mut renderer, gpu)
+                    .expect("GPU rasterization should read back");
+                let gpu = RasterImage::Cpu(gpu);
+                for (x, y) in [(16, 16), (5, 16), (16, 5), (0, 0)] {
+                    assert_eq!(
+                        rgba_at(&cpu, x, y),
+                        rgba_at(&gpu, x, y),
+                        "{fill_rule:?} at ({x}, {y})"
+                    );
+                }
+            }
+        }
+
+        // Vello resolves layer clips with nonzero only, so even-odd clips
+        // are left to the CPU rasterizer.
+        let clipped = VectorGraphic {
+            root: Node::ClipGroup(tellur_core::vector::ClipGroup {
+                commands: donut(),
+                fill_rule: FillRule::EvenOdd,
+                transform: Transform::IDENTITY,
+                child: Box::new(painted_rect(RED, 32.0, 32.0).root),
+            }),
+            ..filled(donut(), FillRule::NonZero)
+        };
+        assert!(
+            GpuRasterBackend::rasterize(&mut renderer, &clipped, Resolution::new(32, 32)).is_none()
+        );
+    }
 }
@@ -251,7 +251,7 @@ impl fmt::Display for CacheMetrics {
         )?;
         writeln!(
             f,
-            "GPU    preference={:?}, attempted={}, available={}, ops={} (composite {}, shadow {}, outline {}, rasterize {}, fill {}, temporal_avg {}, readback {}, vram_failures {})",
+            "GPU    preference={:?}, attempted={}, available={}, ops={} (composite {}, shadow {}, outline {}, blur {}, glow {}, bloom {}, grade {}, matte {}, chroma_key {}, resample {}, project {}, depth_composite {}, warp {}, grain {}, dither {}, pattern {}, rasterize {}, fill {}, temporal_avg {}, readback {}, vram_failures {})",
             self.gpu_preference,
             self.gpu_init_attempted,
             self.gpu_available,
@@ -259,6 +259,19 @@ impl fmt::Display for CacheMetrics {
             self.gpu.composites,
             self.gpu.drop_shadows,
             self.gpu.outlines,
+            self.gpu.blurs,
+            self.gpu.glows,
+            self.gpu.blooms,
+            self.gpu.color_grades,
+            self.gpu.mattes,
+            self.gpu.chroma_keys,
+            self.gpu.resamples,
+            self.gpu.projections,
+            self.gpu.depth_composites,
+            self.gpu.warps,
+            self.gpu.grains,
+            self.gpu.dithers,
+            self.gpu.patterns,
             self.gpu.rasterizes,
             self.gpu.fills,
             self.gpu.temporal_averages,
@@ -1017,7 +1030,7 @@ mod tests {
         GpuSurface, Opacity, PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution,
     };
     use tellur_core::render_context::{CachePolicy, GpuPreference, PassThrough, RenderContext};
-    use tellur_core::shapes::Rectangle;
+    use tellur_core::shapes::{Corners, Rectangle};
     use tellur_core::vector::Paint;
 
     use super::{CacheKey, CachedRasterImage, CachingRenderContext, RenderCacheClass};
@@ -1040,6 +1053,7 @@ mod tests {
             .child(
                 Rectangle {
                     size: Vec2(20.0, 10.0),
+                    corners: Corners::SQUARE,
                     fill: Paint::Solid(Color::rgba_u8(200, 40, 60, 255)).into(),
                     stroke: None,
                 }
@@ -1053,6 +1067,7 @@ mod tests {
     fn positioned_is_transparent_to_cache() {
         let positioned = Rectangle {
             size: Vec2(1.0, 1.0),
+            corners: Corners::SQUARE,
             fill: Paint::Solid(Color::rgb_u8(0, 0, 0)).into(),
             stroke: None,
         }
@@ -34,7 +34,7 @@ pub struct DropShadow {
 /// support of `3 * r` on each side of the source. Both `paint_bounds`
 /// and the per-pixel `make_shadow` padding must agree on this extent so
 /// the shadow does not get hard-cut at the edge of the paint region.
-const BLUR_EXTENT_MULTIPLIER: f32 = 3.0;
+pub(crate) const BLUR_EXTENT_MULTIPLIER: f32 = 3.0;
 
 impl RasterComponent for DropShadow {
     fn layout(&self, constraints: Constraints) -> Vec2 {
@@ -148,7 +148,8 @@ impl RasterComponent for DropShadow {
     }
 }
 
-fn blank_image(target: Resolution) -> RasterImage {
+/// A fully transparent `target`-sized image, for effects with nothing to draw.
+pub(crate) fn blank_image(target: Resolution) -> RasterImage {
     let bytes = (target.width as usize) * (target.height as usize) * 4;
     RasterImage::cpu(
         target.width,
@@ -199,7 +200,7 @@ fn make_shadow(image: &CpuRasterImage, blur_radius: u32, color: Color) -> CpuRas
     CpuRasterImage::new(out_w as u32, out_h as u32, PixelFormat::Rgba8, out)
 }
 
-fn box_blur_3pass(buf: &mut [u8], w: usize, h: usize, radius: usize) {
+pub(crate) fn box_blur_3pass(buf: &mut [u8], w: usize, h: usize, radius: usize) {
     let mut temp = vec![0u8; buf.len()];
     for _ in 0..3 {
         box_blur_h(buf, &mut temp, w, h, radius);
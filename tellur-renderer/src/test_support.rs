@@ -0,0 +1,75 @@
+//! Fixtures shared by the effect tests.
+
+use tellur_core::geometry::{Constraints, Rect, Vec2};
+use tellur_core::raster::{
+    CpuRasterImage, PixelFormat, RasterComponent, RasterImage, RasterResidency, Resolution,
+};
+use tellur_core::render_context::RenderContext;
+
+/// A box of straight-alpha `Rgba8` pixels, one logical unit per pixel,
+/// filling its whole layout box. 8×8 of one value unless built otherwise.
+#[derive(Clone, PartialEq, Hash)]
+pub(crate) struct Square {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Square {
+    /// A `width`×`height` box of one `pixel` value.
+    pub(crate) fn solid(pixel: [u8; 4], width: u32, height: u32) -> Self {
+        Self::from_pixels(width, height, pixel.repeat((width * height) as usize))
+    }
+
+    /// A `width`×`height` box of `pixels`, row by row.
+    pub(crate) fn from_pixels(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        assert_eq!(pixels.len(), (width * height * 4) as usize);
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+impl RasterComponent for Square {
+    fn layout(&self, _constraints: Constraints) -> Vec2 {
+        Vec2(self.width as f32, self.height as f32)
+    }
+    fn paint_bounds(&self, size: Vec2) -> Rect {
+        Rect {
+            origin: Vec2::ZERO,
+            size,
+        }
+    }
+    fn render(
+        &self,
+        _size: Vec2,
+        target: Resolution,
+        _residency: RasterResidency,
+        _ctx: &mut dyn RenderContext,
+    ) -> RasterImage {
+        // Nearest neighbour, so a render at another scale keeps the pixels.
+        let mut pixels = Vec::with_capacity((target.width * target.height * 4) as usize);
+        for y in 0..target.height {
+            let sy = y * self.height / target.height;
+            for x in 0..target.width {
+                let sx = x * self.width / target.width;
+                let i = ((sy * self.width + sx) * 4) as usize;
+                pixels.extend_from_slice(&self.pixels[i..i + 4]);
+            }
+        }
+        RasterImage::cpu(target.width, target.height, PixelFormat::Rgba8, pixels)
+    }
+}
+
+/// A boxed 8×8 [`Square`] of `pixel`, ready to be an effect's child.
+pub(crate) fn square(pixel: [u8; 4]) -> Box<dyn RasterComponent> {
+    Box::new(Square::solid(pixel, 8, 8))
+}
+
+/// The pixel at `(x, y)`.
+pub(crate) fn pixel(image: &CpuRasterImage, x: u32, y: u32) -> [u8; 4] {
+    let i = ((y * image.width + x) * 4) as usize;
+    image.pixels[i..i + 4].try_into().unwrap()
+}
@@ -38,8 +38,9 @@ use std::thread;
 use std::time::{Duration, Instant};
 
 use indicatif::{MultiProgress, ProgressBar, ProgressState, ProgressStyle};
+use tellur_core::grain::{dither_pixels, Dither};
 use tellur_core::raster::{PixelFormat, RasterResidency, Resolution};
-use tellur_core::render_context::{GpuPreference, RenderContext};
+use tellur_core::render_context::{DitherInput, GpuPreference, RenderContext};
 use tellur_core::time::TimelineTime;
 use tellur_core::timeline_component::ResolvedTimeline;
 use thiserror::Error;
@@ -138,6 +139,7 @@ pub struct FfmpegEncoder {
     color_range: ColorRange,
     gpu_preference: GpuPreference,
     audio: AudioExport,
+    dither: Option<Dither>,
 }
 
 #[derive(Debug, Error)]
@@ -183,6 +185,7 @@ impl FfmpegEncoder {
             color_range: ColorRange::default(),
             gpu_preference: GpuPreference::default(),
             audio: AudioExport::default(),
+            dither: None,
         }
     }
 
@@ -214,6 +217,15 @@ impl FfmpegEncoder {
         self
     }
 
+    /// Dithers every frame right before it is handed to ffmpeg for the 8-bit
+    /// YUV conversion, so smooth gradients don't encode as visible bands (see
+    /// [`Dither`]). Off by default. The pattern is a function of the frame
+    /// index, so re-encoding reproduces the same output.
+    pub fn dither(mut self, dither: Dither) -> Self {
+        self.dither = Some(dither);
+        self
+    }
+
     pub fn arg(mut self, a: impl Into<String>) -> Self {
         self.args.push(a.into());
         self
@@ -307,10 +319,36 @@ impl FfmpegEncoder {
         let mut ctx = CachingRenderContext::new().with_gpu_preference(self.gpu_preference);
         let result = self.drive_ffmpeg(&audio_args, total_frames, out, |frame_idx| {
             let t = TimelineTime::new(frame_idx as f64 / self.fps as f64);
+            let Some(dither) = self.dither else {
+                let image = resolved
+                    .frame(t, self.resolution, RasterResidency::Cpu, &mut ctx)
+                    .unwrap_or_else(|| transparent_frame(self.resolution));
+                return Ok(ctx.readback(image));
+            };
+            // Dithering is the last step before readback, so on the GPU the
+            // frame stays resident until the pass has run.
+            let gpu_available = ctx.prefers_gpu() && ctx.gpu_backend().is_some();
+            let residency = if gpu_available {
+                RasterResidency::Gpu
+            } else {
+                RasterResidency::Cpu
+            };
             let image = resolved
-                .frame(t, self.resolution, RasterResidency::Cpu, &mut ctx)
+                .frame(t, self.resolution, residency, &mut ctx)
                 .unwrap_or_else(|| transparent_frame(self.resolution));
-            Ok(ctx.readback(image))
+            if gpu_available {
+                let input = DitherInput {
+                    image: &image,
+                    dither,
+                    frame: frame_idx,
+                };
+                if let Some(dithered) = ctx.gpu_backend().and_then(|gpu| gpu.dither(input)) {
+                    return Ok(ctx.readback(dithered));
+                }
+            }
+            let mut image = ctx.readback(image);
+            image.pixels = dither_pixels(&image, dither, frame_idx).into();
+            Ok(image)
         });
 
         // Best-effort cleanup of the temp WAV regardless of the encode outcome.
@@ -31,11 +31,15 @@ pub use tellur_plugin::{
 /// macros, everyday geometry/color types, and ordered timeline-effect verbs
 /// into scope.
 pub mod prelude {
+    pub use tellur_core::chroma_key::ChromaKeySettings;
     pub use tellur_core::color::Color;
+    pub use tellur_core::composite::BlendMode;
     pub use tellur_core::geometry::{Anchor, Vec2};
+    pub use tellur_core::matte::MatteMode;
     pub use tellur_core::timeline_component::{
-        AudioEffects, AudioEffectsBuilder, EnvelopePoint, GainEnvelope, Timed, TimedBuilder, Trim,
-        TrimBounds,
+        AudioEffects, AudioEffectsBuilder, BlendEffects, BlendEffectsBuilder, Blended,
+        EnvelopePoint, GainEnvelope, GradeEffects, GradeEffectsBuilder, Graded, KeyEffects,
+        KeyEffectsBuilder, Keyed, Timed, TimedBuilder, Trim, TrimBounds,
     };
     pub use tellur_core::{component, raster_component, vector_component, Keyable};
 